@@ -0,0 +1,77 @@
+//! Lighting throughput: per-pixel float math vs the precomputed LUT.
+//!
+//! Run with `cargo bench --features png --bench lighting`.
+
+use crafter_core::image_renderer::{ImageRenderer, ImageRendererConfig, LightingLut};
+use crafter_core::{Session, SessionConfig};
+use std::time::Instant;
+
+/// The shading math as it was before the LUT, kept here as the baseline
+fn naive_apply_rgb(rgb: &mut [u8], daylight: f32) {
+    let tint = (0u8, 16u8, 64u8);
+    let tint_strength = 0.5f32;
+    for px in rgb.chunks_exact_mut(3) {
+        let gray =
+            (px[0] as f32 * 0.299 + px[1] as f32 * 0.587 + px[2] as f32 * 0.114) as u8;
+        let night = |t: u8| {
+            ((gray as f32 * (1.0 - tint_strength)) + (t as f32 * tint_strength)) as u8
+        };
+        px[0] = ((px[0] as f32 * daylight) + (night(tint.0) as f32 * (1.0 - daylight))) as u8;
+        px[1] = ((px[1] as f32 * daylight) + (night(tint.1) as f32 * (1.0 - daylight))) as u8;
+        px[2] = ((px[2] as f32 * daylight) + (night(tint.2) as f32 * (1.0 - daylight))) as u8;
+    }
+}
+
+fn main() {
+    let session = Session::new(SessionConfig {
+        world_size: (48, 48),
+        seed: Some(42),
+        view_radius: 4,
+        ..Default::default()
+    });
+    let state = session.get_state();
+
+    // Large tiles: the regime where lighting dominates render time
+    let renderer = ImageRenderer::new(ImageRendererConfig {
+        tile_size: 12,
+        show_status_bars: false,
+        apply_lighting: false,
+    });
+    let frame = renderer.render_bytes(&state);
+    println!("frame: {} px", frame.len() / 3);
+
+    let daylight = 0.3f32;
+    let iters = 2000;
+
+    let start = Instant::now();
+    for _ in 0..iters {
+        let mut buf = frame.clone();
+        naive_apply_rgb(&mut buf, daylight);
+        std::hint::black_box(&buf);
+    }
+    let naive = start.elapsed();
+
+    let lut = LightingLut::new(daylight);
+    let start = Instant::now();
+    for _ in 0..iters {
+        let mut buf = frame.clone();
+        lut.apply_rgb(&mut buf);
+        std::hint::black_box(&buf);
+    }
+    let cached = start.elapsed();
+
+    println!(
+        "naive:  {:?} total, {:.2} us/frame",
+        naive,
+        naive.as_secs_f64() * 1e6 / iters as f64
+    );
+    println!(
+        "lut:    {:?} total, {:.2} us/frame",
+        cached,
+        cached.as_secs_f64() * 1e6 / iters as f64
+    );
+    println!(
+        "speedup: {:.2}x",
+        naive.as_secs_f64() / cached.as_secs_f64()
+    );
+}
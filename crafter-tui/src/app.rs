@@ -30,6 +30,10 @@ pub fn run() -> Result<()> {
             match event::read()? {
                 Event::Key(key) if key.kind == KeyEventKind::Press => {
                     match key.code {
+                        // The command console takes every key while open
+                        _ if crafter_state.console_active => {
+                            let _ = handle_key(&mut crafter_state, key, &cmd_tx);
+                        }
                         KeyCode::Char('q') | KeyCode::Char('Q') => break,
                         KeyCode::Char('d') | KeyCode::Char('D') => {
                             show_detail = !show_detail;
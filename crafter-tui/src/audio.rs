@@ -0,0 +1,22 @@
+//! Minimal audio cues for the `audio` feature
+//!
+//! Maps core [`SoundEvent`]s onto terminal-bell patterns so human
+//! playtesting gets audible feedback without pulling an audio stack into
+//! the dependency tree. Terminals with the bell muted simply stay
+//! silent; distinct events get distinct bell counts.
+
+use std::io::Write;
+
+use crafter_core::SoundEvent;
+
+/// Play the cue for one event by writing BEL to the terminal
+pub fn play(event: &SoundEvent) {
+    let bells: &[u8] = match event {
+        SoundEvent::Attack | SoundEvent::Mine | SoundEvent::Eat => b"\x07",
+        SoundEvent::NightFalls => b"\x07\x07",
+        SoundEvent::LevelUp => b"\x07\x07\x07",
+    };
+    let mut stderr = std::io::stderr();
+    let _ = stderr.write_all(bells);
+    let _ = stderr.flush();
+}
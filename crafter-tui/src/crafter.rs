@@ -5,8 +5,9 @@ use std::time::{Duration, Instant};
 
 use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
 use crafter_core::image_renderer::{ImageRenderer, ImageRendererConfig};
+use crafter_core::journal::SessionJournal;
 use crafter_core::recording::{Recording, RecordingOptions, RecordingSession, ReplaySession};
-use crafter_core::{Achievements, GameObject, Material, SaveData};
+use crafter_core::{Achievements, ConfigError, GameObject, Material, SaveData};
 use crafter_core::renderer::{Renderer, TextRenderer};
 use crafter_core::{Action, SessionConfig};
 use opentui_sys as ot;
@@ -27,6 +28,8 @@ pub struct CrafterKeyOutcome {
 
 pub enum CrafterCommand {
     Start { config: CrafterConfig },
+    // Resume the crash-journaled session left behind by the last run
+    ResumeJournal,
     Stop,
     StopAndDiscard,
     SetPaused(bool),
@@ -41,8 +44,15 @@ pub enum CrafterCommand {
     SetReplaySpeed(f32),
     BranchFromReplay,
     ListRecordings,
+    // Replay annotation/bookmarks
+    ToggleReplayBookmark,
+    ReplayJumpBookmark { forward: bool },
+    // Console command line (action script or debug command)
+    Console(String),
     // Rendering commands
     SetTileSize(u32),
+    // Capture the current state to a PNG in the screenshots dir
+    Screenshot,
 }
 
 pub enum CrafterUpdate {
@@ -70,6 +80,27 @@ pub enum CrafterUpdate {
         // Inventory
         inventory: InventoryData,
     },
+    /// Incremental frame: only the pixel rows that changed since the
+    /// previous frame, applied in place over the last full `Frame`.
+    /// Sent instead of `Frame` when the state diff is small enough.
+    FramePatch {
+        /// `(pixel row index, RGBA row bytes)` pairs
+        rows: Vec<(u32, Vec<u8>)>,
+        rgba_width: u32,
+        rgba_height: u32,
+        score: u32,
+        health: i32,
+        food: i32,
+        thirst: i32,
+        energy: i32,
+        tick: u64,
+        achievements: Vec<String>,
+        visible_mobs: Vec<MobPreview>,
+        density_lines: Vec<String>,
+        has_adjacent_table: bool,
+        has_adjacent_furnace: bool,
+        inventory: InventoryData,
+    },
     Event { message: String },
     // Recording/replay updates
     RecordingSaved { path: PathBuf },
@@ -181,10 +212,23 @@ pub struct RecordingInfo {
     pub unique_achievements: u32,
 }
 
+/// Sidecar metadata written next to each recording at save time, so the
+/// browser can list stats without loading (or worse, replaying) every
+/// JSON file in the directory
+#[derive(Serialize, Deserialize)]
+struct RecordingMeta {
+    total_steps: u64,
+    total_reward: f32,
+    total_achievements: u32,
+    unique_achievements: u32,
+}
+
 pub struct CrafterState {
     pub running: bool,
     pub paused: bool,
     pub input_capture: bool,
+    /// A crash journal from the last run exists, so [U] offers a resume
+    pub resume_available: bool,
     pub status: String,
     pub frame_lines: Vec<String>,
     // Graphics mode rendering
@@ -213,6 +257,7 @@ pub struct CrafterState {
     pub replay_active: bool,
     pub replay_step: usize,
     pub replay_total: usize,
+    pub replay_speed: f32,
     pub show_recordings: bool,
     pub recordings_search: String,
     pub recordings_search_active: bool,
@@ -234,6 +279,9 @@ pub struct CrafterState {
     pub rule_editor_path: Option<PathBuf>,
     pub rule_editor_doc: Option<RuleConfigDoc>,
     pub rule_editor_config: Option<SessionConfig>,
+    // Command console (`:` while in game)
+    pub console_active: bool,
+    pub console_input: String,
 }
 
 /// Craft menu items
@@ -264,6 +312,8 @@ pub const PLACE_ITEMS: &[(&str, Action, &str)] = &[
 #[derive(Clone, Serialize, Deserialize)]
 pub struct CrafterConfig {
     pub tick_rate: u32,      // Hz (1-30) - only used in real-time mode
+    #[serde(default)]
+    pub frame_rate: u32,     // Hz frames are rendered/sent at; 0 = every tick
     pub world_width: u32,    // 16-64
     pub world_height: u32,   // 16-64
     #[serde(default = "default_view_size")]
@@ -280,6 +330,7 @@ impl Default for CrafterConfig {
     fn default() -> Self {
         Self {
             tick_rate: 10,
+            frame_rate: 0,
             world_width: 64,
             world_height: 64,
             view_size: default_view_size(),
@@ -305,6 +356,16 @@ fn view_radius_from_size(view_size: u32) -> u32 {
     size.saturating_sub(1) / 2
 }
 
+fn step_replay_speed(current: f32, delta: i32) -> f32 {
+    const SPEEDS: &[f32] = &[0.25, 0.5, 1.0, 2.0, 4.0, 10.0];
+    let mut idx = SPEEDS
+        .iter()
+        .position(|&s| (s - current).abs() < 0.01)
+        .unwrap_or(2);
+    idx = (idx as i32 + delta).clamp(0, SPEEDS.len() as i32 - 1) as usize;
+    SPEEDS[idx]
+}
+
 fn step_view_size(current: u32, delta: i32) -> u32 {
     const VIEW_SIZES: &[u32] = &[5, 7, 9, 11, 13];
     let mut idx = VIEW_SIZES
@@ -313,8 +374,8 @@ fn step_view_size(current: u32, delta: i32) -> u32 {
         .unwrap_or_else(|| VIEW_SIZES.iter().position(|&v| v > current).unwrap_or(VIEW_SIZES.len() - 1));
     if delta.is_positive() {
         idx = (idx + 1).min(VIEW_SIZES.len().saturating_sub(1));
-    } else if idx > 0 {
-        idx -= 1;
+    } else {
+        idx = idx.saturating_sub(1);
     }
     VIEW_SIZES[idx]
 }
@@ -332,13 +393,14 @@ pub const CONFIG_ITEMS: &[&str] = &[
     "Rule Config",    // 1: SessionConfig profile
     "Time Mode",      // 2: Logical (AI) vs Real-time
     "Tick Rate",      // 3: Hz (only for real-time)
-    "World Width",    // 4
-    "World Height",   // 5
-    "View Size",      // 6 (odd sizes like 7x7)
-    "Seed Mode",      // 7
-    "Seed Value",     // 8
-    "Graphics Mode",  // 9
-    "--- Start Game ---",  // 10
+    "Frame Rate",     // 4: render/send Hz (0 = every tick)
+    "World Width",    // 5
+    "World Height",   // 6
+    "View Size",      // 7 (odd sizes like 7x7)
+    "Seed Mode",      // 8
+    "Seed Value",     // 9
+    "Graphics Mode",  // 10
+    "--- Start Game ---",  // 11
 ];
 
 impl CrafterState {
@@ -353,6 +415,7 @@ impl CrafterState {
             running: false,
             paused: false,
             input_capture: false,
+            resume_available: journal_path().exists(),
             status: "[S] Settings  [C] Start  [L] Recordings".to_string(),
             frame_lines: Vec::new(),
             frame_rgba: None,
@@ -379,6 +442,7 @@ impl CrafterState {
             replay_active: false,
             replay_step: 0,
             replay_total: 0,
+            replay_speed: 1.0,
             show_recordings: false,
             recordings_search: String::new(),
             recordings_search_active: false,
@@ -398,17 +462,20 @@ impl CrafterState {
             rule_editor_path: None,
             rule_editor_doc: None,
             rule_editor_config: None,
+            console_active: false,
+            console_input: String::new(),
         }
     }
 }
 
+impl Default for CrafterState {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 fn config_dir_path(app_name: &str) -> PathBuf {
-    let mut base = std::env::var_os("HOME")
-        .map(PathBuf::from)
-        .unwrap_or_else(|| PathBuf::from("."));
-    base.push(".config");
-    base.push(app_name);
-    base
+    crafter_core::paths::config_dir(app_name)
 }
 
 fn config_file_path() -> PathBuf {
@@ -440,16 +507,64 @@ fn rule_configs_dir() -> PathBuf {
 }
 
 pub fn default_recordings_dir() -> PathBuf {
-    let mut base = config_dir_path("crafter");
-    base.push("recordings");
-    base
+    crafter_core::paths::data_dir("crafter").join("recordings")
 }
 
 pub fn mission_control_recordings_dir() -> PathBuf {
-    let mut base = config_dir_path("mission-control");
-    base.push("crafter");
-    base.push("recordings");
-    base
+    crafter_core::paths::data_dir("mission-control")
+        .join("crafter")
+        .join("recordings")
+}
+
+/// Where screenshots taken with the in-game key land
+pub fn screenshots_dir() -> PathBuf {
+    crafter_core::paths::data_dir("crafter").join("screenshots")
+}
+
+/// Where the crash-recovery journal for the active session lives; it is
+/// removed on every clean stop, so its presence at startup means the
+/// last session died mid-episode
+pub fn journal_path() -> PathBuf {
+    crafter_core::paths::data_dir("crafter")
+        .join("journals")
+        .join("active.jsonl")
+}
+
+/// The active session's crash journal plus how many recorded steps have
+/// already been appended to it
+struct ActiveJournal {
+    journal: SessionJournal,
+    synced: usize,
+}
+
+fn start_journal(config: &SessionConfig) -> Option<ActiveJournal> {
+    let path = journal_path();
+    if let Some(dir) = path.parent() {
+        std::fs::create_dir_all(dir).ok()?;
+    }
+    SessionJournal::create(&path, config)
+        .ok()
+        .map(|journal| ActiveJournal { journal, synced: 0 })
+}
+
+/// Append any newly recorded actions to the crash journal; a failed
+/// write drops the journal rather than stalling the session
+fn sync_journal(active: &mut Option<ActiveJournal>, recording: &Recording) {
+    if let Some(a) = active.as_mut() {
+        for step in &recording.steps[a.synced..] {
+            if a.journal.record(step.action).is_err() {
+                *active = None;
+                return;
+            }
+        }
+        a.synced = recording.steps.len();
+    }
+}
+
+/// Drop the journal and its file after a clean stop
+fn clear_journal(active: &mut Option<ActiveJournal>) {
+    *active = None;
+    let _ = std::fs::remove_file(journal_path());
 }
 
 #[derive(Clone)]
@@ -460,7 +575,7 @@ pub struct RuleConfigEntry {
     extension: String,
 }
 
-enum RuleConfigDoc {
+pub enum RuleConfigDoc {
     Toml(toml::value::Table),
     Yaml(serde_yaml::Mapping),
 }
@@ -955,22 +1070,15 @@ fn read_rule_config_doc(path: &Path) -> Option<RuleConfigDoc> {
     }
 }
 
-fn write_rule_config_doc(path: &Path, doc: &RuleConfigDoc) -> bool {
+fn write_rule_config_doc(path: &Path, doc: &RuleConfigDoc) -> Result<(), ConfigError> {
     match doc {
         RuleConfigDoc::Toml(table) => {
-            let value = toml::Value::Table(table.clone());
-            if let Ok(contents) = toml::to_string_pretty(&value) {
-                return std::fs::write(path, contents).is_ok();
-            }
+            crafter_core::save_toml_config(path, &toml::Value::Table(table.clone()))
         }
         RuleConfigDoc::Yaml(map) => {
-            let value = serde_yaml::Value::Mapping(map.clone());
-            if let Ok(contents) = serde_yaml::to_string(&value) {
-                return std::fs::write(path, contents).is_ok();
-            }
+            crafter_core::save_yaml_config(path, &serde_yaml::Value::Mapping(map.clone()))
         }
     }
-    false
 }
 
 #[derive(Clone, Copy)]
@@ -1291,18 +1399,18 @@ fn open_rule_editor(state: &mut CrafterState) -> bool {
     true
 }
 
-fn save_rule_editor(state: &mut CrafterState) -> bool {
+fn save_rule_editor(state: &mut CrafterState) -> Result<(), String> {
     let path = match state.rule_editor_path.clone() {
         Some(path) => path,
-        None => return false,
+        None => return Err("no rule config selected".to_string()),
     };
     let config = match state.rule_editor_config.as_ref() {
         Some(config) => config,
-        None => return false,
+        None => return Err("no rule config loaded".to_string()),
     };
     let mut doc = match state.rule_editor_doc.take() {
         Some(doc) => doc,
-        None => return false,
+        None => return Err("no rule config loaded".to_string()),
     };
 
     for field in RULE_EDITOR_FIELDS {
@@ -1310,9 +1418,9 @@ fn save_rule_editor(state: &mut CrafterState) -> bool {
         set_doc_value(&mut doc, field.path, value);
     }
 
-    let ok = write_rule_config_doc(&path, &doc);
+    let result = write_rule_config_doc(&path, &doc).map_err(|e| e.to_string());
     state.rule_editor_doc = Some(doc);
-    ok
+    result
 }
 
 fn rule_config_index(configs: &[RuleConfigEntry], name: &str) -> usize {
@@ -1358,9 +1466,7 @@ fn rule_config_display_name(entry: &RuleConfigEntry) -> String {
 fn refresh_rule_configs(state: &mut CrafterState) {
     state.rule_configs = list_rule_configs();
     state.rule_config_index = rule_config_index(&state.rule_configs, &state.config.rule_config);
-    if state.rule_configs.is_empty() {
-        state.rule_config_index = 0;
-    } else if state.rule_config_index >= state.rule_configs.len() {
+    if state.rule_configs.is_empty() || state.rule_config_index >= state.rule_configs.len() {
         state.rule_config_index = 0;
     }
     state.config.rule_config = selected_rule_config_name(state);
@@ -1409,7 +1515,9 @@ fn create_rule_config_from_selected_with_ext(
         } else {
             format!("base = \"{}\"\n", default_rule_config_name())
         };
-        let _ = std::fs::write(&target, contents);
+        if crafter_core::write_config_atomic(&target, &contents).is_err() {
+            return None;
+        }
     }
 
     Some(name)
@@ -1426,6 +1534,7 @@ fn delete_selected_rule_config(state: &mut CrafterState) -> bool {
     false
 }
 
+#[allow(dead_code)]
 fn edit_selected_rule_config(state: &mut CrafterState) -> bool {
     let editor = std::env::var("EDITOR").unwrap_or_else(|_| "vi".to_string());
     let entry = match state.rule_configs.get(state.rule_config_index) {
@@ -1464,14 +1573,8 @@ fn load_profile_config(profile_name: &str) -> CrafterConfig {
     CrafterConfig::default()
 }
 
-fn save_profile_config(profile_name: &str, config: &CrafterConfig) {
-    let path = profile_path(profile_name);
-    if let Some(dir) = path.parent() {
-        let _ = std::fs::create_dir_all(dir);
-    }
-    if let Ok(contents) = toml::to_string_pretty(config) {
-        let _ = std::fs::write(path, contents);
-    }
+fn save_profile_config(profile_name: &str, config: &CrafterConfig) -> Result<(), ConfigError> {
+    crafter_core::save_toml_config(profile_path(profile_name), config)
 }
 
 fn load_initial_profile() -> (Vec<String>, usize, CrafterConfig) {
@@ -1601,7 +1704,9 @@ fn filtered_recording_indices(recordings: &[RecordingInfo], query: &str) -> Vec<
 }
 
 fn achievement_stats(achievements: &Achievements) -> (u32, u32) {
-    let total = Achievements::all_names()
+    // Count over the full name list so Craftax and extended achievements
+    // show up in the totals; classic runs simply have zeros there
+    let total = Achievements::all_names_with_craftax()
         .iter()
         .filter_map(|name| achievements.get(name))
         .sum();
@@ -1609,6 +1714,49 @@ fn achievement_stats(achievements: &Achievements) -> (u32, u32) {
     (total, unique)
 }
 
+fn recording_meta_path(path: &Path) -> PathBuf {
+    path.with_extension("meta.toml")
+}
+
+/// Read the sidecar for a recording, if present and not stale (the
+/// recording itself may have been rewritten since, e.g. by annotations)
+fn load_recording_meta(path: &Path) -> Option<RecordingMeta> {
+    let meta_path = recording_meta_path(path);
+    let rec_mtime = std::fs::metadata(path).and_then(|m| m.modified()).ok()?;
+    let meta_mtime = std::fs::metadata(&meta_path).and_then(|m| m.modified()).ok()?;
+    if meta_mtime < rec_mtime {
+        return None;
+    }
+    toml::from_str(&std::fs::read_to_string(&meta_path).ok()?).ok()
+}
+
+fn write_recording_meta(path: &Path, meta: &RecordingMeta) {
+    if let Ok(data) = toml::to_string(meta) {
+        let _ = std::fs::write(recording_meta_path(path), data);
+    }
+}
+
+fn recording_meta(recording: &Recording) -> RecordingMeta {
+    let (total_achievements, unique_achievements) = if let Some(last_state) = recording
+        .steps
+        .last()
+        .and_then(|step| step.state_after.as_ref())
+    {
+        achievement_stats(&last_state.achievements)
+    } else {
+        let mut replay = ReplaySession::from_recording(recording);
+        while replay.step().is_some() {}
+        let state = replay.get_state();
+        achievement_stats(&state.achievements)
+    };
+    RecordingMeta {
+        total_steps: recording.total_steps,
+        total_reward: recording.total_reward,
+        total_achievements,
+        unique_achievements,
+    }
+}
+
 fn list_recordings(dir: &Path) -> Vec<RecordingInfo> {
     if !dir.exists() {
         return Vec::new();
@@ -1619,45 +1767,43 @@ fn list_recordings(dir: &Path) -> Vec<RecordingInfo> {
         for entry in entries.flatten() {
             let path = entry.path();
             if path.extension().map(|e| e == "json").unwrap_or(false) {
-                if let Ok(recording) = Recording::load_json(&path) {
-                    let (total_achievements, unique_achievements) =
-                        if let Some(last_state) = recording
-                            .steps
-                            .last()
-                            .and_then(|step| step.state_after.as_ref())
-                        {
-                            achievement_stats(&last_state.achievements)
-                        } else {
-                            let mut replay = ReplaySession::from_recording(&recording);
-                            while replay.step().is_some() {}
-                            let state = replay.get_state();
-                            achievement_stats(&state.achievements)
-                        };
-                    let timestamp = entry
-                        .metadata()
-                        .and_then(|meta| meta.modified())
-                        .and_then(|t| {
-                            t.duration_since(std::time::UNIX_EPOCH).map_err(|err| {
-                                std::io::Error::new(std::io::ErrorKind::Other, err)
-                            })
-                        })
-                        .map(|d| d.as_secs())
-                        .unwrap_or(0);
-
-                    recordings.push(RecordingInfo {
-                        path: path.clone(),
-                        name: path
-                            .file_stem()
-                            .and_then(|s| s.to_str())
-                            .unwrap_or("unknown")
-                            .to_string(),
-                        total_steps: recording.total_steps,
-                        total_reward: recording.total_reward,
-                        timestamp,
-                        total_achievements,
-                        unique_achievements,
-                    });
-                }
+                // Cheap path: stats from the sidecar. Slow path (old
+                // recordings saved before sidecars, or a stale sidecar):
+                // compute once and backfill the sidecar for next time.
+                let meta = match load_recording_meta(&path) {
+                    Some(meta) => meta,
+                    None => match Recording::load_json(&path) {
+                        Ok(recording) => {
+                            let meta = recording_meta(&recording);
+                            write_recording_meta(&path, &meta);
+                            meta
+                        }
+                        Err(_) => continue,
+                    },
+                };
+                let timestamp = entry
+                    .metadata()
+                    .and_then(|meta| meta.modified())
+                    .and_then(|t| {
+                        t.duration_since(std::time::UNIX_EPOCH)
+                            .map_err(std::io::Error::other)
+                    })
+                    .map(|d| d.as_secs())
+                    .unwrap_or(0);
+
+                recordings.push(RecordingInfo {
+                    path: path.clone(),
+                    name: path
+                        .file_stem()
+                        .and_then(|s| s.to_str())
+                        .unwrap_or("unknown")
+                        .to_string(),
+                    total_steps: meta.total_steps,
+                    total_reward: meta.total_reward,
+                    timestamp,
+                    total_achievements: meta.total_achievements,
+                    unique_achievements: meta.unique_achievements,
+                });
             }
         }
     }
@@ -1672,6 +1818,7 @@ fn list_recordings(dir: &Path) -> Vec<RecordingInfo> {
 
 fn make_frame_update(
     state: &crafter_core::GameState,
+    prev_state: Option<&crafter_core::GameState>,
     graphics_mode: bool,
     tile_size: u32,
     reward: f32,
@@ -1684,6 +1831,27 @@ fn make_frame_update(
     if graphics_mode {
         let (rgba_data, pixel_w, pixel_h, _cells_w, _cells_h) =
             render_state_graphics(state, tile_size);
+        if let Some(prev) = prev_state {
+            if let Some(rows) = patch_rows(state, prev, &rgba_data, pixel_w, pixel_h, tile_size) {
+                return CrafterUpdate::FramePatch {
+                    rows,
+                    rgba_width: pixel_w,
+                    rgba_height: pixel_h,
+                    score: (reward * 100.0) as u32,
+                    health: state.inventory.health as i32,
+                    food: state.inventory.food as i32,
+                    thirst: state.inventory.drink as i32,
+                    energy: state.inventory.energy as i32,
+                    tick: state.step,
+                    achievements: newly_unlocked,
+                    visible_mobs,
+                    density_lines,
+                    has_adjacent_table,
+                    has_adjacent_furnace,
+                    inventory: InventoryData::from_crafter(&state.inventory),
+                };
+            }
+        }
         CrafterUpdate::Frame {
             lines: vec![],
             rgba_data: Some(rgba_data),
@@ -1725,6 +1893,96 @@ fn make_frame_update(
     }
 }
 
+/// Decide whether the transition since `prev` is small enough to ship as
+/// row patches, and if so extract the changed RGBA rows from the already
+/// rendered frame. `None` means send a full frame: the viewport shifted
+/// (player moved or turned), the lighting changed noticeably, the frame
+/// geometry changed, or enough tiles changed that patching saves nothing.
+fn patch_rows(
+    state: &crafter_core::GameState,
+    prev: &crafter_core::GameState,
+    rgba_data: &[u8],
+    pixel_w: u32,
+    pixel_h: u32,
+    tile_size: u32,
+) -> Option<Vec<(u32, Vec<u8>)>> {
+    let view = state.view.as_ref()?;
+    let prev_view = prev.view.as_ref()?;
+    if view.radius != prev_view.radius || rgba_data.len() != (pixel_w * pixel_h * 4) as usize {
+        return None;
+    }
+
+    let delta = state.diff(prev);
+    if delta.player_moved.is_some() || delta.player_facing.is_some() {
+        return None;
+    }
+    // Lighting tracks daylight across the whole frame; a visible shift
+    // needs a full redraw
+    if (state.daylight - prev.daylight).abs() > 0.02 {
+        return None;
+    }
+
+    let view_size = view.size() as u32;
+    let mut changed_view_rows = std::collections::BTreeSet::new();
+    for (_, y, _) in &delta.tiles {
+        changed_view_rows.insert(*y as u32);
+    }
+    for (_, y, _) in &delta.entities {
+        changed_view_rows.insert(*y as u32);
+    }
+    // Beyond half the rows, a full frame is no bigger than the patch
+    if changed_view_rows.len() as u32 * 2 > view_size {
+        return None;
+    }
+
+    let row_bytes = (pixel_w * 4) as usize;
+    let row = |pixel_y: u32| {
+        let start = pixel_y as usize * row_bytes;
+        (pixel_y, rgba_data[start..start + row_bytes].to_vec())
+    };
+
+    let mut rows = Vec::new();
+    for view_y in changed_view_rows {
+        for pixel_y in view_y * tile_size..(view_y + 1) * tile_size {
+            rows.push(row(pixel_y));
+        }
+    }
+    // The status bars at the bottom track vitals and inventory
+    if !delta.vitals.is_empty() || !delta.inventory.is_empty() {
+        for pixel_y in view_size * tile_size..pixel_h {
+            rows.push(row(pixel_y));
+        }
+    }
+    Some(rows)
+}
+
+/// HUD-only update for ticks where the frame itself is skipped by the
+/// frame-rate cap: vitals, score and tick advance while the pixels keep
+/// showing the last rendered frame
+fn make_hud_update(
+    state: &crafter_core::GameState,
+    reward: f32,
+    newly_unlocked: Vec<String>,
+) -> CrafterUpdate {
+    CrafterUpdate::FramePatch {
+        rows: Vec::new(),
+        rgba_width: 0,
+        rgba_height: 0,
+        score: (reward * 100.0) as u32,
+        health: state.inventory.health as i32,
+        food: state.inventory.food as i32,
+        thirst: state.inventory.drink as i32,
+        energy: state.inventory.energy as i32,
+        tick: state.step,
+        achievements: newly_unlocked,
+        visible_mobs: visible_mob_previews(state),
+        density_lines: map_density_lines(state),
+        has_adjacent_table: has_adjacent_table(state),
+        has_adjacent_furnace: has_adjacent_furnace(state),
+        inventory: InventoryData::from_crafter(&state.inventory),
+    }
+}
+
 pub fn spawn_crafter_loop(
     cmd_rx: Receiver<CrafterCommand>,
     tx: Sender<CrafterUpdate>,
@@ -1742,12 +2000,28 @@ pub fn spawn_crafter_loop(
         let mut current_seed: Option<u64> = None;
         let mut graphics_mode = true;
         let mut tile_size = 10u32;
+        let mut current_view_size = default_view_size();
         let mut logical_time = false;
 
+        // State behind the last frame sent, for diff-based row patches;
+        // cleared whenever frame continuity breaks (start, reset, seek)
+        let mut last_frame_state: Option<crafter_core::GameState> = None;
+        // Render/send rate, decoupled from the tick rate (0 = every
+        // tick); skipped ticks still ship a HUD-only update
+        let mut frame_rate = 0u32;
+        let mut last_frame_sent = Instant::now();
+
         let mut replay_session: Option<ReplaySession> = None;
+        // Source of the active replay, kept so annotations and bookmarks
+        // can be written back to the recording file
+        let mut replay_source: Option<(PathBuf, Recording)> = None;
         let mut replay_speed = 1.0f32;
         let mut replay_paused = false;
 
+        // Crash journal for the live session; cleared on every clean stop
+        // so a leftover file at startup means "offer to resume"
+        let mut journal: Option<ActiveJournal> = None;
+
         loop {
             let timeout = if running && !paused && !replay_paused && !logical_time {
                 let hz = (target_hz as f32 * replay_speed).max(1.0);
@@ -1760,15 +2034,18 @@ pub fn spawn_crafter_loop(
                 Ok(cmd) => match cmd {
                     CrafterCommand::Start { config: game_config } => {
                         replay_session = None;
+                        replay_source = None;
 
                         running = true;
                         paused = false;
                         target_hz = game_config.tick_rate.clamp(1, 30);
+                        frame_rate = game_config.frame_rate.min(30);
                         frame_width = game_config.world_width.clamp(16, 64);
                         frame_height = game_config.world_height.clamp(16, 64);
                         graphics_mode = game_config.graphics_mode;
                         logical_time = game_config.logical_time;
                         tile_size = tile_size_for_view_size(game_config.view_size);
+                        current_view_size = game_config.view_size;
 
                         let seed = if game_config.random_seed {
                             None
@@ -1800,18 +2077,21 @@ pub fn spawn_crafter_loop(
                             },
                             ..session_config
                         };
+                        journal = start_journal(&session_config);
                         let rec_session =
-                            RecordingSession::new(session_config, RecordingOptions::minimal());
+                            RecordingSession::new(session_config, RecordingOptions::human());
 
                         let initial_state = rec_session.get_state();
                         let initial_frame = make_frame_update(
                             &initial_state,
+                            None,
                             graphics_mode,
                             tile_size,
                             0.0,
                             vec![],
                         );
                         let _ = tx.send(initial_frame);
+                        last_frame_state = Some(initial_state);
 
                         recording_session = Some(rec_session);
                         last_tick = Instant::now();
@@ -1831,14 +2111,96 @@ pub fn spawn_crafter_loop(
                             message: status_msg.to_string(),
                         });
                     }
+                    CrafterCommand::ResumeJournal => {
+                        match SessionJournal::recover(journal_path()) {
+                            Ok(recovery) => {
+                                let config = recovery.config.clone();
+                                // Replaying the journaled actions through a
+                                // fresh RecordingSession restores both the
+                                // game state and the recording, so the
+                                // resumed episode still saves as one file
+                                let mut rec_sess = RecordingSession::new(
+                                    config.clone(),
+                                    RecordingOptions::human(),
+                                );
+                                for action in &recovery.actions {
+                                    if rec_sess.step(*action).done {
+                                        break;
+                                    }
+                                }
+                                let resumed_steps = rec_sess.recording().steps.len();
+
+                                replay_session = None;
+                                replay_source = None;
+                                running = true;
+                                paused = false;
+                                frame_width = config.world_size.0;
+                                frame_height = config.world_size.1;
+                                current_view_size = config.view_radius * 2 + 1;
+                                tile_size = tile_size_for_view_size(current_view_size);
+                                current_seed = config.seed;
+                                logical_time = matches!(
+                                    config.time_mode,
+                                    crafter_core::TimeMode::Logical
+                                );
+                                if let crafter_core::TimeMode::RealTime {
+                                    ticks_per_second, ..
+                                } = &config.time_mode
+                                {
+                                    target_hz = (*ticks_per_second as u32).clamp(1, 30);
+                                }
+
+                                journal = start_journal(&config);
+                                sync_journal(&mut journal, rec_sess.recording());
+
+                                let state = rec_sess.get_state();
+                                let frame = make_frame_update(
+                                    &state,
+                                    None,
+                                    graphics_mode,
+                                    tile_size,
+                                    0.0,
+                                    vec![],
+                                );
+                                let _ = tx.send(frame);
+                                last_frame_state = Some(state);
+
+                                recording_session = Some(rec_sess);
+                                pending_action = Action::Noop;
+                                last_tick = Instant::now();
+                                let _ = tx.send(CrafterUpdate::Running { running: true });
+                                let _ = tx.send(CrafterUpdate::Paused { paused: false });
+                                let _ = tx.send(CrafterUpdate::ReplayMode {
+                                    active: false,
+                                    current_step: 0,
+                                    total_steps: 0,
+                                });
+                                let _ = tx.send(CrafterUpdate::Status {
+                                    message: format!(
+                                        "Resumed last session at step {}",
+                                        resumed_steps
+                                    ),
+                                });
+                            }
+                            Err(e) => {
+                                clear_journal(&mut journal);
+                                let _ = tx.send(CrafterUpdate::Event {
+                                    message: format!("Resume failed: {}", e),
+                                });
+                                let _ = tx.send(CrafterUpdate::Running { running: false });
+                            }
+                        }
+                    }
                     CrafterCommand::Stop => {
                         if let Some(rec_sess) = recording_session.take() {
                             let recording = rec_sess.finish();
                             if recording.total_steps > 0 {
-                                save_recording(&recording, &tx, &recordings_dir);
+                                save_recording(recording, &tx, &recordings_dir);
                             }
                         }
+                        clear_journal(&mut journal);
                         replay_session = None;
+                        replay_source = None;
                         running = false;
                         paused = false;
                         let _ = tx.send(CrafterUpdate::Running { running: false });
@@ -1852,8 +2214,10 @@ pub fn spawn_crafter_loop(
                         });
                     }
                     CrafterCommand::StopAndDiscard => {
+                        clear_journal(&mut journal);
                         recording_session = None;
                         replay_session = None;
+                        replay_source = None;
                         running = false;
                         paused = false;
                         let _ = tx.send(CrafterUpdate::Running { running: false });
@@ -1887,16 +2251,19 @@ pub fn spawn_crafter_loop(
                             if logical_time && running && !paused {
                                 if let Some(ref mut rec_sess) = recording_session {
                                     let result = rec_sess.step(action);
+                                    sync_journal(&mut journal, rec_sess.recording());
 
                                     let game_state = &result.state;
                                     let frame = make_frame_update(
                                         game_state,
+                                        last_frame_state.as_ref(),
                                         graphics_mode,
                                         tile_size,
                                         result.reward,
                                         result.newly_unlocked.clone(),
                                     );
                                     let _ = tx.send(frame);
+                                    last_frame_state = Some(result.state.clone());
 
                                     for ach in &result.newly_unlocked {
                                         let _ = tx.send(CrafterUpdate::Event {
@@ -1904,6 +2271,17 @@ pub fn spawn_crafter_loop(
                                         });
                                     }
 
+                                    for milestone in &result.milestones {
+                                        let _ = tx.send(CrafterUpdate::Event {
+                                            message: milestone.to_string(),
+                                        });
+                                    }
+
+                                    #[cfg(feature = "audio")]
+                                    for sound in &result.sounds {
+                                        crate::audio::play(sound);
+                                    }
+
                                     for event in &result.debug_events {
                                         let _ = tx.send(CrafterUpdate::Event {
                                             message: event.clone(),
@@ -1918,9 +2296,17 @@ pub fn spawn_crafter_loop(
                                         let _ = tx.send(CrafterUpdate::Event {
                                             message: format!("Game Over: {}", reason),
                                         });
+                                        let _ = tx.send(CrafterUpdate::Event {
+                                            message: format!(
+                                                "Seed {} — repro: {}",
+                                                rec_sess.session().episode_seed(),
+                                                rec_sess.session().repro_command()
+                                            ),
+                                        });
 
                                         let recording = rec_sess.recording().clone();
-                                        save_recording(&recording, &tx, &recordings_dir);
+                                        save_recording(recording, &tx, &recordings_dir);
+                                        clear_journal(&mut journal);
 
                                         running = false;
                                         let _ = tx.send(CrafterUpdate::Running { running: false });
@@ -1937,24 +2323,155 @@ pub fn spawn_crafter_loop(
                             }
                         }
                     }
+                    CrafterCommand::Console(line) => {
+                        // During replay the console only takes annotation
+                        // commands, written back to the recording file
+                        if let (Some(ref replay), Some((ref path, ref mut recording))) =
+                            (&replay_session, &mut replay_source)
+                        {
+                            let step = replay.current_step() as u64;
+                            let message = match line.split_once(char::is_whitespace) {
+                                Some(("note", text)) if !text.trim().is_empty() => {
+                                    recording.add_annotation(step, text.trim().to_string());
+                                    match recording.save_json(path) {
+                                        Ok(()) => format!("Noted at step {}", step),
+                                        Err(e) => format!("Failed to save note: {}", e),
+                                    }
+                                }
+                                _ => "Replay console: note <text> (or [N] to bookmark)"
+                                    .to_string(),
+                            };
+                            let _ = tx.send(CrafterUpdate::Status { message });
+                            continue;
+                        }
+                        let Some(ref mut rec_sess) = recording_session else {
+                            let _ = tx.send(CrafterUpdate::Status {
+                                message: "Console: no game running".to_string(),
+                            });
+                            continue;
+                        };
+
+                        let mut tokens = line.split_whitespace();
+                        let message = match tokens.next() {
+                            Some("teleport") | Some("tp") => {
+                                let coords = (
+                                    tokens.next().and_then(|t| t.parse::<i32>().ok()),
+                                    tokens.next().and_then(|t| t.parse::<i32>().ok()),
+                                );
+                                match coords {
+                                    (Some(x), Some(y)) => {
+                                        match rec_sess.session_mut().debug_teleport((x, y)) {
+                                            Ok(()) => format!("Teleported to ({}, {})", x, y),
+                                            Err(err) => format!("teleport: {}", err),
+                                        }
+                                    }
+                                    _ => "usage: teleport <x> <y>".to_string(),
+                                }
+                            }
+                            Some("give") => match tokens.next() {
+                                Some(item) => {
+                                    let count = tokens
+                                        .next()
+                                        .and_then(|t| t.parse::<u8>().ok())
+                                        .unwrap_or(1);
+                                    match rec_sess.session_mut().debug_give(item, count) {
+                                        Ok(()) => format!("Gave {} {}", count, item),
+                                        Err(err) => format!("give: {}", err),
+                                    }
+                                }
+                                None => "usage: give <item> [count]".to_string(),
+                            },
+                            Some("seed") => match tokens.next().and_then(|t| t.parse::<u64>().ok())
+                            {
+                                Some(seed) => {
+                                    rec_sess.session_mut().debug_reseed(seed);
+                                    format!("Restarted episode with seed {}", seed)
+                                }
+                                None => "usage: seed <n>".to_string(),
+                            },
+                            _ => match crafter_core::parse_script(&line) {
+                                Ok(actions) => {
+                                    let count = actions.len();
+                                    for action in actions {
+                                        let result = rec_sess.step(action);
+                                        for event in &result.debug_events {
+                                            let _ = tx.send(CrafterUpdate::Event {
+                                                message: event.clone(),
+                                            });
+                                        }
+                                        for ach in &result.newly_unlocked {
+                                            let _ = tx.send(CrafterUpdate::Event {
+                                                message: format!("Unlocked: {}", ach),
+                                            });
+                                        }
+                                        for milestone in &result.milestones {
+                                            let _ = tx.send(CrafterUpdate::Event {
+                                                message: milestone.to_string(),
+                                            });
+                                        }
+                                        #[cfg(feature = "audio")]
+                                        for sound in &result.sounds {
+                                            crate::audio::play(sound);
+                                        }
+                                        if result.done {
+                                            let reason = result
+                                                .done_reason
+                                                .map(|r| format!("{:?}", r))
+                                                .unwrap_or_else(|| "Unknown".to_string());
+                                            let _ = tx.send(CrafterUpdate::Event {
+                                                message: format!("Game Over: {}", reason),
+                                            });
+                                            let _ = tx.send(CrafterUpdate::Event {
+                                                message: format!(
+                                                    "Seed {} — repro: {}",
+                                                    rec_sess.session().episode_seed(),
+                                                    rec_sess.session().repro_command()
+                                                ),
+                                            });
+                                            let recording = rec_sess.recording().clone();
+                                            save_recording(recording, &tx, &recordings_dir);
+                                            clear_journal(&mut journal);
+                                            running = false;
+                                            let _ = tx
+                                                .send(CrafterUpdate::Running { running: false });
+                                            break;
+                                        }
+                                    }
+                                    format!("Ran {} action(s)", count)
+                                }
+                                Err(err) => err.to_string(),
+                            },
+                        };
+
+                        sync_journal(&mut journal, rec_sess.recording());
+                        let state = rec_sess.get_state();
+                        let frame =
+                            make_frame_update(&state, None, graphics_mode, tile_size, 0.0, Vec::new());
+                        let _ = tx.send(frame);
+                        last_frame_state = Some(state);
+                        let _ = tx.send(CrafterUpdate::Status { message });
+                    }
                     CrafterCommand::Reset => {
                         if let Some(rec_sess) = recording_session.take() {
                             let recording = rec_sess.finish();
                             if recording.total_steps > 0 {
-                                save_recording(&recording, &tx, &recordings_dir);
+                                save_recording(recording, &tx, &recordings_dir);
                             }
                         }
                         replay_session = None;
+                        replay_source = None;
 
                         let config = SessionConfig {
                             world_size: (frame_width, frame_height),
                             seed: current_seed,
-                            view_radius: view_radius_from_size(crafter.config.view_size),
+                            view_radius: view_radius_from_size(current_view_size),
                             full_world_state: true,
                             ..Default::default()
                         };
+                        journal = start_journal(&config);
                         recording_session =
-                            Some(RecordingSession::new(config, RecordingOptions::minimal()));
+                            Some(RecordingSession::new(config, RecordingOptions::human()));
+                        last_frame_state = None;
                         let _ = tx.send(CrafterUpdate::ReplayMode {
                             active: false,
                             current_step: 0,
@@ -1968,7 +2485,7 @@ pub fn spawn_crafter_loop(
                         if let Some(ref rec_sess) = recording_session {
                             let recording = rec_sess.recording().clone();
                             if recording.total_steps > 0 {
-                                save_recording(&recording, &tx, &recordings_dir);
+                                save_recording(recording, &tx, &recordings_dir);
                             }
                         }
                     }
@@ -1977,14 +2494,18 @@ pub fn spawn_crafter_loop(
                         if let Some(rec_sess) = recording_session.take() {
                             let recording = rec_sess.finish();
                             if recording.total_steps > 0 {
-                                save_recording(&recording, &tx, &recordings_dir);
+                                save_recording(recording, &tx, &recordings_dir);
                             }
                         }
+                        clear_journal(&mut journal);
 
                         match Recording::load_json(&path) {
                             Ok(recording) => {
                                 let total = recording.total_steps as usize;
                                 replay_session = Some(ReplaySession::from_recording(&recording));
+                                replay_source = Some((path.clone(), recording));
+                                last_frame_state = None;
+                                replay_speed = 1.0;
                                 running = true;
                                 replay_paused = false;
                                 paused = false;
@@ -2008,6 +2529,7 @@ pub fn spawn_crafter_loop(
                     }
                     CrafterCommand::StopReplay => {
                         replay_session = None;
+                        replay_source = None;
                         running = false;
                         let _ = tx.send(CrafterUpdate::Running { running: false });
                         let _ = tx.send(CrafterUpdate::ReplayMode {
@@ -2025,12 +2547,14 @@ pub fn spawn_crafter_loop(
                                 let state = replay.get_state();
                                 let frame = make_frame_update(
                                     &state,
+                                    last_frame_state.as_ref(),
                                     graphics_mode,
                                     tile_size,
                                     result.reward,
                                     result.newly_unlocked.clone(),
                                 );
                                 let _ = tx.send(frame);
+                                last_frame_state = Some(state);
                                 let _ = tx.send(CrafterUpdate::ReplayMode {
                                     active: true,
                                     current_step: replay.current_step(),
@@ -2060,6 +2584,7 @@ pub fn spawn_crafter_loop(
                                         message: "Replay complete".to_string(),
                                     });
                                     replay_session = None;
+                                    replay_source = None;
                                     running = false;
                                     let _ = tx.send(CrafterUpdate::Running { running: false });
                                     let _ = tx.send(CrafterUpdate::ReplayMode {
@@ -2076,6 +2601,7 @@ pub fn spawn_crafter_loop(
                                     message: "Replay complete".to_string(),
                                 });
                                 replay_session = None;
+                                replay_source = None;
                                 running = false;
                                 let _ = tx.send(CrafterUpdate::Running { running: false });
                                 let _ = tx.send(CrafterUpdate::ReplayMode {
@@ -2092,6 +2618,66 @@ pub fn spawn_crafter_loop(
                     CrafterCommand::SetReplaySpeed(speed) => {
                         replay_speed = speed.clamp(0.1, 10.0);
                     }
+                    CrafterCommand::ToggleReplayBookmark => {
+                        if let (Some(ref replay), Some((ref path, ref mut recording))) =
+                            (&replay_session, &mut replay_source)
+                        {
+                            let step = replay.current_step() as u64;
+                            let set = recording.toggle_bookmark(step);
+                            let message = match recording.save_json(path) {
+                                Ok(()) if set => format!("Bookmarked step {}", step),
+                                Ok(()) => format!("Removed bookmark at step {}", step),
+                                Err(e) => format!("Failed to save bookmark: {}", e),
+                            };
+                            let _ = tx.send(CrafterUpdate::Status { message });
+                        }
+                    }
+                    CrafterCommand::ReplayJumpBookmark { forward } => {
+                        if let (Some(ref mut replay), Some((_, ref recording))) =
+                            (&mut replay_session, &replay_source)
+                        {
+                            let step = replay.current_step() as u64;
+                            let target = if forward {
+                                recording.next_bookmark(step)
+                            } else {
+                                recording.prev_bookmark(step)
+                            };
+                            match target {
+                                Some(target) => {
+                                    replay.seek(target as usize);
+                                    let state = replay.get_state();
+                                    let frame = make_frame_update(
+                                        &state,
+                                        None,
+                                        graphics_mode,
+                                        tile_size,
+                                        0.0,
+                                        Vec::new(),
+                                    );
+                                    let _ = tx.send(frame);
+                                    last_frame_state = Some(state);
+                                    let _ = tx.send(CrafterUpdate::ReplayMode {
+                                        active: true,
+                                        current_step: replay.current_step(),
+                                        total_steps: replay.total_steps(),
+                                    });
+                                    let mut message = format!("Jumped to step {}", target);
+                                    for note in recording.annotations_at(target) {
+                                        if !note.text.is_empty() {
+                                            message = format!("{}: {}", message, note.text);
+                                            break;
+                                        }
+                                    }
+                                    let _ = tx.send(CrafterUpdate::Status { message });
+                                }
+                                None => {
+                                    let _ = tx.send(CrafterUpdate::Status {
+                                        message: "No bookmark in that direction".to_string(),
+                                    });
+                                }
+                            }
+                        }
+                    }
                     CrafterCommand::BranchFromReplay => {
                         if let Some(ref replay) = replay_session {
                             if !replay_paused {
@@ -2102,20 +2688,23 @@ pub fn spawn_crafter_loop(
                                 let save = SaveData::from_session(replay.session(), None);
                                 let session = save.into_session();
                                 let rec_sess =
-                                    RecordingSession::from_session(session, RecordingOptions::minimal());
+                                    RecordingSession::from_session(session, RecordingOptions::human());
                                 let state = rec_sess.get_state();
                                 let frame = make_frame_update(
                                     &state,
+                                    None,
                                     graphics_mode,
                                     tile_size,
                                     0.0,
                                     vec![],
                                 );
                                 let _ = tx.send(frame);
+                                last_frame_state = Some(state);
 
                                 current_seed = rec_sess.session().config.seed;
                                 recording_session = Some(rec_sess);
                                 replay_session = None;
+                                replay_source = None;
                                 replay_paused = false;
                                 running = true;
                                 paused = false;
@@ -2141,28 +2730,82 @@ pub fn spawn_crafter_loop(
                     CrafterCommand::SetTileSize(new_tile_size) => {
                         tile_size = new_tile_size.clamp(4, 16);
                     }
+                    CrafterCommand::Screenshot => {
+                        if let Some(ref rec) = recording_session {
+                            let dir = screenshots_dir();
+                            let message = if std::fs::create_dir_all(&dir).is_err() {
+                                "Failed to create screenshots dir".to_string()
+                            } else {
+                                let step = rec.session().get_state().step;
+                                let timestamp = std::time::SystemTime::now()
+                                    .duration_since(std::time::UNIX_EPOCH)
+                                    .map(|d| d.as_secs())
+                                    .unwrap_or(0);
+                                let filename =
+                                    format!("shot_{}_step{:06}.png", timestamp, step);
+                                let path = dir.join(&filename);
+                                match rec.session().screenshot(
+                                    &path.to_string_lossy(),
+                                    ImageRendererConfig::large(),
+                                ) {
+                                    Ok(()) => format!("Screenshot: {}", filename),
+                                    Err(e) => format!("Screenshot failed: {}", e),
+                                }
+                            };
+                            let _ = tx.send(CrafterUpdate::Event { message });
+                        }
+                    }
                 },
                 Err(std::sync::mpsc::RecvTimeoutError::Timeout) => {
                     if running && !paused && !logical_time {
                         let now = Instant::now();
                         let delta = now.duration_since(last_tick);
                         last_tick = now;
-                        let secs = delta.as_secs_f32();
-                        let actual_hz = if secs > 0.0 { 1.0 / secs } else { 0.0 };
+                        // Core tracks the pause-aware achieved rate; the
+                        // loop rate is only a fallback for replays, which
+                        // have no live session behind them
+                        let actual_hz = recording_session
+                            .as_ref()
+                            .map(|rs| rs.session().achieved_tps() as f32)
+                            .unwrap_or_else(|| {
+                                let secs = delta.as_secs_f32();
+                                if secs > 0.0 {
+                                    1.0 / secs
+                                } else {
+                                    0.0
+                                }
+                            });
                         let _ = tx.send(CrafterUpdate::Tick { actual_hz });
 
+                        // Frames render at their own (usually lower)
+                        // rate; ticks in between only refresh the HUD
+                        let frame_due = frame_rate == 0
+                            || last_frame_sent.elapsed()
+                                >= Duration::from_secs_f32(1.0 / frame_rate as f32);
+
                         if let Some(ref mut replay) = replay_session {
                             if !replay_paused {
                                 if let Some(result) = replay.step() {
                                     let state = replay.get_state();
-                                    let frame = make_frame_update(
-                                        &state,
-                                        graphics_mode,
-                                        tile_size,
-                                        result.reward,
-                                        result.newly_unlocked.clone(),
-                                    );
-                                    let _ = tx.send(frame);
+                                    if frame_due {
+                                        let frame = make_frame_update(
+                                            &state,
+                                            last_frame_state.as_ref(),
+                                            graphics_mode,
+                                            tile_size,
+                                            result.reward,
+                                            result.newly_unlocked.clone(),
+                                        );
+                                        let _ = tx.send(frame);
+                                        last_frame_state = Some(state);
+                                        last_frame_sent = Instant::now();
+                                    } else {
+                                        let _ = tx.send(make_hud_update(
+                                            &state,
+                                            result.reward,
+                                            result.newly_unlocked.clone(),
+                                        ));
+                                    }
                                     let _ = tx.send(CrafterUpdate::ReplayMode {
                                         active: true,
                                         current_step: replay.current_step(),
@@ -2193,6 +2836,7 @@ pub fn spawn_crafter_loop(
                                             message: "Replay complete".to_string(),
                                         });
                                         replay_session = None;
+                                        replay_source = None;
                                         running = false;
                                         replay_paused = false;
                                         let _ = tx.send(CrafterUpdate::Running { running: false });
@@ -2210,6 +2854,7 @@ pub fn spawn_crafter_loop(
                                         message: "Replay complete".to_string(),
                                     });
                                     replay_session = None;
+                                    replay_source = None;
                                     running = false;
                                     replay_paused = false;
                                     let _ = tx.send(CrafterUpdate::Running { running: false });
@@ -2226,16 +2871,28 @@ pub fn spawn_crafter_loop(
                         } else if let Some(ref mut rec_sess) = recording_session {
                             let result = rec_sess.step(pending_action);
                             pending_action = Action::Noop;
+                            sync_journal(&mut journal, rec_sess.recording());
 
                             let game_state = &result.state;
-                            let frame = make_frame_update(
-                                game_state,
-                                graphics_mode,
-                                tile_size,
-                                result.reward,
-                                result.newly_unlocked.clone(),
-                            );
-                            let _ = tx.send(frame);
+                            if frame_due {
+                                let frame = make_frame_update(
+                                    game_state,
+                                    last_frame_state.as_ref(),
+                                    graphics_mode,
+                                    tile_size,
+                                    result.reward,
+                                    result.newly_unlocked.clone(),
+                                );
+                                let _ = tx.send(frame);
+                                last_frame_state = Some(result.state.clone());
+                                last_frame_sent = Instant::now();
+                            } else {
+                                let _ = tx.send(make_hud_update(
+                                    game_state,
+                                    result.reward,
+                                    result.newly_unlocked.clone(),
+                                ));
+                            }
 
                             for ach in &result.newly_unlocked {
                                 let _ = tx.send(CrafterUpdate::Event {
@@ -2243,6 +2900,17 @@ pub fn spawn_crafter_loop(
                                 });
                             }
 
+                            for milestone in &result.milestones {
+                                let _ = tx.send(CrafterUpdate::Event {
+                                    message: milestone.to_string(),
+                                });
+                            }
+
+                            #[cfg(feature = "audio")]
+                            for sound in &result.sounds {
+                                crate::audio::play(sound);
+                            }
+
                             for event in &result.debug_events {
                                 let _ = tx.send(CrafterUpdate::Event {
                                     message: event.clone(),
@@ -2257,9 +2925,17 @@ pub fn spawn_crafter_loop(
                                 let _ = tx.send(CrafterUpdate::Event {
                                     message: format!("Game Over: {}", reason),
                                 });
+                                let _ = tx.send(CrafterUpdate::Event {
+                                    message: format!(
+                                        "Seed {} — repro: {}",
+                                        rec_sess.session().episode_seed(),
+                                        rec_sess.session().repro_command()
+                                    ),
+                                });
 
                                 let recording = rec_sess.recording().clone();
-                                save_recording(&recording, &tx, &recordings_dir);
+                                save_recording(recording, &tx, &recordings_dir);
+                                clear_journal(&mut journal);
 
                                 running = false;
                                 let _ = tx.send(CrafterUpdate::Running { running: false });
@@ -2276,34 +2952,51 @@ pub fn spawn_crafter_loop(
     });
 }
 
-fn save_recording(recording: &Recording, tx: &Sender<CrafterUpdate>, recordings_dir: &Path) {
-    if std::fs::create_dir_all(recordings_dir).is_err() {
-        let _ = tx.send(CrafterUpdate::Event {
-            message: "Failed to create recordings dir".to_string(),
-        });
-        return;
-    }
-
-    let timestamp = std::time::SystemTime::now()
-        .duration_since(std::time::UNIX_EPOCH)
-        .map(|d| d.as_secs())
-        .unwrap_or(0);
-    let filename = format!("session_{}.json", timestamp);
-    let path = recordings_dir.join(&filename);
-
-    match recording.save_json(&path) {
-        Ok(()) => {
-            let _ = tx.send(CrafterUpdate::RecordingSaved { path: path.clone() });
+/// Serialize a recording on a worker thread so long episodes don't stall
+/// the game loop. The JSON is written to a `.tmp` sibling and renamed into
+/// place, so readers never observe a half-written file.
+fn save_recording(recording: Recording, tx: &Sender<CrafterUpdate>, recordings_dir: &Path) {
+    let tx = tx.clone();
+    let recordings_dir = recordings_dir.to_path_buf();
+    thread::spawn(move || {
+        if std::fs::create_dir_all(&recordings_dir).is_err() {
             let _ = tx.send(CrafterUpdate::Event {
-                message: format!("Saved: {}", filename),
+                message: "Failed to create recordings dir".to_string(),
             });
+            return;
         }
-        Err(e) => {
-            let _ = tx.send(CrafterUpdate::Event {
-                message: format!("Save failed: {}", e),
-            });
+
+        let timestamp = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .map(|d| d.as_secs())
+            .unwrap_or(0);
+        let filename = format!("session_{}.json", timestamp);
+        let path = recordings_dir.join(&filename);
+        let tmp_path = recordings_dir.join(format!("{}.tmp", filename));
+
+        let _ = tx.send(CrafterUpdate::Event {
+            message: format!("Saving {} steps...", recording.total_steps),
+        });
+
+        let result = recording
+            .save_json(&tmp_path)
+            .and_then(|()| std::fs::rename(&tmp_path, &path));
+        match result {
+            Ok(()) => {
+                write_recording_meta(&path, &recording_meta(&recording));
+                let _ = tx.send(CrafterUpdate::RecordingSaved { path: path.clone() });
+                let _ = tx.send(CrafterUpdate::Event {
+                    message: format!("Saved: {}", filename),
+                });
+            }
+            Err(e) => {
+                let _ = std::fs::remove_file(&tmp_path);
+                let _ = tx.send(CrafterUpdate::Event {
+                    message: format!("Save failed: {}", e),
+                });
+            }
         }
-    }
+    });
 }
 
 fn render_state(state: &crafter_core::GameState) -> Vec<String> {
@@ -2456,6 +3149,21 @@ fn mob_info(obj: &GameObject) -> Option<(char, &'static str, String)> {
             "Cow (food)",
             format!("{}; food +6 on kill", hits_summary(cow.health)),
         )),
+        GameObject::Sheep(sheep) => Some((
+            'w',
+            "Sheep (wool)",
+            format!("{}; wool +1, food +2 on kill", hits_summary(sheep.health)),
+        )),
+        GameObject::Pig(pig) => Some((
+            'g',
+            "Pig (food)",
+            format!("{}; food +8 on kill", hits_summary(pig.health)),
+        )),
+        GameObject::EscortKnight(knight) => Some((
+            'k',
+            "Knight (escort)",
+            format!("{}; friendly, lead to the village", hits_summary(knight.health)),
+        )),
         GameObject::CraftaxMob(mob) => match mob.kind {
             crafter_core::entity::CraftaxMobKind::OrcSoldier => Some((
                 'O',
@@ -2515,7 +3223,7 @@ fn hits_to_kill(health: u8, damage: u8) -> u8 {
     if damage == 0 {
         return 0;
     }
-    (health + damage - 1) / damage
+    health.div_ceil(damage)
 }
 
 fn format_craftax_detail(health: u8, kind: crafter_core::entity::CraftaxMobKind) -> String {
@@ -2614,6 +3322,8 @@ fn map_density_lines(state: &crafter_core::GameState) -> Vec<String> {
     }
 
     let mut cow = 0usize;
+    let mut sheep = 0usize;
+    let mut pig = 0usize;
     let mut zombie = 0usize;
     let mut skeleton = 0usize;
     let mut orc = 0usize;
@@ -2627,6 +3337,8 @@ fn map_density_lines(state: &crafter_core::GameState) -> Vec<String> {
     for obj in world.objects.values() {
         match obj {
             GameObject::Cow(_) => cow += 1,
+            GameObject::Sheep(_) => sheep += 1,
+            GameObject::Pig(_) => pig += 1,
             GameObject::Zombie(_) => zombie += 1,
             GameObject::Skeleton(_) => skeleton += 1,
             GameObject::CraftaxMob(mob) => match mob.kind {
@@ -2651,6 +3363,8 @@ fn map_density_lines(state: &crafter_core::GameState) -> Vec<String> {
         ("Ruby", ruby),
         ("Chest", chest),
         ("Cow", cow),
+        ("Sheep", sheep),
+        ("Pig", pig),
         ("Zombie", zombie),
         ("Skeleton", skeleton),
         ("Orc", orc),
@@ -2676,7 +3390,7 @@ fn map_density_lines(state: &crafter_core::GameState) -> Vec<String> {
     let max_len = labels.iter().map(|s| s.len()).max().unwrap_or(0);
     let col_width = (max_len + 2).max(10);
     let columns = 2usize;
-    let rows = (labels.len() + columns - 1) / columns;
+    let rows = labels.len().div_ceil(columns);
     let mut lines = Vec::new();
 
     for row in 0..rows {
@@ -2756,6 +3470,40 @@ pub fn handle_key(
 ) -> CrafterKeyOutcome {
     let mut graphics_mode_update = None;
 
+    if crafter.console_active {
+        match key.code {
+            KeyCode::Esc => {
+                crafter.console_active = false;
+                crafter.console_input.clear();
+                crafter.status = "Console closed".to_string();
+            }
+            KeyCode::Enter => {
+                let line = crafter.console_input.trim().to_string();
+                crafter.console_active = false;
+                crafter.console_input.clear();
+                if line.is_empty() {
+                    crafter.status = "Console closed".to_string();
+                } else {
+                    crafter.status = format!(":{}", line);
+                    let _ = cmd_tx.send(CrafterCommand::Console(line));
+                }
+            }
+            KeyCode::Backspace => {
+                crafter.console_input.pop();
+                crafter.status = format!(":{}", crafter.console_input);
+            }
+            KeyCode::Char(ch) => {
+                crafter.console_input.push(ch);
+                crafter.status = format!(":{}", crafter.console_input);
+            }
+            _ => {}
+        }
+        return CrafterKeyOutcome {
+            handled: true,
+            graphics_mode_update,
+        };
+    }
+
     if crafter.show_recordings {
         let filtered = filtered_recording_indices(&crafter.recordings, &crafter.recordings_search);
         if crafter.selected_recording >= filtered.len() {
@@ -2787,6 +3535,7 @@ pub fn handle_key(
                         let _ = cmd_tx.send(CrafterCommand::StartReplay {
                             path: rec.path.clone(),
                         });
+                        crafter.replay_speed = 1.0;
                         crafter.show_recordings = false;
                     }
                 }
@@ -2926,11 +3675,14 @@ pub fn handle_key(
                 true
             }
             KeyCode::Char('s') | KeyCode::Char('S') => {
-                if save_rule_editor(crafter) {
-                    refresh_rule_configs(crafter);
-                    crafter.status = "Saved rule config".to_string();
-                } else {
-                    crafter.status = "Failed to save rule config".to_string();
+                match save_rule_editor(crafter) {
+                    Ok(()) => {
+                        refresh_rule_configs(crafter);
+                        crafter.status = "Saved rule config".to_string();
+                    }
+                    Err(e) => {
+                        crafter.status = format!("Failed to save rule config: {}", e);
+                    }
                 }
                 crafter.show_rule_editor = false;
                 crafter.show_config_menu = true;
@@ -2964,47 +3716,44 @@ pub fn handle_key(
             }
             KeyCode::Left | KeyCode::Char('h') => {
                 match crafter.config_selection {
-                    0 => {
-                        if !crafter.profile_names.is_empty() {
-                            if crafter.profile_index == 0 {
-                                crafter.profile_index = crafter.profile_names.len() - 1;
-                            } else {
-                                crafter.profile_index -= 1;
-                            }
-                            crafter.config = load_profile_config(
-                                &crafter.profile_names[crafter.profile_index],
-                            );
-                            refresh_rule_configs(&mut *crafter);
-                            graphics_mode_update = Some(crafter.config.graphics_mode);
+                    0 if !crafter.profile_names.is_empty() => {
+                        if crafter.profile_index == 0 {
+                            crafter.profile_index = crafter.profile_names.len() - 1;
+                        } else {
+                            crafter.profile_index -= 1;
                         }
+                        crafter.config = load_profile_config(
+                            &crafter.profile_names[crafter.profile_index],
+                        );
+                        refresh_rule_configs(&mut *crafter);
+                        graphics_mode_update = Some(crafter.config.graphics_mode);
                     }
-                    1 => {
-                        if !crafter.rule_configs.is_empty() {
-                            if crafter.rule_config_index == 0 {
-                                crafter.rule_config_index = crafter.rule_configs.len() - 1;
-                            } else {
-                                crafter.rule_config_index -= 1;
-                            }
-                            crafter.config.rule_config = selected_rule_config_name(crafter);
+                    1 if !crafter.rule_configs.is_empty() => {
+                        if crafter.rule_config_index == 0 {
+                            crafter.rule_config_index = crafter.rule_configs.len() - 1;
+                        } else {
+                            crafter.rule_config_index -= 1;
                         }
+                        crafter.config.rule_config = selected_rule_config_name(crafter);
                     }
                     2 => crafter.config.logical_time = !crafter.config.logical_time,
                     3 => {
                         crafter.config.tick_rate =
                             crafter.config.tick_rate.saturating_sub(1).max(1);
                     }
-                    4 => {
+                    4 => crafter.config.frame_rate = crafter.config.frame_rate.saturating_sub(1),
+                    5 => {
                         crafter.config.world_width =
                             crafter.config.world_width.saturating_sub(4).max(16);
                     }
-                    5 => {
+                    6 => {
                         crafter.config.world_height =
                             crafter.config.world_height.saturating_sub(4).max(16);
                     }
-                    6 => crafter.config.view_size = step_view_size(crafter.config.view_size, -1),
-                    7 => crafter.config.random_seed = !crafter.config.random_seed,
-                    8 => crafter.config.seed = crafter.config.seed.saturating_sub(1),
-                    9 => {
+                    7 => crafter.config.view_size = step_view_size(crafter.config.view_size, -1),
+                    8 => crafter.config.random_seed = !crafter.config.random_seed,
+                    9 => crafter.config.seed = crafter.config.seed.saturating_sub(1),
+                    10 => {
                         crafter.config.graphics_mode = !crafter.config.graphics_mode;
                         graphics_mode_update = Some(crafter.config.graphics_mode);
                     }
@@ -3014,33 +3763,30 @@ pub fn handle_key(
             }
             KeyCode::Right | KeyCode::Char('l') => {
                 match crafter.config_selection {
-                    0 => {
-                        if !crafter.profile_names.is_empty() {
-                            crafter.profile_index =
-                                (crafter.profile_index + 1) % crafter.profile_names.len();
-                            crafter.config = load_profile_config(
-                                &crafter.profile_names[crafter.profile_index],
-                            );
-                            refresh_rule_configs(&mut *crafter);
-                            graphics_mode_update = Some(crafter.config.graphics_mode);
-                        }
+                    0 if !crafter.profile_names.is_empty() => {
+                        crafter.profile_index =
+                            (crafter.profile_index + 1) % crafter.profile_names.len();
+                        crafter.config = load_profile_config(
+                            &crafter.profile_names[crafter.profile_index],
+                        );
+                        refresh_rule_configs(&mut *crafter);
+                        graphics_mode_update = Some(crafter.config.graphics_mode);
                     }
-                    1 => {
-                        if !crafter.rule_configs.is_empty() {
-                            crafter.rule_config_index =
-                                (crafter.rule_config_index + 1) % crafter.rule_configs.len();
-                            crafter.config.rule_config = selected_rule_config_name(crafter);
-                        }
+                    1 if !crafter.rule_configs.is_empty() => {
+                        crafter.rule_config_index =
+                            (crafter.rule_config_index + 1) % crafter.rule_configs.len();
+                        crafter.config.rule_config = selected_rule_config_name(crafter);
                     }
                     2 => crafter.config.logical_time = !crafter.config.logical_time,
                     3 => crafter.config.tick_rate = (crafter.config.tick_rate + 1).min(30),
-                    4 => crafter.config.world_width = (crafter.config.world_width + 4).min(64),
-                    5 => crafter.config.world_height =
+                    4 => crafter.config.frame_rate = (crafter.config.frame_rate + 1).min(30),
+                    5 => crafter.config.world_width = (crafter.config.world_width + 4).min(64),
+                    6 => crafter.config.world_height =
                         crafter.config.world_height.saturating_add(4).min(64),
-                    6 => crafter.config.view_size = step_view_size(crafter.config.view_size, 1),
-                    7 => crafter.config.random_seed = !crafter.config.random_seed,
-                    8 => crafter.config.seed = crafter.config.seed.saturating_add(1),
-                    9 => {
+                    7 => crafter.config.view_size = step_view_size(crafter.config.view_size, 1),
+                    8 => crafter.config.random_seed = !crafter.config.random_seed,
+                    9 => crafter.config.seed = crafter.config.seed.saturating_add(1),
+                    10 => {
                         crafter.config.graphics_mode = !crafter.config.graphics_mode;
                         graphics_mode_update = Some(crafter.config.graphics_mode);
                     }
@@ -3048,58 +3794,42 @@ pub fn handle_key(
                 }
                 true
             }
-            KeyCode::Char('n') | KeyCode::Char('N') => {
-                if crafter.config_selection == 1 {
-                    if let Some(name) = create_rule_config_from_selected(crafter) {
-                        refresh_rule_configs(crafter);
-                        crafter.rule_config_index = rule_config_index(&crafter.rule_configs, &name);
-                        crafter.config.rule_config = name;
-                        crafter.status = "Created rule config".to_string();
-                    }
-                    true
-                } else {
-                    false
+            KeyCode::Char('n') | KeyCode::Char('N') if crafter.config_selection == 1 => {
+                if let Some(name) = create_rule_config_from_selected(crafter) {
+                    refresh_rule_configs(crafter);
+                    crafter.rule_config_index = rule_config_index(&crafter.rule_configs, &name);
+                    crafter.config.rule_config = name;
+                    crafter.status = "Created rule config".to_string();
                 }
+                true
             }
-            KeyCode::Char('y') | KeyCode::Char('Y') => {
-                if crafter.config_selection == 1 {
-                    if let Some(name) =
-                        create_rule_config_from_selected_with_ext(crafter, "yaml")
-                    {
-                        refresh_rule_configs(crafter);
-                        crafter.rule_config_index = rule_config_index(&crafter.rule_configs, &name);
-                        crafter.config.rule_config = name;
-                        crafter.status = "Created rule config (yaml)".to_string();
-                    }
-                    true
-                } else {
-                    false
+            KeyCode::Char('y') | KeyCode::Char('Y') if crafter.config_selection == 1 => {
+                if let Some(name) =
+                    create_rule_config_from_selected_with_ext(crafter, "yaml")
+                {
+                    refresh_rule_configs(crafter);
+                    crafter.rule_config_index = rule_config_index(&crafter.rule_configs, &name);
+                    crafter.config.rule_config = name;
+                    crafter.status = "Created rule config (yaml)".to_string();
                 }
+                true
             }
-            KeyCode::Char('d') | KeyCode::Char('D') => {
-                if crafter.config_selection == 1 {
-                    if delete_selected_rule_config(crafter) {
-                        refresh_rule_configs(crafter);
-                        crafter.status = "Deleted rule config".to_string();
-                    } else {
-                        crafter.status = "Cannot delete rule config".to_string();
-                    }
-                    true
+            KeyCode::Char('d') | KeyCode::Char('D') if crafter.config_selection == 1 => {
+                if delete_selected_rule_config(crafter) {
+                    refresh_rule_configs(crafter);
+                    crafter.status = "Deleted rule config".to_string();
                 } else {
-                    false
+                    crafter.status = "Cannot delete rule config".to_string();
                 }
+                true
             }
-            KeyCode::Char('e') | KeyCode::Char('E') => {
-                if crafter.config_selection == 1 {
-                    if open_rule_editor(crafter) {
-                        crafter.status = "Editing rule config".to_string();
-                    } else {
-                        crafter.status = "Cannot edit rule config".to_string();
-                    }
-                    true
+            KeyCode::Char('e') | KeyCode::Char('E') if crafter.config_selection == 1 => {
+                if open_rule_editor(crafter) {
+                    crafter.status = "Editing rule config".to_string();
                 } else {
-                    false
+                    crafter.status = "Cannot edit rule config".to_string();
                 }
+                true
             }
             KeyCode::Enter | KeyCode::Char(' ') => {
                 if crafter.config_selection == CONFIG_ITEMS.len() - 1 {
@@ -3107,7 +3837,9 @@ pub fn handle_key(
                     if let Some(profile_name) =
                         crafter.profile_names.get(crafter.profile_index)
                     {
-                        save_profile_config(profile_name, &crafter.config);
+                        if let Err(e) = save_profile_config(profile_name, &crafter.config) {
+                            crafter.status = format!("Failed to save profile: {}", e);
+                        }
                     }
                     crafter.config.rule_config = selected_rule_config_name(crafter);
                     let _ = cmd_tx.send(CrafterCommand::Start {
@@ -3148,6 +3880,40 @@ pub fn handle_key(
     }
 
     let handled = match key.code {
+        KeyCode::Char(':') => {
+            if crafter.running {
+                crafter.console_active = true;
+                crafter.console_input.clear();
+                crafter.status = ":".to_string();
+            }
+            true
+        }
+        KeyCode::Char('n') | KeyCode::Char('N') if crafter.replay_active => {
+            let _ = cmd_tx.send(CrafterCommand::ToggleReplayBookmark);
+            true
+        }
+        KeyCode::Char('+') | KeyCode::Char('=') if crafter.replay_active => {
+            crafter.replay_speed = step_replay_speed(crafter.replay_speed, 1);
+            let _ = cmd_tx.send(CrafterCommand::SetReplaySpeed(crafter.replay_speed));
+            true
+        }
+        KeyCode::Char('-') | KeyCode::Char('_') if crafter.replay_active => {
+            crafter.replay_speed = step_replay_speed(crafter.replay_speed, -1);
+            let _ = cmd_tx.send(CrafterCommand::SetReplaySpeed(crafter.replay_speed));
+            true
+        }
+        KeyCode::Char('.') if crafter.replay_active && crafter.paused => {
+            let _ = cmd_tx.send(CrafterCommand::ReplayStep);
+            true
+        }
+        KeyCode::Char('[') if crafter.replay_active => {
+            let _ = cmd_tx.send(CrafterCommand::ReplayJumpBookmark { forward: false });
+            true
+        }
+        KeyCode::Char(']') if crafter.replay_active => {
+            let _ = cmd_tx.send(CrafterCommand::ReplayJumpBookmark { forward: true });
+            true
+        }
         KeyCode::Char('s') | KeyCode::Char('S') => {
             if !crafter.running && !crafter.input_capture {
                 crafter.show_config_menu = true;
@@ -3203,6 +3969,9 @@ pub fn handle_key(
                     config: crafter.config.clone(),
                 });
                 crafter.input_capture = true;
+                // Starting fresh replaces the journal; the old session
+                // is no longer resumable
+                crafter.resume_available = false;
             } else if crafter.input_capture {
                 if crafter.has_adjacent_table {
                     crafter.show_craft_menu = true;
@@ -3246,6 +4015,10 @@ pub fn handle_key(
             }
             true
         }
+        KeyCode::Char('v') | KeyCode::Char('V') if crafter.running && !crafter.replay_active => {
+            let _ = cmd_tx.send(CrafterCommand::Screenshot);
+            true
+        }
         KeyCode::Esc => {
             if crafter.input_capture {
                 crafter.input_capture = false;
@@ -3340,6 +4113,14 @@ pub fn handle_key(
             let _ = cmd_tx.send(CrafterCommand::Action(Action::DrinkPotionPink));
             true
         }
+        KeyCode::Char('u') | KeyCode::Char('U')
+            if !crafter.running && crafter.resume_available =>
+        {
+            let _ = cmd_tx.send(CrafterCommand::ResumeJournal);
+            crafter.resume_available = false;
+            crafter.input_capture = true;
+            true
+        }
         KeyCode::Char('i') | KeyCode::Char('I') if crafter.input_capture => {
             let _ = cmd_tx.send(CrafterCommand::Action(Action::DrinkPotionCyan));
             true
@@ -3427,6 +4208,56 @@ pub fn drain_updates(crafter: &mut CrafterState, rx: &Receiver<CrafterUpdate>) {
                 }
                 crafter.inventory = inventory;
             }
+            CrafterUpdate::FramePatch {
+                rows,
+                rgba_width,
+                rgba_height,
+                score,
+                health,
+                food,
+                thirst,
+                energy,
+                tick,
+                achievements,
+                visible_mobs,
+                density_lines,
+                has_adjacent_table,
+                has_adjacent_furnace,
+                inventory,
+            } => {
+                // Patch rows in place; if the buffer doesn't line up
+                // (e.g. a resize raced the patch), keep the old pixels —
+                // the next full frame resyncs
+                if crafter.frame_width == rgba_width && crafter.frame_height == rgba_height {
+                    if let Some(rgba) = &mut crafter.frame_rgba {
+                        let row_bytes = (rgba_width * 4) as usize;
+                        if rgba.len() == row_bytes * rgba_height as usize {
+                            for (row, data) in rows {
+                                if data.len() == row_bytes && row < rgba_height {
+                                    let start = row as usize * row_bytes;
+                                    rgba[start..start + row_bytes].copy_from_slice(&data);
+                                }
+                            }
+                        }
+                    }
+                }
+                crafter.score = score;
+                crafter.health = health;
+                crafter.food = food;
+                crafter.thirst = thirst;
+                crafter.energy = energy;
+                crafter.tick = tick;
+                crafter.visible_mobs = visible_mobs;
+                crafter.density_lines = density_lines;
+                crafter.has_adjacent_table = has_adjacent_table;
+                crafter.has_adjacent_furnace = has_adjacent_furnace;
+                for ach in achievements {
+                    if !crafter.achievements.contains(&ach) {
+                        crafter.achievements.push(ach);
+                    }
+                }
+                crafter.inventory = inventory;
+            }
             CrafterUpdate::Event { message } => {
                 crafter.events.push(message);
                 if crafter.events.len() > 10 {
@@ -3451,6 +4282,7 @@ pub fn drain_updates(crafter: &mut CrafterState, rx: &Receiver<CrafterUpdate>) {
     }
 }
 
+#[allow(clippy::not_unsafe_ptr_arg_deref)]
 pub fn draw_list(
     buffer: *mut ot::OptimizedBuffer,
     crafter: &CrafterState,
@@ -3495,9 +4327,10 @@ pub fn draw_list(
         )
     } else if crafter.replay_active {
         format!(
-            "REPLAY: {}/{}  {}",
+            "REPLAY: {}/{}  {}x  {}",
             crafter.replay_step,
             crafter.replay_total,
+            crafter.replay_speed,
             if crafter.paused { "[PAUSED]" } else { "" }
         )
     } else {
@@ -3666,10 +4499,17 @@ pub fn draw_list(
                     }
                 ),
                 3 => format!("{}: {} Hz", label, crafter.config.tick_rate),
-                4 => format!("{}: {}", label, crafter.config.world_width),
-                5 => format!("{}: {}", label, crafter.config.world_height),
-                6 => format!("{}: {}x{}", label, crafter.config.view_size, crafter.config.view_size),
-                7 => format!(
+                4 => {
+                    if crafter.config.frame_rate == 0 {
+                        format!("{}: every tick", label)
+                    } else {
+                        format!("{}: {} Hz", label, crafter.config.frame_rate)
+                    }
+                }
+                5 => format!("{}: {}", label, crafter.config.world_width),
+                6 => format!("{}: {}", label, crafter.config.world_height),
+                7 => format!("{}: {}x{}", label, crafter.config.view_size, crafter.config.view_size),
+                8 => format!(
                     "{}: {}",
                     label,
                     if crafter.config.random_seed {
@@ -3678,8 +4518,8 @@ pub fn draw_list(
                         "Fixed"
                     }
                 ),
-                8 => format!("{}: {}", label, crafter.config.seed),
-                9 => format!(
+                9 => format!("{}: {}", label, crafter.config.seed),
+                10 => format!(
                     "{}: {}",
                     label,
                     if crafter.config.graphics_mode {
@@ -3880,8 +4720,19 @@ pub fn draw_list(
                 );
             }
         } else {
+            // Page of entries around the selection, so long directories
+            // scroll instead of cutting off at the bottom of the screen
+            let page_size = (max_y.saturating_sub(list_start) as usize).max(1);
+            let page = crafter.selected_recording / page_size;
+            let page_start = page * page_size;
+
             let mut y = list_start;
-            for (i, &idx) in filtered_recordings.iter().enumerate() {
+            for (i, &idx) in filtered_recordings
+                .iter()
+                .enumerate()
+                .skip(page_start)
+                .take(page_size)
+            {
                 if y >= max_y {
                     break;
                 }
@@ -3924,7 +4775,11 @@ pub fn draw_list(
     }
 
     if !crafter.running {
-        let msg = "Press [C] to start, [L] for recordings";
+        let msg = if crafter.resume_available {
+            "Press [C] to start, [U] to resume last session, [L] for recordings"
+        } else {
+            "Press [C] to start, [L] for recordings"
+        };
         unsafe {
             ot::bufferDrawText(
                 buffer,
@@ -4006,6 +4861,7 @@ pub fn draw_list(
     }
 }
 
+#[allow(clippy::not_unsafe_ptr_arg_deref)]
 pub fn draw_detail(
     buffer: *mut ot::OptimizedBuffer,
     crafter: &CrafterState,
@@ -4093,9 +4949,7 @@ pub fn draw_detail(
                 })
                 .max()
                 .unwrap_or(1);
-            let max_rows = ((max_y.saturating_sub(y)) / max_row_height)
-                .max(1)
-                .min(6) as usize;
+            let max_rows = ((max_y.saturating_sub(y)) / max_row_height).clamp(1, 6) as usize;
             for (idx, preview) in visible.iter().take(max_rows).enumerate() {
                 if y >= max_y {
                     break;
@@ -4360,11 +5214,9 @@ pub fn draw_detail(
         .max(achievements_header.len()) as u32;
     let achievements_col_width = achievements_max_len.saturating_add(2).max(12);
     let achievements_available_width = width.saturating_sub(x + 2);
-    let achievements_columns = (achievements_available_width / achievements_col_width)
-        .max(1)
-        .min(3) as usize;
-    let achievements_rows =
-        (all_achievements.len() + achievements_columns - 1) / achievements_columns;
+    let achievements_columns =
+        (achievements_available_width / achievements_col_width).clamp(1, 3) as usize;
+    let achievements_rows = all_achievements.len().div_ceil(achievements_columns);
     let achievements_block_height = 1 + achievements_rows as u32;
     let events_block_height = if crafter.events.is_empty() { 0 } else { 1 + 1 + 3 };
 
@@ -4420,8 +5272,8 @@ pub fn draw_detail(
 
         let legend_col_width = legend_max_len.saturating_add(2).max(12);
         let legend_available_width = width.saturating_sub(x + 2);
-        let legend_columns = (legend_available_width / legend_col_width).max(1).min(2) as usize;
-        let legend_rows = (legend_items.len() + legend_columns - 1) / legend_columns;
+        let legend_columns = (legend_available_width / legend_col_width).clamp(1, 2) as usize;
+        let legend_rows = legend_items.len().div_ceil(legend_columns);
         let legend_rows = legend_rows.min((legend_limit_y.saturating_sub(y)) as usize);
 
         for row in 0..legend_rows {
@@ -4587,6 +5439,17 @@ pub fn draw_detail(
             if y >= max_y {
                 break;
             }
+            let (category, severity) = crafter_core::EventCategory::of(event);
+            let color = if severity == crafter_core::EventSeverity::Critical {
+                [0.9, 0.35, 0.35, 1.0]
+            } else {
+                match category {
+                    crafter_core::EventCategory::Combat => [0.85, 0.55, 0.45, 1.0],
+                    crafter_core::EventCategory::Crafting => [0.75, 0.68, 0.45, 1.0],
+                    crafter_core::EventCategory::Survival => [0.5, 0.72, 0.55, 1.0],
+                    crafter_core::EventCategory::System => dim_fg,
+                }
+            };
             unsafe {
                 ot::bufferDrawText(
                     buffer,
@@ -4594,7 +5457,7 @@ pub fn draw_detail(
                     event.len(),
                     x,
                     y,
-                    dim_fg.as_ptr(),
+                    color.as_ptr(),
                     std::ptr::null(),
                     0,
                 );
@@ -4605,7 +5468,10 @@ pub fn draw_detail(
 }
 
 pub fn action_hint(crafter: &CrafterState) -> String {
-    if crafter.show_rule_editor {
+    if crafter.console_active {
+        "[Enter] Run  [Esc] Cancel  (action script, or: teleport <x> <y>, give <item> [count], seed <n>)"
+            .to_string()
+    } else if crafter.show_rule_editor {
         "[Up/Down] Select  [Left/Right] Adjust  [Enter] Toggle  [S] Save  [Esc] Back"
             .to_string()
     } else if crafter.show_config_menu {
@@ -4616,12 +5482,13 @@ pub fn action_hint(crafter: &CrafterState) -> String {
     } else if crafter.show_recordings {
         "[Up/Down] Select  [Enter] Replay  [/] Search  [C] New game  [Esc] Back".to_string()
     } else if crafter.replay_active {
-        "[P] Pause  [B] Branch  [X/Esc] Stop replay  [C] New game".to_string()
+        "[P] Pause  [+/-] Speed  [.] Step  [N] Bookmark  [[/]] Jump bookmark  [:] Note  [B] Branch  [X/Esc] Stop replay  [C] New game"
+            .to_string()
     } else if crafter.running && crafter.paused {
         "[P] Resume  [Ctrl+S] Stop & save  [Backspace] Delete session  [Ctrl+C] End session  [R] Reset  [L] Recordings"
             .to_string()
     } else if crafter.input_capture {
-        "[WASD] Move  [Space] Interact  [Tab] Sleep  [T/R/F/P] Place  [C] Craft menu  [1-9] Quick craft  [G] Shoot  [Q/E/Y/U/I/O] Potions  [Ctrl+S] Stop & save  [Ctrl+C] End session  [Esc] Release"
+        "[WASD] Move  [Space] Interact  [Tab] Sleep  [T/R/F/P] Place  [C] Craft menu  [1-9] Quick craft  [G] Shoot  [Q/E/Y/U/I/O] Potions  [:] Console  [Ctrl+S] Stop & save  [Ctrl+C] End session  [Esc] Release"
             .to_string()
     } else if crafter.running {
         "[C] Capture input  [P] Pause  [Ctrl+S] Stop & save  [Ctrl+C] End session  [R] Reset  [L] Recordings"
@@ -1,4 +1,6 @@
 pub mod app;
+#[cfg(feature = "audio")]
+pub mod audio;
 pub mod crafter;
 pub mod renderer;
 
@@ -1,6 +1,7 @@
 //! Convert raw RGBA file to PNG for inspection
 #[cfg(feature = "png")]
 use image::RgbaImage;
+#[cfg(feature = "png")]
 use std::fs;
 
 #[cfg(feature = "png")]
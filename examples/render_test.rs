@@ -1,3 +1,4 @@
+#[cfg(feature = "png")]
 use crafter_core::{ImageRenderer, ImageRendererConfig, Session, SessionConfig};
 
 #[cfg(feature = "png")]
@@ -1,8 +1,10 @@
 //! Test that renders exactly what the TUI would render and saves it as PNG
+#[cfg(feature = "png")]
 use crafter_core::{Session, SessionConfig};
+#[cfg(feature = "png")]
 use crafter_core::image_renderer::{ImageRenderer, ImageRendererConfig};
 #[cfg(feature = "png")]
-use image::{RgbaImage, Rgba};
+use image::RgbaImage;
 
 #[cfg(feature = "png")]
 fn main() {
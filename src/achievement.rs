@@ -57,6 +57,22 @@ pub struct Achievements {
     pub drink_potion: u32,
     pub gain_xp: u32,
     pub reach_level: u32,
+    #[serde(default)]
+    pub fire_bow: u32,
+
+    // Extended (crafter-rs only) achievements
+    #[serde(default)]
+    pub survive_horde: u32,
+    #[serde(default)]
+    pub eat_pig: u32,
+    #[serde(default)]
+    pub collect_wool: u32,
+    #[serde(default)]
+    pub defeat_bat: u32,
+    #[serde(default)]
+    pub defeat_snail: u32,
+    #[serde(default)]
+    pub escort_knight: u32,
 }
 
 impl Achievements {
@@ -65,240 +81,179 @@ impl Achievements {
         Self::default()
     }
 
+    /// Canonical achievement order: classic, then Craftax, then extended.
+    /// `counters` and `unlocked_bits` index into this table.
+    pub const NAMES: [&'static str; Self::COUNT] = [
+        "collect_coal",
+        "collect_diamond",
+        "collect_drink",
+        "collect_iron",
+        "collect_sapling",
+        "collect_stone",
+        "collect_wood",
+        "defeat_skeleton",
+        "defeat_zombie",
+        "eat_cow",
+        "eat_plant",
+        "make_iron_pickaxe",
+        "make_iron_sword",
+        "make_stone_pickaxe",
+        "make_stone_sword",
+        "make_wood_pickaxe",
+        "make_wood_sword",
+        "place_furnace",
+        "place_plant",
+        "place_stone",
+        "place_table",
+        "wake_up",
+        "collect_sapphire",
+        "collect_ruby",
+        "open_chest",
+        "make_diamond_pickaxe",
+        "make_diamond_sword",
+        "make_bow",
+        "make_arrow",
+        "make_iron_armor",
+        "make_diamond_armor",
+        "defeat_orc_soldier",
+        "defeat_orc_mage",
+        "defeat_knight",
+        "defeat_knight_archer",
+        "defeat_troll",
+        "drink_potion",
+        "gain_xp",
+        "reach_level",
+        "fire_bow",
+        "survive_horde",
+        "eat_pig",
+        "collect_wool",
+        "defeat_bat",
+        "defeat_snail",
+        "escort_knight",
+    ];
+
+    /// Number of tracked achievements across all tables
+    pub const COUNT: usize = 46;
+
+    /// Per-achievement counters in canonical name order
+    pub fn counters(&self) -> [u32; Self::COUNT] {
+        [
+            self.collect_coal,
+            self.collect_diamond,
+            self.collect_drink,
+            self.collect_iron,
+            self.collect_sapling,
+            self.collect_stone,
+            self.collect_wood,
+            self.defeat_skeleton,
+            self.defeat_zombie,
+            self.eat_cow,
+            self.eat_plant,
+            self.make_iron_pickaxe,
+            self.make_iron_sword,
+            self.make_stone_pickaxe,
+            self.make_stone_sword,
+            self.make_wood_pickaxe,
+            self.make_wood_sword,
+            self.place_furnace,
+            self.place_plant,
+            self.place_stone,
+            self.place_table,
+            self.wake_up,
+            self.collect_sapphire,
+            self.collect_ruby,
+            self.open_chest,
+            self.make_diamond_pickaxe,
+            self.make_diamond_sword,
+            self.make_bow,
+            self.make_arrow,
+            self.make_iron_armor,
+            self.make_diamond_armor,
+            self.defeat_orc_soldier,
+            self.defeat_orc_mage,
+            self.defeat_knight,
+            self.defeat_knight_archer,
+            self.defeat_troll,
+            self.drink_potion,
+            self.gain_xp,
+            self.reach_level,
+            self.fire_bow,
+            self.survive_horde,
+            self.eat_pig,
+            self.collect_wool,
+            self.defeat_bat,
+            self.defeat_snail,
+            self.escort_knight,
+        ]
+    }
+
+    /// Bitset with bit `i` set when the achievement at canonical index
+    /// `i` has been unlocked at least once
+    pub fn unlocked_bits(&self) -> u64 {
+        let mut bits = 0u64;
+        for (i, &count) in self.counters().iter().enumerate() {
+            if count > 0 {
+                bits |= 1 << i;
+            }
+        }
+        bits
+    }
+
     /// Get total number of achievements unlocked (at least once)
     pub fn total_unlocked(&self) -> u32 {
-        let mut count = 0;
-        if self.collect_coal > 0 {
-            count += 1;
-        }
-        if self.collect_diamond > 0 {
-            count += 1;
-        }
-        if self.collect_drink > 0 {
-            count += 1;
-        }
-        if self.collect_iron > 0 {
-            count += 1;
-        }
-        if self.collect_sapling > 0 {
-            count += 1;
-        }
-        if self.collect_stone > 0 {
-            count += 1;
-        }
-        if self.collect_wood > 0 {
-            count += 1;
-        }
-        if self.defeat_skeleton > 0 {
-            count += 1;
-        }
-        if self.defeat_zombie > 0 {
-            count += 1;
-        }
-        if self.eat_cow > 0 {
-            count += 1;
-        }
-        if self.eat_plant > 0 {
-            count += 1;
-        }
-        if self.make_iron_pickaxe > 0 {
-            count += 1;
-        }
-        if self.make_iron_sword > 0 {
-            count += 1;
-        }
-        if self.make_stone_pickaxe > 0 {
-            count += 1;
-        }
-        if self.make_stone_sword > 0 {
-            count += 1;
-        }
-        if self.make_wood_pickaxe > 0 {
-            count += 1;
-        }
-        if self.make_wood_sword > 0 {
-            count += 1;
-        }
-        if self.place_furnace > 0 {
-            count += 1;
-        }
-        if self.place_plant > 0 {
-            count += 1;
-        }
-        if self.place_stone > 0 {
-            count += 1;
-        }
-        if self.place_table > 0 {
-            count += 1;
-        }
-        if self.wake_up > 0 {
-            count += 1;
-        }
-        if self.collect_sapphire > 0 {
-            count += 1;
-        }
-        if self.collect_ruby > 0 {
-            count += 1;
-        }
-        if self.open_chest > 0 {
-            count += 1;
-        }
-        if self.make_diamond_pickaxe > 0 {
-            count += 1;
-        }
-        if self.make_diamond_sword > 0 {
-            count += 1;
-        }
-        if self.make_bow > 0 {
-            count += 1;
-        }
-        if self.make_arrow > 0 {
-            count += 1;
-        }
-        if self.make_iron_armor > 0 {
-            count += 1;
-        }
-        if self.make_diamond_armor > 0 {
-            count += 1;
-        }
-        if self.defeat_orc_soldier > 0 {
-            count += 1;
-        }
-        if self.defeat_orc_mage > 0 {
-            count += 1;
-        }
-        if self.defeat_knight > 0 {
-            count += 1;
-        }
-        if self.defeat_knight_archer > 0 {
-            count += 1;
-        }
-        if self.defeat_troll > 0 {
-            count += 1;
-        }
-        if self.drink_potion > 0 {
-            count += 1;
-        }
-        if self.gain_xp > 0 {
-            count += 1;
-        }
-        if self.reach_level > 0 {
-            count += 1;
-        }
-        count
+        self.unlocked_bits().count_ones()
+    }
+
+    /// Names unlocked here that were still locked in `prev`, in canonical
+    /// order. One bitset diff instead of per-name string lookups, shared
+    /// by reward calculation and recording analysis.
+    pub fn newly_unlocked_since(&self, prev: &Achievements) -> Vec<&'static str> {
+        let mut bits = self.unlocked_bits() & !prev.unlocked_bits();
+        let mut unlocked = Vec::new();
+        while bits != 0 {
+            let i = bits.trailing_zeros() as usize;
+            unlocked.push(Self::NAMES[i]);
+            bits &= bits - 1;
+        }
+        unlocked
     }
 
     /// Get a list of all achievement names
     pub fn all_names() -> &'static [&'static str] {
-        &[
-            "collect_coal",
-            "collect_diamond",
-            "collect_drink",
-            "collect_iron",
-            "collect_sapling",
-            "collect_stone",
-            "collect_wood",
-            "defeat_skeleton",
-            "defeat_zombie",
-            "eat_cow",
-            "eat_plant",
-            "make_iron_pickaxe",
-            "make_iron_sword",
-            "make_stone_pickaxe",
-            "make_stone_sword",
-            "make_wood_pickaxe",
-            "make_wood_sword",
-            "place_furnace",
-            "place_plant",
-            "place_stone",
-            "place_table",
-            "wake_up",
-        ]
+        &Self::NAMES[..22]
     }
 
     pub fn craftax_names() -> &'static [&'static str] {
-        &[
-            "collect_sapphire",
-            "collect_ruby",
-            "open_chest",
-            "make_diamond_pickaxe",
-            "make_diamond_sword",
-            "make_bow",
-            "make_arrow",
-            "make_iron_armor",
-            "make_diamond_armor",
-            "defeat_orc_soldier",
-            "defeat_orc_mage",
-            "defeat_knight",
-            "defeat_knight_archer",
-            "defeat_troll",
-            "drink_potion",
-            "gain_xp",
-            "reach_level",
-        ]
+        &Self::NAMES[22..40]
+    }
+
+    /// Achievements specific to crafter-rs extensions (not in Python Crafter
+    /// or Craftax)
+    pub fn extended_names() -> &'static [&'static str] {
+        &Self::NAMES[40..]
     }
 
     pub fn all_names_with_craftax() -> Vec<&'static str> {
-        let mut names = Vec::from(Self::all_names());
-        names.extend(Self::craftax_names());
-        names
+        Self::NAMES.to_vec()
     }
 
     /// Get achievement count by name
     pub fn get(&self, name: &str) -> Option<u32> {
-        match name {
-            "collect_coal" => Some(self.collect_coal),
-            "collect_diamond" => Some(self.collect_diamond),
-            "collect_drink" => Some(self.collect_drink),
-            "collect_iron" => Some(self.collect_iron),
-            "collect_sapling" => Some(self.collect_sapling),
-            "collect_stone" => Some(self.collect_stone),
-            "collect_wood" => Some(self.collect_wood),
-            "defeat_skeleton" => Some(self.defeat_skeleton),
-            "defeat_zombie" => Some(self.defeat_zombie),
-            "eat_cow" => Some(self.eat_cow),
-            "eat_plant" => Some(self.eat_plant),
-            "make_iron_pickaxe" => Some(self.make_iron_pickaxe),
-            "make_iron_sword" => Some(self.make_iron_sword),
-            "make_stone_pickaxe" => Some(self.make_stone_pickaxe),
-            "make_stone_sword" => Some(self.make_stone_sword),
-            "make_wood_pickaxe" => Some(self.make_wood_pickaxe),
-            "make_wood_sword" => Some(self.make_wood_sword),
-            "place_furnace" => Some(self.place_furnace),
-            "place_plant" => Some(self.place_plant),
-            "place_stone" => Some(self.place_stone),
-            "place_table" => Some(self.place_table),
-            "wake_up" => Some(self.wake_up),
-            "collect_sapphire" => Some(self.collect_sapphire),
-            "collect_ruby" => Some(self.collect_ruby),
-            "open_chest" => Some(self.open_chest),
-            "make_diamond_pickaxe" => Some(self.make_diamond_pickaxe),
-            "make_diamond_sword" => Some(self.make_diamond_sword),
-            "make_bow" => Some(self.make_bow),
-            "make_arrow" => Some(self.make_arrow),
-            "make_iron_armor" => Some(self.make_iron_armor),
-            "make_diamond_armor" => Some(self.make_diamond_armor),
-            "defeat_orc_soldier" => Some(self.defeat_orc_soldier),
-            "defeat_orc_mage" => Some(self.defeat_orc_mage),
-            "defeat_knight" => Some(self.defeat_knight),
-            "defeat_knight_archer" => Some(self.defeat_knight_archer),
-            "defeat_troll" => Some(self.defeat_troll),
-            "drink_potion" => Some(self.drink_potion),
-            "gain_xp" => Some(self.gain_xp),
-            "reach_level" => Some(self.reach_level),
-            _ => None,
-        }
+        Self::NAMES
+            .iter()
+            .position(|&n| n == name)
+            .map(|i| self.counters()[i])
     }
 
-    /// Convert to a map of achievement name -> count
+    /// Convert to a map of achievement name -> count. Covers every tracked
+    /// achievement (classic, Craftax, and extended), so exported maps never
+    /// silently drop counts from enabled addons.
     pub fn to_map(&self) -> std::collections::HashMap<String, u32> {
-        let mut map = std::collections::HashMap::new();
-        for name in Self::all_names() {
-            if let Some(count) = self.get(name) {
-                map.insert(name.to_string(), count);
-            }
-        }
-        map
+        Self::NAMES
+            .iter()
+            .zip(self.counters())
+            .map(|(&name, count)| (name.to_string(), count))
+            .collect()
     }
 
     /// Get rewards vector (binary: 0 or 1 for each achievement this tick)
@@ -313,3 +268,51 @@ impl Achievements {
             .collect()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_name_tables_slice_canonical_order() {
+        assert_eq!(Achievements::all_names().len(), 22);
+        assert_eq!(Achievements::craftax_names().len(), 18);
+        assert_eq!(Achievements::extended_names().len(), 6);
+        assert_eq!(Achievements::all_names_with_craftax().len(), Achievements::COUNT);
+        // Every canonical name resolves through the by-name accessor
+        for name in Achievements::NAMES {
+            assert_eq!(Achievements::default().get(name), Some(0));
+        }
+    }
+
+    #[test]
+    fn test_unlocked_bits_and_total() {
+        let mut a = Achievements::default();
+        assert_eq!(a.unlocked_bits(), 0);
+        assert_eq!(a.total_unlocked(), 0);
+
+        a.collect_wood = 3;
+        a.escort_knight = 1;
+        assert_eq!(a.total_unlocked(), 2);
+        assert_ne!(a.unlocked_bits() & (1 << (Achievements::COUNT - 1)), 0);
+    }
+
+    #[test]
+    fn test_newly_unlocked_since_diffs_by_bitset() {
+        let prev = Achievements {
+            collect_wood: 1,
+            ..Default::default()
+        };
+
+        let mut curr = prev.clone();
+        curr.collect_wood = 5; // count increase, not a new unlock
+        curr.place_table = 1;
+        curr.defeat_zombie = 1;
+
+        assert_eq!(
+            curr.newly_unlocked_since(&prev),
+            vec!["defeat_zombie", "place_table"]
+        );
+        assert!(prev.newly_unlocked_since(&curr).is_empty());
+    }
+}
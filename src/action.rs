@@ -1,6 +1,7 @@
 //! Player actions - classic + craftax extensions
 
 use serde::{Deserialize, Serialize};
+use std::fmt;
 
 /// All possible player actions in the game
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Serialize, Deserialize, Default)]
@@ -62,9 +63,173 @@ pub enum Action {
     DrinkPotionPink = 27,
     DrinkPotionCyan = 28,
     DrinkPotionYellow = 29,
+    /// Craft spike trap (requires: table, wood >= 1, stone >= 1)
+    MakeSpikeTrap = 30,
+    /// Craft door (requires: table, wood >= 2)
+    MakeDoor = 31,
+    /// Craft fence (requires: table, wood >= 1)
+    MakeFence = 32,
+    /// Place spike trap
+    PlaceSpikeTrap = 33,
+    /// Place door (placed closed)
+    PlaceDoor = 34,
+    /// Place fence
+    PlaceFence = 35,
+}
+
+/// Named action-space profiles. Each profile defines which `Action`
+/// variants are legal and a stable profile-local integer indexing for RL,
+/// so classic-Crafter agents are not forced into the enlarged Craftax
+/// action space.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Serialize, Deserialize, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum ActionProfile {
+    /// Movement, interaction, and sleep only (7 actions)
+    Minimal,
+    /// The 17 Python Crafter actions
+    Classic,
+    /// Every action, including Craftax and crafter-rs extensions
+    #[default]
+    CraftaxFull,
+}
+
+impl ActionProfile {
+    /// The legal actions in stable index order; an action's position in
+    /// this slice is its integer encoding under the profile. These tables
+    /// are append-only: new actions must be added at the end so existing
+    /// encodings in recorded data and trained policies never shift.
+    pub const fn action_table(&self) -> &'static [Action] {
+        match self {
+            ActionProfile::Minimal => &Action::MINIMAL,
+            ActionProfile::Classic => &Action::CLASSIC,
+            ActionProfile::CraftaxFull => &Action::ALL,
+        }
+    }
+
+    /// The legal actions in stable index order; an action's position in
+    /// this list is its integer encoding under the profile
+    pub fn actions(&self) -> Vec<Action> {
+        self.action_table().to_vec()
+    }
+
+    /// Check whether an action is legal under this profile
+    pub fn contains(&self, action: Action) -> bool {
+        match self {
+            ActionProfile::CraftaxFull => true,
+            _ => self.action_table().contains(&action),
+        }
+    }
+
+    /// Map a profile-local integer index to an action
+    pub fn action_from_index(&self, index: usize) -> Option<Action> {
+        self.action_table().get(index).copied()
+    }
+
+    /// The profile-local integer index of an action, if it is legal
+    pub fn index_of(&self, action: Action) -> Option<usize> {
+        self.action_table().iter().position(|&a| a == action)
+    }
+
+    /// The size of the action space under this profile
+    pub fn num_actions(&self) -> usize {
+        self.action_table().len()
+    }
 }
 
 impl Action {
+    /// Every action in stable index order: `ALL[i].to_index() == i`.
+    /// Append-only — new actions go at the end with the next discriminant,
+    /// never in the middle, so serialized encodings stay valid forever.
+    /// The `const _` guard below enforces this at compile time.
+    pub const ALL: [Action; 36] = [
+        Action::Noop,
+        Action::MoveLeft,
+        Action::MoveRight,
+        Action::MoveUp,
+        Action::MoveDown,
+        Action::Do,
+        Action::Sleep,
+        Action::PlaceStone,
+        Action::PlaceTable,
+        Action::PlaceFurnace,
+        Action::PlacePlant,
+        Action::MakeWoodPickaxe,
+        Action::MakeStonePickaxe,
+        Action::MakeIronPickaxe,
+        Action::MakeWoodSword,
+        Action::MakeStoneSword,
+        Action::MakeIronSword,
+        Action::MakeDiamondPickaxe,
+        Action::MakeDiamondSword,
+        Action::MakeIronArmor,
+        Action::MakeDiamondArmor,
+        Action::MakeBow,
+        Action::MakeArrow,
+        Action::ShootArrow,
+        Action::DrinkPotionRed,
+        Action::DrinkPotionGreen,
+        Action::DrinkPotionBlue,
+        Action::DrinkPotionPink,
+        Action::DrinkPotionCyan,
+        Action::DrinkPotionYellow,
+        Action::MakeSpikeTrap,
+        Action::MakeDoor,
+        Action::MakeFence,
+        Action::PlaceSpikeTrap,
+        Action::PlaceDoor,
+        Action::PlaceFence,
+    ];
+
+    /// The 17 Python Crafter actions in the classic index order
+    pub const CLASSIC: [Action; 17] = [
+        Action::Noop,
+        Action::MoveLeft,
+        Action::MoveRight,
+        Action::MoveUp,
+        Action::MoveDown,
+        Action::Do,
+        Action::Sleep,
+        Action::PlaceStone,
+        Action::PlaceTable,
+        Action::PlaceFurnace,
+        Action::PlacePlant,
+        Action::MakeWoodPickaxe,
+        Action::MakeStonePickaxe,
+        Action::MakeIronPickaxe,
+        Action::MakeWoodSword,
+        Action::MakeStoneSword,
+        Action::MakeIronSword,
+    ];
+
+    /// Movement, interaction, and sleep only
+    pub const MINIMAL: [Action; 7] = [
+        Action::Noop,
+        Action::MoveLeft,
+        Action::MoveRight,
+        Action::MoveUp,
+        Action::MoveDown,
+        Action::Do,
+        Action::Sleep,
+    ];
+
+    /// The stable global integer encoding of this action (its position in
+    /// [`Action::ALL`]). For the profile-local encoding use
+    /// [`Action::index_in`] / [`ActionProfile::index_of`].
+    pub const fn to_index(&self) -> u8 {
+        *self as u8
+    }
+
+    /// Map a profile-local integer index back to an action
+    pub fn from_index_in(profile: ActionProfile, index: usize) -> Option<Action> {
+        profile.action_from_index(index)
+    }
+
+    /// The profile-local integer index of this action, if it is legal
+    /// under the profile
+    pub fn index_in(&self, profile: ActionProfile) -> Option<usize> {
+        profile.index_of(*self)
+    }
+
     /// Get the movement delta for this action, if it's a movement action
     pub fn movement_delta(&self) -> Option<(i32, i32)> {
         match self {
@@ -100,6 +265,9 @@ impl Action {
                 | Action::MakeDiamondArmor
                 | Action::MakeBow
                 | Action::MakeArrow
+                | Action::MakeSpikeTrap
+                | Action::MakeDoor
+                | Action::MakeFence
         )
     }
 
@@ -107,11 +275,17 @@ impl Action {
     pub fn is_placement(&self) -> bool {
         matches!(
             self,
-            Action::PlaceStone | Action::PlaceTable | Action::PlaceFurnace | Action::PlacePlant
+            Action::PlaceStone
+                | Action::PlaceTable
+                | Action::PlaceFurnace
+                | Action::PlacePlant
+                | Action::PlaceSpikeTrap
+                | Action::PlaceDoor
+                | Action::PlaceFence
         )
     }
 
-    /// Convert from action index (0-29) to Action
+    /// Convert from action index (0-35) to Action
     pub fn from_index(index: u8) -> Option<Action> {
         match index {
             0 => Some(Action::Noop),
@@ -144,70 +318,49 @@ impl Action {
             27 => Some(Action::DrinkPotionPink),
             28 => Some(Action::DrinkPotionCyan),
             29 => Some(Action::DrinkPotionYellow),
+            30 => Some(Action::MakeSpikeTrap),
+            31 => Some(Action::MakeDoor),
+            32 => Some(Action::MakeFence),
+            33 => Some(Action::PlaceSpikeTrap),
+            34 => Some(Action::PlaceDoor),
+            35 => Some(Action::PlaceFence),
             _ => None,
         }
     }
 
     /// Get all available actions
     pub fn all() -> Vec<Action> {
-        vec![
-            Action::Noop,
-            Action::MoveLeft,
-            Action::MoveRight,
-            Action::MoveUp,
-            Action::MoveDown,
-            Action::Do,
-            Action::Sleep,
-            Action::PlaceStone,
-            Action::PlaceTable,
-            Action::PlaceFurnace,
-            Action::PlacePlant,
-            Action::MakeWoodPickaxe,
-            Action::MakeStonePickaxe,
-            Action::MakeIronPickaxe,
-            Action::MakeWoodSword,
-            Action::MakeStoneSword,
-            Action::MakeIronSword,
-            Action::MakeDiamondPickaxe,
-            Action::MakeDiamondSword,
-            Action::MakeIronArmor,
-            Action::MakeDiamondArmor,
-            Action::MakeBow,
-            Action::MakeArrow,
-            Action::ShootArrow,
-            Action::DrinkPotionRed,
-            Action::DrinkPotionGreen,
-            Action::DrinkPotionBlue,
-            Action::DrinkPotionPink,
-            Action::DrinkPotionCyan,
-            Action::DrinkPotionYellow,
-        ]
+        Self::ALL.to_vec()
     }
 
     /// Classic action set (17 actions)
     pub fn classic_actions() -> [Action; 17] {
-        [
-            Action::Noop,
-            Action::MoveLeft,
-            Action::MoveRight,
-            Action::MoveUp,
-            Action::MoveDown,
-            Action::Do,
-            Action::Sleep,
-            Action::PlaceStone,
-            Action::PlaceTable,
-            Action::PlaceFurnace,
-            Action::PlacePlant,
-            Action::MakeWoodPickaxe,
-            Action::MakeStonePickaxe,
-            Action::MakeIronPickaxe,
-            Action::MakeWoodSword,
-            Action::MakeStoneSword,
-            Action::MakeIronSword,
-        ]
+        Self::CLASSIC
     }
 }
 
+// Compile-time guard: every action's position in `ALL` equals its explicit
+// discriminant, and the profile tables are strictly increasing by
+// discriminant. Inserting a variant in the middle of the enum or a table
+// fails the build instead of silently shifting integer encodings.
+const _: () = {
+    let mut i = 0;
+    while i < Action::ALL.len() {
+        assert!(Action::ALL[i] as usize == i);
+        i += 1;
+    }
+    let mut i = 1;
+    while i < Action::CLASSIC.len() {
+        assert!((Action::CLASSIC[i - 1] as u8) < Action::CLASSIC[i] as u8);
+        i += 1;
+    }
+    let mut i = 1;
+    while i < Action::MINIMAL.len() {
+        assert!((Action::MINIMAL[i - 1] as u8) < Action::MINIMAL[i] as u8);
+        i += 1;
+    }
+};
+
 impl From<Action> for u8 {
     fn from(action: Action) -> u8 {
         action as u8
@@ -221,3 +374,191 @@ impl TryFrom<u8> for Action {
         Action::from_index(value).ok_or(())
     }
 }
+
+/// Error from [`parse_script`]: the offending token and the 1-based line
+/// it appeared on
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ScriptError {
+    pub line: usize,
+    pub token: String,
+}
+
+impl fmt::Display for ScriptError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "unknown action token '{}' on line {}", self.token, self.line)
+    }
+}
+
+impl std::error::Error for ScriptError {}
+
+/// Parse a single action token: the canonical snake_case name
+/// (`move_left`, `make_wood_pickaxe`, ...) or a common alias
+/// (`w`/`a`/`s`/`d`, compass directions, `use`, `wood_pickaxe`, ...)
+pub fn parse_action_token(token: &str) -> Option<Action> {
+    match token.to_ascii_lowercase().as_str() {
+        "noop" => Some(Action::Noop),
+        "w" | "up" | "north" | "move_up" => Some(Action::MoveUp),
+        "s" | "down" | "south" | "move_down" => Some(Action::MoveDown),
+        "a" | "left" | "west" | "move_left" => Some(Action::MoveLeft),
+        "d" | "right" | "east" | "move_right" => Some(Action::MoveRight),
+        "e" | "do" | "use" | "interact" => Some(Action::Do),
+        "sleep" => Some(Action::Sleep),
+        "table" | "place_table" => Some(Action::PlaceTable),
+        "furnace" | "place_furnace" => Some(Action::PlaceFurnace),
+        "plant" | "place_plant" => Some(Action::PlacePlant),
+        "stone" | "place_stone" => Some(Action::PlaceStone),
+        "wood_sword" | "make_wood_sword" => Some(Action::MakeWoodSword),
+        "wood_pickaxe" | "make_wood_pickaxe" => Some(Action::MakeWoodPickaxe),
+        "stone_sword" | "make_stone_sword" => Some(Action::MakeStoneSword),
+        "stone_pickaxe" | "make_stone_pickaxe" => Some(Action::MakeStonePickaxe),
+        "iron_sword" | "make_iron_sword" => Some(Action::MakeIronSword),
+        "iron_pickaxe" | "make_iron_pickaxe" => Some(Action::MakeIronPickaxe),
+        "diamond_sword" | "make_diamond_sword" => Some(Action::MakeDiamondSword),
+        "diamond_pickaxe" | "make_diamond_pickaxe" => Some(Action::MakeDiamondPickaxe),
+        "bow" | "make_bow" => Some(Action::MakeBow),
+        "arrow" | "make_arrow" => Some(Action::MakeArrow),
+        "iron_armor" | "make_iron_armor" => Some(Action::MakeIronArmor),
+        "diamond_armor" | "make_diamond_armor" => Some(Action::MakeDiamondArmor),
+        "shoot" | "shoot_arrow" => Some(Action::ShootArrow),
+        "potion_red" | "drink_red" | "drink_potion_red" => Some(Action::DrinkPotionRed),
+        "potion_green" | "drink_green" | "drink_potion_green" => Some(Action::DrinkPotionGreen),
+        "potion_blue" | "drink_blue" | "drink_potion_blue" => Some(Action::DrinkPotionBlue),
+        "potion_pink" | "drink_pink" | "drink_potion_pink" => Some(Action::DrinkPotionPink),
+        "potion_cyan" | "drink_cyan" | "drink_potion_cyan" => Some(Action::DrinkPotionCyan),
+        "potion_yellow" | "drink_yellow" | "drink_potion_yellow" => Some(Action::DrinkPotionYellow),
+        "trap" | "make_spike_trap" => Some(Action::MakeSpikeTrap),
+        "door" | "make_door" => Some(Action::MakeDoor),
+        "fence" | "make_fence" => Some(Action::MakeFence),
+        "place_trap" | "place_spike_trap" => Some(Action::PlaceSpikeTrap),
+        "place_door" => Some(Action::PlaceDoor),
+        "place_fence" => Some(Action::PlaceFence),
+        _ => None,
+    }
+}
+
+/// Expand one script token into actions, honoring a numeric repeat suffix
+/// ("w5", "right3", "do2"). Returns `None` for unknown tokens.
+pub fn expand_action_token(token: &str) -> Option<Vec<Action>> {
+    let trimmed = token.trim();
+    if trimmed.is_empty() {
+        return Some(Vec::new());
+    }
+    let split = trimmed
+        .char_indices()
+        .find(|(_, ch)| ch.is_ascii_digit())
+        .map(|(idx, _)| idx)
+        .unwrap_or(trimmed.len());
+    let (prefix, suffix) = trimmed.split_at(split);
+    let count = if suffix.is_empty() {
+        1
+    } else {
+        suffix.parse::<usize>().ok()?
+    };
+    let action = parse_action_token(prefix)?;
+    Some(vec![action; count])
+}
+
+/// Parse a whitespace-separated action script into a flat action list.
+///
+/// Accepts every token [`parse_action_token`] knows, numeric repeat
+/// suffixes ("w5"), and `#` comments running to end of line. This is the
+/// one parser shared by the snapshot console, scenario files, and any
+/// agent that drives the game with text commands.
+pub fn parse_script(input: &str) -> Result<Vec<Action>, ScriptError> {
+    let mut actions = Vec::new();
+    for (line_idx, line) in input.lines().enumerate() {
+        let code = line.split('#').next().unwrap_or("");
+        for token in code.split_whitespace() {
+            match expand_action_token(token) {
+                Some(expanded) => actions.extend(expanded),
+                None => {
+                    return Err(ScriptError {
+                        line: line_idx + 1,
+                        token: token.to_string(),
+                    })
+                }
+            }
+        }
+    }
+    Ok(actions)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_to_index_from_index_roundtrip() {
+        for action in Action::ALL {
+            assert_eq!(Action::from_index(action.to_index()), Some(action));
+        }
+        assert_eq!(Action::from_index(Action::ALL.len() as u8), None);
+    }
+
+    #[test]
+    fn test_profile_tables_are_stable() {
+        assert_eq!(Action::ALL.len(), 36);
+        assert_eq!(Action::CLASSIC.len(), 17);
+        assert_eq!(Action::MINIMAL.len(), 7);
+
+        // Spot-check encodings that recorded data depends on
+        assert_eq!(Action::Noop.to_index(), 0);
+        assert_eq!(Action::MakeIronSword.to_index(), 16);
+        assert_eq!(Action::MakeFence.to_index(), 32);
+        assert_eq!(Action::PlaceFence.to_index(), 35);
+
+        // Profile tables are prefixes/subsets in global discriminant order
+        for (i, action) in Action::CLASSIC.iter().enumerate() {
+            assert_eq!(action.to_index() as usize, i);
+        }
+    }
+
+    #[test]
+    fn test_profile_local_indexing_helpers() {
+        let profile = ActionProfile::Classic;
+        for (i, &action) in profile.action_table().iter().enumerate() {
+            assert_eq!(action.index_in(profile), Some(i));
+            assert_eq!(Action::from_index_in(profile, i), Some(action));
+        }
+        assert_eq!(Action::MakeFence.index_in(profile), None);
+        assert_eq!(Action::from_index_in(profile, 17), None);
+    }
+
+    #[test]
+    fn test_parse_script_expands_repeats_and_aliases() {
+        let actions = parse_script("w2 right do fence").unwrap();
+        assert_eq!(
+            actions,
+            vec![
+                Action::MoveUp,
+                Action::MoveUp,
+                Action::MoveRight,
+                Action::Do,
+                Action::MakeFence,
+            ]
+        );
+    }
+
+    #[test]
+    fn test_parse_script_strips_comments() {
+        let script = "# gather wood\nd3 e # chop the tree\n\n# done\n";
+        let actions = parse_script(script).unwrap();
+        assert_eq!(
+            actions,
+            vec![
+                Action::MoveRight,
+                Action::MoveRight,
+                Action::MoveRight,
+                Action::Do,
+            ]
+        );
+    }
+
+    #[test]
+    fn test_parse_script_reports_unknown_tokens() {
+        let err = parse_script("w2\nbogus3").unwrap_err();
+        assert_eq!(err.line, 2);
+        assert_eq!(err.token, "bogus3");
+        assert!(err.to_string().contains("bogus3"));
+    }
+}
@@ -0,0 +1,826 @@
+//! `crafter-serve`: a persistent session server for ops deployments.
+//!
+//! Serves the snapshot API as JSON over HTTP on a single port, with the
+//! pieces a long-running environment service needs in one binary:
+//!
+//! - `POST /step` — a [`SnapshotRequest`] as JSON; creates or resumes a
+//!   session, applies the actions, returns the snapshot as JSON
+//! - `GET /sessions` — IDs of live sessions
+//! - `POST /sessions/<id>/save`, `POST /sessions/<id>/load`,
+//!   `DELETE /sessions/<id>` — persistence to the state dir and cleanup
+//! - `GET /metrics` — plain-text counters (uptime, requests, steps,
+//!   config reloads) for scraping
+//! - `GET /healthz` — liveness probe
+//! - `GET /admin/usage` — per-token usage accounting (admin tokens only)
+//!
+//! Rule configs live in a watched directory (`--rules-dir`, default
+//! `configs/`). Whenever any config file in it changes, the server
+//! reloads the named default rules (`--rules <name>`) and applies them
+//! to sessions created afterwards; running sessions keep the rules they
+//! started with. The rules dir also backs `config_name` lookups in step
+//! requests.
+//!
+//! With `--tokens <file>` the server requires `Authorization: Bearer`
+//! on everything except `/healthz` and `/metrics`, and enforces
+//! per-token quotas. The token file is TOML:
+//!
+//! ```toml
+//! [[tokens]]
+//! token = "alice-secret"
+//! name = "alice"
+//! max_sessions = 8        # omit for unlimited
+//! max_steps_per_sec = 200 # omit for unlimited
+//! admin = false
+//! ```
+//!
+//! Sessions belong to the token that created them; other tokens cannot
+//! step, save, or delete them (admin tokens can). Without `--tokens`
+//! the server runs open, as before.
+//!
+//! With `--idle-timeout <secs>` sessions untouched for that long are
+//! checkpointed into the state dir and dropped from memory; a later
+//! request for the same session ID restores them transparently, so
+//! long-lived deployments don't accumulate every session ever started.
+//!
+//! Flags: `--addr <host:port>` (default `127.0.0.1:7878`),
+//! `--rules-dir <dir>`, `--rules <name>` (default `default`),
+//! `--state-dir <dir>` (default `saves/`), `--tokens <file>`,
+//! `--idle-timeout <secs>` (default off).
+
+use crafter_core::saveload::SaveData;
+use crafter_core::snapshot::IdlePolicy;
+use crafter_core::{SessionConfig, SnapshotAction, SnapshotManager, SnapshotRequest, SnapshotResponse};
+use serde::Deserialize;
+use serde_json::json;
+use std::collections::HashMap;
+use std::io::{BufRead, BufReader, Read, Write};
+use std::net::{TcpListener, TcpStream};
+use std::path::{Path, PathBuf};
+use std::time::{Instant, SystemTime};
+
+/// Step request wire format; actions use the same names as the
+/// interactive snapshot demo (`move_left`, `do`, `make_arrow*5`, ...)
+#[derive(Debug, Deserialize)]
+struct WireRequest {
+    session_id: Option<String>,
+    seed: Option<u64>,
+    #[serde(default)]
+    actions: Vec<String>,
+    view_size: Option<u32>,
+    config_name: Option<String>,
+    config_toml: Option<String>,
+    /// Roll the whole batch back if any action's precondition fails
+    #[serde(default)]
+    transactional: bool,
+}
+
+/// One entry in the `--tokens` file
+#[derive(Debug, Clone, Deserialize)]
+struct TokenEntry {
+    token: String,
+    /// Display name used in usage accounting; defaults to the token
+    name: Option<String>,
+    /// Most live sessions this token may own at once
+    max_sessions: Option<usize>,
+    /// Most steps this token may submit per wall-clock second
+    max_steps_per_sec: Option<u64>,
+    #[serde(default)]
+    admin: bool,
+}
+
+#[derive(Debug, Deserialize)]
+struct TokensFile {
+    tokens: Vec<TokenEntry>,
+}
+
+/// Per-token usage counters and rate-limit window
+#[derive(Debug, Default)]
+struct TokenUsage {
+    requests: u64,
+    steps: u64,
+    rejected: u64,
+    /// Steps submitted in the current one-second window
+    window_steps: u64,
+    window_start: Option<Instant>,
+}
+
+/// Bearer-token registry, quotas, and session ownership. `None` when
+/// the server runs without `--tokens` (open mode).
+struct Auth {
+    tokens: HashMap<String, TokenEntry>,
+    usage: HashMap<String, TokenUsage>,
+    /// session_id -> owning token
+    owners: HashMap<String, String>,
+}
+
+/// Why a request was refused before reaching the snapshot manager
+enum Refusal {
+    Unauthorized,
+    Forbidden(&'static str),
+    QuotaExceeded(&'static str),
+}
+
+impl Refusal {
+    fn status(&self) -> u16 {
+        match self {
+            Refusal::Unauthorized => 401,
+            Refusal::Forbidden(_) => 403,
+            Refusal::QuotaExceeded(_) => 429,
+        }
+    }
+
+    fn message(&self) -> &str {
+        match self {
+            Refusal::Unauthorized => "missing or unknown bearer token",
+            Refusal::Forbidden(msg) | Refusal::QuotaExceeded(msg) => msg,
+        }
+    }
+}
+
+impl Auth {
+    fn load(path: &Path) -> Result<Self, String> {
+        let contents = std::fs::read_to_string(path).map_err(|e| e.to_string())?;
+        let file: TokensFile = toml::from_str(&contents).map_err(|e| e.to_string())?;
+        if file.tokens.is_empty() {
+            return Err("token file lists no tokens".to_string());
+        }
+        let mut tokens = HashMap::new();
+        for entry in file.tokens {
+            tokens.insert(entry.token.clone(), entry);
+        }
+        Ok(Self {
+            tokens,
+            usage: HashMap::new(),
+            owners: HashMap::new(),
+        })
+    }
+
+    /// Resolve the bearer token to a registered entry
+    fn authenticate(&mut self, bearer: Option<&str>) -> Result<TokenEntry, Refusal> {
+        let entry = bearer
+            .and_then(|t| self.tokens.get(t))
+            .cloned()
+            .ok_or(Refusal::Unauthorized)?;
+        self.usage.entry(entry.token.clone()).or_default().requests += 1;
+        Ok(entry)
+    }
+
+    /// Check quotas for a step request carrying `steps` actions against
+    /// `session_id` (None = new session), and account for it if allowed
+    fn check_step(
+        &mut self,
+        entry: &TokenEntry,
+        session_id: Option<&str>,
+        steps: u64,
+    ) -> Result<(), Refusal> {
+        if let Some(id) = session_id {
+            if let Some(owner) = self.owners.get(id) {
+                if owner != &entry.token && !entry.admin {
+                    self.reject(entry);
+                    return Err(Refusal::Forbidden("session belongs to another token"));
+                }
+            }
+        }
+
+        let owned = self
+            .owners
+            .values()
+            .filter(|owner| *owner == &entry.token)
+            .count();
+        let known = session_id.map(|id| self.owners.contains_key(id)).unwrap_or(false);
+        if !known {
+            if let Some(max) = entry.max_sessions {
+                if owned >= max {
+                    self.reject(entry);
+                    return Err(Refusal::QuotaExceeded("session quota exceeded"));
+                }
+            }
+        }
+
+        let usage = self.usage.entry(entry.token.clone()).or_default();
+        if let Some(max) = entry.max_steps_per_sec {
+            let now = Instant::now();
+            let fresh = usage
+                .window_start
+                .map(|start| now.duration_since(start).as_secs_f64() >= 1.0)
+                .unwrap_or(true);
+            if fresh {
+                usage.window_start = Some(now);
+                usage.window_steps = 0;
+            }
+            if usage.window_steps + steps > max {
+                usage.rejected += 1;
+                return Err(Refusal::QuotaExceeded("step rate exceeded"));
+            }
+            usage.window_steps += steps;
+        }
+        usage.steps += steps;
+        Ok(())
+    }
+
+    /// Require ownership (or admin) of an existing session
+    fn check_owner(&mut self, entry: &TokenEntry, session_id: &str) -> Result<(), Refusal> {
+        match self.owners.get(session_id) {
+            Some(owner) if owner == &entry.token || entry.admin => Ok(()),
+            Some(_) => {
+                self.reject(entry);
+                Err(Refusal::Forbidden("session belongs to another token"))
+            }
+            // Unowned sessions (e.g. created before auth was enabled)
+            // are fair game
+            None => Ok(()),
+        }
+    }
+
+    fn record_session(&mut self, session_id: &str, entry: &TokenEntry) {
+        self.owners
+            .entry(session_id.to_string())
+            .or_insert_with(|| entry.token.clone());
+    }
+
+    fn forget_session(&mut self, session_id: &str) {
+        self.owners.remove(session_id);
+    }
+
+    fn reject(&mut self, entry: &TokenEntry) {
+        self.usage.entry(entry.token.clone()).or_default().rejected += 1;
+    }
+
+    /// Usage accounting for the admin endpoint
+    fn usage_json(&self) -> serde_json::Value {
+        let mut clients = Vec::new();
+        for (token, entry) in &self.tokens {
+            let usage = self.usage.get(token);
+            let sessions = self.owners.values().filter(|owner| *owner == token).count();
+            clients.push(json!({
+                "name": entry.name.as_deref().unwrap_or(token.as_str()),
+                "admin": entry.admin,
+                "sessions_active": sessions,
+                "max_sessions": entry.max_sessions,
+                "max_steps_per_sec": entry.max_steps_per_sec,
+                "requests_total": usage.map(|u| u.requests).unwrap_or(0),
+                "steps_total": usage.map(|u| u.steps).unwrap_or(0),
+                "rejected_total": usage.map(|u| u.rejected).unwrap_or(0),
+            }));
+        }
+        clients.sort_by_key(|c| c["name"].as_str().unwrap_or("").to_string());
+        json!({ "clients": clients })
+    }
+}
+
+/// Counters exposed on `/metrics`
+struct ServeMetrics {
+    started: Instant,
+    requests: u64,
+    steps: u64,
+    errors: u64,
+    config_reloads: u64,
+}
+
+/// Watches the rules dir for changes by fingerprinting config files
+struct RulesWatcher {
+    dir: PathBuf,
+    name: String,
+    /// (path, modified, len) of every config file at the last check
+    fingerprint: Vec<(PathBuf, SystemTime, u64)>,
+}
+
+impl RulesWatcher {
+    fn new(dir: PathBuf, name: String) -> Self {
+        Self {
+            dir,
+            name,
+            fingerprint: Vec::new(),
+        }
+    }
+
+    fn scan(&self) -> Vec<(PathBuf, SystemTime, u64)> {
+        let mut entries = Vec::new();
+        let Ok(dir) = std::fs::read_dir(&self.dir) else {
+            return entries;
+        };
+        for entry in dir.flatten() {
+            let path = entry.path();
+            let is_config = path
+                .extension()
+                .and_then(|ext| ext.to_str())
+                .map(|ext| matches!(ext, "toml" | "yaml" | "yml"))
+                .unwrap_or(false);
+            if !is_config {
+                continue;
+            }
+            if let Ok(meta) = entry.metadata() {
+                let modified = meta.modified().unwrap_or(SystemTime::UNIX_EPOCH);
+                entries.push((path, modified, meta.len()));
+            }
+        }
+        entries.sort();
+        entries
+    }
+
+    /// Reload the default rules if any config file changed since the
+    /// last check. Returns the freshly loaded config when it did.
+    fn poll(&mut self) -> Option<SessionConfig> {
+        let current = self.scan();
+        if current == self.fingerprint {
+            return None;
+        }
+        self.fingerprint = current;
+        match SessionConfig::load_named(&self.name) {
+            Ok(config) => Some(config),
+            Err(err) => {
+                eprintln!(
+                    "crafter-serve: rules '{}' changed but failed to load, keeping previous: {}",
+                    self.name, err
+                );
+                None
+            }
+        }
+    }
+}
+
+fn main() {
+    let mut addr = "127.0.0.1:7878".to_string();
+    let mut rules_dir = PathBuf::from("configs");
+    let mut rules_name = "default".to_string();
+    let mut state_dir = PathBuf::from("saves");
+    let mut tokens_path: Option<PathBuf> = None;
+    let mut idle_timeout_secs: u64 = 0;
+
+    let mut args = std::env::args().skip(1);
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--addr" => addr = args.next().unwrap_or(addr),
+            "--rules-dir" => rules_dir = args.next().map(PathBuf::from).unwrap_or(rules_dir),
+            "--rules" => rules_name = args.next().unwrap_or(rules_name),
+            "--state-dir" => state_dir = args.next().map(PathBuf::from).unwrap_or(state_dir),
+            "--tokens" => tokens_path = args.next().map(PathBuf::from),
+            "--idle-timeout" => {
+                idle_timeout_secs = args.next().and_then(|v| v.parse().ok()).unwrap_or(0)
+            }
+            other => {
+                eprintln!("crafter-serve: unknown flag {}", other);
+                eprintln!("usage: crafter-serve [--addr host:port] [--rules-dir dir] [--rules name] [--state-dir dir] [--tokens file] [--idle-timeout secs]");
+                std::process::exit(2);
+            }
+        }
+    }
+
+    let mut auth = match &tokens_path {
+        Some(path) => match Auth::load(path) {
+            Ok(auth) => {
+                println!(
+                    "crafter-serve: auth enabled, {} token(s) from {}",
+                    auth.tokens.len(),
+                    path.display()
+                );
+                Some(auth)
+            }
+            Err(err) => {
+                eprintln!("crafter-serve: failed to load tokens {}: {}", path.display(), err);
+                std::process::exit(1);
+            }
+        },
+        None => None,
+    };
+
+    // Make `config_name` in step requests resolve against the rules dir
+    std::env::set_var("CRAFTER_CONFIG_DIR", &rules_dir);
+
+    let mut manager = SnapshotManager::new();
+    if idle_timeout_secs > 0 {
+        manager.set_idle_policy(Some(IdlePolicy {
+            idle_timeout: std::time::Duration::from_secs(idle_timeout_secs),
+            checkpoint_dir: state_dir.clone(),
+        }));
+        println!(
+            "crafter-serve: idle sessions checkpointed after {}s",
+            idle_timeout_secs
+        );
+    }
+    let mut watcher = RulesWatcher::new(rules_dir.clone(), rules_name.clone());
+    let mut metrics = ServeMetrics {
+        started: Instant::now(),
+        requests: 0,
+        steps: 0,
+        errors: 0,
+        config_reloads: 0,
+    };
+
+    // Establish the starting fingerprint; apply the rules if present so
+    // the first session already uses them
+    if let Some(config) = watcher.poll() {
+        manager.set_default_config(config);
+        println!(
+            "crafter-serve: loaded rules '{}' from {}",
+            rules_name,
+            rules_dir.display()
+        );
+    } else {
+        println!(
+            "crafter-serve: no rules '{}' in {}, using built-in defaults",
+            rules_name,
+            rules_dir.display()
+        );
+    }
+
+    let listener = match TcpListener::bind(&addr) {
+        Ok(listener) => listener,
+        Err(err) => {
+            eprintln!("crafter-serve: failed to bind {}: {}", addr, err);
+            std::process::exit(1);
+        }
+    };
+    println!("crafter-serve: listening on http://{}", addr);
+    println!("crafter-serve: state dir {}", state_dir.display());
+
+    for stream in listener.incoming() {
+        let Ok(stream) = stream else { continue };
+
+        // Pick up rule changes before the request so a new session in
+        // this very request already sees them
+        if let Some(config) = watcher.poll() {
+            manager.set_default_config(config);
+            metrics.config_reloads += 1;
+            println!("crafter-serve: reloaded rules '{}'", rules_name);
+        }
+
+        let evicted = manager.evict_idle();
+        if !evicted.is_empty() {
+            println!("crafter-serve: checkpointed {} idle session(s)", evicted.len());
+        }
+
+        metrics.requests += 1;
+        if let Err(err) = handle_connection(stream, &mut manager, &mut metrics, &mut auth, &state_dir) {
+            metrics.errors += 1;
+            eprintln!("crafter-serve: connection error: {}", err);
+        }
+    }
+}
+
+fn handle_connection(
+    stream: TcpStream,
+    manager: &mut SnapshotManager,
+    metrics: &mut ServeMetrics,
+    auth: &mut Option<Auth>,
+    state_dir: &Path,
+) -> std::io::Result<()> {
+    let mut reader = BufReader::new(stream);
+
+    let mut request_line = String::new();
+    reader.read_line(&mut request_line)?;
+    let mut parts = request_line.split_whitespace();
+    let method = parts.next().unwrap_or("").to_string();
+    let path = parts.next().unwrap_or("").to_string();
+
+    let mut content_length = 0usize;
+    let mut bearer: Option<String> = None;
+    loop {
+        let mut line = String::new();
+        reader.read_line(&mut line)?;
+        let line = line.trim_end();
+        if line.is_empty() {
+            break;
+        }
+        let lower = line.to_ascii_lowercase();
+        if let Some(value) = lower
+            .strip_prefix("content-length:")
+            .map(str::trim)
+            .and_then(|v| v.parse::<usize>().ok())
+        {
+            content_length = value;
+        }
+        if lower.starts_with("authorization:") {
+            bearer = line
+                .split_once(':')
+                .map(|(_, v)| v.trim())
+                .and_then(|v| v.strip_prefix("Bearer "))
+                .map(|t| t.trim().to_string());
+        }
+    }
+
+    let mut body = vec![0u8; content_length];
+    reader.read_exact(&mut body)?;
+    let stream = reader.into_inner();
+
+    let segments: Vec<&str> = path.split('/').filter(|s| !s.is_empty()).collect();
+
+    // Probes and scrapes stay open; everything else authenticates when
+    // a token file was given
+    let open_route = matches!(
+        (method.as_str(), segments.as_slice()),
+        ("GET", ["healthz"]) | ("GET", ["metrics"])
+    );
+    let client = match auth.as_mut() {
+        Some(auth) if !open_route => match auth.authenticate(bearer.as_deref()) {
+            Ok(entry) => Some(entry),
+            Err(refusal) => {
+                return write_json(
+                    stream,
+                    refusal.status(),
+                    &json!({ "error": refusal.message() }),
+                );
+            }
+        },
+        _ => None,
+    };
+
+    match (method.as_str(), segments.as_slice()) {
+        ("GET", ["healthz"]) => write_text(stream, 200, "ok\n"),
+        ("GET", ["metrics"]) => write_text(stream, 200, &render_metrics(manager, metrics)),
+        ("GET", ["admin", "usage"]) => match (auth.as_ref(), &client) {
+            (Some(auth), Some(entry)) if entry.admin => {
+                write_json(stream, 200, &auth.usage_json())
+            }
+            (Some(_), _) => write_json(stream, 403, &json!({ "error": "admin token required" })),
+            (None, _) => write_json(stream, 404, &json!({ "error": "auth not enabled" })),
+        },
+        ("GET", ["sessions"]) => {
+            let ids = manager.session_ids();
+            write_json(stream, 200, &json!({ "sessions": ids }))
+        }
+        ("POST", ["step"]) => handle_step(stream, manager, metrics, auth, client.as_ref(), &body),
+        ("POST", ["sessions", id, "save"]) => {
+            if let Some(refusal) = check_session_access(auth, client.as_ref(), id) {
+                return write_json(stream, refusal.status(), &json!({ "error": refusal.message() }));
+            }
+            handle_save(stream, manager, state_dir, id)
+        }
+        ("POST", ["sessions", id, "load"]) => {
+            if let Some(refusal) = check_session_access(auth, client.as_ref(), id) {
+                return write_json(stream, refusal.status(), &json!({ "error": refusal.message() }));
+            }
+            if let (Some(auth), Some(entry)) = (auth.as_mut(), client.as_ref()) {
+                auth.record_session(id, entry);
+            }
+            handle_load(stream, manager, state_dir, id)
+        }
+        ("DELETE", ["sessions", id]) => {
+            if let Some(refusal) = check_session_access(auth, client.as_ref(), id) {
+                return write_json(stream, refusal.status(), &json!({ "error": refusal.message() }));
+            }
+            if manager.remove_session(id).is_some() {
+                if let Some(auth) = auth.as_mut() {
+                    auth.forget_session(id);
+                }
+                write_json(stream, 200, &json!({ "removed": id }))
+            } else {
+                write_json(stream, 404, &json!({ "error": "unknown session" }))
+            }
+        }
+        _ => write_json(stream, 404, &json!({ "error": "unknown route" })),
+    }
+}
+
+fn check_session_access(
+    auth: &mut Option<Auth>,
+    client: Option<&TokenEntry>,
+    session_id: &str,
+) -> Option<Refusal> {
+    match (auth.as_mut(), client) {
+        (Some(auth), Some(entry)) => auth.check_owner(entry, session_id).err(),
+        _ => None,
+    }
+}
+
+fn handle_step(
+    stream: TcpStream,
+    manager: &mut SnapshotManager,
+    metrics: &mut ServeMetrics,
+    auth: &mut Option<Auth>,
+    client: Option<&TokenEntry>,
+    body: &[u8],
+) -> std::io::Result<()> {
+    let wire: WireRequest = match serde_json::from_slice(body) {
+        Ok(wire) => wire,
+        Err(err) => {
+            return write_json(stream, 400, &json!({ "error": format!("bad request: {}", err) }));
+        }
+    };
+
+    let mut actions = Vec::with_capacity(wire.actions.len());
+    for name in &wire.actions {
+        match SnapshotAction::from_str(name) {
+            Some(action) => actions.push(action),
+            None => {
+                let err = crafter_core::SnapshotError::invalid_action(name);
+                return write_json(
+                    stream,
+                    400,
+                    &json!({ "error": { "code": err.code, "message": err.message } }),
+                );
+            }
+        }
+    }
+
+    if let (Some(auth), Some(entry)) = (auth.as_mut(), client) {
+        if let Err(refusal) =
+            auth.check_step(entry, wire.session_id.as_deref(), actions.len() as u64)
+        {
+            return write_json(stream, refusal.status(), &json!({ "error": refusal.message() }));
+        }
+    }
+    metrics.steps += actions.len() as u64;
+
+    let response = manager.process(SnapshotRequest {
+        session_id: wire.session_id,
+        seed: wire.seed,
+        actions,
+        view_size: wire.view_size,
+        config_name: wire.config_name,
+        config_path: None,
+        config_toml: wire.config_toml,
+        transactional: wire.transactional,
+    });
+
+    // Map structured errors onto HTTP statuses; the body is the same
+    // response JSON either way so clients branch on `error.code`
+    let status = match &response.error {
+        Some(err) if err.code == crafter_core::SnapshotError::UNKNOWN_SESSION => 404,
+        Some(err) if err.code == crafter_core::SnapshotError::TRANSACTION_FAILED => 409,
+        Some(_) => 400,
+        None => 200,
+    };
+    if status == 200 {
+        if let (Some(auth), Some(entry)) = (auth.as_mut(), client) {
+            auth.record_session(&response.session_id, entry);
+        }
+    } else {
+        metrics.errors += 1;
+    }
+
+    write_json(stream, status, &response_json(&response))
+}
+
+fn handle_save(
+    stream: TcpStream,
+    manager: &mut SnapshotManager,
+    state_dir: &Path,
+    id: &str,
+) -> std::io::Result<()> {
+    let Some(session) = manager.get_session(id) else {
+        return write_json(stream, 404, &json!({ "error": "unknown session" }));
+    };
+    if let Err(err) = std::fs::create_dir_all(state_dir) {
+        return write_json(stream, 500, &json!({ "error": format!("state dir: {}", err) }));
+    }
+    let path = save_path(state_dir, id);
+    let save = SaveData::from_session(session, Some(id.to_string()));
+    match save.save_binary(&path) {
+        Ok(()) => write_json(
+            stream,
+            200,
+            &json!({ "saved": id, "path": path.display().to_string(), "step": save.step }),
+        ),
+        Err(err) => write_json(stream, 500, &json!({ "error": format!("save failed: {}", err) })),
+    }
+}
+
+fn handle_load(
+    stream: TcpStream,
+    manager: &mut SnapshotManager,
+    state_dir: &Path,
+    id: &str,
+) -> std::io::Result<()> {
+    let path = save_path(state_dir, id);
+    match SaveData::load_binary(&path) {
+        Ok(save) => {
+            let step = save.step;
+            manager.insert_session(id.to_string(), save.into_session());
+            write_json(stream, 200, &json!({ "loaded": id, "step": step }))
+        }
+        Err(err) => write_json(stream, 404, &json!({ "error": format!("load failed: {}", err) })),
+    }
+}
+
+/// Session IDs are UUIDs, but keep the filename safe regardless
+fn save_path(state_dir: &Path, id: &str) -> PathBuf {
+    let safe: String = id
+        .chars()
+        .map(|c| if c.is_ascii_alphanumeric() || c == '-' { c } else { '_' })
+        .collect();
+    state_dir.join(format!("{}.crafter", safe))
+}
+
+fn render_metrics(manager: &SnapshotManager, metrics: &ServeMetrics) -> String {
+    format!(
+        "crafter_serve_uptime_seconds {}\n\
+         crafter_serve_requests_total {}\n\
+         crafter_serve_errors_total {}\n\
+         crafter_serve_steps_total {}\n\
+         crafter_serve_sessions_active {}\n\
+         crafter_serve_config_reloads_total {}\n",
+        metrics.started.elapsed().as_secs(),
+        metrics.requests,
+        metrics.errors,
+        metrics.steps,
+        manager.session_ids().len(),
+        metrics.config_reloads,
+    )
+}
+
+fn response_json(response: &SnapshotResponse) -> serde_json::Value {
+    json!({
+        "session_id": response.session_id,
+        "step": response.step,
+        "done": response.done,
+        "done_reason": response.done_reason,
+        "player_pos": response.player_pos,
+        "player_facing": response.player_facing,
+        "stats": {
+            "health": response.stats.health,
+            "food": response.stats.food,
+            "drink": response.stats.drink,
+            "energy": response.stats.energy,
+        },
+        "inventory": {
+            "wood": response.inventory.wood,
+            "stone": response.inventory.stone,
+            "coal": response.inventory.coal,
+            "iron": response.inventory.iron,
+            "diamond": response.inventory.diamond,
+            "sapphire": response.inventory.sapphire,
+            "ruby": response.inventory.ruby,
+            "sapling": response.inventory.sapling,
+            "wood_pickaxe": response.inventory.wood_pickaxe,
+            "stone_pickaxe": response.inventory.stone_pickaxe,
+            "iron_pickaxe": response.inventory.iron_pickaxe,
+            "diamond_pickaxe": response.inventory.diamond_pickaxe,
+            "wood_sword": response.inventory.wood_sword,
+            "stone_sword": response.inventory.stone_sword,
+            "iron_sword": response.inventory.iron_sword,
+            "diamond_sword": response.inventory.diamond_sword,
+            "bow": response.inventory.bow,
+            "arrows": response.inventory.arrows,
+            "armor_helmet": response.inventory.armor_helmet,
+            "armor_chestplate": response.inventory.armor_chestplate,
+            "armor_leggings": response.inventory.armor_leggings,
+            "armor_boots": response.inventory.armor_boots,
+            "potion_red": response.inventory.potion_red,
+            "potion_green": response.inventory.potion_green,
+            "potion_blue": response.inventory.potion_blue,
+            "potion_pink": response.inventory.potion_pink,
+            "potion_cyan": response.inventory.potion_cyan,
+            "potion_yellow": response.inventory.potion_yellow,
+            "xp": response.inventory.xp,
+            "level": response.inventory.level,
+            "stat_points": response.inventory.stat_points,
+        },
+        "map_lines": response.map_lines,
+        "entities": response.entities.iter().map(|e| json!({
+            "kind": e.kind,
+            "pos": e.pos,
+            "health": e.health,
+        })).collect::<Vec<_>>(),
+        "achievements": response.achievements,
+        "newly_unlocked": response.newly_unlocked,
+        "reward": response.reward,
+        "available_actions": response.available_actions,
+        "hints": response.hints,
+        "run_id": response.run_id,
+        "labels": response.labels,
+        "effective_config": response
+            .effective_config
+            .as_ref()
+            .and_then(|c| serde_json::to_value(c).ok()),
+        "error": response.error.as_ref().map(|e| json!({
+            "code": e.code,
+            "message": e.message,
+            "failed_index": e.failed_index,
+        })),
+    })
+}
+
+fn write_json(stream: TcpStream, status: u16, body: &serde_json::Value) -> std::io::Result<()> {
+    write_response(stream, status, "application/json", &body.to_string())
+}
+
+fn write_text(stream: TcpStream, status: u16, body: &str) -> std::io::Result<()> {
+    write_response(stream, status, "text/plain; charset=utf-8", body)
+}
+
+fn write_response(
+    mut stream: TcpStream,
+    status: u16,
+    content_type: &str,
+    body: &str,
+) -> std::io::Result<()> {
+    let reason = match status {
+        200 => "OK",
+        400 => "Bad Request",
+        404 => "Not Found",
+        409 => "Conflict",
+        500 => "Internal Server Error",
+        _ => "OK",
+    };
+    write!(
+        stream,
+        "HTTP/1.1 {} {}\r\nContent-Type: {}\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+        status,
+        reason,
+        content_type,
+        body.len(),
+        body
+    )?;
+    stream.flush()
+}
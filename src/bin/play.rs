@@ -3,13 +3,6 @@ use crafter_core::material::Material;
 use crafter_core::entity::GameObject;
 
 fn main() {
-    let config = SessionConfig {
-        world_size: (64, 64),
-        seed: Some(777),
-        ..Default::default()
-    };
-    let mut session = Session::new(config);
-
     let mut args: Vec<String> = std::env::args().skip(1).collect();
     let repl_mode = if let Some(pos) = args.iter().position(|arg| arg == "--repl") {
         args.remove(pos);
@@ -17,6 +10,34 @@ fn main() {
     } else {
         false
     };
+    // `--seed N` and `--world-size WxH` match Session::repro_command, so
+    // a seed logged from any run can be replayed here directly
+    let mut seed = 777u64;
+    if let Some(pos) = args.iter().position(|arg| arg == "--seed") {
+        args.remove(pos);
+        if pos < args.len() {
+            seed = args.remove(pos).parse().expect("--seed takes a number");
+        }
+    }
+    let mut world_size = (64, 64);
+    if let Some(pos) = args.iter().position(|arg| arg == "--world-size") {
+        args.remove(pos);
+        if pos < args.len() {
+            let spec = args.remove(pos);
+            let (w, h) = spec.split_once('x').expect("--world-size takes WxH");
+            world_size = (
+                w.parse().expect("--world-size takes WxH"),
+                h.parse().expect("--world-size takes WxH"),
+            );
+        }
+    }
+
+    let config = SessionConfig {
+        world_size,
+        seed: Some(seed),
+        ..Default::default()
+    };
+    let mut session = Session::new(config);
 
     // Execute all actions
     for arg in &args {
@@ -158,6 +179,10 @@ fn print_state(session: &Session) {
                     Some(Material::Sapphire) => 'S',
                     Some(Material::Ruby) => 'R',
                     Some(Material::Chest) => 'H',
+                    Some(Material::SpikeTrap) => '^',
+                    Some(Material::DoorClosed) => 'D',
+                    Some(Material::DoorOpen) => '/',
+                    Some(Material::Fence) => 'x',
                     None => ' ',
                 }
             });
@@ -14,6 +14,8 @@ fn main() {
             run_headless_achievements(&config_name);
         } else if mode == "interactive" {
             run_interactive(&config_name);
+        } else if mode == "duel" {
+            run_duel(&config_name);
         } else {
             run_headless_probe(&config_name);
         }
@@ -34,6 +36,7 @@ fn main() {
         config_name: None,
         config_path: None,
         config_toml: None,
+        transactional: false,
     });
 
     print_snapshot(&response);
@@ -54,6 +57,7 @@ fn main() {
         config_name: None,
         config_path: None,
         config_toml: None,
+        transactional: false,
     });
 
     print_snapshot(&response);
@@ -84,6 +88,7 @@ fn main() {
         config_name: None,
         config_path: None,
         config_toml: None,
+        transactional: false,
     });
 
     print_snapshot(&response);
@@ -102,6 +107,7 @@ fn main() {
         config_name: None,
         config_path: None,
         config_toml: None,
+        transactional: false,
     });
 
     print_snapshot(&response);
@@ -133,6 +139,7 @@ fn run_interactive(config_name: &str) {
         config_name,
         config_path,
         config_toml: None,
+        transactional: false,
     });
     print_snapshot(&response);
 
@@ -156,15 +163,13 @@ fn run_interactive(config_name: &str) {
             continue;
         }
 
-        let mut actions = Vec::new();
-        for token in line.split_whitespace() {
-            let expanded = expand_actions_from_token(token);
-            if expanded.is_empty() {
-                println!("Unknown token: {}", token);
-            } else {
-                actions.extend(expanded);
+        let actions: Vec<SnapshotAction> = match crafter_core::action::parse_script(line) {
+            Ok(parsed) => parsed.into_iter().map(SnapshotAction::from).collect(),
+            Err(err) => {
+                println!("{}", err);
+                continue;
             }
-        }
+        };
 
         if actions.is_empty() {
             continue;
@@ -178,6 +183,7 @@ fn run_interactive(config_name: &str) {
             config_name: None,
             config_path: None,
             config_toml: None,
+            transactional: false,
         });
 
         print_snapshot(&response);
@@ -188,84 +194,133 @@ fn run_interactive(config_name: &str) {
     }
 }
 
-fn snapshot_config_source(config_name: &str) -> (Option<String>, Option<String>) {
-    if std::path::Path::new(config_name).exists() {
-        (None, Some(config_name.to_string()))
+fn run_duel(config_name: &str) {
+    let mut config = if let Ok(loaded) = SessionConfig::load_named(config_name) {
+        loaded
+    } else if std::path::Path::new(config_name).exists() {
+        SessionConfig::load_from_path(config_name).unwrap_or_default()
     } else {
-        (Some(config_name.to_string()), None)
+        SessionConfig::default()
+    };
+    if let Some(seed) = std::env::var("CRAFTER_SEED")
+        .ok()
+        .and_then(|v| v.parse::<u64>().ok())
+    {
+        config.seed = Some(seed);
     }
-}
 
-fn expand_actions_from_token(token: &str) -> Vec<SnapshotAction> {
-    let trimmed = token.trim();
-    if trimmed.is_empty() {
-        return Vec::new();
-    }
-    let mut split = trimmed.len();
-    for (idx, ch) in trimmed.char_indices() {
-        if ch.is_ascii_digit() {
-            split = idx;
+    let mut duel = crafter_core::Duel::new(config, Box::new(crafter_core::SurvivalPolicy::new()));
+
+    println!("=== Crafter Duel ===");
+    println!("You and the bot race on identical worlds; every action you");
+    println!("take gives the bot one too. Most achievements wins.");
+    println!("Type 'help' for commands, 'quit' to concede.\n");
+    print_duel_view(&duel);
+
+    let mut input = String::new();
+    loop {
+        input.clear();
+        print!("\n[{}] action> ", duel.scoreboard());
+        let _ = std::io::Write::flush(&mut std::io::stdout());
+        if std::io::stdin().read_line(&mut input).is_err() {
+            break;
+        }
+        let line = input.trim();
+        if line.is_empty() {
+            continue;
+        }
+        if line.eq_ignore_ascii_case("quit") || line.eq_ignore_ascii_case("exit") {
+            break;
+        }
+        if line.eq_ignore_ascii_case("help") {
+            print_interactive_help();
+            continue;
+        }
+
+        let actions = match crafter_core::action::parse_script(line) {
+            Ok(parsed) => parsed,
+            Err(err) => {
+                println!("{}", err);
+                continue;
+            }
+        };
+        for action in actions {
+            duel.step_human(action);
+            if duel.is_over() {
+                break;
+            }
+        }
+
+        print_duel_view(&duel);
+        if duel.is_over() {
             break;
         }
     }
-    let (prefix, suffix) = trimmed.split_at(split);
-    let count = if suffix.is_empty() {
-        1
-    } else {
-        suffix.parse::<usize>().unwrap_or(1)
-    };
-    if let Some(action) = parse_action_token(prefix) {
-        std::iter::repeat(action).take(count).collect()
-    } else {
-        Vec::new()
+
+    println!();
+    for line in duel.summary().lines() {
+        println!("{}", line);
     }
 }
 
-fn parse_action_token(token: &str) -> Option<SnapshotAction> {
-    match token.to_ascii_lowercase().as_str() {
-        "w" | "up" | "north" => Some(SnapshotAction::MoveUp),
-        "s" | "down" | "south" => Some(SnapshotAction::MoveDown),
-        "a" | "left" | "west" => Some(SnapshotAction::MoveLeft),
-        "d" | "right" | "east" => Some(SnapshotAction::MoveRight),
-        "e" | "do" | "use" => Some(SnapshotAction::Do),
-        "sleep" => Some(SnapshotAction::Sleep),
-        "table" | "place_table" => Some(SnapshotAction::PlaceTable),
-        "furnace" | "place_furnace" => Some(SnapshotAction::PlaceFurnace),
-        "plant" | "place_plant" => Some(SnapshotAction::PlacePlant),
-        "stone" | "place_stone" => Some(SnapshotAction::PlaceStone),
-        "wood_sword" => Some(SnapshotAction::MakeWoodSword),
-        "wood_pickaxe" => Some(SnapshotAction::MakeWoodPickaxe),
-        "stone_sword" => Some(SnapshotAction::MakeStoneSword),
-        "stone_pickaxe" => Some(SnapshotAction::MakeStonePickaxe),
-        "iron_sword" => Some(SnapshotAction::MakeIronSword),
-        "iron_pickaxe" => Some(SnapshotAction::MakeIronPickaxe),
-        "diamond_sword" => Some(SnapshotAction::MakeDiamondSword),
-        "diamond_pickaxe" => Some(SnapshotAction::MakeDiamondPickaxe),
-        "bow" | "make_bow" => Some(SnapshotAction::MakeBow),
-        "arrow" | "make_arrow" => Some(SnapshotAction::MakeArrow),
-        "iron_armor" => Some(SnapshotAction::MakeIronArmor),
-        "diamond_armor" => Some(SnapshotAction::MakeDiamondArmor),
-        "shoot" | "shoot_arrow" => Some(SnapshotAction::ShootArrow),
-        "potion_red" | "drink_red" => Some(SnapshotAction::DrinkPotionRed),
-        "potion_green" | "drink_green" => Some(SnapshotAction::DrinkPotionGreen),
-        "potion_blue" | "drink_blue" => Some(SnapshotAction::DrinkPotionBlue),
-        "potion_pink" | "drink_pink" => Some(SnapshotAction::DrinkPotionPink),
-        "potion_cyan" | "drink_cyan" => Some(SnapshotAction::DrinkPotionCyan),
-        "potion_yellow" | "drink_yellow" => Some(SnapshotAction::DrinkPotionYellow),
-        "noop" => Some(SnapshotAction::Noop),
-        _ => None,
+fn print_duel_view(duel: &crafter_core::Duel) {
+    let state = duel.human.get_state();
+    let inv = &state.inventory;
+    println!(
+        "HP:{} Food:{} Drink:{} Energy:{} | Wood:{} Stone:{} Coal:{} Iron:{}",
+        inv.health, inv.food, inv.drink, inv.energy, inv.wood, inv.stone, inv.coal, inv.iron
+    );
+    let half = duel.human.config.view_radius as i32;
+    for dy in -half..=half {
+        let mut row = String::new();
+        for dx in -half..=half {
+            let pos = (state.player_pos.0 + dx, state.player_pos.1 + dy);
+            if dx == 0 && dy == 0 {
+                row.push('@');
+                continue;
+            }
+            if let Some(obj) = duel.human.world.get_object_at(pos) {
+                row.push(obj.display_char());
+                continue;
+            }
+            row.push(match duel.human.world.get_material(pos) {
+                Some(Material::Grass) => '.',
+                Some(Material::Water) => '~',
+                Some(Material::Stone) => '#',
+                Some(Material::Tree) => 'T',
+                Some(Material::Coal) => 'c',
+                Some(Material::Iron) => 'i',
+                Some(Material::Diamond) => 'D',
+                Some(Material::Table) => '+',
+                Some(Material::Furnace) => 'F',
+                Some(Material::Sand) => ':',
+                Some(Material::Lava) => '%',
+                Some(_) => '?',
+                None => ' ',
+            });
+        }
+        println!("  {}", row);
+    }
+}
+
+fn snapshot_config_source(config_name: &str) -> (Option<String>, Option<String>) {
+    if std::path::Path::new(config_name).exists() {
+        (None, Some(config_name.to_string()))
+    } else {
+        (Some(config_name.to_string()), None)
     }
 }
 
 fn print_interactive_help() {
     println!("Movement: w a s d (or up/down/left/right)");
     println!("Interact: e (do), sleep");
-    println!("Place: table, furnace, stone, plant");
+    println!("Place: table, furnace, stone, plant, place_trap, place_door, place_fence");
     println!("Craft: wood_sword, wood_pickaxe, stone_sword, stone_pickaxe, iron_sword, iron_pickaxe");
-    println!("Craftax: diamond_sword, diamond_pickaxe, bow, arrow, iron_armor, diamond_armor");
+    println!("Craftax: diamond_sword, diamond_pickaxe, bow, arrow, iron_armor, diamond_armor, trap, door, fence");
     println!("Combat: shoot");
     println!("Potions: drink_red, drink_green, drink_blue, drink_pink, drink_cyan, drink_yellow");
     println!("Repeat with suffix: w5, right3, do2");
+    println!("Comments: everything after # is ignored");
 }
 
 fn run_headless_probe(config_name: &str) {
@@ -285,64 +340,35 @@ fn run_headless_probe(config_name: &str) {
         config.seed = Some(seed);
     }
     let mut session = Session::new(config);
-    let mut counts = std::collections::HashMap::<&'static str, u32>::new();
-
-    for y in 0..session.world.area.1 {
-        for x in 0..session.world.area.0 {
-            let pos = (x as i32, y as i32);
-            if let Some(mat) = session.world.get_material(pos) {
-                let key = match mat {
-                    Material::Tree => "tree",
-                    Material::Coal => "coal",
-                    Material::Iron => "iron",
-                    Material::Diamond => "diamond",
-                    Material::Sapphire => "sapphire",
-                    Material::Ruby => "ruby",
-                    Material::Chest => "chest",
-                    _ => "other",
-                };
-                *counts.entry(key).or_insert(0) += 1;
-            }
-        }
-    }
-
-    let mut mob_counts = std::collections::HashMap::<&'static str, u32>::new();
-    for obj in session.world.objects.values() {
-        if let GameObject::CraftaxMob(mob) = obj {
-            let key = match mob.kind {
-                crafter_core::entity::CraftaxMobKind::OrcSoldier => "orc_soldier",
-                crafter_core::entity::CraftaxMobKind::OrcMage => "orc_mage",
-                crafter_core::entity::CraftaxMobKind::Knight => "knight",
-                crafter_core::entity::CraftaxMobKind::KnightArcher => "knight_archer",
-                crafter_core::entity::CraftaxMobKind::Troll => "troll",
-                crafter_core::entity::CraftaxMobKind::Bat => "bat",
-                crafter_core::entity::CraftaxMobKind::Snail => "snail",
-            };
-            *mob_counts.entry(key).or_insert(0) += 1;
-        }
-    }
+    let stats = session.world.stats();
 
     println!("=== Headless Craftax Probe ===");
     println!("Config: {}", config_name);
     println!(
         "Materials: tree={} coal={} iron={} diamond={} sapphire={} ruby={} chest={}",
-        counts.get("tree").unwrap_or(&0),
-        counts.get("coal").unwrap_or(&0),
-        counts.get("iron").unwrap_or(&0),
-        counts.get("diamond").unwrap_or(&0),
-        counts.get("sapphire").unwrap_or(&0),
-        counts.get("ruby").unwrap_or(&0),
-        counts.get("chest").unwrap_or(&0)
+        stats.material_count(Material::Tree),
+        stats.material_count(Material::Coal),
+        stats.material_count(Material::Iron),
+        stats.material_count(Material::Diamond),
+        stats.material_count(Material::Sapphire),
+        stats.material_count(Material::Ruby),
+        stats.material_count(Material::Chest)
     );
     println!(
         "Mobs: orc_soldier={} orc_mage={} knight={} knight_archer={} troll={} bat={} snail={}",
-        mob_counts.get("orc_soldier").unwrap_or(&0),
-        mob_counts.get("orc_mage").unwrap_or(&0),
-        mob_counts.get("knight").unwrap_or(&0),
-        mob_counts.get("knight_archer").unwrap_or(&0),
-        mob_counts.get("troll").unwrap_or(&0),
-        mob_counts.get("bat").unwrap_or(&0),
-        mob_counts.get("snail").unwrap_or(&0)
+        stats.mob_count("orc_soldier"),
+        stats.mob_count("orc_mage"),
+        stats.mob_count("knight"),
+        stats.mob_count("knight_archer"),
+        stats.mob_count("troll"),
+        stats.mob_count("bat"),
+        stats.mob_count("snail")
+    );
+    println!(
+        "Terrain: water_components={} land_components={} reachable_trees={}",
+        stats.water_components,
+        stats.land_components,
+        stats.reachable_count(Material::Tree)
     );
 
     let _ = session.step(SnapshotAction::MoveRight.to_action());
@@ -418,11 +444,10 @@ fn run_headless_achievements(config_name: &str) {
             continue;
         }
 
-        if !plan.is_empty() && state.inventory.best_sword_tier() == 0 {
-            if hostile_nearby(&session, 4) {
+        if !plan.is_empty() && state.inventory.best_sword_tier() == 0
+            && hostile_nearby(&session, 4) {
                 plan.clear();
             }
-        }
 
         if plan.is_empty() {
             if let Some(new_plan) = plan_actions_if_needed(&session) {
@@ -439,7 +464,7 @@ fn run_headless_achievements(config_name: &str) {
         let action = choose_action(&session, last_action);
         last_action = action;
         let _ = session.step(action);
-        if session.get_state().step % 1000 == 0 {
+        if session.get_state().step.is_multiple_of(1000) {
             let inv = &session.get_state().inventory;
             println!(
                 "Step {}: HP:{} Food:{} Drink:{} Energy:{} XP:{} Level:{}",
@@ -535,7 +560,7 @@ fn plan_actions_if_needed(session: &Session) -> Option<std::collections::VecDequ
                 }
             }
         }
-        if let Some(path) = find_path_to_face_any(world, pos, facing, &water_targets) {
+        if let Some(path) = crafter_core::nav::find_path_to_face_any(world, pos, facing, &water_targets) {
             if !path.is_empty() {
                 return Some(path.into_iter().collect());
             }
@@ -549,15 +574,14 @@ fn plan_actions_if_needed(session: &Session) -> Option<std::collections::VecDequ
                 GameObject::Cow(cow) => {
                     food_targets.insert(cow.pos);
                 }
-                GameObject::Plant(plant) => {
-                    if plant.is_ripe() {
+                GameObject::Plant(plant)
+                    if plant.is_ripe() => {
                         food_targets.insert(plant.pos);
                     }
-                }
                 _ => {}
             }
         }
-        if let Some(path) = find_path_to_face_any(world, pos, facing, &food_targets) {
+        if let Some(path) = crafter_core::nav::find_path_to_face_any(world, pos, facing, &food_targets) {
             if !path.is_empty() {
                 return Some(path.into_iter().collect());
             }
@@ -566,28 +590,26 @@ fn plan_actions_if_needed(session: &Session) -> Option<std::collections::VecDequ
 
     let targets = find_targets(session);
     if !targets.is_empty() {
-        if let Some(path) = find_path_to_face_any(world, pos, facing, &targets) {
+        if let Some(path) = crafter_core::nav::find_path_to_face_any(world, pos, facing, &targets) {
             if !path.is_empty() {
                 return Some(path.into_iter().collect());
             }
         }
     }
 
-    if inv.wood >= 4 && !world.has_adjacent_table(pos) {
-        if world.get_material((pos.0 + facing.0 as i32, pos.1 + facing.1 as i32))
+    if inv.wood >= 4 && !world.has_adjacent_table(pos)
+        && world.get_material((pos.0 + facing.0 as i32, pos.1 + facing.1 as i32))
             == Some(Material::Grass)
         {
             return Some([crafter_core::Action::PlaceTable].into_iter().collect());
         }
-    }
 
-    if inv.stone >= 5 && !world.has_adjacent_furnace(pos) {
-        if world.get_material((pos.0 + facing.0 as i32, pos.1 + facing.1 as i32))
+    if inv.stone >= 5 && !world.has_adjacent_furnace(pos)
+        && world.get_material((pos.0 + facing.0 as i32, pos.1 + facing.1 as i32))
             == Some(Material::Grass)
         {
             return Some([crafter_core::Action::PlaceFurnace].into_iter().collect());
         }
-    }
 
     None
 }
@@ -811,26 +833,22 @@ fn choose_action(session: &Session, last_action: crafter_core::Action) -> crafte
         }
     }
 
-    if inv.wood >= 4 && !near_table && ach.place_table == 0 {
-        if world.get_material(facing_pos) == Some(Material::Grass) {
+    if inv.wood >= 4 && !near_table && ach.place_table == 0
+        && world.get_material(facing_pos) == Some(Material::Grass) {
             return crafter_core::Action::PlaceTable;
         }
-    }
-    if inv.stone >= 5 && !near_furnace && ach.place_furnace == 0 {
-        if world.get_material(facing_pos) == Some(Material::Grass) {
+    if inv.stone >= 5 && !near_furnace && ach.place_furnace == 0
+        && world.get_material(facing_pos) == Some(Material::Grass) {
             return crafter_core::Action::PlaceFurnace;
         }
-    }
-    if inv.stone > 0 && ach.place_stone == 0 {
-        if world.get_material(facing_pos) == Some(Material::Grass) {
+    if inv.stone > 0 && ach.place_stone == 0
+        && world.get_material(facing_pos) == Some(Material::Grass) {
             return crafter_core::Action::PlaceStone;
         }
-    }
-    if inv.sapling > 0 && ach.place_plant == 0 {
-        if world.get_material(facing_pos) == Some(Material::Grass) {
+    if inv.sapling > 0 && ach.place_plant == 0
+        && world.get_material(facing_pos) == Some(Material::Grass) {
             return crafter_core::Action::PlacePlant;
         }
-    }
 
     if inv.energy <= 2 && !state.player_sleeping {
         let mut safe = true;
@@ -893,11 +911,10 @@ fn flee_action(session: &Session, radius: i32) -> Option<crafter_core::Action> {
     for obj in session.world.objects.values() {
         if obj.is_hostile() {
             let dist = (obj.position().0 - pos.0).abs() + (obj.position().1 - pos.1).abs();
-            if dist <= radius {
-                if nearest.map(|(_, d)| dist < d).unwrap_or(true) {
+            if dist <= radius
+                && nearest.map(|(_, d)| dist < d).unwrap_or(true) {
                     nearest = Some((obj.position(), dist));
                 }
-            }
         }
     }
 
@@ -1052,11 +1069,10 @@ fn find_targets(session: &Session) -> std::collections::HashSet<(i32, i32)> {
                 GameObject::Cow(cow) => {
                     targets.insert(cow.pos);
                 }
-                GameObject::Plant(plant) => {
-                    if plant.is_ripe() {
+                GameObject::Plant(plant)
+                    if plant.is_ripe() => {
                         targets.insert(plant.pos);
                     }
-                }
                 _ => {}
             }
         }
@@ -1079,60 +1095,6 @@ fn find_targets(session: &Session) -> std::collections::HashSet<(i32, i32)> {
     targets
 }
 
-fn find_path_to_face_any(
-    world: &crafter_core::world::World,
-    start_pos: (i32, i32),
-    start_facing: (i8, i8),
-    targets: &std::collections::HashSet<(i32, i32)>,
-) -> Option<Vec<crafter_core::Action>> {
-    use std::collections::{HashMap, VecDeque};
-    let dirs = [
-        (crafter_core::Action::MoveUp, (0, -1)),
-        (crafter_core::Action::MoveDown, (0, 1)),
-        (crafter_core::Action::MoveLeft, (-1, 0)),
-        (crafter_core::Action::MoveRight, (1, 0)),
-    ];
-    let mut queue = VecDeque::new();
-    let mut came_from: HashMap<((i32, i32), (i8, i8)), (((i32, i32), (i8, i8)), crafter_core::Action)> = HashMap::new();
-    let start = (start_pos, start_facing);
-    queue.push_back(start);
-    let mut visited = std::collections::HashSet::new();
-    visited.insert(start);
-
-    while let Some((pos, facing)) = queue.pop_front() {
-        let facing_pos = (pos.0 + facing.0 as i32, pos.1 + facing.1 as i32);
-        if targets.contains(&facing_pos) {
-            let mut actions = Vec::new();
-            let mut current = (pos, facing);
-            while current != start {
-                if let Some((prev, action)) = came_from.get(&current) {
-                    actions.push(*action);
-                    current = *prev;
-                } else {
-                    break;
-                }
-            }
-            actions.reverse();
-            return Some(actions);
-        }
-
-        for (action, (dx, dy)) in dirs {
-            let next_pos = (pos.0 + dx, pos.1 + dy);
-            let next_facing = (dx as i8, dy as i8);
-            if !world.is_walkable(next_pos) {
-                continue;
-            }
-            let next_state = (next_pos, next_facing);
-            if visited.insert(next_state) {
-                came_from.insert(next_state, ((pos, facing), action));
-                queue.push_back(next_state);
-            }
-        }
-    }
-
-    None
-}
-
 fn print_snapshot(response: &crafter_core::SnapshotResponse) {
     println!("Session: {}", response.session_id);
     println!("Step: {}", response.step);
@@ -0,0 +1,216 @@
+//! Cross-version replay compatibility
+//!
+//! Recordings embed the crate version and a hash of the ruleset that was
+//! in effect when they were made. After a balance change, old datasets
+//! can still be replayed by registering the previous ruleset here: the
+//! archived entry rewrites a modern config so the old rules apply.
+
+use crate::config::SessionConfig;
+use crate::recipes::RecipeBook;
+use crate::recording::{Recording, ReplaySession};
+use std::hash::{Hash, Hasher};
+
+/// Crate version baked into new recordings
+pub const CRATE_VERSION: &str = env!("CARGO_PKG_VERSION");
+
+/// Hash of the ruleset currently compiled into this crate.
+///
+/// The hash covers the default session config and the classic recipe
+/// book, so any balance change to default rule values (food drain,
+/// recipe costs, spawn rates, ...) produces a new hash. It is stable
+/// across processes and platforms for a given crate version because it
+/// hashes the canonical JSON serialization, not in-memory layout.
+pub fn ruleset_hash() -> u64 {
+    let config = serde_json::to_string(&SessionConfig::default()).unwrap_or_default();
+    let recipes = serde_json::to_string(&RecipeBook::default()).unwrap_or_default();
+    let mut hasher = fnv1a();
+    config.hash(&mut hasher);
+    recipes.hash(&mut hasher);
+    hasher.finish()
+}
+
+/// A ruleset from a previous release that recordings may reference
+pub struct ArchivedRuleset {
+    /// The `ruleset_hash` embedded by that era's builds (0 marks
+    /// recordings made before hashes were embedded)
+    pub hash: u64,
+    /// Human-readable tag, typically the crate version range
+    pub description: &'static str,
+    /// Rewrite a modern config so the old rules apply during replay
+    pub restore: fn(SessionConfig) -> SessionConfig,
+}
+
+/// Rulesets this build knows how to restore. Add an entry here whenever
+/// a balance change alters `ruleset_hash`, capturing the old values.
+pub fn archived_rulesets() -> &'static [ArchivedRuleset] {
+    &[
+        // Recordings made before versioning was embedded deserialize
+        // with hash 0. The rules have not changed since, so they replay
+        // against the current config unchanged.
+        ArchivedRuleset {
+            hash: 0,
+            description: "pre-versioning recordings (<= 0.1.1)",
+            restore: |config| config,
+        },
+    ]
+}
+
+/// How a recording's ruleset relates to this build
+pub enum Compatibility {
+    /// Recorded under the rules compiled into this crate
+    Current,
+    /// Recorded under an archived ruleset this build can restore
+    Archived(&'static ArchivedRuleset),
+    /// Recorded under rules this build does not know; replay would diverge
+    Unknown,
+}
+
+/// Classify a recording against this build's ruleset
+pub fn check(recording: &Recording) -> Compatibility {
+    if recording.ruleset_hash == ruleset_hash() {
+        return Compatibility::Current;
+    }
+    match archived_rulesets()
+        .iter()
+        .find(|r| r.hash == recording.ruleset_hash)
+    {
+        Some(archived) => Compatibility::Archived(archived),
+        None => Compatibility::Unknown,
+    }
+}
+
+/// Build a replay session for a recording, restoring archived rules if
+/// needed. Returns `None` when the recording's ruleset is unknown to
+/// this build, since replaying it would silently diverge.
+pub fn replay_session(recording: &Recording) -> Option<ReplaySession> {
+    match check(recording) {
+        Compatibility::Current => Some(ReplaySession::from_recording(recording)),
+        Compatibility::Archived(archived) => {
+            let mut restored = recording.clone();
+            restored.config = (archived.restore)(restored.config);
+            Some(ReplaySession::from_recording(&restored))
+        }
+        Compatibility::Unknown => None,
+    }
+}
+
+/// Stable hash of a canonical JSON string, shared with
+/// `SessionConfig::resolved`
+pub(crate) fn stable_hash(json: &str) -> u64 {
+    let mut hasher = fnv1a();
+    json.hash(&mut hasher);
+    hasher.finish()
+}
+
+/// FNV-1a, chosen over `DefaultHasher` because its output must be stable
+/// across Rust releases once hashes are embedded in archived datasets
+pub(crate) fn fnv1a() -> impl Hasher {
+    struct Fnv1a(u64);
+    impl Hasher for Fnv1a {
+        fn finish(&self) -> u64 {
+            self.0
+        }
+        fn write(&mut self, bytes: &[u8]) {
+            for &b in bytes {
+                self.0 ^= b as u64;
+                self.0 = self.0.wrapping_mul(0x0000_0100_0000_01b3);
+            }
+        }
+    }
+    Fnv1a(0xcbf2_9ce4_8422_2325)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::action::Action;
+    use crate::recording::{RecordingOptions, RecordingSession};
+
+    #[test]
+    fn test_new_recordings_embed_version_and_hash() {
+        let config = SessionConfig {
+            world_size: (16, 16),
+            seed: Some(1),
+            ..Default::default()
+        };
+        let mut rec_session = RecordingSession::new(config, RecordingOptions::minimal());
+        rec_session.step(Action::MoveRight);
+        let recording = rec_session.finish();
+
+        assert_eq!(recording.crate_version, CRATE_VERSION);
+        assert_eq!(recording.ruleset_hash, ruleset_hash());
+        assert!(matches!(check(&recording), Compatibility::Current));
+        assert!(replay_session(&recording).is_some());
+    }
+
+    #[test]
+    fn test_pre_versioning_recordings_use_archived_shim() {
+        let config = SessionConfig {
+            world_size: (16, 16),
+            seed: Some(1),
+            ..Default::default()
+        };
+        let mut recording = Recording::new(config, 1);
+        recording.ruleset_hash = 0;
+        recording.crate_version = String::new();
+
+        assert!(matches!(check(&recording), Compatibility::Archived(_)));
+        assert!(replay_session(&recording).is_some());
+    }
+
+    #[test]
+    fn test_unknown_ruleset_is_rejected() {
+        let config = SessionConfig::default();
+        let mut recording = Recording::new(config, 1);
+        recording.ruleset_hash = 0xdead_beef;
+
+        assert!(matches!(check(&recording), Compatibility::Unknown));
+        assert!(replay_session(&recording).is_none());
+    }
+
+    #[test]
+    fn test_ruleset_hash_is_stable() {
+        assert_eq!(ruleset_hash(), ruleset_hash());
+        assert_ne!(ruleset_hash(), 0);
+    }
+
+    #[test]
+    fn test_resolved_config_hashes_rules_not_attribution() {
+        let base = SessionConfig::default();
+
+        // Seed, run id, and labels identify the run, not the rules
+        let mut attributed = base.clone();
+        attributed.seed = Some(42);
+        attributed.run_id = Some("exp-07".to_string());
+        attributed.labels.insert("sweep".to_string(), "lr".to_string());
+        assert_eq!(base.resolved().hash, attributed.resolved().hash);
+
+        // Sub-toggles are dead rules while craftax is disabled
+        let mut toggled = base.clone();
+        toggled.craftax.potions_enabled = false;
+        assert!(!toggled.craftax.enabled);
+        assert_eq!(base.resolved().hash, toggled.resolved().hash);
+        toggled.craftax.enabled = true;
+        assert_ne!(base.resolved().hash, toggled.resolved().hash);
+
+        // An actual balance change produces a new hash
+        let mut rebalanced = base.clone();
+        rebalanced.hunger_rate += 1;
+        assert_ne!(base.resolved().hash, rebalanced.resolved().hash);
+    }
+
+    #[test]
+    fn test_session_effective_config_and_recording_embed_the_hash() {
+        let config = SessionConfig {
+            world_size: (16, 16),
+            seed: Some(3),
+            ..Default::default()
+        };
+        let session = crate::session::Session::new(config.clone());
+        assert_eq!(session.effective_config().hash, config.resolved().hash);
+
+        let recording = Recording::new(config.clone(), 1);
+        assert_eq!(recording.config_hash, config.resolved().hash);
+        assert_ne!(recording.config_hash, 0);
+    }
+}
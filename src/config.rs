@@ -1,7 +1,10 @@
 //! Session configuration for game sessions
 
-use crate::session::TimeMode;
+use crate::action::ActionProfile;
+use crate::rng::RngKind;
+use crate::session::{LagPolicy, TimeMode};
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 use std::error::Error;
 use std::fmt;
 use std::fs;
@@ -17,6 +20,13 @@ pub struct SessionConfig {
     /// Random seed for world generation (None = random)
     pub seed: Option<u64>,
 
+    /// Which RNG drives in-episode game logic (default: chacha8).
+    /// ChaCha8 streams are stable across platforms and rand releases;
+    /// `small` is faster but only replays under the same build. World
+    /// generation always uses ChaCha8, so maps are identical either way.
+    #[serde(default)]
+    pub rng_kind: RngKind,
+
     /// Chunk size for spatial partitioning (default: 12x12)
     pub chunk_size: (u32, u32),
 
@@ -55,6 +65,87 @@ pub struct SessionConfig {
     /// Cow despawn rate when >30 tiles away (default: 0.01, per tick)
     pub cow_despawn_rate: f32,
 
+    /// Spawn caps and density targets applied on top of the spawn rates
+    #[serde(default)]
+    pub spawn_balance: SpawnBalanceConfig,
+
+    /// Difficulty scaling tied to the number of nights survived
+    #[serde(default)]
+    pub night_scaling: NightScalingConfig,
+
+    /// Scripted night-time zombie horde events
+    #[serde(default)]
+    pub horde: HordeConfig,
+
+    /// Passive wildlife beyond cows (sheep and pigs)
+    #[serde(default)]
+    pub wildlife: WildlifeConfig,
+
+    /// Scripted knight escort mission
+    #[serde(default)]
+    pub escort: EscortConfig,
+
+    /// Multi-hit mining with per-material hardness
+    #[serde(default)]
+    pub mining: MiningConfig,
+
+    /// Fortune bonus drops for over-tiered tools
+    #[serde(default)]
+    pub fortune: FortuneConfig,
+
+    /// Carry-weight limit on total stackable items
+    #[serde(default)]
+    pub carry: CarryConfig,
+
+    /// Versioned balance constants referenced by session logic
+    #[serde(default)]
+    pub rules: Rules,
+
+    /// Resample classic tool recipe costs per episode within the bounds
+    /// declared in the recipe registry (default: false). The sampled book
+    /// is exposed via `GameState::recipes` so agents can observe it.
+    #[serde(default)]
+    pub recipe_mutation_enabled: bool,
+
+    /// Which action-space profile is legal for this session (default:
+    /// craftax_full). Actions outside the profile are ignored like a noop.
+    #[serde(default)]
+    pub action_profile: ActionProfile,
+
+    /// Atari-style frame skip: each `step` call applies its action for
+    /// this many ticks, summing rewards and returning the final state
+    /// (default: 1). Values below 1 behave as 1; the episode ending
+    /// cuts a repeat short.
+    #[serde(default)]
+    pub action_repeat: u32,
+
+    /// Atari-style sticky actions: probability that a `step` repeats
+    /// the previous step's action instead of the one supplied
+    /// (default: 0.0). Draws come from the session RNG, so runs stay
+    /// reproducible under a fixed seed.
+    #[serde(default)]
+    pub sticky_action_prob: f32,
+
+    /// Pre-generate this many worlds at session creation and cycle
+    /// them round-robin across episodes, trading per-episode variety
+    /// for maximum reset throughput (default: 0 = off). The first pool
+    /// world is the session seed's own map; the rest derive
+    /// deterministically from it.
+    #[serde(default)]
+    pub world_pool_size: u32,
+
+    // ===== Run Attribution =====
+    /// Identifier of the experiment run this session belongs to. The
+    /// config is embedded in recordings and saves and echoed in snapshot
+    /// responses, so every artifact can be attributed without filename
+    /// conventions (default: none).
+    #[serde(default)]
+    pub run_id: Option<String>,
+
+    /// Free-form labels propagated alongside `run_id` (default: empty)
+    #[serde(default)]
+    pub labels: HashMap<String, String>,
+
     // ===== Game Mechanics =====
     /// Episode length in steps (default: 10000, None = infinite)
     pub max_steps: Option<u32>,
@@ -110,6 +201,13 @@ pub struct SessionConfig {
     /// Include full world state vs local view only
     pub full_world_state: bool,
 
+    /// Throughput mode for headless training loops (default: false).
+    /// `step` skips debug-event string formatting and returns a
+    /// lightweight state without the view grid or world copy; call
+    /// `get_state` explicitly when a full observation is needed.
+    #[serde(default)]
+    pub fast_mode: bool,
+
     // ===== Timing =====
     /// Time mode for this session
     pub time_mode: TimeMode,
@@ -117,11 +215,393 @@ pub struct SessionConfig {
     /// Default ticks per second for real-time mode (default: 10.0)
     pub default_ticks_per_second: f32,
 
+    /// In real-time modes, the most ticks one `update` call will
+    /// process before the lag policy kicks in (default: 10)
+    #[serde(default)]
+    pub max_ticks_per_update: Option<u32>,
+
+    /// What to do when real-time processing falls behind the tick
+    /// budget (default: drop the backlog, matching old behavior — but
+    /// now reported via events instead of silently)
+    #[serde(default)]
+    pub lag_policy: LagPolicy,
+
+    /// In real-time modes, auto-pause after this many seconds with only
+    /// Noop actions; the session resumes on the next real input. Keeps
+    /// AFK human sessions from flooding recordings with noop steps.
+    /// None (default) disables idle detection.
+    #[serde(default)]
+    pub idle_pause_secs: Option<f32>,
+
     /// Craftax feature toggles and parameters
     #[serde(default)]
     pub craftax: CraftaxConfig,
 }
 
+/// Scripted zombie horde events. A horde is announced in the debug event
+/// stream a few ticks ahead, then spawns a wave of zombies around the player;
+/// staying alive until the wave resolves earns the `survive_horde`
+/// achievement.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct HordeConfig {
+    /// Enable horde events (default: false)
+    pub enabled: bool,
+
+    /// Chance per night tick of announcing a horde (default: 0.005)
+    pub trigger_chance: f32,
+
+    /// Ticks between the announcement and the wave spawning (default: 10)
+    pub warning_ticks: u32,
+
+    /// Number of zombies in the wave (default: 6)
+    pub wave_size: u32,
+
+    /// Minimum ticks between hordes (default: 600)
+    pub min_interval: u32,
+
+    /// Ticks the player must survive after the wave spawns (default: 100)
+    pub survive_ticks: u32,
+}
+
+impl Default for HordeConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            trigger_chance: 0.005,
+            warning_ticks: 10,
+            wave_size: 6,
+            min_interval: 600,
+            survive_ticks: 100,
+        }
+    }
+}
+
+/// Passive wildlife beyond cows. Sheep drop wool plus a little food, pigs
+/// drop more food than a cow; each species has its own spawn biome and
+/// wander speed so the food economy is not a cow monoculture.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct WildlifeConfig {
+    /// Initial sheep spawn probability multiplier on grass (base: 0.01)
+    pub sheep_density: f32,
+
+    /// Initial pig spawn probability multiplier on grass/sand (base: 0.008)
+    pub pig_density: f32,
+
+    /// Runtime sheep spawn rate, same scale as `cow_spawn_rate` (default: 0.008)
+    pub sheep_spawn_rate: f32,
+
+    /// Runtime pig spawn rate, same scale as `cow_spawn_rate` (default: 0.006)
+    pub pig_spawn_rate: f32,
+
+    /// Sheep health (default: 3)
+    pub sheep_health: u8,
+
+    /// Pig health (default: 4)
+    pub pig_health: u8,
+
+    /// Chance per tick that a sheep wanders (default: 0.35; cows use 0.5)
+    pub sheep_move_chance: f32,
+
+    /// Chance per tick that a pig wanders (default: 0.6; cows use 0.5)
+    pub pig_move_chance: f32,
+
+    /// Maximum sheep alive at once (default: 10)
+    pub sheep_cap: u32,
+
+    /// Maximum pigs alive at once (default: 10)
+    pub pig_cap: u32,
+}
+
+impl Default for WildlifeConfig {
+    fn default() -> Self {
+        Self {
+            sheep_density: 1.0,
+            pig_density: 1.0,
+            sheep_spawn_rate: 0.008,
+            pig_spawn_rate: 0.006,
+            sheep_health: 3,
+            pig_health: 4,
+            sheep_move_chance: 0.35,
+            pig_move_chance: 0.6,
+            sheep_cap: 10,
+            pig_cap: 10,
+        }
+    }
+}
+
+/// Scripted knight escort mission. A friendly knight spawns near the player
+/// partway into the episode together with a village site some distance away;
+/// leading the knight to the village before the deadline earns the
+/// `escort_knight` achievement, while the mission fails if the knight dies or
+/// the time limit runs out. Progress is reported via the debug event stream,
+/// like horde events.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct EscortConfig {
+    /// Enable the escort mission (default: false)
+    pub enabled: bool,
+
+    /// Step at which the knight appears near the player (default: 100)
+    pub start_step: u64,
+
+    /// Knight health (default: 9)
+    pub knight_health: u8,
+
+    /// Damage the knight deals to zombies (default: 4)
+    pub knight_damage: u8,
+
+    /// Distance from the knight's spawn to the village site (default: 30.0)
+    pub village_distance: f32,
+
+    /// Ticks allowed to complete the mission once it starts (default: 1000)
+    pub time_limit: u32,
+}
+
+impl Default for EscortConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            start_step: 100,
+            knight_health: 9,
+            knight_damage: 4,
+            village_distance: 30.0,
+            time_limit: 1000,
+        }
+    }
+}
+
+/// Multi-hit mining: harder materials take several `Do` actions to break,
+/// with progress tracked per tile and a better pickaxe shaving hits off, so
+/// tool tiers matter beyond the binary can/cannot-mine gate. Disabled by
+/// default to preserve Python Crafter's single-hit mining.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct MiningConfig {
+    /// Enable multi-hit mining (default: false)
+    pub enabled: bool,
+
+    /// Multiplier on each material's base hardness (default: 1.0)
+    pub hardness_mult: f32,
+
+    /// Hits removed per pickaxe tier above the material's required tier
+    /// (default: 1). A tile never needs fewer than one hit.
+    pub tier_bonus: u8,
+}
+
+impl Default for MiningConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            hardness_mult: 1.0,
+            tier_bonus: 1,
+        }
+    }
+}
+
+/// Balance constants that session logic used to hard-code, gathered into
+/// one versioned table.
+///
+/// A balance patch edits a value here and bumps [`RULES_VERSION`];
+/// because the table lives in the config, recordings and saves capture
+/// exactly which rules produced them, and `compat::ruleset_hash` changes
+/// automatically. Individual values can be overridden under `[rules]` in
+/// config files for experiments.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Rules {
+    /// Version of the built-in table these values started from
+    pub version: u32,
+    /// Food restored by eating a cow (default: 6, matching Python Crafter)
+    pub cow_food: u8,
+    /// Food restored by eating a sheep, alongside its wool (default: 2)
+    pub sheep_food: u8,
+    /// Food restored by eating a pig (default: 8)
+    pub pig_food: u8,
+    /// Food restored by a ripe plant (default: 4, matching Python Crafter)
+    pub plant_food: u8,
+    /// Zombie melee damage before config multipliers (default: 2)
+    pub zombie_base_damage: f32,
+    /// Damage multiplier against a sleeping player (default: 3.5, so a
+    /// zombie hit for 2 becomes 7)
+    pub zombie_sleep_multiplier: f32,
+    /// Manhattan distance from the player beyond which mobs may despawn
+    /// (default: 30)
+    pub mob_despawn_distance: i32,
+}
+
+/// Version of the built-in [`Rules`] table; bump on any balance change
+pub const RULES_VERSION: u32 = 1;
+
+impl Default for Rules {
+    fn default() -> Self {
+        Self {
+            version: RULES_VERSION,
+            cow_food: 6,
+            sheep_food: 2,
+            pig_food: 8,
+            plant_food: 4,
+            zombie_base_damage: 2.0,
+            zombie_sleep_multiplier: 3.5,
+            mob_despawn_distance: 30,
+        }
+    }
+}
+
+/// Fortune mechanics: mining with a pickaxe above the material's required
+/// tier can yield an extra unit, with a per-material chance scaled by how
+/// many tiers the tool is ahead. Bonus units go straight to the inventory
+/// like normal mining yields and are announced on the debug event stream.
+/// Disabled by default to preserve single-unit parity.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct FortuneConfig {
+    /// Enable fortune bonus drops (default: false)
+    pub enabled: bool,
+
+    /// Bonus chance per spare tier when chopping trees (default: 0.2)
+    pub wood_chance: f32,
+
+    /// Bonus chance per spare tier for stone (default: 0.15)
+    pub stone_chance: f32,
+
+    /// Bonus chance per spare tier for coal (default: 0.2)
+    pub coal_chance: f32,
+
+    /// Bonus chance per spare tier for iron (default: 0.15)
+    pub iron_chance: f32,
+
+    /// Bonus chance per spare tier for diamond (default: 0.1)
+    pub diamond_chance: f32,
+
+    /// Bonus chance per spare tier for sapphire (default: 0.1)
+    pub sapphire_chance: f32,
+
+    /// Bonus chance per spare tier for ruby (default: 0.1)
+    pub ruby_chance: f32,
+}
+
+impl Default for FortuneConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            wood_chance: 0.2,
+            stone_chance: 0.15,
+            coal_chance: 0.2,
+            iron_chance: 0.15,
+            diamond_chance: 0.1,
+            sapphire_chance: 0.1,
+            ruby_chance: 0.1,
+        }
+    }
+}
+
+/// Carry-weight limit: a cap on the total number of stackable items held at
+/// once, on top of the per-slot cap of 9. With the limit on, pickups fail
+/// once the player is loaded up, so agents have to decide what to haul and
+/// bank the rest in chests. Disabled by default for classic parity.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct CarryConfig {
+    /// Enable the total-item carry limit (default: false)
+    pub enabled: bool,
+
+    /// Maximum total stackable items carried at once (default: 30)
+    pub max_total_items: u32,
+}
+
+impl Default for CarryConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            max_total_items: 30,
+        }
+    }
+}
+
+/// Difficulty curve over episode length: hostile spawn rates and health grow
+/// with each night the player survives, so the late game stays dangerous for
+/// long-horizon survival benchmarks.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct NightScalingConfig {
+    /// Enable night-based difficulty scaling (default: false)
+    pub enabled: bool,
+
+    /// Additive spawn rate multiplier per night survived (default: 0.15).
+    /// Effective multiplier is `1.0 + nights * spawn_rate_per_night`.
+    pub spawn_rate_per_night: f32,
+
+    /// Extra hostile health per night survived, rounded down (default: 0.5)
+    pub health_per_night: f32,
+
+    /// Upper bound on the spawn rate multiplier (default: 3.0)
+    pub max_spawn_multiplier: f32,
+
+    /// Upper bound on bonus health (default: 6)
+    pub max_bonus_health: u8,
+}
+
+impl Default for NightScalingConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            spawn_rate_per_night: 0.15,
+            health_per_night: 0.5,
+            max_spawn_multiplier: 3.0,
+            max_bonus_health: 6,
+        }
+    }
+}
+
+/// Caps and density targets that bound runtime mob spawning.
+///
+/// The spawn rates in [`SessionConfig`] control how often a spawn is rolled;
+/// this config decides whether a successful roll is actually allowed to place
+/// a mob, so long nights can no longer flood the map with zombies.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct SpawnBalanceConfig {
+    /// Maximum number of mobs alive in the world at once (default: 48)
+    pub global_mob_cap: u32,
+
+    /// Maximum zombies alive at once (default: 12)
+    pub zombie_cap: u32,
+
+    /// Maximum cows alive at once (default: 16)
+    pub cow_cap: u32,
+
+    /// Maximum hostile craftax mobs alive at once (default: 10)
+    pub craftax_hostile_cap: u32,
+
+    /// Maximum passive craftax mobs (bats, snails) alive at once (default: 8)
+    pub craftax_passive_cap: u32,
+
+    /// Radius around the player used for the density targets below (default: 12)
+    pub density_radius: i32,
+
+    /// Maximum hostile mobs within `density_radius` of the player (default: 6)
+    pub hostiles_near_player_target: u32,
+
+    /// Maximum passive mobs within `density_radius` of the player (default: 8)
+    pub passives_near_player_target: u32,
+
+    /// Hostiles only spawn when daylight is below this level (default: 0.5)
+    pub hostile_max_light: f32,
+
+    /// Passives only spawn when daylight is at or above this level (default: 0.0)
+    pub passive_min_light: f32,
+}
+
+impl Default for SpawnBalanceConfig {
+    fn default() -> Self {
+        Self {
+            global_mob_cap: 48,
+            zombie_cap: 12,
+            cow_cap: 16,
+            craftax_hostile_cap: 10,
+            craftax_passive_cap: 8,
+            density_radius: 12,
+            hostiles_near_player_target: 6,
+            passives_near_player_target: 8,
+            hostile_max_light: 0.5,
+            passive_min_light: 0.0,
+        }
+    }
+}
+
 #[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct CraftaxConfig {
     pub enabled: bool,
@@ -207,6 +687,7 @@ impl Default for CraftaxLootConfig {
 pub enum ConfigError {
     Io(std::io::Error),
     Toml(toml::de::Error),
+    TomlSer(toml::ser::Error),
     Yaml(serde_yaml::Error),
     NotFound(String),
 }
@@ -216,6 +697,7 @@ impl fmt::Display for ConfigError {
         match self {
             ConfigError::Io(err) => write!(f, "config io error: {}", err),
             ConfigError::Toml(err) => write!(f, "config toml error: {}", err),
+            ConfigError::TomlSer(err) => write!(f, "config toml error: {}", err),
             ConfigError::Yaml(err) => write!(f, "config yaml error: {}", err),
             ConfigError::NotFound(name) => write!(f, "config not found: {}", name),
         }
@@ -236,6 +718,12 @@ impl From<toml::de::Error> for ConfigError {
     }
 }
 
+impl From<toml::ser::Error> for ConfigError {
+    fn from(err: toml::ser::Error) -> Self {
+        ConfigError::TomlSer(err)
+    }
+}
+
 impl From<serde_yaml::Error> for ConfigError {
     fn from(err: serde_yaml::Error) -> Self {
         ConfigError::Yaml(err)
@@ -246,6 +734,7 @@ impl From<serde_yaml::Error> for ConfigError {
 struct SessionConfigOverrides {
     world_size: Option<(u32, u32)>,
     seed: Option<u64>,
+    rng_kind: Option<RngKind>,
     chunk_size: Option<(u32, u32)>,
     tree_density: Option<f32>,
     coal_density: Option<f32>,
@@ -258,6 +747,19 @@ struct SessionConfigOverrides {
     zombie_despawn_rate: Option<f32>,
     cow_spawn_rate: Option<f32>,
     cow_despawn_rate: Option<f32>,
+    spawn_balance: Option<SpawnBalanceConfigOverrides>,
+    night_scaling: Option<NightScalingConfigOverrides>,
+    horde: Option<HordeConfigOverrides>,
+    wildlife: Option<WildlifeConfigOverrides>,
+    escort: Option<EscortConfigOverrides>,
+    mining: Option<MiningConfigOverrides>,
+    fortune: Option<FortuneConfigOverrides>,
+    carry: Option<CarryConfigOverrides>,
+    rules: Option<RulesOverrides>,
+    recipe_mutation_enabled: Option<bool>,
+    action_profile: Option<ActionProfile>,
+    run_id: Option<String>,
+    labels: Option<HashMap<String, String>>,
     max_steps: Option<u32>,
     day_night_cycle: Option<bool>,
     day_cycle_period: Option<u32>,
@@ -275,8 +777,12 @@ struct SessionConfigOverrides {
     skeleton_health: Option<u8>,
     view_radius: Option<u32>,
     full_world_state: Option<bool>,
+    fast_mode: Option<bool>,
     time_mode: Option<TimeMode>,
     default_ticks_per_second: Option<f32>,
+    idle_pause_secs: Option<f32>,
+    max_ticks_per_update: Option<u32>,
+    lag_policy: Option<LagPolicy>,
     craftax: Option<CraftaxConfigOverrides>,
 }
 
@@ -288,6 +794,9 @@ impl SessionConfigOverrides {
         if let Some(value) = self.seed {
             base.seed = Some(value);
         }
+        if let Some(value) = self.rng_kind {
+            base.rng_kind = value;
+        }
         if let Some(value) = self.chunk_size {
             base.chunk_size = value;
         }
@@ -324,6 +833,45 @@ impl SessionConfigOverrides {
         if let Some(value) = self.cow_despawn_rate {
             base.cow_despawn_rate = value;
         }
+        if let Some(value) = self.spawn_balance {
+            base.spawn_balance = value.apply_to(base.spawn_balance);
+        }
+        if let Some(value) = self.night_scaling {
+            base.night_scaling = value.apply_to(base.night_scaling);
+        }
+        if let Some(value) = self.horde {
+            base.horde = value.apply_to(base.horde);
+        }
+        if let Some(value) = self.wildlife {
+            base.wildlife = value.apply_to(base.wildlife);
+        }
+        if let Some(value) = self.escort {
+            base.escort = value.apply_to(base.escort);
+        }
+        if let Some(value) = self.mining {
+            base.mining = value.apply_to(base.mining);
+        }
+        if let Some(value) = self.fortune {
+            base.fortune = value.apply_to(base.fortune);
+        }
+        if let Some(value) = self.carry {
+            base.carry = value.apply_to(base.carry);
+        }
+        if let Some(value) = self.rules {
+            base.rules = value.apply_to(base.rules);
+        }
+        if let Some(value) = self.recipe_mutation_enabled {
+            base.recipe_mutation_enabled = value;
+        }
+        if let Some(value) = self.action_profile {
+            base.action_profile = value;
+        }
+        if let Some(value) = self.run_id {
+            base.run_id = Some(value);
+        }
+        if let Some(value) = self.labels {
+            base.labels = value;
+        }
         if let Some(value) = self.max_steps {
             base.max_steps = Some(value);
         }
@@ -375,12 +923,24 @@ impl SessionConfigOverrides {
         if let Some(value) = self.full_world_state {
             base.full_world_state = value;
         }
+        if let Some(value) = self.fast_mode {
+            base.fast_mode = value;
+        }
         if let Some(value) = self.time_mode {
             base.time_mode = value;
         }
         if let Some(value) = self.default_ticks_per_second {
             base.default_ticks_per_second = value;
         }
+        if let Some(value) = self.idle_pause_secs {
+            base.idle_pause_secs = Some(value);
+        }
+        if let Some(value) = self.max_ticks_per_update {
+            base.max_ticks_per_update = Some(value);
+        }
+        if let Some(value) = self.lag_policy {
+            base.lag_policy = value;
+        }
         if let Some(value) = self.craftax {
             base.craftax = value.apply_to(base.craftax);
         }
@@ -388,6 +948,324 @@ impl SessionConfigOverrides {
     }
 }
 
+#[derive(Clone, Debug, Deserialize, Default)]
+struct HordeConfigOverrides {
+    enabled: Option<bool>,
+    trigger_chance: Option<f32>,
+    warning_ticks: Option<u32>,
+    wave_size: Option<u32>,
+    min_interval: Option<u32>,
+    survive_ticks: Option<u32>,
+}
+
+impl HordeConfigOverrides {
+    fn apply_to(self, mut base: HordeConfig) -> HordeConfig {
+        if let Some(value) = self.enabled {
+            base.enabled = value;
+        }
+        if let Some(value) = self.trigger_chance {
+            base.trigger_chance = value;
+        }
+        if let Some(value) = self.warning_ticks {
+            base.warning_ticks = value;
+        }
+        if let Some(value) = self.wave_size {
+            base.wave_size = value;
+        }
+        if let Some(value) = self.min_interval {
+            base.min_interval = value;
+        }
+        if let Some(value) = self.survive_ticks {
+            base.survive_ticks = value;
+        }
+        base
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, Default)]
+struct WildlifeConfigOverrides {
+    sheep_density: Option<f32>,
+    pig_density: Option<f32>,
+    sheep_spawn_rate: Option<f32>,
+    pig_spawn_rate: Option<f32>,
+    sheep_health: Option<u8>,
+    pig_health: Option<u8>,
+    sheep_move_chance: Option<f32>,
+    pig_move_chance: Option<f32>,
+    sheep_cap: Option<u32>,
+    pig_cap: Option<u32>,
+}
+
+impl WildlifeConfigOverrides {
+    fn apply_to(self, mut base: WildlifeConfig) -> WildlifeConfig {
+        if let Some(value) = self.sheep_density {
+            base.sheep_density = value;
+        }
+        if let Some(value) = self.pig_density {
+            base.pig_density = value;
+        }
+        if let Some(value) = self.sheep_spawn_rate {
+            base.sheep_spawn_rate = value;
+        }
+        if let Some(value) = self.pig_spawn_rate {
+            base.pig_spawn_rate = value;
+        }
+        if let Some(value) = self.sheep_health {
+            base.sheep_health = value;
+        }
+        if let Some(value) = self.pig_health {
+            base.pig_health = value;
+        }
+        if let Some(value) = self.sheep_move_chance {
+            base.sheep_move_chance = value;
+        }
+        if let Some(value) = self.pig_move_chance {
+            base.pig_move_chance = value;
+        }
+        if let Some(value) = self.sheep_cap {
+            base.sheep_cap = value;
+        }
+        if let Some(value) = self.pig_cap {
+            base.pig_cap = value;
+        }
+        base
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, Default)]
+struct EscortConfigOverrides {
+    enabled: Option<bool>,
+    start_step: Option<u64>,
+    knight_health: Option<u8>,
+    knight_damage: Option<u8>,
+    village_distance: Option<f32>,
+    time_limit: Option<u32>,
+}
+
+impl EscortConfigOverrides {
+    fn apply_to(self, mut base: EscortConfig) -> EscortConfig {
+        if let Some(value) = self.enabled {
+            base.enabled = value;
+        }
+        if let Some(value) = self.start_step {
+            base.start_step = value;
+        }
+        if let Some(value) = self.knight_health {
+            base.knight_health = value;
+        }
+        if let Some(value) = self.knight_damage {
+            base.knight_damage = value;
+        }
+        if let Some(value) = self.village_distance {
+            base.village_distance = value;
+        }
+        if let Some(value) = self.time_limit {
+            base.time_limit = value;
+        }
+        base
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, Default)]
+struct MiningConfigOverrides {
+    enabled: Option<bool>,
+    hardness_mult: Option<f32>,
+    tier_bonus: Option<u8>,
+}
+
+impl MiningConfigOverrides {
+    fn apply_to(self, mut base: MiningConfig) -> MiningConfig {
+        if let Some(value) = self.enabled {
+            base.enabled = value;
+        }
+        if let Some(value) = self.hardness_mult {
+            base.hardness_mult = value;
+        }
+        if let Some(value) = self.tier_bonus {
+            base.tier_bonus = value;
+        }
+        base
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, Default)]
+struct RulesOverrides {
+    cow_food: Option<u8>,
+    sheep_food: Option<u8>,
+    pig_food: Option<u8>,
+    plant_food: Option<u8>,
+    zombie_base_damage: Option<f32>,
+    zombie_sleep_multiplier: Option<f32>,
+    mob_despawn_distance: Option<i32>,
+}
+
+impl RulesOverrides {
+    fn apply_to(self, mut base: Rules) -> Rules {
+        if let Some(value) = self.cow_food {
+            base.cow_food = value;
+        }
+        if let Some(value) = self.sheep_food {
+            base.sheep_food = value;
+        }
+        if let Some(value) = self.pig_food {
+            base.pig_food = value;
+        }
+        if let Some(value) = self.plant_food {
+            base.plant_food = value;
+        }
+        if let Some(value) = self.zombie_base_damage {
+            base.zombie_base_damage = value;
+        }
+        if let Some(value) = self.zombie_sleep_multiplier {
+            base.zombie_sleep_multiplier = value;
+        }
+        if let Some(value) = self.mob_despawn_distance {
+            base.mob_despawn_distance = value;
+        }
+        base
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, Default)]
+struct FortuneConfigOverrides {
+    enabled: Option<bool>,
+    wood_chance: Option<f32>,
+    stone_chance: Option<f32>,
+    coal_chance: Option<f32>,
+    iron_chance: Option<f32>,
+    diamond_chance: Option<f32>,
+    sapphire_chance: Option<f32>,
+    ruby_chance: Option<f32>,
+}
+
+impl FortuneConfigOverrides {
+    fn apply_to(self, mut base: FortuneConfig) -> FortuneConfig {
+        if let Some(value) = self.enabled {
+            base.enabled = value;
+        }
+        if let Some(value) = self.wood_chance {
+            base.wood_chance = value;
+        }
+        if let Some(value) = self.stone_chance {
+            base.stone_chance = value;
+        }
+        if let Some(value) = self.coal_chance {
+            base.coal_chance = value;
+        }
+        if let Some(value) = self.iron_chance {
+            base.iron_chance = value;
+        }
+        if let Some(value) = self.diamond_chance {
+            base.diamond_chance = value;
+        }
+        if let Some(value) = self.sapphire_chance {
+            base.sapphire_chance = value;
+        }
+        if let Some(value) = self.ruby_chance {
+            base.ruby_chance = value;
+        }
+        base
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, Default)]
+struct CarryConfigOverrides {
+    enabled: Option<bool>,
+    max_total_items: Option<u32>,
+}
+
+impl CarryConfigOverrides {
+    fn apply_to(self, mut base: CarryConfig) -> CarryConfig {
+        if let Some(value) = self.enabled {
+            base.enabled = value;
+        }
+        if let Some(value) = self.max_total_items {
+            base.max_total_items = value;
+        }
+        base
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, Default)]
+struct NightScalingConfigOverrides {
+    enabled: Option<bool>,
+    spawn_rate_per_night: Option<f32>,
+    health_per_night: Option<f32>,
+    max_spawn_multiplier: Option<f32>,
+    max_bonus_health: Option<u8>,
+}
+
+impl NightScalingConfigOverrides {
+    fn apply_to(self, mut base: NightScalingConfig) -> NightScalingConfig {
+        if let Some(value) = self.enabled {
+            base.enabled = value;
+        }
+        if let Some(value) = self.spawn_rate_per_night {
+            base.spawn_rate_per_night = value;
+        }
+        if let Some(value) = self.health_per_night {
+            base.health_per_night = value;
+        }
+        if let Some(value) = self.max_spawn_multiplier {
+            base.max_spawn_multiplier = value;
+        }
+        if let Some(value) = self.max_bonus_health {
+            base.max_bonus_health = value;
+        }
+        base
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, Default)]
+struct SpawnBalanceConfigOverrides {
+    global_mob_cap: Option<u32>,
+    zombie_cap: Option<u32>,
+    cow_cap: Option<u32>,
+    craftax_hostile_cap: Option<u32>,
+    craftax_passive_cap: Option<u32>,
+    density_radius: Option<i32>,
+    hostiles_near_player_target: Option<u32>,
+    passives_near_player_target: Option<u32>,
+    hostile_max_light: Option<f32>,
+    passive_min_light: Option<f32>,
+}
+
+impl SpawnBalanceConfigOverrides {
+    fn apply_to(self, mut base: SpawnBalanceConfig) -> SpawnBalanceConfig {
+        if let Some(value) = self.global_mob_cap {
+            base.global_mob_cap = value;
+        }
+        if let Some(value) = self.zombie_cap {
+            base.zombie_cap = value;
+        }
+        if let Some(value) = self.cow_cap {
+            base.cow_cap = value;
+        }
+        if let Some(value) = self.craftax_hostile_cap {
+            base.craftax_hostile_cap = value;
+        }
+        if let Some(value) = self.craftax_passive_cap {
+            base.craftax_passive_cap = value;
+        }
+        if let Some(value) = self.density_radius {
+            base.density_radius = value;
+        }
+        if let Some(value) = self.hostiles_near_player_target {
+            base.hostiles_near_player_target = value;
+        }
+        if let Some(value) = self.passives_near_player_target {
+            base.passives_near_player_target = value;
+        }
+        if let Some(value) = self.hostile_max_light {
+            base.hostile_max_light = value;
+        }
+        if let Some(value) = self.passive_min_light {
+            base.passive_min_light = value;
+        }
+        base
+    }
+}
+
 #[derive(Clone, Debug, Deserialize, Default)]
 struct CraftaxConfigOverrides {
     enabled: Option<bool>,
@@ -525,6 +1403,7 @@ impl Default for SessionConfig {
         Self {
             world_size: (64, 64),
             seed: None,
+            rng_kind: RngKind::default(),
             chunk_size: (12, 12),
             tree_density: 1.0,
             coal_density: 1.0,
@@ -537,6 +1416,22 @@ impl Default for SessionConfig {
             zombie_despawn_rate: 0.4,
             cow_spawn_rate: 0.01,
             cow_despawn_rate: 0.01,
+            spawn_balance: SpawnBalanceConfig::default(),
+            night_scaling: NightScalingConfig::default(),
+            horde: HordeConfig::default(),
+            wildlife: WildlifeConfig::default(),
+            escort: EscortConfig::default(),
+            mining: MiningConfig::default(),
+            fortune: FortuneConfig::default(),
+            carry: CarryConfig::default(),
+            rules: Rules::default(),
+            recipe_mutation_enabled: false,
+            action_profile: ActionProfile::default(),
+            action_repeat: 1,
+            sticky_action_prob: 0.0,
+            world_pool_size: 0,
+            run_id: None,
+            labels: HashMap::new(),
             max_steps: Some(10000),
             day_night_cycle: true,
             day_cycle_period: 300,
@@ -554,8 +1449,12 @@ impl Default for SessionConfig {
             skeleton_health: 3,
             view_radius: 4,
             full_world_state: false,
+            fast_mode: false,
             time_mode: TimeMode::Logical,
             default_ticks_per_second: 10.0,
+            max_ticks_per_update: None,
+            lag_policy: LagPolicy::default(),
+            idle_pause_secs: None,
             craftax: CraftaxConfig::default(),
         }
     }
@@ -570,6 +1469,7 @@ impl SessionConfig {
             hunger_enabled: false,
             thirst_enabled: false,
             fatigue_enabled: false,
+            fast_mode: true,
             time_mode: TimeMode::Logical,
             ..Default::default()
         }
@@ -649,6 +1549,41 @@ impl SessionConfig {
             .ok_or_else(|| ConfigError::NotFound(name.to_string()))?;
         SessionConfig::load_from_path(path)
     }
+
+    /// The fully-resolved rules this config plays under, plus a stable
+    /// hash of them.
+    ///
+    /// Presets, `base =` inheritance, and override files are already
+    /// applied at load time, so resolution here is normalization: run
+    /// attribution (`seed`, `run_id`, `labels`) is cleared and disabled
+    /// craftax sub-toggles are canonicalized, so two configs that play
+    /// by the same rules hash identically. The hash uses the same stable
+    /// FNV-1a over canonical JSON as `compat::ruleset_hash`, making it
+    /// safe to embed in recordings and leaderboards.
+    pub fn resolved(&self) -> ResolvedConfig {
+        let mut config = self.clone();
+        config.seed = None;
+        config.run_id = None;
+        config.labels = HashMap::new();
+        // Sub-toggles are dead rules while craftax is off; pin them to
+        // the canonical disabled form
+        if !config.craftax.enabled {
+            config.craftax = CraftaxConfig::default();
+        }
+        let json = serde_json::to_string(&config).unwrap_or_default();
+        ResolvedConfig {
+            hash: crate::compat::stable_hash(&json),
+            config,
+        }
+    }
+}
+
+/// A fully-resolved ruleset with its stable hash; see
+/// [`SessionConfig::resolved`]
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct ResolvedConfig {
+    pub config: SessionConfig,
+    pub hash: u64,
 }
 
 fn resolve_named_config_path(name: &str) -> Option<PathBuf> {
@@ -686,3 +1621,81 @@ fn resolve_named_config_path(name: &str) -> Option<PathBuf> {
 
     None
 }
+
+/// Write a config file atomically: contents land in a `.tmp` sibling
+/// first and rename into place, so a crash mid-write can never leave a
+/// truncated file where startup expects a config.
+pub fn write_config_atomic<P: AsRef<Path>>(path: P, contents: &str) -> Result<(), ConfigError> {
+    let path = path.as_ref();
+    if let Some(dir) = path.parent() {
+        if !dir.as_os_str().is_empty() {
+            fs::create_dir_all(dir)?;
+        }
+    }
+    let file_name = path
+        .file_name()
+        .and_then(|name| name.to_str())
+        .unwrap_or("config");
+    let tmp = path.with_file_name(format!("{}.tmp", file_name));
+    fs::write(&tmp, contents)?;
+    fs::rename(&tmp, path).inspect_err(|_| {
+        let _ = fs::remove_file(&tmp);
+    })?;
+    Ok(())
+}
+
+/// Serialize `value` as TOML, validate the output re-parses into the
+/// same type, and write it atomically; the caller gets the first error
+/// instead of a silently corrupted file
+pub fn save_toml_config<T, P>(path: P, value: &T) -> Result<(), ConfigError>
+where
+    T: Serialize + serde::de::DeserializeOwned,
+    P: AsRef<Path>,
+{
+    let contents = toml::to_string_pretty(value)?;
+    toml::from_str::<T>(&contents)?;
+    write_config_atomic(path, &contents)
+}
+
+/// YAML twin of [`save_toml_config`]
+pub fn save_yaml_config<T, P>(path: P, value: &T) -> Result<(), ConfigError>
+where
+    T: Serialize + serde::de::DeserializeOwned,
+    P: AsRef<Path>,
+{
+    let contents = serde_yaml::to_string(value)?;
+    serde_yaml::from_str::<T>(&contents)?;
+    write_config_atomic(path, &contents)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_save_toml_config_is_atomic_and_round_trips() {
+        let path = std::env::temp_dir().join("crafter_test_profile.toml");
+        let config = SessionConfig {
+            seed: Some(7),
+            ..Default::default()
+        };
+
+        save_toml_config(&path, &config).unwrap();
+        let loaded = SessionConfig::load_from_path(&path).unwrap();
+        assert_eq!(loaded.seed, Some(7));
+        // The staging file is gone once the write lands
+        assert!(!path.with_file_name("crafter_test_profile.toml.tmp").exists());
+
+        fs::remove_file(&path).ok();
+    }
+
+    #[test]
+    fn test_write_config_atomic_preserves_previous_contents_on_failure() {
+        let path = std::env::temp_dir().join("crafter_test_profile_kept.toml");
+        write_config_atomic(&path, "a = 1\n").unwrap();
+        write_config_atomic(&path, "a = 2\n").unwrap();
+        assert_eq!(fs::read_to_string(&path).unwrap(), "a = 2\n");
+
+        fs::remove_file(&path).ok();
+    }
+}
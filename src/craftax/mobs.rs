@@ -8,6 +8,9 @@ pub struct CraftaxMobStats {
     pub range: i32,
     pub cooldown: u8,
     pub projectile: ProjectileKind,
+    pub projectile_speed: u8,
+    pub projectile_pierce: bool,
+    pub armor: u8,
 }
 
 impl CraftaxMobStats {
@@ -18,6 +21,12 @@ impl CraftaxMobStats {
     pub fn is_melee(&self) -> bool {
         self.melee_damage > 0
     }
+
+    /// Damage actually dealt to this mob after armor; a hit always lands
+    /// for at least 1 so armored mobs stay killable with any weapon.
+    pub fn absorb(&self, damage: u8) -> u8 {
+        damage.saturating_sub(self.armor).max(1)
+    }
 }
 
 pub fn stats(kind: CraftaxMobKind) -> CraftaxMobStats {
@@ -29,6 +38,9 @@ pub fn stats(kind: CraftaxMobKind) -> CraftaxMobStats {
             range: 1,
             cooldown: 3,
             projectile: ProjectileKind::Arrow,
+            projectile_speed: 1,
+            projectile_pierce: false,
+            armor: 0,
         },
         CraftaxMobKind::OrcMage => CraftaxMobStats {
             health: 3,
@@ -37,6 +49,9 @@ pub fn stats(kind: CraftaxMobKind) -> CraftaxMobStats {
             range: 6,
             cooldown: 4,
             projectile: ProjectileKind::Fireball,
+            projectile_speed: 1,
+            projectile_pierce: false,
+            armor: 0,
         },
         CraftaxMobKind::Knight => CraftaxMobStats {
             health: 9,
@@ -45,6 +60,9 @@ pub fn stats(kind: CraftaxMobKind) -> CraftaxMobStats {
             range: 1,
             cooldown: 2,
             projectile: ProjectileKind::Arrow,
+            projectile_speed: 1,
+            projectile_pierce: false,
+            armor: 0,
         },
         CraftaxMobKind::KnightArcher => CraftaxMobStats {
             health: 8,
@@ -53,6 +71,9 @@ pub fn stats(kind: CraftaxMobKind) -> CraftaxMobStats {
             range: 7,
             cooldown: 3,
             projectile: ProjectileKind::Arrow,
+            projectile_speed: 2,
+            projectile_pierce: true,
+            armor: 0,
         },
         CraftaxMobKind::Troll => CraftaxMobStats {
             health: 12,
@@ -61,6 +82,9 @@ pub fn stats(kind: CraftaxMobKind) -> CraftaxMobStats {
             range: 1,
             cooldown: 3,
             projectile: ProjectileKind::Arrow,
+            projectile_speed: 1,
+            projectile_pierce: false,
+            armor: 0,
         },
         CraftaxMobKind::Bat => CraftaxMobStats {
             health: 2,
@@ -69,6 +93,9 @@ pub fn stats(kind: CraftaxMobKind) -> CraftaxMobStats {
             range: 0,
             cooldown: 0,
             projectile: ProjectileKind::Arrow,
+            projectile_speed: 1,
+            projectile_pierce: false,
+            armor: 0,
         },
         CraftaxMobKind::Snail => CraftaxMobStats {
             health: 3,
@@ -77,6 +104,9 @@ pub fn stats(kind: CraftaxMobKind) -> CraftaxMobStats {
             range: 0,
             cooldown: 0,
             projectile: ProjectileKind::Arrow,
+            projectile_speed: 1,
+            projectile_pierce: false,
+            armor: 1,
         },
     }
 }
@@ -0,0 +1,197 @@
+//! Curriculum scheduling over [`SessionConfig`]
+//!
+//! A [`CurriculumScheduler`] holds an ordered list of one-shot config
+//! mutations, each gated on an episode number or on the performance of
+//! the episode that just ended. Attach one with
+//! [`Session::set_curriculum`](crate::session::Session::set_curriculum)
+//! and every [`reset`](crate::session::Session::reset) consults it
+//! before regenerating the world, so e.g. the world can grow or mobs
+//! switch on as training progresses — no manual config juggling in the
+//! training loop.
+//!
+//! ```
+//! use crafter_core::config::SessionConfig;
+//! use crafter_core::curriculum::CurriculumScheduler;
+//!
+//! let mut curriculum = CurriculumScheduler::new();
+//! curriculum
+//!     .at_episode(100, "bigger world", |config| config.world_size = (64, 64))
+//!     .at_performance(5, "harder nights", |config| config.zombie_spawn_rate = 0.3);
+//! ```
+
+use crate::config::SessionConfig;
+
+/// When a curriculum stage fires
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum CurriculumTrigger {
+    /// Fires on the reset that starts this episode number (or later, if
+    /// the scheduler was attached mid-run)
+    Episode(u32),
+    /// Fires once the episode that just ended unlocked at least this
+    /// many achievements
+    Performance { min_achievements: u32 },
+}
+
+/// One config mutation plus the condition that unlocks it
+pub struct CurriculumStage {
+    /// Label surfaced in the step's debug events when the stage applies
+    pub name: String,
+    /// Condition under which the stage fires
+    pub trigger: CurriculumTrigger,
+    mutation: Box<dyn FnMut(&mut SessionConfig) + Send>,
+    applied: bool,
+}
+
+/// Schedule of one-shot [`SessionConfig`] mutations
+///
+/// Stages apply at most once, in registration order, and every stage
+/// whose trigger is satisfied applies on the same reset — so a resumed
+/// run at episode 500 catches up on all earlier episode-keyed stages.
+#[derive(Default)]
+pub struct CurriculumScheduler {
+    stages: Vec<CurriculumStage>,
+}
+
+impl CurriculumScheduler {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Register a stage that fires when the given episode begins
+    pub fn at_episode(
+        &mut self,
+        episode: u32,
+        name: &str,
+        mutation: impl FnMut(&mut SessionConfig) + Send + 'static,
+    ) -> &mut Self {
+        self.stages.push(CurriculumStage {
+            name: name.to_string(),
+            trigger: CurriculumTrigger::Episode(episode),
+            mutation: Box::new(mutation),
+            applied: false,
+        });
+        self
+    }
+
+    /// Register a stage that fires once an episode ends with at least
+    /// `min_achievements` achievements unlocked
+    pub fn at_performance(
+        &mut self,
+        min_achievements: u32,
+        name: &str,
+        mutation: impl FnMut(&mut SessionConfig) + Send + 'static,
+    ) -> &mut Self {
+        self.stages.push(CurriculumStage {
+            name: name.to_string(),
+            trigger: CurriculumTrigger::Performance { min_achievements },
+            mutation: Box::new(mutation),
+            applied: false,
+        });
+        self
+    }
+
+    /// Number of stages that have fired so far
+    pub fn stages_applied(&self) -> usize {
+        self.stages.iter().filter(|s| s.applied).count()
+    }
+
+    /// Apply every unapplied stage whose trigger is satisfied and
+    /// return their names. `next_episode` is the episode about to
+    /// start; `achievements_unlocked` is the unlock count of the
+    /// episode that just ended.
+    pub fn advance(
+        &mut self,
+        next_episode: u32,
+        achievements_unlocked: u32,
+        config: &mut SessionConfig,
+    ) -> Vec<String> {
+        let mut fired = Vec::new();
+        for stage in &mut self.stages {
+            if stage.applied {
+                continue;
+            }
+            let ready = match stage.trigger {
+                CurriculumTrigger::Episode(episode) => next_episode >= episode,
+                CurriculumTrigger::Performance { min_achievements } => {
+                    achievements_unlocked >= min_achievements
+                }
+            };
+            if ready {
+                (stage.mutation)(config);
+                stage.applied = true;
+                fired.push(stage.name.clone());
+            }
+        }
+        fired
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::action::Action;
+    use crate::session::Session;
+
+    fn config() -> SessionConfig {
+        SessionConfig {
+            world_size: (32, 32),
+            seed: Some(42),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_episode_stage_applies_on_the_right_reset() {
+        let mut curriculum = CurriculumScheduler::new();
+        curriculum.at_episode(3, "grow world", |config| config.world_size = (64, 64));
+
+        let mut session = Session::new(config());
+        session.set_curriculum(curriculum);
+
+        // Episode 2: too early
+        session.reset();
+        assert_eq!(session.config.world_size, (32, 32));
+
+        // Episode 3: fires, and the new world is generated at the new size
+        session.reset();
+        assert_eq!(session.config.world_size, (64, 64));
+        assert_eq!(session.world.width(), 64);
+        assert_eq!(session.curriculum().unwrap().stages_applied(), 1);
+
+        // One-shot: later resets don't re-apply
+        session.config.world_size = (32, 32);
+        session.reset();
+        assert_eq!(session.config.world_size, (32, 32));
+
+        // The stage name surfaces in the debug events of the next step
+        let mut session = Session::new(config());
+        let mut curriculum = CurriculumScheduler::new();
+        curriculum.at_episode(2, "grow world", |config| config.world_size = (64, 64));
+        session.set_curriculum(curriculum);
+        session.reset();
+        let result = session.step(Action::Noop);
+        assert!(result.debug_events.iter().any(|e| e.contains("grow world")));
+    }
+
+    #[test]
+    fn test_performance_stage_reads_last_episode() {
+        let mut curriculum = CurriculumScheduler::new();
+        curriculum.at_performance(2, "reward milestone", |config| config.max_steps = Some(500));
+
+        let mut session = Session::new(config());
+        let default_max_steps = session.config.max_steps;
+        session.set_curriculum(curriculum);
+
+        // No achievements yet: nothing fires
+        session.reset();
+        assert_eq!(session.config.max_steps, default_max_steps);
+
+        // Two unlocks in this episode satisfy the trigger on the next reset
+        if let Some(player) = session.world.get_player_mut() {
+            player.achievements.collect_wood = 1;
+            player.achievements.collect_stone = 1;
+        }
+        session.reset();
+        assert_eq!(session.config.max_steps, Some(500));
+    }
+}
@@ -0,0 +1,221 @@
+//! Human-vs-agent duels
+//!
+//! A [`Duel`] races a human against a [`Policy`] on identical terrain:
+//! both contestants get their own session created from the same config
+//! and seed, so resources are split — each side mines its own copy of
+//! the same world and neither can grief the other. Every human step
+//! advances the agent by one policy action, keeping the race at the
+//! human's pace.
+//!
+//! Scores track reward, achievements, steps survived, and liveness per
+//! side; [`Duel::summary`] declares a winner once the duel is over and
+//! renders a summary screen as text lines for whatever frontend is
+//! hosting the duel.
+
+use crate::action::Action;
+use crate::config::SessionConfig;
+use crate::policy::Policy;
+use crate::session::Session;
+
+/// One contestant's running score
+#[derive(Debug, Clone, Default)]
+pub struct DuelScore {
+    /// Total reward accumulated
+    pub reward: f32,
+    /// Distinct achievements unlocked
+    pub unlocked: u32,
+    /// Steps taken
+    pub steps: u64,
+    /// Whether this side's episode has ended
+    pub done: bool,
+}
+
+/// Which side of the duel
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DuelSide {
+    Human,
+    Agent,
+}
+
+/// Final standings; `winner` is `None` on a draw
+#[derive(Debug, Clone)]
+pub struct DuelSummary {
+    pub human: DuelScore,
+    pub agent: DuelScore,
+    pub agent_name: String,
+    pub winner: Option<DuelSide>,
+}
+
+impl DuelSummary {
+    /// Render the summary screen as plain text lines
+    pub fn lines(&self) -> Vec<String> {
+        let verdict = match self.winner {
+            Some(DuelSide::Human) => "You win!".to_string(),
+            Some(DuelSide::Agent) => format!("{} wins!", self.agent_name),
+            None => "Draw.".to_string(),
+        };
+        vec![
+            "=== Duel Summary ===".to_string(),
+            format!(
+                "{:<14} {:>12} {:>8} {:>7}",
+                "", "achievements", "reward", "steps"
+            ),
+            format!(
+                "{:<14} {:>12} {:>8.1} {:>7}",
+                "you", self.human.unlocked, self.human.reward, self.human.steps
+            ),
+            format!(
+                "{:<14} {:>12} {:>8.1} {:>7}",
+                self.agent_name, self.agent.unlocked, self.agent.reward, self.agent.steps
+            ),
+            verdict,
+        ]
+    }
+}
+
+/// A mirrored-world race between a human and a policy
+pub struct Duel {
+    pub human: Session,
+    pub agent: Session,
+    policy: Box<dyn Policy>,
+    human_score: DuelScore,
+    agent_score: DuelScore,
+}
+
+impl Duel {
+    /// Start a duel; both sessions share the config (and therefore the
+    /// seed, so the terrain is identical)
+    pub fn new(config: SessionConfig, policy: Box<dyn Policy>) -> Self {
+        Self {
+            human: Session::new(config.clone()),
+            agent: Session::new(config),
+            policy,
+            human_score: DuelScore::default(),
+            agent_score: DuelScore::default(),
+        }
+    }
+
+    /// Apply the human's action, then let the agent take one action of
+    /// its own. Finished sides stop stepping but the other may play on.
+    pub fn step_human(&mut self, action: Action) {
+        if !self.human_score.done {
+            let result = self.human.step(action);
+            self.human_score.reward += result.reward;
+            self.human_score.steps += 1;
+            self.human_score.done = result.done;
+        }
+        if !self.agent_score.done {
+            let action = self.policy.act(&self.agent);
+            let result = self.agent.step(action);
+            self.agent_score.reward += result.reward;
+            self.agent_score.steps += 1;
+            self.agent_score.done = result.done;
+        }
+        self.human_score.unlocked = self.human.get_state().achievements.total_unlocked();
+        self.agent_score.unlocked = self.agent.get_state().achievements.total_unlocked();
+    }
+
+    /// Both sides have finished their episodes
+    pub fn is_over(&self) -> bool {
+        self.human_score.done && self.agent_score.done
+    }
+
+    pub fn scores(&self) -> (&DuelScore, &DuelScore) {
+        (&self.human_score, &self.agent_score)
+    }
+
+    /// One-line scoreboard for in-game display
+    pub fn scoreboard(&self) -> String {
+        format!(
+            "you {}🏆 {:.1}r | {} {}🏆 {:.1}r",
+            self.human_score.unlocked,
+            self.human_score.reward,
+            self.policy.name(),
+            self.agent_score.unlocked,
+            self.agent_score.reward,
+        )
+    }
+
+    /// Final standings: achievements decide, reward breaks ties
+    pub fn summary(&self) -> DuelSummary {
+        let winner = match self.human_score.unlocked.cmp(&self.agent_score.unlocked) {
+            std::cmp::Ordering::Greater => Some(DuelSide::Human),
+            std::cmp::Ordering::Less => Some(DuelSide::Agent),
+            std::cmp::Ordering::Equal => {
+                if self.human_score.reward > self.agent_score.reward {
+                    Some(DuelSide::Human)
+                } else if self.agent_score.reward > self.human_score.reward {
+                    Some(DuelSide::Agent)
+                } else {
+                    None
+                }
+            }
+        };
+        DuelSummary {
+            human: self.human_score.clone(),
+            agent: self.agent_score.clone(),
+            agent_name: self.policy.name().to_string(),
+            winner,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::policy::ScriptedPolicy;
+
+    fn duel_config() -> SessionConfig {
+        SessionConfig {
+            world_size: (32, 32),
+            seed: Some(42),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_duel_worlds_are_identical() {
+        let duel = Duel::new(
+            duel_config(),
+            Box::new(ScriptedPolicy::new(vec![])),
+        );
+        assert_eq!(duel.human.world.materials, duel.agent.world.materials);
+        assert_eq!(
+            duel.human.get_state().player_pos,
+            duel.agent.get_state().player_pos
+        );
+    }
+
+    #[test]
+    fn test_duel_tracks_scores_separately() {
+        // Human chops the tree four tiles right (seed 777 layout, same
+        // as the snapshot tests); the agent just idles
+        let mut duel = Duel::new(
+            SessionConfig {
+                world_size: (64, 64),
+                seed: Some(777),
+                ..Default::default()
+            },
+            Box::new(ScriptedPolicy::new(vec![])),
+        );
+        for action in [
+            Action::MoveRight,
+            Action::MoveRight,
+            Action::MoveRight,
+            Action::MoveRight,
+            Action::Do,
+        ] {
+            duel.step_human(action);
+        }
+
+        let (human, agent) = duel.scores();
+        assert_eq!(human.steps, 5);
+        assert_eq!(agent.steps, 5);
+        assert!(human.unlocked >= 1, "human should have collected wood");
+        assert_eq!(agent.unlocked, 0);
+
+        let summary = duel.summary();
+        assert_eq!(summary.winner, Some(DuelSide::Human));
+        assert!(summary.lines().iter().any(|l| l.contains("You win!")));
+    }
+}
@@ -15,14 +15,18 @@ pub type Facing = (i8, i8);
 
 /// All game object types
 #[derive(Clone, Debug, Serialize, Deserialize)]
+#[allow(clippy::large_enum_variant)]
 pub enum GameObject {
     Player(Player),
     Cow(Cow),
+    Sheep(Sheep),
+    Pig(Pig),
     Zombie(Zombie),
     Skeleton(Skeleton),
     Arrow(Arrow),
     Plant(Plant),
     CraftaxMob(CraftaxMob),
+    EscortKnight(EscortKnight),
 }
 
 impl GameObject {
@@ -31,11 +35,14 @@ impl GameObject {
         match self {
             GameObject::Player(p) => p.pos,
             GameObject::Cow(c) => c.pos,
+            GameObject::Sheep(s) => s.pos,
+            GameObject::Pig(p) => p.pos,
             GameObject::Zombie(z) => z.pos,
             GameObject::Skeleton(s) => s.pos,
             GameObject::Arrow(a) => a.pos,
             GameObject::Plant(p) => p.pos,
             GameObject::CraftaxMob(m) => m.pos,
+            GameObject::EscortKnight(k) => k.pos,
         }
     }
 
@@ -44,11 +51,14 @@ impl GameObject {
         match self {
             GameObject::Player(p) => p.pos = pos,
             GameObject::Cow(c) => c.pos = pos,
+            GameObject::Sheep(s) => s.pos = pos,
+            GameObject::Pig(p) => p.pos = pos,
             GameObject::Zombie(z) => z.pos = pos,
             GameObject::Skeleton(s) => s.pos = pos,
             GameObject::Arrow(a) => a.pos = pos,
             GameObject::Plant(p) => p.pos = pos,
             GameObject::CraftaxMob(m) => m.pos = pos,
+            GameObject::EscortKnight(k) => k.pos = pos,
         }
     }
 
@@ -69,17 +79,44 @@ impl GameObject {
     /// Check if this is a passive mob
     pub fn is_passive(&self) -> bool {
         match self {
-            GameObject::Cow(_) => true,
+            GameObject::Cow(_) | GameObject::Sheep(_) | GameObject::Pig(_) => true,
             GameObject::CraftaxMob(m) => m.is_passive(),
             _ => false,
         }
     }
 
+    /// Stable snake_case name for this object's type, as used in
+    /// snapshot entity lists and world statistics
+    pub fn kind_name(&self) -> &'static str {
+        match self {
+            GameObject::Player(_) => "player",
+            GameObject::Cow(_) => "cow",
+            GameObject::Sheep(_) => "sheep",
+            GameObject::Pig(_) => "pig",
+            GameObject::Zombie(_) => "zombie",
+            GameObject::Skeleton(_) => "skeleton",
+            GameObject::Arrow(_) => "arrow",
+            GameObject::Plant(_) => "plant",
+            GameObject::CraftaxMob(m) => match m.kind {
+                CraftaxMobKind::OrcSoldier => "orc_soldier",
+                CraftaxMobKind::OrcMage => "orc_mage",
+                CraftaxMobKind::Knight => "knight",
+                CraftaxMobKind::KnightArcher => "knight_archer",
+                CraftaxMobKind::Troll => "troll",
+                CraftaxMobKind::Bat => "bat",
+                CraftaxMobKind::Snail => "snail",
+            },
+            GameObject::EscortKnight(_) => "escort_knight",
+        }
+    }
+
     /// Get the display character for text rendering
     pub fn display_char(&self) -> char {
         match self {
             GameObject::Player(_) => '@',
             GameObject::Cow(_) => 'C',
+            GameObject::Sheep(_) => 'w',
+            GameObject::Pig(_) => 'g',
             GameObject::Zombie(_) => 'Z',
             GameObject::Skeleton(_) => 'S',
             GameObject::Arrow(_) => '*',
@@ -91,6 +128,7 @@ impl GameObject {
                 }
             }
             GameObject::CraftaxMob(m) => m.display_char(),
+            GameObject::EscortKnight(_) => 'k',
         }
     }
 }
@@ -119,6 +157,9 @@ pub struct Player {
     pub last_health: u8,
     #[serde(default)]
     pub last_damage_source: Option<DamageSource>,
+    /// Remaining burn ticks from a fireball hit (1 damage every 3 ticks)
+    #[serde(default)]
+    pub burn_ticks: u8,
 }
 
 #[derive(Clone, Copy, Debug, Serialize, Deserialize)]
@@ -130,6 +171,7 @@ pub enum DamageSource {
     CraftaxMelee,
     CraftaxRanged,
     CraftaxMagic,
+    Burn,
     Lava,
     Starvation,
     Thirst,
@@ -147,6 +189,7 @@ impl DamageSource {
             DamageSource::CraftaxMelee => "craftax_melee",
             DamageSource::CraftaxRanged => "craftax_ranged",
             DamageSource::CraftaxMagic => "craftax_magic",
+            DamageSource::Burn => "burn",
             DamageSource::Lava => "lava",
             DamageSource::Starvation => "starvation",
             DamageSource::Thirst => "thirst",
@@ -177,6 +220,7 @@ impl Player {
             recover_counter: 0.0,
             last_health: 9,
             last_damage_source: None,
+            burn_ticks: 0,
         }
     }
 
@@ -364,6 +408,92 @@ impl Mob for Cow {
     }
 }
 
+/// Sheep - passive mob that drops wool and a little food
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct Sheep {
+    pub pos: Position,
+    pub health: u8,
+}
+
+impl Default for Sheep {
+    fn default() -> Self {
+        Self::new((0, 0))
+    }
+}
+
+impl Sheep {
+    pub fn new(pos: Position) -> Self {
+        Self { pos, health: 3 }
+    }
+
+    pub fn with_health(pos: Position, health: u8) -> Self {
+        Self { pos, health }
+    }
+}
+
+impl Mob for Sheep {
+    fn health(&self) -> u8 {
+        self.health
+    }
+
+    fn take_damage(&mut self, damage: u8) -> bool {
+        if self.health > damage {
+            self.health -= damage;
+            true
+        } else {
+            self.health = 0;
+            false
+        }
+    }
+
+    fn is_alive(&self) -> bool {
+        self.health > 0
+    }
+}
+
+/// Pig - passive mob that provides more food than a cow
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct Pig {
+    pub pos: Position,
+    pub health: u8,
+}
+
+impl Default for Pig {
+    fn default() -> Self {
+        Self::new((0, 0))
+    }
+}
+
+impl Pig {
+    pub fn new(pos: Position) -> Self {
+        Self { pos, health: 4 }
+    }
+
+    pub fn with_health(pos: Position, health: u8) -> Self {
+        Self { pos, health }
+    }
+}
+
+impl Mob for Pig {
+    fn health(&self) -> u8 {
+        self.health
+    }
+
+    fn take_damage(&mut self, damage: u8) -> bool {
+        if self.health > damage {
+            self.health -= damage;
+            true
+        } else {
+            self.health = 0;
+            false
+        }
+    }
+
+    fn is_alive(&self) -> bool {
+        self.health > 0
+    }
+}
+
 /// Zombie - hostile mob that chases and attacks player
 #[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct Zombie {
@@ -520,6 +650,12 @@ pub struct Arrow {
     pub damage: u8,
     #[serde(default = "default_arrow_source")]
     pub source: DamageSource,
+    /// Tiles travelled per tick (default: 1)
+    #[serde(default = "default_arrow_speed")]
+    pub speed: u8,
+    /// Whether the projectile keeps flying after killing a target
+    #[serde(default)]
+    pub pierce: bool,
 }
 
 fn default_arrow_damage() -> u8 {
@@ -530,6 +666,10 @@ fn default_arrow_source() -> DamageSource {
     DamageSource::Arrow
 }
 
+fn default_arrow_speed() -> u8 {
+    1
+}
+
 impl Arrow {
     pub fn new(pos: Position, facing: Facing) -> Self {
         Self::with_stats(pos, facing, ProjectileKind::Arrow, 2, DamageSource::Arrow)
@@ -545,6 +685,18 @@ impl Arrow {
         kind: ProjectileKind,
         damage: u8,
         source: DamageSource,
+    ) -> Self {
+        Self::with_flight(pos, facing, kind, damage, source, 1, false)
+    }
+
+    pub fn with_flight(
+        pos: Position,
+        facing: Facing,
+        kind: ProjectileKind,
+        damage: u8,
+        source: DamageSource,
+        speed: u8,
+        pierce: bool,
     ) -> Self {
         Self {
             pos,
@@ -552,6 +704,8 @@ impl Arrow {
             kind,
             damage,
             source,
+            speed,
+            pierce,
         }
     }
 
@@ -647,6 +801,53 @@ impl Mob for CraftaxMob {
     }
 }
 
+/// Friendly knight NPC from the escort mission. Follows the player toward
+/// the village and fights zombies along the way; never attacks the player.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct EscortKnight {
+    pub pos: Position,
+    pub health: u8,
+    pub cooldown: u8,
+}
+
+impl EscortKnight {
+    pub fn new(pos: Position) -> Self {
+        Self {
+            pos,
+            health: 9,
+            cooldown: 0,
+        }
+    }
+
+    pub fn with_health(pos: Position, health: u8) -> Self {
+        Self {
+            pos,
+            health,
+            cooldown: 0,
+        }
+    }
+}
+
+impl Mob for EscortKnight {
+    fn health(&self) -> u8 {
+        self.health
+    }
+
+    fn take_damage(&mut self, damage: u8) -> bool {
+        if self.health > damage {
+            self.health -= damage;
+            true
+        } else {
+            self.health = 0;
+            false
+        }
+    }
+
+    fn is_alive(&self) -> bool {
+        self.health > 0
+    }
+}
+
 /// Plant - can be placed by player, grows over time, provides food when ripe
 #[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct Plant {
@@ -0,0 +1,180 @@
+//! Gym-style environment adapter
+//!
+//! Every RL integration ends up writing the same shim around
+//! [`Session`]: encode the view as a tensor, split `done` into
+//! terminated vs truncated, surface step metadata as an info dict.
+//! [`CrafterEnv`] is that shim, with Gymnasium semantics:
+//! `reset()` returns the first observation and
+//! `step(action)` returns `(observation, reward, terminated, truncated,
+//! info)`, where terminated means the episode ended inside the MDP
+//! (death) and truncated means it was cut off externally (step limit,
+//! manual reset).
+//!
+//! The [`Env`] trait keeps wrappers composable: anything that steps
+//! observations can wrap anything else that does.
+
+use crate::action::Action;
+use crate::config::SessionConfig;
+use crate::obs;
+use crate::session::{DoneReason, Session};
+
+/// A `[C, H, W]` uint8 view tensor plus its shape (see [`obs`])
+#[derive(Clone, Debug)]
+pub struct Observation {
+    pub data: Vec<u8>,
+    /// `(channels, height, width)`
+    pub shape: (usize, usize, usize),
+}
+
+/// Step metadata, the Gymnasium info dict
+#[derive(Clone, Debug, Default)]
+pub struct Info {
+    /// Step number after the action
+    pub step: u64,
+    /// Achievements unlocked this step
+    pub newly_unlocked: Vec<String>,
+    /// Why the episode ended, if it did
+    pub done_reason: Option<DoneReason>,
+}
+
+/// Gymnasium-shaped environment interface
+pub trait Env {
+    /// Start a fresh episode and return its first observation
+    fn reset(&mut self) -> Observation;
+
+    /// Apply an action; returns
+    /// `(observation, reward, terminated, truncated, info)`
+    fn step(&mut self, action: Action) -> (Observation, f32, bool, bool, Info);
+}
+
+// Boxed envs are still envs, so declaratively built wrapper stacks
+// (see `wrappers::wrap`) compose like concrete ones
+impl Env for Box<dyn Env> {
+    fn reset(&mut self) -> Observation {
+        (**self).reset()
+    }
+
+    fn step(&mut self, action: Action) -> (Observation, f32, bool, bool, Info) {
+        (**self).step(action)
+    }
+}
+
+/// [`Env`] implementation over a [`Session`]
+pub struct CrafterEnv {
+    session: Session,
+}
+
+impl CrafterEnv {
+    pub fn new(config: SessionConfig) -> Self {
+        Self {
+            session: Session::new(config),
+        }
+    }
+
+    pub fn from_session(session: Session) -> Self {
+        Self { session }
+    }
+
+    pub fn session(&self) -> &Session {
+        &self.session
+    }
+
+    pub fn session_mut(&mut self) -> &mut Session {
+        &mut self.session
+    }
+
+    /// The current observation without stepping
+    pub fn observation(&self) -> Observation {
+        match self.session.get_state().view {
+            Some(view) => Observation {
+                data: obs::view_tensor_u8(&view),
+                shape: obs::view_tensor_shape(&view),
+            },
+            // No player, no view (post-death edge): a zeroed tensor of
+            // the configured shape keeps downstream buffers fixed-size
+            None => {
+                let size = (2 * self.session.config.view_radius + 1) as usize;
+                Observation {
+                    data: vec![0; obs::NUM_CHANNELS * size * size],
+                    shape: (obs::NUM_CHANNELS, size, size),
+                }
+            }
+        }
+    }
+}
+
+impl Env for CrafterEnv {
+    fn reset(&mut self) -> Observation {
+        self.session.reset();
+        self.observation()
+    }
+
+    fn step(&mut self, action: Action) -> (Observation, f32, bool, bool, Info) {
+        let result = self.session.step(action);
+        let truncated = result.truncated;
+        let terminated = result.done && !truncated;
+        let info = Info {
+            step: result.state.step,
+            newly_unlocked: result.newly_unlocked,
+            done_reason: result.done_reason,
+        };
+        (self.observation(), result.reward, terminated, truncated, info)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn config() -> SessionConfig {
+        SessionConfig {
+            world_size: (24, 24),
+            seed: Some(42),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_env_steps_with_gymnasium_semantics() {
+        let mut env = CrafterEnv::new(config());
+        let first = env.observation();
+        assert_eq!(first.data.len(), first.shape.0 * first.shape.1 * first.shape.2);
+
+        let (observation, _reward, terminated, truncated, info) = env.step(Action::MoveRight);
+        assert_eq!(observation.shape, first.shape);
+        assert!(!terminated);
+        assert!(!truncated);
+        assert_eq!(info.step, 1);
+
+        let fresh = env.reset();
+        assert_eq!(env.session().episode, 2);
+        assert_eq!(fresh.shape, first.shape);
+    }
+
+    #[test]
+    fn test_termination_and_truncation_are_distinguished() {
+        // Death terminates
+        let mut env = CrafterEnv::new(config());
+        env.session_mut()
+            .world
+            .get_player_mut()
+            .unwrap()
+            .inventory
+            .health = 0;
+        let (_, _, terminated, truncated, info) = env.step(Action::Noop);
+        assert!(terminated);
+        assert!(!truncated);
+        assert!(matches!(info.done_reason, Some(DoneReason::Death)));
+
+        // The step limit truncates
+        let mut env = CrafterEnv::new(SessionConfig {
+            max_steps: Some(2),
+            ..config()
+        });
+        env.step(Action::Noop);
+        let (_, _, terminated, truncated, info) = env.step(Action::Noop);
+        assert!(!terminated);
+        assert!(truncated);
+        assert!(matches!(info.done_reason, Some(DoneReason::MaxSteps)));
+    }
+}
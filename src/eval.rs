@@ -0,0 +1,696 @@
+//! Standard evaluation perturbations
+//!
+//! Published Crafter/Atari-style protocols evaluate under two
+//! perturbations: *sticky actions* (each step the previous action is
+//! repeated with probability `p` instead of the requested one) and
+//! *random starts* (up to `N` no-op or random actions executed at
+//! episode start before the policy takes over). [`EvalSession`] applies
+//! both around a [`Session`] so evaluation matches those protocols
+//! without external wrappers, deterministically under a given seed.
+//!
+//! On top of the perturbations sit two runners: [`evaluate`] benchmarks
+//! one policy over K seeded episodes and reports the official score,
+//! per-achievement success rates, and reward curves (plus recordings on
+//! request), and [`matrix`] sweeps policies × configs × seeds into a
+//! results table.
+
+use crate::action::Action;
+use crate::config::SessionConfig;
+use crate::rng::{RngKind, SessionRng};
+use crate::session::{Session, StepResult};
+use rand::Rng;
+
+/// The perturbations to apply during evaluation
+#[derive(Clone, Debug)]
+pub struct EvalProtocol {
+    /// Probability of repeating the previous action instead of the
+    /// requested one (0.25 in the common sticky-action protocol)
+    pub sticky_action_prob: f32,
+    /// Up to this many start actions are executed before the policy's
+    /// first step; the count is drawn uniformly from `0..=N`
+    pub max_start_actions: u32,
+    /// Draw start actions uniformly from the action profile instead of
+    /// using no-ops
+    pub random_start_actions: bool,
+}
+
+impl Default for EvalProtocol {
+    fn default() -> Self {
+        Self {
+            sticky_action_prob: 0.25,
+            max_start_actions: 30,
+            random_start_actions: false,
+        }
+    }
+}
+
+impl EvalProtocol {
+    /// No perturbations; useful as a control
+    pub fn none() -> Self {
+        Self {
+            sticky_action_prob: 0.0,
+            max_start_actions: 0,
+            random_start_actions: false,
+        }
+    }
+}
+
+/// A session evaluated under an [`EvalProtocol`]
+pub struct EvalSession {
+    session: Session,
+    protocol: EvalProtocol,
+    /// Protocol RNG, separate from the session's game RNG so the
+    /// perturbation sequence is reproducible per evaluation seed
+    rng: SessionRng,
+    last_action: Option<Action>,
+    /// Start actions executed at the most recent episode start
+    start_actions_taken: u32,
+}
+
+impl EvalSession {
+    pub fn new(config: SessionConfig, protocol: EvalProtocol, eval_seed: u64) -> Self {
+        let mut wrapper = Self {
+            session: Session::new(config),
+            protocol,
+            rng: SessionRng::from_seed_kind(RngKind::Chacha8, eval_seed),
+            last_action: None,
+            start_actions_taken: 0,
+        };
+        wrapper.apply_random_start();
+        wrapper
+    }
+
+    /// Step with sticky actions: with probability `p` the previous
+    /// action is executed instead of `action`
+    pub fn step(&mut self, action: Action) -> StepResult {
+        let executed = match self.last_action {
+            Some(last) if self.rng.gen::<f32>() < self.protocol.sticky_action_prob => last,
+            _ => action,
+        };
+        self.last_action = Some(executed);
+        self.session.step(executed)
+    }
+
+    /// Start the next episode and apply the random-start perturbation
+    pub fn reset(&mut self) {
+        self.session.reset();
+        self.last_action = None;
+        self.apply_random_start();
+    }
+
+    /// The action actually executed on the last step, after stickiness
+    pub fn last_executed(&self) -> Option<Action> {
+        self.last_action
+    }
+
+    /// Start actions executed at the most recent episode start
+    pub fn start_actions_taken(&self) -> u32 {
+        self.start_actions_taken
+    }
+
+    pub fn session(&self) -> &Session {
+        &self.session
+    }
+
+    pub fn session_mut(&mut self) -> &mut Session {
+        &mut self.session
+    }
+
+    fn apply_random_start(&mut self) {
+        self.start_actions_taken = 0;
+        if self.protocol.max_start_actions == 0 {
+            return;
+        }
+        let count = self.rng.gen_range(0..=self.protocol.max_start_actions);
+        let table = self.session.config.action_profile.action_table();
+        for _ in 0..count {
+            let action = if self.protocol.random_start_actions {
+                table[self.rng.gen_range(0..table.len())]
+            } else {
+                Action::Noop
+            };
+            let result = self.session.step(action);
+            self.start_actions_taken += 1;
+            if result.done {
+                break;
+            }
+        }
+    }
+}
+
+/// Builds a fresh policy instance for one evaluation episode. Policies
+/// hold state, so every cell of the matrix gets its own instance.
+pub type PolicyFactory = dyn Fn() -> Box<dyn crate::policy::Policy> + Sync;
+
+/// One completed cell of an evaluation matrix
+#[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
+pub struct MatrixCell {
+    pub policy: String,
+    pub config: String,
+    pub seed: u64,
+    /// Total reward over the episode
+    pub reward: f32,
+    /// Steps survived (capped at the runner's `max_steps`)
+    pub steps: u64,
+    /// Achievements unlocked by episode end
+    pub achievements: u32,
+}
+
+impl MatrixCell {
+    fn key(policy: &str, config: &str, seed: u64) -> String {
+        format!("{}\u{1f}{}\u{1f}{}", policy, config, seed)
+    }
+}
+
+/// Options for [`matrix`]
+#[derive(Clone, Debug)]
+pub struct MatrixOptions {
+    /// Episode length cap
+    pub max_steps: u64,
+    /// Worker threads; 1 runs everything on the calling thread
+    pub threads: usize,
+    /// Perturbations applied to every episode
+    pub protocol: EvalProtocol,
+    /// Write completed cells here after each episode so an interrupted
+    /// sweep resumes instead of restarting; existing cells are skipped
+    pub checkpoint_path: Option<std::path::PathBuf>,
+}
+
+impl Default for MatrixOptions {
+    fn default() -> Self {
+        Self {
+            max_steps: 10_000,
+            threads: 1,
+            protocol: EvalProtocol::none(),
+            checkpoint_path: None,
+        }
+    }
+}
+
+/// Results of an evaluation sweep: one cell per policy × config × seed
+#[derive(Clone, Debug, Default, serde::Serialize, serde::Deserialize)]
+pub struct MatrixResults {
+    pub cells: Vec<MatrixCell>,
+}
+
+impl MatrixResults {
+    pub fn load_json<P: AsRef<std::path::Path>>(path: P) -> std::io::Result<Self> {
+        let data = std::fs::read_to_string(path)?;
+        serde_json::from_str(&data)
+            .map_err(|e| std::io::Error::new(std::io::ErrorKind::InvalidData, e))
+    }
+
+    pub fn save_json<P: AsRef<std::path::Path>>(&self, path: P) -> std::io::Result<()> {
+        let data = serde_json::to_string_pretty(self)
+            .map_err(|e| std::io::Error::new(std::io::ErrorKind::InvalidData, e))?;
+        std::fs::write(path, data)
+    }
+
+    /// Mean episode reward across seeds for one policy × config pair
+    pub fn mean_reward(&self, policy: &str, config: &str) -> Option<f32> {
+        let rewards: Vec<f32> = self
+            .cells
+            .iter()
+            .filter(|c| c.policy == policy && c.config == config)
+            .map(|c| c.reward)
+            .collect();
+        (!rewards.is_empty()).then(|| rewards.iter().sum::<f32>() / rewards.len() as f32)
+    }
+
+    /// The results as CSV, one row per cell
+    pub fn to_csv(&self) -> String {
+        let mut csv = String::from("policy,config,seed,reward,steps,achievements\n");
+        for cell in &self.cells {
+            csv.push_str(&format!(
+                "{},{},{},{},{},{}\n",
+                cell.policy, cell.config, cell.seed, cell.reward, cell.steps, cell.achievements
+            ));
+        }
+        csv
+    }
+}
+
+/// Run every policy on every config and seed, in parallel, producing a
+/// policy × config × seed results table.
+///
+/// With a `checkpoint_path` set, completed cells are flushed to disk
+/// after every episode and already-present cells are skipped on the
+/// next call, so a long sweep survives restarts.
+#[cfg(not(target_arch = "wasm32"))]
+pub fn matrix(
+    configs: &[(String, SessionConfig)],
+    seeds: &[u64],
+    policies: &[(String, &PolicyFactory)],
+    options: &MatrixOptions,
+) -> std::io::Result<MatrixResults> {
+    let mut results = match &options.checkpoint_path {
+        Some(path) if path.exists() => MatrixResults::load_json(path)?,
+        _ => MatrixResults::default(),
+    };
+    let done: std::collections::HashSet<String> = results
+        .cells
+        .iter()
+        .map(|c| MatrixCell::key(&c.policy, &c.config, c.seed))
+        .collect();
+
+    // Every not-yet-completed cell of the matrix
+    let mut pending = Vec::new();
+    for (policy_name, factory) in policies {
+        for (config_name, config) in configs {
+            for &seed in seeds {
+                if !done.contains(&MatrixCell::key(policy_name, config_name, seed)) {
+                    pending.push((policy_name, *factory, config_name, config, seed));
+                }
+            }
+        }
+    }
+
+    let next = std::sync::atomic::AtomicUsize::new(0);
+    let shared = std::sync::Mutex::new((results.cells.drain(..).collect::<Vec<_>>(), Ok(())));
+
+    std::thread::scope(|scope| {
+        for _ in 0..options.threads.max(1) {
+            scope.spawn(|| loop {
+                let index = next.fetch_add(1, std::sync::atomic::Ordering::Relaxed);
+                let Some((policy_name, factory, config_name, config, seed)) =
+                    pending.get(index).copied()
+                else {
+                    break;
+                };
+                let cell = run_cell(policy_name, factory, config_name, config, seed, options);
+
+                let mut guard = shared.lock().unwrap();
+                guard.0.push(cell);
+                if let Some(path) = &options.checkpoint_path {
+                    let snapshot = MatrixResults {
+                        cells: guard.0.clone(),
+                    };
+                    if let Err(err) = snapshot.save_json(path) {
+                        guard.1 = Err(err);
+                    }
+                }
+            });
+        }
+    });
+
+    let (cells, flush_result) = shared.into_inner().unwrap();
+    flush_result?;
+    let results = MatrixResults { cells };
+    if let Some(path) = &options.checkpoint_path {
+        results.save_json(path)?;
+    }
+    Ok(results)
+}
+
+/// Run one episode of the matrix
+#[cfg(not(target_arch = "wasm32"))]
+fn run_cell(
+    policy_name: &str,
+    factory: &PolicyFactory,
+    config_name: &str,
+    config: &SessionConfig,
+    seed: u64,
+    options: &MatrixOptions,
+) -> MatrixCell {
+    let mut config = config.clone();
+    config.seed = Some(seed);
+    let mut policy = factory();
+    let mut eval = EvalSession::new(config, options.protocol.clone(), seed);
+
+    let mut reward = 0.0;
+    for _ in 0..options.max_steps {
+        let action = policy.act(eval.session());
+        let result = eval.step(action);
+        reward += result.reward;
+        if result.done {
+            break;
+        }
+    }
+    let session = eval.session();
+    MatrixCell {
+        policy: policy_name.to_string(),
+        config: config_name.to_string(),
+        seed,
+        reward,
+        steps: session.get_state().step,
+        achievements: session
+            .world
+            .get_player()
+            .map(|p| p.achievements.total_unlocked())
+            .unwrap_or(0),
+    }
+}
+
+/// Options for [`evaluate`]
+#[derive(Clone, Debug)]
+pub struct EvaluateOptions {
+    /// Episode length cap
+    pub max_steps: u64,
+    /// Worker threads; 1 runs everything on the calling thread
+    pub threads: usize,
+    /// Perturbations applied to every episode
+    pub protocol: EvalProtocol,
+    /// Keep each episode's recording in the report
+    pub record: bool,
+}
+
+impl Default for EvaluateOptions {
+    fn default() -> Self {
+        Self {
+            max_steps: 10_000,
+            threads: 1,
+            protocol: EvalProtocol::none(),
+            record: false,
+        }
+    }
+}
+
+/// One evaluated episode
+#[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
+pub struct EpisodeReport {
+    pub seed: u64,
+    /// Total reward over the episode
+    pub reward: f32,
+    /// Steps survived (capped at the runner's `max_steps`)
+    pub steps: u64,
+    /// Achievements unlocked by episode end, canonical names
+    pub unlocked: Vec<String>,
+    /// Cumulative reward after each step, for plotting
+    pub reward_curve: Vec<f32>,
+}
+
+/// The standard evaluation report for one policy over K seeded episodes
+#[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
+pub struct EvalReport {
+    pub policy: String,
+    /// Per-episode results, in the order the seeds were given
+    pub episodes: Vec<EpisodeReport>,
+    /// The official Crafter score; see [`crate::stats::crafter_score`]
+    pub score: f32,
+    /// Per-achievement success rates over the 22 classic achievements,
+    /// in canonical order
+    pub success_rates: Vec<(String, f32)>,
+    pub mean_reward: f32,
+    pub mean_episode_length: f32,
+    /// One recording per episode when the options asked for them
+    #[serde(default)]
+    pub recordings: Vec<crate::recording::Recording>,
+}
+
+impl EvalReport {
+    pub fn load_json<P: AsRef<std::path::Path>>(path: P) -> std::io::Result<Self> {
+        let data = std::fs::read_to_string(path)?;
+        serde_json::from_str(&data)
+            .map_err(|e| std::io::Error::new(std::io::ErrorKind::InvalidData, e))
+    }
+
+    pub fn save_json<P: AsRef<std::path::Path>>(&self, path: P) -> std::io::Result<()> {
+        let data = serde_json::to_string_pretty(self)
+            .map_err(|e| std::io::Error::new(std::io::ErrorKind::InvalidData, e))?;
+        std::fs::write(path, data)
+    }
+}
+
+/// Run one policy for an episode per seed, in parallel, and report the
+/// official score, per-achievement success rates, and reward curves —
+/// the standard way to benchmark a policy, replacing hand-rolled
+/// act/step loops. Recordings of every episode are included when
+/// [`EvaluateOptions::record`] is set, so interesting runs replay in
+/// the TUI.
+#[cfg(not(target_arch = "wasm32"))]
+pub fn evaluate(
+    policy_name: &str,
+    factory: &PolicyFactory,
+    config: &SessionConfig,
+    seeds: &[u64],
+    options: &EvaluateOptions,
+) -> EvalReport {
+    let next = std::sync::atomic::AtomicUsize::new(0);
+    let finished = std::sync::Mutex::new(Vec::new());
+
+    std::thread::scope(|scope| {
+        for _ in 0..options.threads.max(1) {
+            scope.spawn(|| loop {
+                let index = next.fetch_add(1, std::sync::atomic::Ordering::Relaxed);
+                let Some(&seed) = seeds.get(index) else {
+                    break;
+                };
+                let outcome = run_eval_episode(factory, config, seed, options);
+                finished.lock().unwrap().push((index, outcome));
+            });
+        }
+    });
+
+    let mut outcomes = finished.into_inner().unwrap();
+    outcomes.sort_by_key(|(index, _)| *index);
+
+    let mut episodes = Vec::with_capacity(outcomes.len());
+    let mut recordings = Vec::new();
+    for (_, (episode, recording)) in outcomes {
+        episodes.push(episode);
+        if options.record {
+            recordings.push(recording);
+        }
+    }
+
+    let count = episodes.len().max(1) as f32;
+    let success_rates: Vec<(String, f32)> = crate::achievement::Achievements::all_names()
+        .iter()
+        .map(|&name| {
+            let unlocked = episodes
+                .iter()
+                .filter(|e| e.unlocked.iter().any(|u| u == name))
+                .count();
+            (name.to_string(), unlocked as f32 / count)
+        })
+        .collect();
+    let rates: Vec<f32> = success_rates.iter().map(|(_, rate)| *rate).collect();
+
+    EvalReport {
+        policy: policy_name.to_string(),
+        score: crate::stats::crafter_score(&rates),
+        success_rates,
+        mean_reward: episodes.iter().map(|e| e.reward).sum::<f32>() / count,
+        mean_episode_length: episodes.iter().map(|e| e.steps).sum::<u64>() as f32 / count,
+        episodes,
+        recordings,
+    }
+}
+
+/// Run one seeded episode of an evaluation. The protocol is applied
+/// here rather than through [`EvalSession`] so the recording captures
+/// the *executed* actions and replays faithfully.
+#[cfg(not(target_arch = "wasm32"))]
+fn run_eval_episode(
+    factory: &PolicyFactory,
+    config: &SessionConfig,
+    seed: u64,
+    options: &EvaluateOptions,
+) -> (EpisodeReport, crate::recording::Recording) {
+    let mut config = config.clone();
+    config.seed = Some(seed);
+    let mut policy = factory();
+    let mut rng = SessionRng::from_seed_kind(RngKind::Chacha8, seed);
+    let mut recording = crate::recording::RecordingSession::new(
+        config,
+        crate::recording::RecordingOptions::minimal(),
+    );
+
+    let mut reward = 0.0;
+    let mut reward_curve = Vec::new();
+    let mut unlocked = Vec::new();
+    let mut done = false;
+
+    // Random starts count toward the episode's reward and curve: they
+    // are part of the trajectory even though the policy never saw them
+    if options.protocol.max_start_actions > 0 {
+        let count = rng.gen_range(0..=options.protocol.max_start_actions);
+        let table = recording.session().config.action_profile.action_table();
+        for _ in 0..count {
+            let action = if options.protocol.random_start_actions {
+                table[rng.gen_range(0..table.len())]
+            } else {
+                Action::Noop
+            };
+            let result = recording.step(action);
+            reward += result.reward;
+            reward_curve.push(reward);
+            unlocked.extend(result.newly_unlocked);
+            if result.done {
+                done = true;
+                break;
+            }
+        }
+    }
+
+    let mut last_action: Option<Action> = None;
+    for _ in 0..options.max_steps {
+        if done {
+            break;
+        }
+        let requested = policy.act(recording.session());
+        let action = match last_action {
+            Some(last) if rng.gen::<f32>() < options.protocol.sticky_action_prob => last,
+            _ => requested,
+        };
+        last_action = Some(action);
+        let result = recording.step(action);
+        reward += result.reward;
+        reward_curve.push(reward);
+        unlocked.extend(result.newly_unlocked);
+        done = result.done;
+    }
+
+    let episode = EpisodeReport {
+        seed,
+        reward,
+        steps: recording.session().get_state().step,
+        unlocked,
+        reward_curve,
+    };
+    (episode, recording.recording().clone())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn config() -> SessionConfig {
+        SessionConfig {
+            world_size: (24, 24),
+            seed: Some(42),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_sticky_actions_repeat_the_previous_action() {
+        let protocol = EvalProtocol {
+            sticky_action_prob: 1.0,
+            max_start_actions: 0,
+            random_start_actions: false,
+        };
+        let mut eval = EvalSession::new(config(), protocol, 7);
+
+        eval.step(Action::MoveRight);
+        for _ in 0..5 {
+            eval.step(Action::MoveLeft);
+            assert_eq!(eval.last_executed(), Some(Action::MoveRight));
+        }
+
+        // With p = 0 the requested action always goes through
+        let mut control = EvalSession::new(config(), EvalProtocol::none(), 7);
+        control.step(Action::MoveRight);
+        control.step(Action::MoveLeft);
+        assert_eq!(control.last_executed(), Some(Action::MoveLeft));
+    }
+
+    #[test]
+    fn test_random_starts_are_seeded_and_bounded() {
+        let protocol = EvalProtocol {
+            sticky_action_prob: 0.0,
+            max_start_actions: 8,
+            random_start_actions: false,
+        };
+        let a = EvalSession::new(config(), protocol.clone(), 3);
+        let b = EvalSession::new(config(), protocol, 3);
+
+        assert!(a.start_actions_taken() <= 8);
+        assert_eq!(a.start_actions_taken(), b.start_actions_taken());
+        assert_eq!(
+            a.session().get_state().step,
+            u64::from(a.start_actions_taken())
+        );
+
+        // A reset rolls fresh start actions rather than replaying none
+        let protocol = EvalProtocol {
+            sticky_action_prob: 0.0,
+            max_start_actions: 8,
+            random_start_actions: true,
+        };
+        let mut eval = EvalSession::new(config(), protocol, 11);
+        eval.step(Action::MoveRight);
+        eval.reset();
+        assert_eq!(eval.last_executed(), None);
+        assert_eq!(eval.session().episode, 2);
+        assert!(eval.start_actions_taken() <= 8);
+    }
+
+    #[test]
+    fn test_evaluate_reports_score_curves_and_recordings() {
+        let factory: &PolicyFactory = &|| Box::new(crate::policy::SurvivalPolicy::new());
+        let options = EvaluateOptions {
+            max_steps: 40,
+            threads: 2,
+            record: true,
+            ..Default::default()
+        };
+        let report = evaluate("survival", factory, &config(), &[1, 2, 3], &options);
+
+        assert_eq!(report.policy, "survival");
+        // Episodes come back in seed order despite parallel workers
+        assert_eq!(
+            report.episodes.iter().map(|e| e.seed).collect::<Vec<_>>(),
+            vec![1, 2, 3]
+        );
+        assert_eq!(report.recordings.len(), 3);
+        assert_eq!(
+            report.success_rates.len(),
+            crate::achievement::Achievements::all_names().len()
+        );
+        for episode in &report.episodes {
+            // The curve is cumulative: one point per step, ending at
+            // the episode total
+            assert_eq!(episode.reward_curve.len() as u64, episode.steps);
+            let last = episode.reward_curve.last().copied().unwrap_or(0.0);
+            assert!((last - episode.reward).abs() < 1e-5);
+        }
+        // The aggregates agree with the per-episode results
+        let by_hand: f32 = report.episodes.iter().map(|e| e.reward).sum::<f32>() / 3.0;
+        assert!((report.mean_reward - by_hand).abs() < 1e-5);
+        assert!(report.score >= 0.0);
+
+        // A recorded episode replays to the reported step count
+        let mut replay =
+            crate::recording::ReplaySession::from_recording(&report.recordings[0]);
+        let replayed = replay.step_n(100);
+        assert_eq!(replayed.len() as u64, report.episodes[0].steps);
+    }
+
+    #[test]
+    fn test_matrix_covers_cells_and_resumes_from_checkpoint() {
+        let checkpoint = std::env::temp_dir().join("crafter_test_eval_matrix.json");
+        let _ = std::fs::remove_file(&checkpoint);
+
+        let configs = vec![("small".to_string(), config())];
+        let seeds = [1u64, 2];
+        let noop_factory: &PolicyFactory =
+            &|| Box::new(crate::policy::ScriptedPolicy::new(vec![]));
+        let random_factory: &PolicyFactory =
+            &|| Box::new(crate::policy::RandomPolicy::new(0));
+        let policies: Vec<(String, &PolicyFactory)> = vec![
+            ("noop".to_string(), noop_factory),
+            ("random-v1".to_string(), random_factory),
+        ];
+        let options = MatrixOptions {
+            max_steps: 10,
+            threads: 2,
+            checkpoint_path: Some(checkpoint.clone()),
+            ..Default::default()
+        };
+
+        let results = matrix(&configs, &seeds, &policies, &options).unwrap();
+        assert_eq!(results.cells.len(), 4);
+        assert!(results.mean_reward("noop", "small").is_some());
+        assert!(results.to_csv().lines().count() == 5);
+
+        // A rerun over the same checkpoint skips the finished cells and
+        // a widened sweep only runs the new ones
+        let results = matrix(&configs, &[1, 2, 3], &policies, &options).unwrap();
+        assert_eq!(results.cells.len(), 6);
+        let reloaded = MatrixResults::load_json(&checkpoint).unwrap();
+        assert_eq!(reloaded.cells.len(), 6);
+
+        let _ = std::fs::remove_file(&checkpoint);
+    }
+}
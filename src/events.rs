@@ -0,0 +1,108 @@
+//! Categorized event log entries
+//!
+//! The session produces its debug events as flat strings with stable
+//! prefixes ("DAMAGE:", "FORTUNE:", "lag:", ...). This module
+//! classifies those strings into categories with a severity, so the TUI
+//! can filter and color its event log and analytics can count event
+//! categories per episode without doing string matching of their own.
+//! Step results carry both forms: the raw strings for backwards
+//! compatibility and the classified entries in `log`.
+
+use serde::{Deserialize, Serialize};
+
+/// Which game system an event belongs to
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum EventCategory {
+    /// Damage, hordes, escort fights
+    Combat,
+    /// Gathering and inventory: fortune bonuses, capacity limits
+    Crafting,
+    /// Life stats: food, drink, energy, death
+    Survival,
+    /// Session machinery: lag handling, idle pause, curriculum
+    System,
+}
+
+/// How urgent an event is
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum EventSeverity {
+    Info,
+    Warning,
+    Critical,
+}
+
+/// One classified event; see [`LogEntry::classify`]
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct LogEntry {
+    pub category: EventCategory,
+    pub severity: EventSeverity,
+    pub message: String,
+}
+
+impl EventCategory {
+    /// Classify an event string by its prefix. Unrecognized messages
+    /// land in `System`/`Info` rather than erroring, so new event kinds
+    /// degrade gracefully.
+    pub fn of(message: &str) -> (EventCategory, EventSeverity) {
+        use EventCategory::*;
+        use EventSeverity::*;
+        if message.starts_with("DAMAGE:") || message.starts_with("HORDE:") {
+            (Combat, Warning)
+        } else if message.starts_with("ESCORT:") {
+            (Combat, Info)
+        } else if message.starts_with("Death cause:") {
+            (Survival, Critical)
+        } else if message.starts_with("DRINK")
+            || message.starts_with("FOOD")
+            || message.starts_with("ENERGY")
+        {
+            (Survival, Info)
+        } else if message.starts_with("FORTUNE:") {
+            (Crafting, Info)
+        } else if message.starts_with("CAPACITY:") {
+            (Crafting, Warning)
+        } else if message.starts_with("lag:") {
+            (System, Warning)
+        } else {
+            (System, Info)
+        }
+    }
+}
+
+impl LogEntry {
+    /// Build a classified entry from a raw event string
+    pub fn classify(message: &str) -> Self {
+        let (category, severity) = EventCategory::of(message);
+        Self {
+            category,
+            severity,
+            message: message.to_string(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_prefix_classification() {
+        let cases = [
+            ("DAMAGE: 9 -> 7 (cause: zombie)", EventCategory::Combat, EventSeverity::Warning),
+            ("Death cause: lava", EventCategory::Survival, EventSeverity::Critical),
+            ("DRINK: 5 -> 6 (from action Do)", EventCategory::Survival, EventSeverity::Info),
+            ("FORTUNE: rich vein yielded bonus coal", EventCategory::Crafting, EventSeverity::Info),
+            ("CAPACITY: carrying too much, pickup failed", EventCategory::Crafting, EventSeverity::Warning),
+            ("lag: dropped 120ms backlog after 4 ticks (drop_ticks)", EventCategory::System, EventSeverity::Warning),
+            ("curriculum stage applied: harder nights", EventCategory::System, EventSeverity::Info),
+        ];
+        for (message, category, severity) in cases {
+            let entry = LogEntry::classify(message);
+            assert_eq!(entry.category, category, "{}", message);
+            assert_eq!(entry.severity, severity, "{}", message);
+            assert_eq!(entry.message, message);
+        }
+    }
+}
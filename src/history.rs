@@ -0,0 +1,247 @@
+//! Keyframe-compressed world history
+//!
+//! A [`WorldHistory`] records how the terrain evolved over an episode:
+//! full keyframes every so often, tile diffs in between. It answers
+//! "what did the map look like at step 3000" without storing every
+//! frame, which makes terraforming analysis and time-lapse rendering
+//! of long runs practical.
+//!
+//! Recording is optional and per-session: call
+//! [`Session::enable_world_history`](crate::session::Session::enable_world_history)
+//! and the session feeds the recorder after every step. Only terrain is
+//! tracked — mobs move every tick and would defeat the compression;
+//! recordings already capture entities.
+
+use crate::material::Material;
+use crate::world::World;
+use serde::{Deserialize, Serialize};
+
+/// One recorded point in the history
+#[derive(Clone, Debug, Serialize, Deserialize)]
+enum HistoryEntry {
+    /// Full terrain snapshot
+    Keyframe { step: u64, materials: Vec<Material> },
+    /// Tiles that changed since the previous entry
+    Diff {
+        step: u64,
+        changes: Vec<(u32, Material)>,
+    },
+}
+
+impl HistoryEntry {
+    fn step(&self) -> u64 {
+        match self {
+            HistoryEntry::Keyframe { step, .. } | HistoryEntry::Diff { step, .. } => *step,
+        }
+    }
+}
+
+/// Terrain history as keyframes plus tile diffs
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct WorldHistory {
+    area: (u32, u32),
+    /// A fresh keyframe is written after this many diff entries
+    keyframe_interval: u32,
+    /// Entries in ascending step order, starting with a keyframe
+    entries: Vec<HistoryEntry>,
+    /// Terrain as of the last entry, diffed against on `record`
+    current: Vec<Material>,
+    /// Diff entries since the last keyframe
+    diffs_since_keyframe: u32,
+    /// World change counter at the last `record`, to skip unchanged steps
+    last_version: u64,
+}
+
+impl WorldHistory {
+    /// Default number of diff entries between keyframes
+    pub const DEFAULT_KEYFRAME_INTERVAL: u32 = 64;
+
+    /// Start a history at `step` with the world's current terrain as
+    /// the first keyframe
+    pub fn new(world: &World, step: u64, keyframe_interval: u32) -> Self {
+        Self {
+            area: world.area,
+            keyframe_interval: keyframe_interval.max(1),
+            entries: vec![HistoryEntry::Keyframe {
+                step,
+                materials: world.materials.clone(),
+            }],
+            current: world.materials.clone(),
+            diffs_since_keyframe: 0,
+            last_version: world.version(),
+        }
+    }
+
+    /// Record the world's terrain as of `step`. Steps where no tile
+    /// changed are skipped entirely, so calling this every step is fine.
+    pub fn record(&mut self, step: u64, world: &World) {
+        if world.version() == self.last_version {
+            return;
+        }
+        self.last_version = world.version();
+
+        let changes: Vec<(u32, Material)> = world
+            .materials
+            .iter()
+            .enumerate()
+            .filter(|&(i, &mat)| mat != self.current[i])
+            .map(|(i, &mat)| (i as u32, mat))
+            .collect();
+        if changes.is_empty() {
+            // Object churn bumps the version without touching terrain
+            return;
+        }
+
+        for &(i, mat) in &changes {
+            self.current[i as usize] = mat;
+        }
+
+        if self.diffs_since_keyframe >= self.keyframe_interval {
+            self.entries.push(HistoryEntry::Keyframe {
+                step,
+                materials: self.current.clone(),
+            });
+            self.diffs_since_keyframe = 0;
+        } else {
+            self.entries.push(HistoryEntry::Diff { step, changes });
+            self.diffs_since_keyframe += 1;
+        }
+    }
+
+    /// World dimensions the history was recorded against
+    pub fn area(&self) -> (u32, u32) {
+        self.area
+    }
+
+    /// Number of diff entries between keyframes
+    pub fn keyframe_interval(&self) -> u32 {
+        self.keyframe_interval
+    }
+
+    /// Step of the first recorded entry
+    pub fn first_step(&self) -> u64 {
+        self.entries[0].step()
+    }
+
+    /// Step of the most recent recorded entry
+    pub fn last_step(&self) -> u64 {
+        self.entries[self.entries.len() - 1].step()
+    }
+
+    /// Number of stored entries (keyframes plus diffs)
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Reconstruct the terrain as of `step` (row-major, same layout as
+    /// [`World::materials`]). `None` when `step` predates the history.
+    pub fn materials_at(&self, step: u64) -> Option<Vec<Material>> {
+        let upto = self.entries.partition_point(|e| e.step() <= step);
+        if upto == 0 {
+            return None;
+        }
+
+        // Walk back to the governing keyframe, then replay diffs forward
+        let base = self.entries[..upto]
+            .iter()
+            .rposition(|e| matches!(e, HistoryEntry::Keyframe { .. }))
+            .expect("histories start with a keyframe");
+        let mut materials = match &self.entries[base] {
+            HistoryEntry::Keyframe { materials, .. } => materials.clone(),
+            HistoryEntry::Diff { .. } => unreachable!(),
+        };
+        for entry in &self.entries[base + 1..upto] {
+            if let HistoryEntry::Diff { changes, .. } = entry {
+                for &(i, mat) in changes {
+                    materials[i as usize] = mat;
+                }
+            }
+        }
+        Some(materials)
+    }
+
+    /// The material of one tile as of `step`
+    pub fn material_at(&self, step: u64, pos: (i32, i32)) -> Option<Material> {
+        if pos.0 < 0 || pos.0 >= self.area.0 as i32 || pos.1 < 0 || pos.1 >= self.area.1 as i32 {
+            return None;
+        }
+        let materials = self.materials_at(step)?;
+        Some(materials[(pos.1 as u32 * self.area.0 + pos.0 as u32) as usize])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::action::Action;
+    use crate::config::SessionConfig;
+    use crate::session::Session;
+
+    #[test]
+    fn test_history_reconstructs_past_terrain() {
+        let mut world = World::new(8, 8, 0);
+        let mut history = WorldHistory::new(&world, 0, WorldHistory::DEFAULT_KEYFRAME_INTERVAL);
+
+        world.set_material((2, 2), Material::Stone);
+        history.record(10, &world);
+        world.set_material((2, 2), Material::Path);
+        world.set_material((5, 5), Material::Water);
+        history.record(20, &world);
+
+        assert_eq!(history.material_at(0, (2, 2)), Some(Material::Grass));
+        assert_eq!(history.material_at(10, (2, 2)), Some(Material::Stone));
+        assert_eq!(history.material_at(15, (2, 2)), Some(Material::Stone));
+        assert_eq!(history.material_at(20, (2, 2)), Some(Material::Path));
+        assert_eq!(history.material_at(20, (5, 5)), Some(Material::Water));
+        assert_eq!(history.first_step(), 0);
+        assert_eq!(history.last_step(), 20);
+    }
+
+    #[test]
+    fn test_history_skips_unchanged_steps_and_inserts_keyframes() {
+        let mut world = World::new(8, 8, 0);
+        let mut history = WorldHistory::new(&world, 0, 2);
+
+        // Nothing changed: nothing stored
+        history.record(1, &world);
+        assert_eq!(history.len(), 1);
+
+        for step in 0..6u64 {
+            let x = (step % 8) as i32;
+            world.set_material((x, 3), Material::Sand);
+            history.record(10 + step, &world);
+        }
+        // 1 initial keyframe + 6 changes with a keyframe every 2 diffs
+        assert_eq!(history.len(), 7);
+
+        // Reconstruction across keyframe boundaries stays consistent
+        assert_eq!(history.material_at(12, (2, 3)), Some(Material::Sand));
+        assert_eq!(history.material_at(12, (3, 3)), Some(Material::Grass));
+        assert_eq!(history.material_at(100, (5, 3)), Some(Material::Sand));
+        assert!(history.materials_at(100).is_some());
+    }
+
+    #[test]
+    fn test_session_records_history_during_play() {
+        let config = SessionConfig {
+            world_size: (32, 32),
+            seed: Some(5),
+            ..Default::default()
+        };
+        let mut session = Session::new(config);
+        session.enable_world_history(WorldHistory::DEFAULT_KEYFRAME_INTERVAL);
+
+        let before = session.world.get_material((1, 1)).unwrap();
+        session.world.set_material((1, 1), Material::Furnace);
+        session.step(Action::Noop);
+        let step = session.get_state().step;
+
+        let history = session.world_history.as_ref().unwrap();
+        assert_eq!(history.material_at(0, (1, 1)), Some(before));
+        assert_eq!(history.material_at(step, (1, 1)), Some(Material::Furnace));
+    }
+}
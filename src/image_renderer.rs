@@ -14,8 +14,6 @@ use crate::entity::GameObject;
 #[cfg(feature = "png")]
 use crate::material::Material;
 use crate::session::GameState;
-#[cfg(feature = "png")]
-use crate::world::WorldView;
 
 /// Embedded sprite data (16x16 PNG files from Python Crafter)
 #[cfg(feature = "png")]
@@ -34,6 +32,7 @@ mod sprites {
     pub const CHEST: &[u8] = include_bytes!("../assets/chest.png");
     pub const TABLE: &[u8] = include_bytes!("../assets/table.png");
     pub const FURNACE: &[u8] = include_bytes!("../assets/furnace.png");
+    pub const FENCE: &[u8] = include_bytes!("../assets/fence.png");
     pub const LAVA: &[u8] = include_bytes!("../assets/lava.png");
     pub const PATH: &[u8] = include_bytes!("../assets/path.png");
     pub const WOOD: &[u8] = include_bytes!("../assets/wood.png");
@@ -134,6 +133,17 @@ impl SpriteCache {
         self.load("player-left", sprites::PLAYER_LEFT);
         self.load("player-right", sprites::PLAYER_RIGHT);
         self.load("cow", sprites::COW);
+        // No dedicated art yet for the extra wildlife; reuse the cow sprite
+        self.load("sheep", sprites::COW);
+        self.load("pig", sprites::COW);
+        // The friendly escort knight reuses the hostile knight's art
+        self.load("escort_knight", sprites::KNIGHT);
+        // Defensive structures: fence has shipped art; doors and traps reuse
+        // the closest existing tiles
+        self.load("fence", sprites::FENCE);
+        self.load("door_closed", sprites::TABLE);
+        self.load("door_open", sprites::PATH);
+        self.load("spike_trap", sprites::IRON);
         self.load("zombie", sprites::ZOMBIE);
         self.load("skeleton", sprites::SKELETON);
         self.load("orc_soldier", sprites::ORC_SOLDIER);
@@ -242,12 +252,96 @@ impl ImageRendererConfig {
     }
 }
 
+/// Number of daylight buckets the lighting cache distinguishes; the
+/// daylight value is quantized to the bucket center before shading
+#[cfg(feature = "png")]
+const LIGHT_BUCKETS: usize = 64;
+
+/// Precomputed lighting table for one daylight level.
+///
+/// The night shade desaturates toward a dark blue tint; doing that per
+/// pixel costs five multiplications. The table folds everything that
+/// depends only on a single channel value (or on the gray level) into
+/// 256-entry lookups, so shading a pixel is six lookups and five adds.
+#[cfg(feature = "png")]
+pub struct LightingLut {
+    gray_r: [f32; 256],
+    gray_g: [f32; 256],
+    gray_b: [f32; 256],
+    day: [f32; 256],
+    night_r: [f32; 256],
+    night_g: [f32; 256],
+    night_b: [f32; 256],
+}
+
+#[cfg(feature = "png")]
+impl LightingLut {
+    /// Build the table for a daylight level in `[0, 1]`
+    pub fn new(daylight: f32) -> Self {
+        // Night tint color (dark blue) and strength, matching the
+        // original per-pixel math
+        let tint = (0u8, 16u8, 64u8);
+        let tint_strength = 0.5;
+
+        let mut lut = Self {
+            gray_r: [0.0; 256],
+            gray_g: [0.0; 256],
+            gray_b: [0.0; 256],
+            day: [0.0; 256],
+            night_r: [0.0; 256],
+            night_g: [0.0; 256],
+            night_b: [0.0; 256],
+        };
+        for v in 0..256 {
+            lut.gray_r[v] = v as f32 * 0.299;
+            lut.gray_g[v] = v as f32 * 0.587;
+            lut.gray_b[v] = v as f32 * 0.114;
+            lut.day[v] = v as f32 * daylight;
+            // Indexed by gray level, premultiplied by the night weight
+            let night = |t: u8| {
+                ((v as u8 as f32 * (1.0 - tint_strength)) + (t as f32 * tint_strength)) as u8 as f32
+                    * (1.0 - daylight)
+            };
+            lut.night_r[v] = night(tint.0);
+            lut.night_g[v] = night(tint.1);
+            lut.night_b[v] = night(tint.2);
+        }
+        lut
+    }
+
+    /// Shade one pixel
+    #[inline]
+    pub fn shade(&self, r: u8, g: u8, b: u8) -> (u8, u8, u8) {
+        let gray = (self.gray_r[r as usize] + self.gray_g[g as usize] + self.gray_b[b as usize])
+            .min(255.0) as usize;
+        (
+            (self.day[r as usize] + self.night_r[gray]) as u8,
+            (self.day[g as usize] + self.night_g[gray]) as u8,
+            (self.day[b as usize] + self.night_b[gray]) as u8,
+        )
+    }
+
+    /// Shade a packed RGB byte buffer in place
+    pub fn apply_rgb(&self, rgb: &mut [u8]) {
+        for px in rgb.chunks_exact_mut(3) {
+            let (r, g, b) = self.shade(px[0], px[1], px[2]);
+            px[0] = r;
+            px[1] = g;
+            px[2] = b;
+        }
+    }
+}
+
 /// PNG image renderer using sprites
 #[cfg(feature = "png")]
 pub struct ImageRenderer {
     #[allow(dead_code)]
     config: ImageRendererConfig,
     sprites: SpriteCache,
+    /// Lazily built lighting tables, one per daylight bucket. Heap-
+    /// allocated: the tables total a few hundred KB, too big to carry
+    /// inline in every renderer.
+    light_luts: Vec<std::sync::OnceLock<LightingLut>>,
 }
 
 #[cfg(not(feature = "png"))]
@@ -263,14 +357,25 @@ impl ImageRenderer {
         Self {
             config,
             sprites: SpriteCache::new(),
+            light_luts: (0..LIGHT_BUCKETS).map(|_| std::sync::OnceLock::new()).collect(),
         }
     }
 
     /// Render game state to raw RGB bytes
     pub fn render_bytes(&self, state: &GameState) -> Vec<u8> {
+        let mut out = Vec::new();
+        self.render_bytes_into(state, &mut out);
+        out
+    }
+
+    /// Render game state into a reused RGB byte buffer. The buffer is
+    /// cleared first, so a loop that renders every step allocates only
+    /// on the first iteration.
+    pub fn render_bytes_into(&self, state: &GameState, out: &mut Vec<u8>) {
+        out.clear();
         let view = match &state.view {
             Some(v) => v,
-            None => return Vec::new(),
+            None => return,
         };
 
         let view_size = view.size() as u32;
@@ -345,6 +450,25 @@ impl ImageRenderer {
             }
         }
 
+        // Crack overlay on partially mined tiles (multi-hit mining)
+        if !view.mining_progress.is_empty() {
+            for vy in 0..view_size as usize {
+                for vx in 0..view_size as usize {
+                    let progress = view.get_mining_progress(vx as i32, vy as i32);
+                    if progress == 0 {
+                        continue;
+                    }
+                    let hardness = view
+                        .get_material(vx as i32, vy as i32)
+                        .map(|m| m.hardness())
+                        .unwrap_or(1)
+                        .max(1);
+                    let fraction = progress as f32 / hardness as f32;
+                    self.draw_cracks(&mut img, vx as u32 * tile_size, vy as u32 * tile_size, fraction);
+                }
+            }
+        }
+
         // Render entities (objects from view)
         // Note: view.objects coordinates are 0-indexed view coordinates (0 to size-1)
         for (vx, vy, obj) in &view.objects {
@@ -474,14 +598,47 @@ impl ImageRenderer {
         }
 
         // Convert RGBA to RGB
-        let mut rgb_bytes = Vec::with_capacity((width * height * 3) as usize);
+        out.reserve((width * height * 3) as usize);
         for pixel in img.pixels() {
-            rgb_bytes.push(pixel[0]);
-            rgb_bytes.push(pixel[1]);
-            rgb_bytes.push(pixel[2]);
+            out.extend_from_slice(&pixel.0[..3]);
+        }
+    }
+
+    /// Render a batch of states, parallelized across available cores.
+    /// For dataset frame generation and vectorized pixel observations.
+    pub fn render_batch(&self, states: &[GameState]) -> Vec<Vec<u8>> {
+        let mut out = Vec::new();
+        self.render_batch_into(states, &mut out);
+        out
+    }
+
+    /// Like `render_batch`, but reuses the byte buffers already in
+    /// `out` across calls
+    pub fn render_batch_into(&self, states: &[GameState], out: &mut Vec<Vec<u8>>) {
+        out.resize_with(states.len(), Vec::new);
+        out.truncate(states.len());
+
+        let workers = std::thread::available_parallelism()
+            .map(|n| n.get())
+            .unwrap_or(1)
+            .min(states.len().max(1));
+        if workers <= 1 {
+            for (state, buf) in states.iter().zip(out.iter_mut()) {
+                self.render_bytes_into(state, buf);
+            }
+            return;
         }
 
-        rgb_bytes
+        let chunk = states.len().div_ceil(workers);
+        std::thread::scope(|scope| {
+            for (states_chunk, out_chunk) in states.chunks(chunk).zip(out.chunks_mut(chunk)) {
+                scope.spawn(move || {
+                    for (state, buf) in states_chunk.iter().zip(out_chunk.iter_mut()) {
+                        self.render_bytes_into(state, buf);
+                    }
+                });
+            }
+        });
     }
 
     /// Render a single entity sprite to raw RGBA bytes.
@@ -503,7 +660,7 @@ impl ImageRenderer {
 
     /// Draw a number (1-9) at the given position
     fn draw_number(&self, img: &mut RgbaImage, value: u32, x: u32, y: u32, size: u32) {
-        let digit = value.min(9).max(1);
+        let digit = value.clamp(1, 9);
         let sprite_name = format!("{}", digit);
         if let Some(sprite) = self.sprites.get(sprite_name.as_str()) {
             self.draw_sprite_at_size(img, sprite, x, y, size);
@@ -628,6 +785,31 @@ impl ImageRenderer {
         }
     }
 
+    /// Overlay crack scratches on a partially mined tile. The main diagonal
+    /// appears first and the off-diagonals join in as `fraction` (progress
+    /// over hardness) approaches 1, so tiles visibly wear down.
+    fn draw_cracks(&self, img: &mut RgbaImage, x: u32, y: u32, fraction: f32) {
+        let tile_size = self.config.tile_size;
+        let stages = ((fraction * 3.0).ceil() as u32).clamp(1, 3);
+
+        for t in 0..tile_size {
+            let mut points = vec![(t, t)];
+            if stages >= 2 {
+                points.push((tile_size - 1 - t, t / 2));
+            }
+            if stages >= 3 {
+                points.push((t / 2, tile_size - 1 - t));
+            }
+            for (px, py) in points {
+                if x + px < img.width() && y + py < img.height() {
+                    let dst = img.get_pixel(x + px, y + py);
+                    let darkened = Rgba([dst[0] / 3, dst[1] / 3, dst[2] / 3, 255]);
+                    img.put_pixel(x + px, y + py, darkened);
+                }
+            }
+        }
+    }
+
     /// Apply day/night lighting effect to entire image
     #[allow(dead_code)]
     fn apply_daylight(&self, img: &mut RgbaImage, daylight: f32) {
@@ -640,32 +822,27 @@ impl ImageRenderer {
             return;
         }
 
-        // Night tint color (dark blue)
-        let tint = (0u8, 16u8, 64u8);
-        let tint_strength = 0.5;
-
+        let lut = self.lighting_lut(daylight);
         for y in y_start..y_end.min(img.height()) {
             for x in 0..img.width() {
                 let pixel = img.get_pixel_mut(x, y);
-                // Desaturate
-                let gray = (pixel[0] as f32 * 0.299
-                    + pixel[1] as f32 * 0.587
-                    + pixel[2] as f32 * 0.114) as u8;
-
-                let night_r =
-                    ((gray as f32 * (1.0 - tint_strength)) + (tint.0 as f32 * tint_strength)) as u8;
-                let night_g =
-                    ((gray as f32 * (1.0 - tint_strength)) + (tint.1 as f32 * tint_strength)) as u8;
-                let night_b =
-                    ((gray as f32 * (1.0 - tint_strength)) + (tint.2 as f32 * tint_strength)) as u8;
-
-                pixel[0] = ((pixel[0] as f32 * daylight) + (night_r as f32 * (1.0 - daylight))) as u8;
-                pixel[1] = ((pixel[1] as f32 * daylight) + (night_g as f32 * (1.0 - daylight))) as u8;
-                pixel[2] = ((pixel[2] as f32 * daylight) + (night_b as f32 * (1.0 - daylight))) as u8;
+                let (r, g, b) = lut.shade(pixel[0], pixel[1], pixel[2]);
+                pixel[0] = r;
+                pixel[1] = g;
+                pixel[2] = b;
             }
         }
     }
 
+    /// The cached lighting table for a daylight level, built on first
+    /// use of its bucket
+    pub fn lighting_lut(&self, daylight: f32) -> &LightingLut {
+        let bucket = ((daylight.clamp(0.0, 1.0) * LIGHT_BUCKETS as f32) as usize)
+            .min(LIGHT_BUCKETS - 1);
+        self.light_luts[bucket]
+            .get_or_init(|| LightingLut::new((bucket as f32 + 0.5) / LIGHT_BUCKETS as f32))
+    }
+
     /// Get sprite name for a material
     fn material_sprite(&self, mat: Material) -> &'static str {
         match mat {
@@ -684,6 +861,10 @@ impl ImageRenderer {
             Material::Lava => "lava",
             Material::Path => "path",
             Material::Chest => "chest",
+            Material::SpikeTrap => "spike_trap",
+            Material::DoorClosed => "door_closed",
+            Material::DoorOpen => "door_open",
+            Material::Fence => "fence",
         }
     }
 
@@ -691,6 +872,8 @@ impl ImageRenderer {
     fn entity_sprite(&self, obj: &GameObject) -> &'static str {
         match obj {
             GameObject::Cow(_) => "cow",
+            GameObject::Sheep(_) => "sheep",
+            GameObject::Pig(_) => "pig",
             GameObject::Zombie(_) => "zombie",
             GameObject::Skeleton(_) => "skeleton",
             GameObject::CraftaxMob(mob) => match mob.kind {
@@ -715,6 +898,7 @@ impl ImageRenderer {
                 crate::entity::ProjectileKind::Iceball => "iceball",
             },
             GameObject::Player(_) => "player",
+            GameObject::EscortKnight(_) => "escort_knight",
         }
     }
 }
@@ -729,11 +913,176 @@ impl ImageRenderer {
         Vec::new()
     }
 
+    pub fn render_bytes_into(&self, _state: &GameState, out: &mut Vec<u8>) {
+        out.clear();
+    }
+
+    pub fn render_batch(&self, states: &[GameState]) -> Vec<Vec<u8>> {
+        vec![Vec::new(); states.len()]
+    }
+
+    pub fn render_batch_into(&self, states: &[GameState], out: &mut Vec<Vec<u8>>) {
+        out.resize_with(states.len(), Vec::new);
+        out.truncate(states.len());
+        for buf in out.iter_mut() {
+            buf.clear();
+        }
+    }
+
     pub fn render_entity_icon(&self, _obj: &GameObject) -> Option<(Vec<u8>, u32, u32)> {
         None
     }
 }
 
+/// Options for [`TimelapseExporter`]
+#[cfg(feature = "png")]
+#[derive(Clone, Debug)]
+pub struct TimelapseOptions {
+    /// Pixels per tile
+    pub scale: u32,
+    /// Steps between frames
+    pub step_interval: u64,
+    /// Per-frame delay in milliseconds
+    pub frame_delay_ms: u32,
+    /// Loop the animation forever
+    pub loop_forever: bool,
+}
+
+#[cfg(feature = "png")]
+impl Default for TimelapseOptions {
+    fn default() -> Self {
+        Self {
+            scale: 4,
+            step_interval: 50,
+            frame_delay_ms: 100,
+            loop_forever: true,
+        }
+    }
+}
+
+/// Renders a [`WorldHistory`](crate::history::WorldHistory) into an
+/// animated GIF time-lapse of the full map — deforestation, mining
+/// tunnels, and base construction at a glance.
+///
+/// Frames use flat per-material colors rather than sprites: at a few
+/// pixels per tile the whole episode fits in a small file, and terrain
+/// (the only thing the history tracks) is what reads at that scale.
+/// For MP4, feed the GIF to ffmpeg; the crate deliberately carries no
+/// video encoder dependency.
+#[cfg(feature = "png")]
+pub struct TimelapseExporter {
+    options: TimelapseOptions,
+}
+
+#[cfg(feature = "png")]
+impl TimelapseExporter {
+    pub fn new(options: TimelapseOptions) -> Self {
+        Self {
+            options: TimelapseOptions {
+                scale: options.scale.max(1),
+                step_interval: options.step_interval.max(1),
+                ..options
+            },
+        }
+    }
+
+    /// Flat map color for one material
+    fn material_color(material: Material) -> [u8; 3] {
+        let palette = ColorPalette::default();
+        match material {
+            Material::Water => palette.water,
+            Material::Grass => palette.grass,
+            Material::Stone => palette.stone,
+            Material::Path => palette.path,
+            Material::Sand => palette.sand,
+            Material::Tree => palette.tree,
+            Material::Lava => palette.lava,
+            Material::Coal => palette.coal,
+            Material::Iron => palette.iron,
+            Material::Diamond => palette.diamond,
+            Material::Table => palette.table,
+            Material::Furnace => palette.furnace,
+            Material::Sapphire => [70, 100, 230],
+            Material::Ruby => [200, 40, 80],
+            Material::Chest => [160, 120, 60],
+            Material::SpikeTrap => [90, 90, 90],
+            Material::DoorClosed => [120, 80, 40],
+            Material::DoorOpen => [150, 110, 70],
+            Material::Fence => [110, 75, 40],
+        }
+    }
+
+    /// Render the terrain as of `step` as one full-map frame
+    pub fn render_frame(
+        &self,
+        history: &crate::history::WorldHistory,
+        step: u64,
+    ) -> Option<RgbaImage> {
+        let materials = history.materials_at(step)?;
+        let (width, height) = history.area();
+        let scale = self.options.scale;
+        let mut image = RgbaImage::new(width * scale, height * scale);
+        for (i, &material) in materials.iter().enumerate() {
+            let [r, g, b] = Self::material_color(material);
+            let (tx, ty) = (i as u32 % width, i as u32 / width);
+            for dy in 0..scale {
+                for dx in 0..scale {
+                    image.put_pixel(tx * scale + dx, ty * scale + dy, Rgba([r, g, b, 255]));
+                }
+            }
+        }
+        Some(image)
+    }
+
+    /// The steps the export will render: one frame per `step_interval`
+    /// from the first recorded step, plus the final state
+    pub fn frame_steps(&self, history: &crate::history::WorldHistory) -> Vec<u64> {
+        let (first, last) = (history.first_step(), history.last_step());
+        let mut steps: Vec<u64> = (first..=last)
+            .step_by(self.options.step_interval as usize)
+            .collect();
+        if steps.last() != Some(&last) {
+            steps.push(last);
+        }
+        steps
+    }
+
+    /// Encode the time-lapse as an animated GIF
+    pub fn export_gif<W: std::io::Write>(
+        &self,
+        history: &crate::history::WorldHistory,
+        writer: W,
+    ) -> image::ImageResult<()> {
+        use image::codecs::gif::{GifEncoder, Repeat};
+        use image::{Delay, Frame};
+
+        let mut encoder = GifEncoder::new(writer);
+        if self.options.loop_forever {
+            encoder.set_repeat(Repeat::Infinite)?;
+        }
+        let delay = Delay::from_numer_denom_ms(self.options.frame_delay_ms, 1);
+        for step in self.frame_steps(history) {
+            let image = self
+                .render_frame(history, step)
+                .expect("frame steps lie within the history");
+            encoder.encode_frame(Frame::from_parts(image, 0, 0, delay))?;
+        }
+        Ok(())
+    }
+
+    /// Write the time-lapse GIF to a file
+    pub fn export_gif_to_path<P: AsRef<std::path::Path>>(
+        &self,
+        history: &crate::history::WorldHistory,
+        path: P,
+    ) -> std::io::Result<()> {
+        let file = std::fs::File::create(path)?;
+        let writer = std::io::BufWriter::new(file);
+        self.export_gif(history, writer)
+            .map_err(|e| std::io::Error::new(std::io::ErrorKind::InvalidData, e))
+    }
+}
+
 // Keep the old ColorPalette for compatibility
 #[derive(Clone, Debug)]
 pub struct ColorPalette {
@@ -828,4 +1177,110 @@ mod tests {
         let expected_height = 9 * 7;
         assert_eq!(bytes.len(), (expected_width * expected_height * 3) as usize);
     }
+
+    #[test]
+    fn test_render_batch_matches_individual_renders() {
+        let mut session = Session::new(SessionConfig {
+            world_size: (32, 32),
+            seed: Some(42),
+            view_radius: 4,
+            ..Default::default()
+        });
+
+        let mut states = vec![session.get_state()];
+        for _ in 0..7 {
+            states.push(session.step(crate::action::Action::MoveRight).state);
+        }
+
+        let renderer = ImageRenderer::new(ImageRendererConfig::small());
+        let batch = renderer.render_batch(&states);
+        assert_eq!(batch.len(), states.len());
+        for (frame, state) in batch.iter().zip(&states) {
+            assert_eq!(*frame, renderer.render_bytes(state));
+        }
+
+        // Buffers survive across calls when rendering into the same batch
+        let mut reused = batch;
+        renderer.render_batch_into(&states[..4], &mut reused);
+        assert_eq!(reused.len(), 4);
+        assert_eq!(reused[0], renderer.render_bytes(&states[0]));
+    }
+
+    #[test]
+    fn test_lighting_lut_matches_per_pixel_math() {
+        let daylight = 0.3f32;
+        let lut = LightingLut::new(daylight);
+        let tint = (0u8, 16u8, 64u8);
+        let tint_strength = 0.5f32;
+
+        for &(r, g, b) in &[(0u8, 0u8, 0u8), (255, 255, 255), (102, 176, 50), (66, 135, 245)] {
+            let gray =
+                (r as f32 * 0.299 + g as f32 * 0.587 + b as f32 * 0.114) as u8;
+            let night = |t: u8| {
+                ((gray as f32 * (1.0 - tint_strength)) + (t as f32 * tint_strength)) as u8
+            };
+            let expected = (
+                ((r as f32 * daylight) + (night(tint.0) as f32 * (1.0 - daylight))) as u8,
+                ((g as f32 * daylight) + (night(tint.1) as f32 * (1.0 - daylight))) as u8,
+                ((b as f32 * daylight) + (night(tint.2) as f32 * (1.0 - daylight))) as u8,
+            );
+            assert_eq!(lut.shade(r, g, b), expected);
+        }
+    }
+
+    #[test]
+    fn test_timelapse_export_produces_animated_gif() {
+        use crate::history::WorldHistory;
+        use crate::world::World;
+
+        let mut world = World::new(8, 8, 0);
+        let mut history = WorldHistory::new(&world, 0, 4);
+        world.set_material((1, 1), Material::Stone);
+        history.record(10, &world);
+        world.set_material((2, 1), Material::Stone);
+        history.record(60, &world);
+
+        let exporter = TimelapseExporter::new(TimelapseOptions {
+            scale: 2,
+            step_interval: 50,
+            ..Default::default()
+        });
+        // One frame per interval plus the final state
+        assert_eq!(exporter.frame_steps(&history), vec![0, 50, 60]);
+
+        let frame = exporter.render_frame(&history, 10).unwrap();
+        assert_eq!(frame.dimensions(), (16, 16));
+        let palette = ColorPalette::default();
+        let stone = palette.stone;
+        let grass = palette.grass;
+        assert_eq!(frame.get_pixel(2, 2).0, [stone[0], stone[1], stone[2], 255]);
+        assert_eq!(frame.get_pixel(4, 2).0, [grass[0], grass[1], grass[2], 255]);
+
+        let mut bytes = Vec::new();
+        exporter.export_gif(&history, &mut bytes).unwrap();
+        assert_eq!(&bytes[0..6], b"GIF89a");
+    }
+
+    #[test]
+    fn test_lighting_applies_at_night() {
+        let session = Session::new(SessionConfig {
+            world_size: (32, 32),
+            seed: Some(42),
+            ..Default::default()
+        });
+        let mut state = session.get_state();
+
+        let renderer = ImageRenderer::new(ImageRendererConfig {
+            tile_size: 10,
+            show_status_bars: false,
+            apply_lighting: true,
+        });
+
+        state.daylight = 1.0;
+        let day = renderer.render_bytes(&state);
+        state.daylight = 0.1;
+        let night = renderer.render_bytes(&state);
+        assert_eq!(day.len(), night.len());
+        assert_ne!(day, night);
+    }
 }
@@ -1,5 +1,6 @@
 //! Player inventory system
 
+use crate::recipes::RecipeCost;
 use serde::{Deserialize, Serialize};
 
 /// Maximum value for any inventory slot
@@ -28,6 +29,16 @@ pub struct Inventory {
     pub diamond: u8,
     pub sapphire: u8,
     pub ruby: u8,
+    #[serde(default)]
+    pub wool: u8,
+
+    // Defensive structures (crafted, placed via the Place* actions)
+    #[serde(default)]
+    pub spike_trap: u8,
+    #[serde(default)]
+    pub door: u8,
+    #[serde(default)]
+    pub fence: u8,
 
     // Tools (all start at 0, max 9)
     pub wood_pickaxe: u8,
@@ -86,6 +97,11 @@ impl Inventory {
             diamond: 0,
             sapphire: 0,
             ruby: 0,
+            wool: 0,
+
+            spike_trap: 0,
+            door: 0,
+            fence: 0,
 
             // Tools start at 0
             wood_pickaxe: 0,
@@ -157,6 +173,11 @@ impl Inventory {
         add_capped(&mut self.sapling, amount);
     }
 
+    /// Add wool
+    pub fn add_wool(&mut self, amount: u8) {
+        add_capped(&mut self.wool, amount);
+    }
+
     /// Add food
     pub fn add_food(&mut self, amount: u8) {
         add_capped(&mut self.food, amount);
@@ -225,6 +246,36 @@ impl Inventory {
         self.health > 0
     }
 
+    /// Total stackable items carried: raw resources, arrows, potions, and
+    /// placeable structures. Vitals, tools, and armor never count toward
+    /// the carry limit.
+    pub fn total_carried(&self) -> u32 {
+        [
+            self.sapling,
+            self.wood,
+            self.stone,
+            self.coal,
+            self.iron,
+            self.diamond,
+            self.sapphire,
+            self.ruby,
+            self.wool,
+            self.spike_trap,
+            self.door,
+            self.fence,
+            self.arrows,
+            self.potion_red,
+            self.potion_green,
+            self.potion_blue,
+            self.potion_pink,
+            self.potion_cyan,
+            self.potion_yellow,
+        ]
+        .iter()
+        .map(|&count| count as u32)
+        .sum()
+    }
+
     /// Get the best pickaxe tier (0 = none, 1 = wood, 2 = stone, 3 = iron, 4 = diamond)
     pub fn best_pickaxe_tier(&self) -> u8 {
         if self.diamond_pickaxe > 0 {
@@ -323,6 +374,21 @@ impl Inventory {
         self.wood >= 1 && self.stone >= 1
     }
 
+    /// Check if player can craft a spike trap (needs table nearby, 1 wood, 1 stone)
+    pub fn can_craft_spike_trap(&self) -> bool {
+        self.wood >= 1 && self.stone >= 1
+    }
+
+    /// Check if player can craft a door (needs table nearby, 2 wood)
+    pub fn can_craft_door(&self) -> bool {
+        self.wood >= 2
+    }
+
+    /// Check if player can craft a fence (needs table nearby, 1 wood)
+    pub fn can_craft_fence(&self) -> bool {
+        self.wood >= 1
+    }
+
     /// Consume materials for wood pickaxe
     pub fn craft_wood_pickaxe(&mut self) -> bool {
         if self.can_craft_wood_pickaxe() {
@@ -359,6 +425,68 @@ impl Inventory {
         }
     }
 
+    /// Craft a wood pickaxe from an explicit recipe cost. The session uses
+    /// these `_with` variants so classic and mutated recipe books share one
+    /// code path; the fixed `craft_*` methods keep the classic costs.
+    pub fn craft_wood_pickaxe_with(&mut self, cost: &RecipeCost) -> bool {
+        if cost.consume(self) {
+            add_capped(&mut self.wood_pickaxe, 1);
+            true
+        } else {
+            false
+        }
+    }
+
+    /// Craft a stone pickaxe from an explicit recipe cost
+    pub fn craft_stone_pickaxe_with(&mut self, cost: &RecipeCost) -> bool {
+        if cost.consume(self) {
+            add_capped(&mut self.stone_pickaxe, 1);
+            true
+        } else {
+            false
+        }
+    }
+
+    /// Craft an iron pickaxe from an explicit recipe cost
+    pub fn craft_iron_pickaxe_with(&mut self, cost: &RecipeCost) -> bool {
+        if cost.consume(self) {
+            add_capped(&mut self.iron_pickaxe, 1);
+            true
+        } else {
+            false
+        }
+    }
+
+    /// Craft a wood sword from an explicit recipe cost
+    pub fn craft_wood_sword_with(&mut self, cost: &RecipeCost) -> bool {
+        if cost.consume(self) {
+            add_capped(&mut self.wood_sword, 1);
+            true
+        } else {
+            false
+        }
+    }
+
+    /// Craft a stone sword from an explicit recipe cost
+    pub fn craft_stone_sword_with(&mut self, cost: &RecipeCost) -> bool {
+        if cost.consume(self) {
+            add_capped(&mut self.stone_sword, 1);
+            true
+        } else {
+            false
+        }
+    }
+
+    /// Craft an iron sword from an explicit recipe cost
+    pub fn craft_iron_sword_with(&mut self, cost: &RecipeCost) -> bool {
+        if cost.consume(self) {
+            add_capped(&mut self.iron_sword, 1);
+            true
+        } else {
+            false
+        }
+    }
+
     /// Consume materials for diamond pickaxe
     pub fn craft_diamond_pickaxe(&mut self) -> bool {
         if self.can_craft_diamond_pickaxe() {
@@ -481,6 +609,45 @@ impl Inventory {
         }
     }
 
+    /// Consume materials for a spike trap
+    pub fn craft_spike_trap(&mut self) -> bool {
+        if self.can_craft_spike_trap() {
+            self.wood -= 1;
+            self.stone -= 1;
+            add_capped(&mut self.spike_trap, 1);
+            true
+        } else {
+            false
+        }
+    }
+
+    /// Consume materials for a door
+    pub fn craft_door(&mut self) -> bool {
+        if self.can_craft_door() {
+            self.wood -= 2;
+            add_capped(&mut self.door, 1);
+            true
+        } else {
+            false
+        }
+    }
+
+    /// Consume materials for a fence
+    pub fn craft_fence(&mut self) -> bool {
+        if self.can_craft_fence() {
+            self.wood -= 1;
+            add_capped(&mut self.fence, 1);
+            true
+        } else {
+            false
+        }
+    }
+
+    /// Add a collected fence back
+    pub fn add_fence(&mut self, amount: u8) {
+        add_capped(&mut self.fence, amount);
+    }
+
     /// Check if has stone to place
     pub fn has_stone(&self) -> bool {
         self.stone > 0
@@ -540,4 +707,34 @@ impl Inventory {
             false
         }
     }
+
+    /// Use a crafted spike trap for placement
+    pub fn use_spike_trap(&mut self) -> bool {
+        if self.spike_trap > 0 {
+            self.spike_trap -= 1;
+            true
+        } else {
+            false
+        }
+    }
+
+    /// Use a crafted door for placement
+    pub fn use_door(&mut self) -> bool {
+        if self.door > 0 {
+            self.door -= 1;
+            true
+        } else {
+            false
+        }
+    }
+
+    /// Use a crafted fence for placement
+    pub fn use_fence(&mut self) -> bool {
+        if self.fence > 0 {
+            self.fence -= 1;
+            true
+        } else {
+            false
+        }
+    }
 }
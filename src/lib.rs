@@ -6,6 +6,8 @@
 //! ## Features
 //!
 //! - `png` - Enable PNG image rendering (requires the `image` crate)
+//! - `python` - Build the `crafter_core` Python extension module (PyO3)
+//! - `wasm` - wasm-bindgen bindings for `wasm32-unknown-unknown`
 //!
 //! ## Modules
 //!
@@ -18,46 +20,113 @@
 
 pub mod action;
 pub mod achievement;
+pub mod compat;
 pub mod config;
 pub mod craftax;
+pub mod curriculum;
+pub mod duel;
 pub mod entity;
+pub mod env;
+pub mod eval;
+pub mod events;
+pub mod history;
 pub mod image_renderer;
 pub mod inventory;
+pub mod journal;
 pub mod material;
+pub mod multi_env;
+pub mod multiplayer;
+pub mod nav;
+pub mod obs;
 mod parity; // Parity tests against Python Crafter
+pub mod paths;
+pub mod platform;
+pub mod policy;
+#[cfg(feature = "python")]
+pub mod python;
+pub mod quests;
+pub mod recipes;
 pub mod recording;
 pub mod renderer;
+pub mod rng;
 pub mod rewards;
+#[cfg(not(target_arch = "wasm32"))]
+pub mod rollout;
 pub mod saveload;
 pub mod session;
+#[cfg(feature = "shm")]
+pub mod shm;
 pub mod snapshot;
+pub mod stats;
+#[cfg(feature = "telemetry")]
+pub mod telemetry;
+pub mod vec_env;
+#[cfg(feature = "wasm")]
+pub mod wasm;
 pub mod world;
 pub mod worldgen;
+pub mod wrappers;
 
 // Core types
-pub use action::Action;
+pub use action::{parse_script, Action, ActionProfile, ScriptError};
 pub use achievement::Achievements;
-pub use config::SessionConfig;
+pub use config::{
+    save_toml_config, save_yaml_config, write_config_atomic, ConfigError, ResolvedConfig, Rules,
+    SessionConfig, RULES_VERSION,
+};
+pub use curriculum::{CurriculumScheduler, CurriculumStage, CurriculumTrigger};
 pub use entity::{Arrow, Cow, GameObject, Mob, Plant, Player, Position, Skeleton, Zombie};
+pub use env::{CrafterEnv, Env, Info, Observation};
+#[cfg(not(target_arch = "wasm32"))]
+pub use eval::matrix;
+pub use eval::{
+    EvalProtocol, EvalSession, MatrixCell, MatrixOptions, MatrixResults, PolicyFactory,
+};
+pub use events::{EventCategory, EventSeverity, LogEntry};
+pub use history::WorldHistory;
 pub use inventory::Inventory;
+pub use journal::{JournalRecovery, SessionJournal};
 pub use material::Material;
-pub use session::{GameState, Session, StepResult, TimeMode};
-pub use world::World;
+pub use multi_env::{AgentStep, ParallelMultiEnv, TurnBasedMultiEnv};
+pub use multiplayer::{MultiPlayerSession, PlayerId};
+pub use nav::{DistanceField, NavFields};
+pub use recipes::{RecipeBook, RecipeCost};
+pub use duel::{Duel, DuelScore, DuelSide, DuelSummary};
+pub use policy::{
+    AchievementRusherPolicy, GreedySurvivalPolicy, Policy, RandomPolicy, ScriptedPolicy,
+    SurvivalPolicy, BASELINES_VERSION,
+};
+pub use quests::{QuestEffect, QuestEngine, QuestRule, QuestScript, QuestTrigger};
+pub use rng::{RngKind, RngStreams, SessionRng};
+pub use session::{
+    DoneReason, GameState, LagPolicy, MilestoneEvent, Session, SoundEvent, StateDelta, StepResult,
+    TimeMode, TransactionError,
+};
+pub use stats::EpisodeStats;
+pub use vec_env::VecSession;
+pub use world::{NamedRegion, RegionKind, World, WorldStats};
 
 // Recording and replay
-pub use recording::{Recording, RecordingOptions, RecordingSession, ReplaySession};
+pub use recording::{
+    ActionMismatch, Annotation, CompactOptions, CompactView, DatasetFilter, OfflineEnv, Recording,
+    RecordingOptions, RecordingSession, ReplaySession,
+};
 
 // Save/load
 pub use saveload::{SaveData, SessionSaveLoad};
 
 // Rewards
-pub use rewards::{RewardCalculator, RewardConfig, RewardResult};
+pub use rewards::{RewardCalculator, RewardConfig, RewardResult, RewardShaper};
+#[cfg(not(target_arch = "wasm32"))]
+pub use rollout::{rollouts, RolloutOptions};
 
 // Image rendering
 pub use image_renderer::{ColorPalette, ImageRenderer, ImageRendererConfig};
+#[cfg(feature = "png")]
+pub use image_renderer::{TimelapseExporter, TimelapseOptions};
 
 // Snapshot API
 pub use snapshot::{
-    SnapshotAction, SnapshotEntity, SnapshotInventory, SnapshotLine, SnapshotManager,
-    SnapshotRequest, SnapshotResponse, SnapshotStats,
+    SnapshotAction, SnapshotEntity, SnapshotError, SnapshotInventory, SnapshotLine,
+    SnapshotManager, SnapshotRequest, SnapshotResponse, SnapshotStats,
 };
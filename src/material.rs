@@ -37,6 +37,14 @@ pub enum Material {
     Ruby = 13,
     /// Chest block - craftax addon
     Chest = 14,
+    /// Spike trap - walkable, damages mobs standing on it
+    SpikeTrap = 15,
+    /// Closed door - blocks movement; only the player can open it
+    DoorClosed = 16,
+    /// Open door - walkable by anyone
+    DoorOpen = 17,
+    /// Fence - blocks movement, can be collected back
+    Fence = 18,
 }
 
 impl Material {
@@ -45,7 +53,12 @@ impl Material {
     pub fn is_walkable(&self) -> bool {
         matches!(
             self,
-            Material::Grass | Material::Path | Material::Sand | Material::Lava
+            Material::Grass
+                | Material::Path
+                | Material::Sand
+                | Material::Lava
+                | Material::SpikeTrap
+                | Material::DoorOpen
         )
     }
 
@@ -66,6 +79,7 @@ impl Material {
                 | Material::Diamond
                 | Material::Sapphire
                 | Material::Ruby
+                | Material::Fence
         )
     }
 
@@ -90,6 +104,20 @@ impl Material {
         }
     }
 
+    /// Base number of `Do` hits needed to break this material when multi-hit
+    /// mining is enabled (1 = breaks on the first hit, as everything does
+    /// when the feature is off)
+    pub fn hardness(&self) -> u8 {
+        match self {
+            Material::Tree => 2,
+            Material::Stone | Material::Coal => 3,
+            Material::Iron => 4,
+            Material::Diamond => 5,
+            Material::Sapphire | Material::Ruby => 6,
+            _ => 1,
+        }
+    }
+
     /// Get the material that replaces this one when mined
     pub fn mined_replacement(&self) -> Material {
         match self {
@@ -101,6 +129,7 @@ impl Material {
             | Material::Sapphire
             | Material::Ruby => Material::Path,
             Material::Table | Material::Furnace => Material::Grass,
+            Material::Fence => Material::Grass,
             _ => *self,
         }
     }
@@ -123,6 +152,10 @@ impl Material {
             12 => Some(Material::Sapphire),
             13 => Some(Material::Ruby),
             14 => Some(Material::Chest),
+            15 => Some(Material::SpikeTrap),
+            16 => Some(Material::DoorClosed),
+            17 => Some(Material::DoorOpen),
+            18 => Some(Material::Fence),
             _ => None,
         }
     }
@@ -145,6 +178,10 @@ impl Material {
             Material::Sapphire => 's',
             Material::Ruby => 'r',
             Material::Chest => 'H',
+            Material::SpikeTrap => '^',
+            Material::DoorClosed => 'D',
+            Material::DoorOpen => '/',
+            Material::Fence => '=',
         }
     }
 
@@ -166,6 +203,10 @@ impl Material {
             Material::Sapphire => (15, 82, 186),   // Sapphire blue
             Material::Ruby => (224, 17, 95),       // Ruby red
             Material::Chest => (184, 115, 51),     // Chest bronze
+            Material::SpikeTrap => (110, 110, 110), // Spike gray
+            Material::DoorClosed => (101, 67, 33),  // Dark wood
+            Material::DoorOpen => (181, 137, 90),   // Light wood
+            Material::Fence => (160, 82, 45),       // Sienna
         }
     }
 }
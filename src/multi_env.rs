@@ -0,0 +1,254 @@
+//! PettingZoo-style multi-agent environment adapters
+//!
+//! Two interfaces over [`MultiPlayerSession`], mirroring PettingZoo's
+//! parallel and AEC (agent-environment-cycle) APIs:
+//!
+//! - [`ParallelMultiEnv`] — all agents act simultaneously; `step` takes
+//!   one action per agent and returns one [`AgentStep`] per agent.
+//! - [`TurnBasedMultiEnv`] — agents act one at a time in a fixed cycle;
+//!   the world ticks once each time the cycle completes, so a full round
+//!   of turns is one game step.
+//!
+//! Both use the same observation encoding and terminated/truncated split
+//! as the single-agent [`CrafterEnv`](crate::env::CrafterEnv): the view
+//! tensor from [`obs`], termination on death, truncation on external
+//! cutoffs like the step limit.
+
+use std::collections::HashMap;
+
+use crate::action::Action;
+use crate::config::SessionConfig;
+use crate::env::{Info, Observation};
+use crate::multiplayer::{MultiPlayerSession, PlayerId};
+use crate::obs;
+use crate::session::{DoneReason, GameState, StepResult};
+
+/// One agent's share of a step: observation, reward, and episode status
+#[derive(Clone, Debug)]
+pub struct AgentStep {
+    pub observation: Observation,
+    pub reward: f32,
+    /// The episode ended inside the MDP for this agent (death)
+    pub terminated: bool,
+    /// The episode was cut off externally (step limit, manual reset)
+    pub truncated: bool,
+    pub info: Info,
+}
+
+/// Encode one player's view the way [`CrafterEnv`](crate::env::CrafterEnv)
+/// does, falling back to a zeroed tensor when the player has no view
+fn observation_from(state: &GameState, view_radius: u32) -> Observation {
+    match &state.view {
+        Some(view) => Observation {
+            data: obs::view_tensor_u8(view),
+            shape: obs::view_tensor_shape(view),
+        },
+        None => {
+            let size = (2 * view_radius + 1) as usize;
+            Observation {
+                data: vec![0; obs::NUM_CHANNELS * size * size],
+                shape: (obs::NUM_CHANNELS, size, size),
+            }
+        }
+    }
+}
+
+fn agent_step_from(result: &StepResult, view_radius: u32) -> AgentStep {
+    let terminated = matches!(result.done_reason, Some(DoneReason::Death));
+    AgentStep {
+        observation: observation_from(&result.state, view_radius),
+        reward: result.reward,
+        terminated,
+        truncated: result.done && !terminated,
+        info: Info {
+            step: result.state.step,
+            newly_unlocked: result.newly_unlocked.clone(),
+            done_reason: result.done_reason.clone(),
+        },
+    }
+}
+
+/// Simultaneous-action multi-agent environment (PettingZoo parallel API)
+pub struct ParallelMultiEnv {
+    multi: MultiPlayerSession,
+}
+
+impl ParallelMultiEnv {
+    pub fn new(config: SessionConfig, num_agents: u32) -> Self {
+        Self {
+            multi: MultiPlayerSession::new(config, num_agents),
+        }
+    }
+
+    /// Agent ids in a fixed order
+    pub fn agents(&self) -> &[PlayerId] {
+        self.multi.player_ids()
+    }
+
+    /// The underlying multi-player session
+    pub fn session(&self) -> &MultiPlayerSession {
+        &self.multi
+    }
+
+    /// Start a fresh episode and return each agent's first observation
+    pub fn reset(&mut self) -> HashMap<PlayerId, Observation> {
+        self.multi.reset();
+        self.observations()
+    }
+
+    /// Every agent's current observation without stepping
+    pub fn observations(&mut self) -> HashMap<PlayerId, Observation> {
+        let radius = self.multi.session().config.view_radius;
+        let ids: Vec<PlayerId> = self.multi.player_ids().to_vec();
+        ids.into_iter()
+            .map(|id| {
+                let state = self.multi.player_state(id);
+                (id, observation_from(&state, radius))
+            })
+            .collect()
+    }
+
+    /// Advance one tick with one action per agent; missing agents Noop
+    pub fn step(&mut self, actions: &HashMap<PlayerId, Action>) -> HashMap<PlayerId, AgentStep> {
+        let radius = self.multi.session().config.view_radius;
+        self.multi
+            .step_multi(actions)
+            .iter()
+            .map(|(&id, result)| (id, agent_step_from(result, radius)))
+            .collect()
+    }
+}
+
+/// Turn-based multi-agent environment (PettingZoo AEC API)
+///
+/// Agents act in player-id order; each action is buffered until every
+/// agent has moved, then the whole round applies as one world tick.
+pub struct TurnBasedMultiEnv {
+    multi: MultiPlayerSession,
+    pending: HashMap<PlayerId, Action>,
+    current: usize,
+    last_round: HashMap<PlayerId, StepResult>,
+}
+
+impl TurnBasedMultiEnv {
+    pub fn new(config: SessionConfig, num_agents: u32) -> Self {
+        Self {
+            multi: MultiPlayerSession::new(config, num_agents),
+            pending: HashMap::new(),
+            current: 0,
+            last_round: HashMap::new(),
+        }
+    }
+
+    /// Agent ids in turn order
+    pub fn agents(&self) -> &[PlayerId] {
+        self.multi.player_ids()
+    }
+
+    /// The agent whose turn it is
+    pub fn agent_selection(&self) -> PlayerId {
+        self.multi.player_ids()[self.current]
+    }
+
+    /// The underlying multi-player session
+    pub fn session(&self) -> &MultiPlayerSession {
+        &self.multi
+    }
+
+    /// One agent's current observation
+    pub fn observe(&mut self, agent: PlayerId) -> Observation {
+        let radius = self.multi.session().config.view_radius;
+        let state = self.multi.player_state(agent);
+        observation_from(&state, radius)
+    }
+
+    /// The selected agent's view of the last completed round, or its
+    /// initial observation before any round has run
+    pub fn last(&mut self) -> AgentStep {
+        let agent = self.agent_selection();
+        let radius = self.multi.session().config.view_radius;
+        match self.last_round.get(&agent) {
+            Some(result) => agent_step_from(result, radius),
+            None => AgentStep {
+                observation: self.observe(agent),
+                reward: 0.0,
+                terminated: false,
+                truncated: false,
+                info: Info::default(),
+            },
+        }
+    }
+
+    /// Record the selected agent's action and advance the turn; when the
+    /// last agent moves, the buffered round applies as one world tick
+    pub fn step(&mut self, action: Action) {
+        let agent = self.agent_selection();
+        self.pending.insert(agent, action);
+        self.current += 1;
+        if self.current >= self.multi.player_ids().len() {
+            let pending = std::mem::take(&mut self.pending);
+            self.last_round = self.multi.step_multi(&pending);
+            self.current = 0;
+        }
+    }
+
+    /// Start a fresh episode with all turns reset
+    pub fn reset(&mut self) {
+        self.multi.reset();
+        self.pending.clear();
+        self.last_round.clear();
+        self.current = 0;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn config() -> SessionConfig {
+        SessionConfig {
+            world_size: (32, 32),
+            seed: Some(42),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_parallel_env_steps_all_agents() {
+        let mut env = ParallelMultiEnv::new(config(), 2);
+        let first = env.observations();
+        assert_eq!(first.len(), 2);
+
+        let actions: HashMap<PlayerId, Action> = env
+            .agents()
+            .iter()
+            .map(|&id| (id, Action::MoveDown))
+            .collect();
+        let round = env.step(&actions);
+        assert_eq!(round.len(), 2);
+        for (id, step) in &round {
+            assert_eq!(step.observation.shape, first[id].shape);
+            assert!(!step.terminated);
+            assert_eq!(step.info.step, 1);
+        }
+    }
+
+    #[test]
+    fn test_turn_based_env_ticks_once_per_round() {
+        let mut env = TurnBasedMultiEnv::new(config(), 3);
+        let agents: Vec<PlayerId> = env.agents().to_vec();
+
+        for (i, &agent) in agents.iter().enumerate() {
+            assert_eq!(env.agent_selection(), agent);
+            env.step(Action::MoveDown);
+            // The world only advances when the round completes
+            let expected = if i + 1 == agents.len() { 1 } else { 0 };
+            assert_eq!(env.session().session().timing.step, expected);
+        }
+
+        // Back to the first agent, whose last() reflects the round
+        assert_eq!(env.agent_selection(), agents[0]);
+        let last = env.last();
+        assert_eq!(last.info.step, 1);
+    }
+}
@@ -0,0 +1,302 @@
+//! Multi-agent sessions: several players sharing one world
+//!
+//! A [`MultiPlayerSession`] wraps a [`Session`] and spawns extra
+//! [`Player`](crate::entity::Player) entities next to the original spawn,
+//! so all agents mine, craft, and fight in the same terrain.
+//! [`step_multi`](MultiPlayerSession::step_multi) takes one action per
+//! player and advances the world exactly one tick, returning a
+//! [`StepResult`] per player with that player's own view, inventory,
+//! achievements, and reward — the substrate for cooperative and
+//! competitive Crafter research.
+//!
+//! Players are identified by their [`ObjectId`] in the world. The first
+//! id is the *primary* player: world-level systems that track "the
+//! player" (mob aggression, hordes, escorts) follow the primary, and the
+//! shared episode ends when the primary dies or the step limit is hit.
+//! Other players additionally report `done` as soon as they die.
+
+use std::collections::HashMap;
+
+use crate::action::Action;
+use crate::achievement::Achievements;
+use crate::config::SessionConfig;
+use crate::entity::{GameObject, ObjectId, Player, Position};
+use crate::session::{DoneReason, Session, StepResult};
+
+/// Identifies one player within a [`MultiPlayerSession`]
+pub type PlayerId = ObjectId;
+
+/// A shared-world session stepped with one action per player
+pub struct MultiPlayerSession {
+    session: Session,
+    /// Player ids in step order; index 0 is the primary player
+    player_ids: Vec<PlayerId>,
+    /// Previous achievements per player, for per-player rewards
+    prev_achievements: HashMap<PlayerId, Achievements>,
+}
+
+impl MultiPlayerSession {
+    /// Create a session with `num_players` players (clamped to at least 1)
+    /// spawned on adjacent walkable tiles
+    pub fn new(config: SessionConfig, num_players: u32) -> Self {
+        let session = Session::new(config);
+        let mut multi = Self {
+            session,
+            player_ids: Vec::new(),
+            prev_achievements: HashMap::new(),
+        };
+        multi.spawn_players(num_players.max(1));
+        multi
+    }
+
+    /// Player ids in step order; the first is the primary player
+    pub fn player_ids(&self) -> &[PlayerId] {
+        &self.player_ids
+    }
+
+    /// The underlying shared session
+    pub fn session(&self) -> &Session {
+        &self.session
+    }
+
+    /// One player's current state (view, inventory, achievements)
+    pub fn player_state(&mut self, id: PlayerId) -> crate::session::GameState {
+        let primary = self.player_ids[0];
+        self.session.world.player_id = id;
+        let state = self.session.get_state();
+        self.session.world.player_id = primary;
+        state
+    }
+
+    /// Advance the world one tick with one action per player.
+    ///
+    /// Missing entries and dead players fall back to [`Action::Noop`].
+    /// Actions apply in player-id order within the tick, then the world
+    /// advances once (mobs, plants, daylight, spawning).
+    pub fn step_multi(&mut self, actions: &HashMap<PlayerId, Action>) -> HashMap<PlayerId, StepResult> {
+        let primary = self.player_ids[0];
+
+        // Secondary players act first; the primary's step carries the
+        // world tick so every action lands in the same step
+        for &id in &self.player_ids[1..] {
+            if !self.player_alive(id) {
+                continue;
+            }
+            let action = actions.get(&id).copied().unwrap_or(Action::Noop);
+            self.session.world.player_id = id;
+            self.session.process_player_action(action);
+            if let Some(player) = self.session.world.get_player_mut() {
+                player.update_life_stats(
+                    self.session.config.hunger_enabled,
+                    self.session.config.thirst_enabled,
+                    self.session.config.fatigue_enabled,
+                    self.session.config.health_enabled,
+                    self.session.config.hunger_rate as f32,
+                    self.session.config.thirst_rate as f32,
+                );
+                if player.sleeping
+                    && player.inventory.energy >= crate::inventory::MAX_INVENTORY_VALUE
+                {
+                    player.wake_up();
+                }
+            }
+        }
+
+        self.session.world.player_id = primary;
+        let primary_action = actions.get(&primary).copied().unwrap_or(Action::Noop);
+        let primary_result = self.session.step(primary_action);
+
+        let mut results = HashMap::new();
+        let secondary_ids: Vec<PlayerId> = self.player_ids[1..].to_vec();
+        for id in secondary_ids {
+            results.insert(id, self.secondary_result(id, &primary_result));
+        }
+        self.session.world.player_id = primary;
+        self.prev_achievements.insert(
+            primary,
+            self.session
+                .world
+                .get_player()
+                .map(|p| p.achievements.clone())
+                .unwrap_or_default(),
+        );
+        results.insert(primary, primary_result);
+        results
+    }
+
+    /// Start a new episode: the world regenerates and all players respawn
+    pub fn reset(&mut self) {
+        let num_players = self.player_ids.len() as u32;
+        self.session.reset();
+        self.spawn_players(num_players);
+    }
+
+    /// Whether the shared episode is over (primary dead or step limit)
+    pub fn is_done(&self) -> bool {
+        let alive = self
+            .session
+            .world
+            .get_player()
+            .map(|p| p.is_alive())
+            .unwrap_or(false);
+        let out_of_steps = self
+            .session
+            .config
+            .max_steps
+            .is_some_and(|max| self.session.timing.step >= u64::from(max));
+        !alive || out_of_steps
+    }
+
+    fn player_alive(&self, id: PlayerId) -> bool {
+        matches!(
+            self.session.world.get_object(id),
+            Some(GameObject::Player(p)) if p.is_alive()
+        )
+    }
+
+    /// Build one secondary player's view of the tick that just ran
+    fn secondary_result(&mut self, id: PlayerId, primary_result: &StepResult) -> StepResult {
+        self.session.world.player_id = id;
+
+        let achievements = self
+            .session
+            .world
+            .get_player()
+            .map(|p| p.achievements.clone())
+            .unwrap_or_default();
+        let prev = self.prev_achievements.entry(id).or_default();
+        let (reward, newly_unlocked) = self.session.achievement_reward(&achievements, prev);
+        *prev = achievements;
+
+        let alive = self.player_alive(id);
+        let (done, done_reason) = if !alive {
+            (true, Some(DoneReason::Death))
+        } else {
+            (primary_result.done, primary_result.done_reason.clone())
+        };
+
+        StepResult {
+            state: self.session.get_state(),
+            reward,
+            done,
+            truncated: done && !matches!(done_reason, Some(DoneReason::Death)),
+            done_reason,
+            newly_unlocked,
+            debug_events: Vec::new(),
+            milestones: Vec::new(),
+            sounds: Vec::new(),
+            log: Vec::new(),
+            action_mask: if self.session.config.fast_mode {
+                Vec::new()
+            } else {
+                self.session.action_mask()
+            },
+        }
+    }
+
+    /// Register the generated player as primary and spawn the rest nearby
+    fn spawn_players(&mut self, num_players: u32) {
+        self.player_ids.clear();
+        self.prev_achievements.clear();
+
+        let primary = self.session.world.player_id;
+        self.player_ids.push(primary);
+        self.prev_achievements.insert(
+            primary,
+            self.session
+                .world
+                .get_player()
+                .map(|p| p.achievements.clone())
+                .unwrap_or_default(),
+        );
+
+        let spawn = self
+            .session
+            .world
+            .get_player()
+            .map(|p| p.pos)
+            .unwrap_or((0, 0));
+        for _ in 1..num_players {
+            let Some(pos) = self.free_tile_near(spawn) else { break };
+            let id = self.session.world.add_object(GameObject::Player(Player::new(pos)));
+            self.player_ids.push(id);
+            self.prev_achievements.insert(id, Achievements::default());
+        }
+    }
+
+    /// Nearest walkable, unoccupied tile in growing rings around `center`
+    fn free_tile_near(&self, center: Position) -> Option<Position> {
+        for r in 1..=8i32 {
+            for dy in -r..=r {
+                for dx in -r..=r {
+                    if dx.abs().max(dy.abs()) != r {
+                        continue;
+                    }
+                    let pos = (center.0 + dx, center.1 + dy);
+                    if self.session.world.is_walkable(pos)
+                        && self.session.world.get_object_at(pos).is_none()
+                    {
+                        return Some(pos);
+                    }
+                }
+            }
+        }
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn session_with(players: u32) -> MultiPlayerSession {
+        let config = SessionConfig {
+            world_size: (32, 32),
+            seed: Some(42),
+            ..Default::default()
+        };
+        MultiPlayerSession::new(config, players)
+    }
+
+    #[test]
+    fn test_players_coexist_and_step_together() {
+        let mut multi = session_with(3);
+        assert_eq!(multi.player_ids().len(), 3);
+
+        let actions: HashMap<PlayerId, Action> = multi
+            .player_ids()
+            .iter()
+            .map(|&id| (id, Action::MoveDown))
+            .collect();
+        let results = multi.step_multi(&actions);
+
+        assert_eq!(results.len(), 3);
+        for &id in multi.player_ids() {
+            let result = &results[&id];
+            assert_eq!(result.state.step, 1);
+            assert!(result.state.inventory.is_alive());
+        }
+        // The world advanced exactly one tick despite three actions
+        assert_eq!(multi.session().timing.step, 1);
+    }
+
+    #[test]
+    fn test_per_player_inventories_and_views_are_distinct() {
+        let mut multi = session_with(2);
+        let ids: Vec<PlayerId> = multi.player_ids().to_vec();
+
+        let results = multi.step_multi(&HashMap::new());
+        let a = &results[&ids[0]].state;
+        let b = &results[&ids[1]].state;
+        assert_ne!(a.player_pos, b.player_pos);
+    }
+
+    #[test]
+    fn test_reset_respawns_all_players() {
+        let mut multi = session_with(2);
+        multi.step_multi(&HashMap::new());
+        multi.reset();
+        assert_eq!(multi.player_ids().len(), 2);
+        assert_eq!(multi.session().timing.step, 0);
+    }
+}
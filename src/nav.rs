@@ -0,0 +1,345 @@
+//! Distance-to-nearest fields for key materials
+//!
+//! A [`DistanceField`] holds, for every tile, the grid distance (in
+//! steps, 4-connected) to the nearest tile of one material. Shaped
+//! rewards and heuristic agents use these as dense signals ("how far is
+//! water?") instead of re-scanning the map every step.
+//!
+//! [`NavFields`] bundles the fields for the materials agents care about
+//! and keeps them current against the world's change counter: `update`
+//! is a no-op while the world hasn't changed and a recompute (one BFS
+//! per material, linear in the map size) after any mutation, so callers
+//! can refresh every step without thinking about cost.
+
+use crate::action::Action;
+use crate::material::Material;
+use crate::world::World;
+use std::collections::{HashMap, HashSet, VecDeque};
+
+/// Distance marker for tiles with no source material on the map
+pub const UNREACHABLE: u16 = u16::MAX;
+
+/// Per-tile distance to the nearest tile of one material
+#[derive(Debug, Clone)]
+pub struct DistanceField {
+    pub material: Material,
+    area: (u32, u32),
+    /// Row-major distances; [`UNREACHABLE`] where no source exists
+    dist: Vec<u16>,
+}
+
+impl DistanceField {
+    /// Compute the field with a multi-source BFS from every tile of
+    /// `material`. Distances ignore walkability: they measure "as the
+    /// crow flies" along the grid, which is what shaping wants.
+    pub fn compute(world: &World, material: Material) -> Self {
+        let (width, height) = world.area;
+        let mut dist = vec![UNREACHABLE; (width * height) as usize];
+        let mut queue = VecDeque::new();
+
+        for y in 0..height as i32 {
+            for x in 0..width as i32 {
+                if world.get_material((x, y)) == Some(material) {
+                    dist[(y as u32 * width + x as u32) as usize] = 0;
+                    queue.push_back((x, y));
+                }
+            }
+        }
+
+        while let Some((x, y)) = queue.pop_front() {
+            let here = dist[(y as u32 * width + x as u32) as usize];
+            for (dx, dy) in [(-1, 0), (1, 0), (0, -1), (0, 1)] {
+                let (nx, ny) = (x + dx, y + dy);
+                if nx < 0 || nx >= width as i32 || ny < 0 || ny >= height as i32 {
+                    continue;
+                }
+                let idx = (ny as u32 * width + nx as u32) as usize;
+                if dist[idx] == UNREACHABLE {
+                    dist[idx] = here + 1;
+                    queue.push_back((nx, ny));
+                }
+            }
+        }
+
+        Self {
+            material,
+            area: (width, height),
+            dist,
+        }
+    }
+
+    /// Distance from a position to the nearest source tile; `None` out
+    /// of bounds or when the material does not exist on the map
+    pub fn distance(&self, pos: (i32, i32)) -> Option<u16> {
+        if pos.0 < 0 || pos.0 >= self.area.0 as i32 || pos.1 < 0 || pos.1 >= self.area.1 as i32 {
+            return None;
+        }
+        let value = self.dist[(pos.1 as u32 * self.area.0 + pos.0 as u32) as usize];
+        (value != UNREACHABLE).then_some(value)
+    }
+
+    /// Write the field around `center` as one `[H, W]` observation
+    /// channel (`H = W = 2 * radius + 1`), reusing the caller's buffer.
+    /// Out-of-bounds and unreachable tiles get `f32::INFINITY`.
+    pub fn write_view_channel(&self, center: (i32, i32), radius: u32, out: &mut Vec<f32>) {
+        let size = (2 * radius + 1) as i32;
+        out.clear();
+        out.reserve((size * size) as usize);
+        for dy in 0..size {
+            for dx in 0..size {
+                let pos = (
+                    center.0 + dx - radius as i32,
+                    center.1 + dy - radius as i32,
+                );
+                out.push(
+                    self.distance(pos)
+                        .map(f32::from)
+                        .unwrap_or(f32::INFINITY),
+                );
+            }
+        }
+    }
+}
+
+/// The materials worth tracking by default
+pub const STANDARD_MATERIALS: [Material; 4] = [
+    Material::Water,
+    Material::Tree,
+    Material::Table,
+    Material::Lava,
+];
+
+/// A set of distance fields kept current against a world
+pub struct NavFields {
+    fields: HashMap<Material, DistanceField>,
+    materials: Vec<Material>,
+    world_version: u64,
+}
+
+impl NavFields {
+    /// Track the given materials
+    pub fn new(world: &World, materials: &[Material]) -> Self {
+        let fields = materials
+            .iter()
+            .map(|&m| (m, DistanceField::compute(world, m)))
+            .collect();
+        Self {
+            fields,
+            materials: materials.to_vec(),
+            world_version: world.version(),
+        }
+    }
+
+    /// Track water, trees, tables, and lava
+    pub fn standard(world: &World) -> Self {
+        Self::new(world, &STANDARD_MATERIALS)
+    }
+
+    /// Refresh the fields if the world has changed since the last call.
+    /// Returns whether a recompute happened.
+    pub fn update(&mut self, world: &World) -> bool {
+        if world.version() == self.world_version {
+            return false;
+        }
+        for material in &self.materials {
+            self.fields
+                .insert(*material, DistanceField::compute(world, *material));
+        }
+        self.world_version = world.version();
+        true
+    }
+
+    pub fn field(&self, material: Material) -> Option<&DistanceField> {
+        self.fields.get(&material)
+    }
+
+    /// Distance from `pos` to the nearest tile of `material`
+    pub fn distance_to(&self, material: Material, pos: (i32, i32)) -> Option<u16> {
+        self.fields.get(&material)?.distance(pos)
+    }
+}
+
+/// Shortest action sequence that leaves the player adjacent to and
+/// facing any of `targets`, or `None` when no target can be faced.
+///
+/// BFS over (position, facing) states using the same move semantics as
+/// the session, so replaying the returned actions step by step lands the
+/// player one `Do`/`Place` away from the target tile (or the tile an
+/// entity stands on). Returns an empty sequence when the player already
+/// faces a target. This used to live in the headless achievement runner;
+/// it is a core API so consumers stop reimplementing it.
+pub fn find_path_to_face_any(
+    world: &World,
+    start_pos: (i32, i32),
+    start_facing: (i8, i8),
+    targets: &HashSet<(i32, i32)>,
+) -> Option<Vec<Action>> {
+    let dirs = [
+        (Action::MoveUp, (0, -1)),
+        (Action::MoveDown, (0, 1)),
+        (Action::MoveLeft, (-1, 0)),
+        (Action::MoveRight, (1, 0)),
+    ];
+    type SearchNode = ((i32, i32), (i8, i8));
+    let mut queue = VecDeque::new();
+    let mut came_from: HashMap<SearchNode, (SearchNode, Action)> = HashMap::new();
+    let start = (start_pos, start_facing);
+    queue.push_back(start);
+    let mut visited = HashSet::new();
+    visited.insert(start);
+
+    while let Some((pos, facing)) = queue.pop_front() {
+        let facing_pos = (pos.0 + facing.0 as i32, pos.1 + facing.1 as i32);
+        if targets.contains(&facing_pos) {
+            let mut actions = Vec::new();
+            let mut current = (pos, facing);
+            while current != start {
+                if let Some((prev, action)) = came_from.get(&current) {
+                    actions.push(*action);
+                    current = *prev;
+                } else {
+                    break;
+                }
+            }
+            actions.reverse();
+            return Some(actions);
+        }
+
+        for (action, (dx, dy)) in dirs {
+            let next_pos = (pos.0 + dx, pos.1 + dy);
+            let next_facing = (dx as i8, dy as i8);
+            if !world.is_walkable(next_pos) {
+                continue;
+            }
+            let next_state = (next_pos, next_facing);
+            if visited.insert(next_state) {
+                came_from.insert(next_state, ((pos, facing), action));
+                queue.push_back(next_state);
+            }
+        }
+    }
+
+    None
+}
+
+/// [`find_path_to_face_any`] for a single target tile
+pub fn find_path_to_face(
+    world: &World,
+    start_pos: (i32, i32),
+    start_facing: (i8, i8),
+    target: (i32, i32),
+) -> Option<Vec<Action>> {
+    let mut targets = HashSet::new();
+    targets.insert(target);
+    find_path_to_face_any(world, start_pos, start_facing, &targets)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn empty_world() -> World {
+        // World::new starts as all grass, which is exactly what we want
+        World::new(16, 16, 0)
+    }
+
+    #[test]
+    fn test_distance_field_bfs() {
+        let mut world = empty_world();
+        world.set_material((4, 4), Material::Water);
+        world.set_material((12, 4), Material::Water);
+
+        let field = DistanceField::compute(&world, Material::Water);
+        assert_eq!(field.distance((4, 4)), Some(0));
+        assert_eq!(field.distance((6, 4)), Some(2));
+        // Midpoint between sources: nearest wins
+        assert_eq!(field.distance((8, 4)), Some(4));
+        // Manhattan path around, not diagonal
+        assert_eq!(field.distance((4, 7)), Some(3));
+        assert_eq!(field.distance((-1, 0)), None);
+
+        // No lava anywhere: distances are None
+        let lava = DistanceField::compute(&world, Material::Lava);
+        assert_eq!(lava.distance((4, 4)), None);
+    }
+
+    #[test]
+    fn test_nav_fields_update_tracks_world_version() {
+        let mut world = empty_world();
+        world.set_material((2, 2), Material::Water);
+        let mut nav = NavFields::standard(&world);
+
+        assert_eq!(nav.distance_to(Material::Table, (2, 4)), None);
+        assert!(!nav.update(&world), "unchanged world should be a no-op");
+
+        world.set_material((2, 5), Material::Table);
+        assert!(nav.update(&world));
+        assert_eq!(nav.distance_to(Material::Table, (2, 4)), Some(1));
+    }
+
+    #[test]
+    fn test_view_channel_layout() {
+        let mut world = empty_world();
+        world.set_material((8, 8), Material::Tree);
+        let field = DistanceField::compute(&world, Material::Tree);
+
+        let mut channel = Vec::new();
+        field.write_view_channel((8, 8), 1, &mut channel);
+        assert_eq!(channel.len(), 9);
+        // Center tile is the source; corners are two steps away
+        assert_eq!(channel[4], 0.0);
+        assert_eq!(channel[0], 2.0);
+        assert_eq!(channel[1], 1.0);
+
+        // At the map corner, out-of-bounds tiles are infinite
+        field.write_view_channel((0, 0), 1, &mut channel);
+        assert!(channel[0].is_infinite());
+    }
+
+    #[test]
+    fn test_find_path_to_face_ends_adjacent_and_facing() {
+        let mut world = empty_world();
+        world.set_material((8, 2), Material::Tree);
+
+        let path =
+            find_path_to_face(&world, (2, 2), (0, 1), (8, 2)).expect("target should be reachable");
+        assert!(!path.is_empty());
+
+        // Replay the moves: each step moves one tile and turns that way
+        let (mut pos, mut facing) = ((2, 2), (0i8, 1i8));
+        for action in &path {
+            let (dx, dy) = match action {
+                Action::MoveUp => (0, -1),
+                Action::MoveDown => (0, 1),
+                Action::MoveLeft => (-1, 0),
+                Action::MoveRight => (1, 0),
+                other => panic!("path should only contain moves, got {:?}", other),
+            };
+            pos = (pos.0 + dx, pos.1 + dy);
+            facing = (dx as i8, dy as i8);
+        }
+        let facing_pos = (pos.0 + facing.0 as i32, pos.1 + facing.1 as i32);
+        assert_eq!(facing_pos, (8, 2), "path should end facing the target");
+        assert_eq!(path.len(), 5, "straight line should be optimal");
+    }
+
+    #[test]
+    fn test_find_path_to_face_handles_walls_and_dead_targets() {
+        let mut world = empty_world();
+        // Seal a tree inside a stone box
+        world.set_material((8, 8), Material::Tree);
+        for x in 6..=10 {
+            for y in 6..=10 {
+                if (x, y) != (8, 8) {
+                    world.set_material((x, y), Material::Stone);
+                }
+            }
+        }
+        assert!(find_path_to_face(&world, (2, 2), (0, 1), (8, 8)).is_none());
+
+        // Already facing a target: nothing to do
+        world.set_material((2, 3), Material::Tree);
+        let path = find_path_to_face(&world, (2, 2), (0, 1), (2, 3)).unwrap();
+        assert!(path.is_empty());
+    }
+}
@@ -0,0 +1,495 @@
+//! GPU-friendly tile-id observation tensors
+//!
+//! Encodes the local view as a contiguous `[C, H, W]` buffer (channel,
+//! then row, then column — C-order), so frameworks can wrap it with
+//! `torch.from_numpy(...).view(C, H, W)` without reshaping or copying.
+//! `H` and `W` are the view size (`2 * view_radius + 1`).
+//!
+//! Channel order, stable across releases:
+//!
+//! | channel | contents |
+//! |---------|----------|
+//! | 0 | material id + 1 (`Material as u8 + 1`); 0 = out of bounds |
+//! | 1 | entity code ([`entity_code`]); 0 = no entity |
+//! | 2 | mining progress (hits landed on a partially mined tile) |
+//!
+//! The `f32` variant carries the same values uncast and unnormalized;
+//! scaling is model business.
+
+use crate::entity::{CraftaxMobKind, GameObject};
+use crate::world::WorldView;
+
+/// Channel index for material ids
+pub const MATERIAL_CHANNEL: usize = 0;
+/// Channel index for entity codes
+pub const ENTITY_CHANNEL: usize = 1;
+/// Channel index for mining progress
+pub const MINING_CHANNEL: usize = 2;
+/// Number of channels in a view tensor
+pub const NUM_CHANNELS: usize = 3;
+
+/// Stable numeric code for an entity occupying a tile (0 = none)
+pub fn entity_code(obj: &GameObject) -> u8 {
+    match obj {
+        GameObject::Player(_) => 1,
+        GameObject::Cow(_) => 2,
+        GameObject::Sheep(_) => 3,
+        GameObject::Pig(_) => 4,
+        GameObject::Zombie(_) => 5,
+        GameObject::Skeleton(_) => 6,
+        GameObject::Arrow(_) => 7,
+        GameObject::Plant(_) => 8,
+        GameObject::EscortKnight(_) => 9,
+        GameObject::CraftaxMob(mob) => match mob.kind {
+            CraftaxMobKind::OrcSoldier => 10,
+            CraftaxMobKind::OrcMage => 11,
+            CraftaxMobKind::Knight => 12,
+            CraftaxMobKind::KnightArcher => 13,
+            CraftaxMobKind::Troll => 14,
+            CraftaxMobKind::Bat => 15,
+            CraftaxMobKind::Snail => 16,
+        },
+    }
+}
+
+/// Tensor shape for a view: `(channels, height, width)`
+pub fn view_tensor_shape(view: &WorldView) -> (usize, usize, usize) {
+    let size = view.size();
+    (NUM_CHANNELS, size, size)
+}
+
+/// Encode a view as `[C, H, W]` u8, reusing the caller's buffer
+pub fn write_view_tensor_u8(view: &WorldView, out: &mut Vec<u8>) {
+    let size = view.size();
+    let plane = size * size;
+    out.clear();
+    out.resize(NUM_CHANNELS * plane, 0);
+
+    for y in 0..size {
+        for x in 0..size {
+            let idx = y * size + x;
+            if view.is_in_bounds(x as i32, y as i32) {
+                if let Some(mat) = view.get_material(x as i32, y as i32) {
+                    out[MATERIAL_CHANNEL * plane + idx] = mat as u8 + 1;
+                }
+                out[MINING_CHANNEL * plane + idx] =
+                    view.get_mining_progress(x as i32, y as i32);
+            }
+        }
+    }
+
+    for (x, y, obj) in &view.objects {
+        if *x >= 0 && (*x as usize) < size && *y >= 0 && (*y as usize) < size {
+            out[ENTITY_CHANNEL * plane + *y as usize * size + *x as usize] = entity_code(obj);
+        }
+    }
+}
+
+/// Encode a view as `[C, H, W]` u8
+pub fn view_tensor_u8(view: &WorldView) -> Vec<u8> {
+    let mut out = Vec::new();
+    write_view_tensor_u8(view, &mut out);
+    out
+}
+
+/// Encode a view as `[C, H, W]` f32 (same values as the u8 tensor)
+pub fn view_tensor_f32(view: &WorldView) -> Vec<f32> {
+    view_tensor_u8(view).into_iter().map(f32::from).collect()
+}
+
+/// Names of the scalar features appended to a state tensor, index-
+/// aligned with [`scalar_features`]. Order is stable across releases;
+/// new features are only ever appended.
+pub const SCALAR_FEATURES: &[&str] = &[
+    "health",
+    "food",
+    "drink",
+    "energy",
+    "sapling",
+    "wood",
+    "stone",
+    "coal",
+    "iron",
+    "diamond",
+    "sapphire",
+    "ruby",
+    "wool",
+    "wood_pickaxe",
+    "stone_pickaxe",
+    "iron_pickaxe",
+    "diamond_pickaxe",
+    "wood_sword",
+    "stone_sword",
+    "iron_sword",
+    "diamond_sword",
+    "bow",
+    "arrows",
+];
+
+/// Inventory and vitals of a state as scalars, ordered per
+/// [`SCALAR_FEATURES`]
+pub fn scalar_features(state: &crate::session::GameState) -> Vec<u8> {
+    let inv = &state.inventory;
+    vec![
+        inv.health,
+        inv.food,
+        inv.drink,
+        inv.energy,
+        inv.sapling,
+        inv.wood,
+        inv.stone,
+        inv.coal,
+        inv.iron,
+        inv.diamond,
+        inv.sapphire,
+        inv.ruby,
+        inv.wool,
+        inv.wood_pickaxe,
+        inv.stone_pickaxe,
+        inv.iron_pickaxe,
+        inv.diamond_pickaxe,
+        inv.wood_sword,
+        inv.stone_sword,
+        inv.iron_sword,
+        inv.diamond_sword,
+        inv.bow,
+        inv.arrows,
+    ]
+}
+
+/// Tensor shape for a full state: `(height, width, channels)` — HWC,
+/// with the spatial channels of [`view_tensor_u8`] followed by one
+/// broadcast plane per [`SCALAR_FEATURES`] entry
+pub fn state_tensor_shape(view: &WorldView) -> (usize, usize, usize) {
+    let size = view.size();
+    (size, size, NUM_CHANNELS + SCALAR_FEATURES.len())
+}
+
+/// Encode a full state as a flat `[H, W, C]` u8 tensor: materials,
+/// entities and mining progress per tile, then inventory and vitals
+/// broadcast across the plane so convolutions see them everywhere.
+/// Returns `None` when the state has no view (dead player).
+pub fn state_tensor_u8(state: &crate::session::GameState) -> Option<Vec<u8>> {
+    let view = state.view.as_ref()?;
+    let size = view.size();
+    let channels = NUM_CHANNELS + SCALAR_FEATURES.len();
+    let spatial = view_tensor_u8(view);
+    let scalars = scalar_features(state);
+    let plane = size * size;
+
+    let mut out = vec![0; plane * channels];
+    for y in 0..size {
+        for x in 0..size {
+            let idx = y * size + x;
+            let base = idx * channels;
+            for c in 0..NUM_CHANNELS {
+                out[base + c] = spatial[c * plane + idx];
+            }
+            out[base + NUM_CHANNELS..base + channels].copy_from_slice(&scalars);
+        }
+    }
+    Some(out)
+}
+
+/// Tile classes in the symbolic observation: one per [`Material`] id
+/// plus class 0 for out of bounds, matching the material channel's
+/// `id + 1` convention
+///
+/// [`Material`]: crate::material::Material
+pub const SYMBOLIC_TILE_CLASSES: usize = 20;
+/// Entity classes in the symbolic observation: every [`entity_code`]
+/// plus class 0 for an empty tile
+pub const SYMBOLIC_ENTITY_CLASSES: usize = 17;
+/// One-hot slots per tile: a tile-class block then an entity-class block
+pub const SYMBOLIC_TILE_SLOTS: usize = SYMBOLIC_TILE_CLASSES + SYMBOLIC_ENTITY_CLASSES;
+
+/// Length of the flat symbolic observation for a square view of
+/// `view_size` tiles per side
+pub fn symbolic_len(view_size: usize) -> usize {
+    view_size * view_size * SYMBOLIC_TILE_SLOTS + SCALAR_FEATURES.len()
+}
+
+/// Encode a full state as the flat Craftax-style symbolic observation:
+/// per tile (row-major) a one-hot material block then a one-hot entity
+/// block, followed by the [`SCALAR_FEATURES`] inventory and vitals
+/// scaled by `1/10` the way Craftax normalizes its scalars. Layout is
+/// stable across releases. Returns `None` when the state has no view
+/// (dead player).
+pub fn symbolic_observation(state: &crate::session::GameState) -> Option<Vec<f32>> {
+    let view = state.view.as_ref()?;
+    let size = view.size();
+    let plane = size * size;
+    let spatial = view_tensor_u8(view);
+
+    let mut out = vec![0.0; symbolic_len(size)];
+    for idx in 0..plane {
+        let base = idx * SYMBOLIC_TILE_SLOTS;
+        let mat = spatial[MATERIAL_CHANNEL * plane + idx] as usize;
+        out[base + mat.min(SYMBOLIC_TILE_CLASSES - 1)] = 1.0;
+        let entity = spatial[ENTITY_CHANNEL * plane + idx] as usize;
+        out[base + SYMBOLIC_TILE_CLASSES + entity.min(SYMBOLIC_ENTITY_CLASSES - 1)] = 1.0;
+    }
+    let scalar_base = plane * SYMBOLIC_TILE_SLOTS;
+    for (i, value) in scalar_features(state).into_iter().enumerate() {
+        out[scalar_base + i] = f32::from(value) / 10.0;
+    }
+    Some(out)
+}
+
+/// Stacks the last K view tensors into one `[K * C, H, W]` buffer so
+/// recurrent-free policies see short-term history out of the box.
+///
+/// Frames are ordered oldest to newest, each contributing its full
+/// channel block. After a [`reset`](FrameStack::reset) the first pushed
+/// frame is repeated K times — the conventional fill that keeps the
+/// shape fixed without leaking frames from the previous episode.
+pub struct FrameStack {
+    depth: usize,
+    frames: std::collections::VecDeque<Vec<u8>>,
+    scratch: Vec<u8>,
+}
+
+impl FrameStack {
+    pub fn new(depth: usize) -> Self {
+        Self {
+            depth: depth.max(1),
+            frames: std::collections::VecDeque::new(),
+            scratch: Vec::new(),
+        }
+    }
+
+    /// Number of stacked frames (K)
+    pub fn depth(&self) -> usize {
+        self.depth
+    }
+
+    /// Stacked tensor shape for a view: `(K * channels, height, width)`
+    pub fn stacked_shape(&self, view: &WorldView) -> (usize, usize, usize) {
+        let (c, h, w) = view_tensor_shape(view);
+        (self.depth * c, h, w)
+    }
+
+    /// Drop all held frames. Call on episode reset so stale frames
+    /// never leak across episodes.
+    pub fn reset(&mut self) {
+        self.frames.clear();
+    }
+
+    /// Push this step's view and write the stacked `[K * C, H, W]`
+    /// tensor into `out`, reusing the caller's buffer
+    pub fn write_stacked(&mut self, view: &WorldView, out: &mut Vec<u8>) {
+        let mut scratch = std::mem::take(&mut self.scratch);
+        write_view_tensor_u8(view, &mut scratch);
+
+        // A size change means a different view config: start over
+        // rather than concatenating mismatched planes
+        if self.frames.front().is_some_and(|f| f.len() != scratch.len()) {
+            self.frames.clear();
+        }
+        if self.frames.is_empty() {
+            for _ in 0..self.depth {
+                self.frames.push_back(scratch.clone());
+            }
+        } else {
+            self.frames.push_back(scratch.clone());
+            while self.frames.len() > self.depth {
+                self.frames.pop_front();
+            }
+        }
+        self.scratch = scratch;
+
+        out.clear();
+        out.reserve(self.depth * self.scratch.len());
+        for frame in &self.frames {
+            out.extend_from_slice(frame);
+        }
+    }
+
+    /// Push this step's view and return the stacked tensor
+    pub fn push(&mut self, view: &WorldView) -> Vec<u8> {
+        let mut out = Vec::new();
+        self.write_stacked(view, &mut out);
+        out
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::SessionConfig;
+    use crate::session::Session;
+
+    #[test]
+    fn test_view_tensor_layout_and_channels() {
+        let session = Session::new(SessionConfig {
+            world_size: (32, 32),
+            seed: Some(42),
+            view_radius: 4,
+            ..Default::default()
+        });
+        let state = session.get_state();
+        let view = state.view.as_ref().unwrap();
+
+        let (c, h, w) = view_tensor_shape(view);
+        assert_eq!((c, h, w), (NUM_CHANNELS, 9, 9));
+
+        let tensor = view_tensor_u8(view);
+        assert_eq!(tensor.len(), c * h * w);
+
+        // The player sits at the view center in the entity channel
+        let center = view.radius as usize;
+        let plane = h * w;
+        assert_eq!(tensor[ENTITY_CHANNEL * plane + center * w + center], 1);
+
+        // Material ids are shifted by one so 0 is reserved for out of
+        // bounds; every in-bounds tile must be nonzero
+        for y in 0..h {
+            for x in 0..w {
+                let encoded = tensor[MATERIAL_CHANNEL * plane + y * w + x];
+                if view.is_in_bounds(x as i32, y as i32) {
+                    assert!(encoded > 0);
+                } else {
+                    assert_eq!(encoded, 0);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_f32_tensor_matches_u8() {
+        let session = Session::new(SessionConfig {
+            world_size: (24, 24),
+            seed: Some(7),
+            ..Default::default()
+        });
+        let state = session.get_state();
+        let view = state.view.as_ref().unwrap();
+
+        let bytes = view_tensor_u8(view);
+        let floats = view_tensor_f32(view);
+        assert_eq!(bytes.len(), floats.len());
+        for (b, f) in bytes.iter().zip(&floats) {
+            assert_eq!(f32::from(*b), *f);
+        }
+    }
+
+    #[test]
+    fn test_state_tensor_broadcasts_inventory_and_vitals() {
+        let mut session = Session::new(SessionConfig {
+            world_size: (32, 32),
+            seed: Some(42),
+            view_radius: 3,
+            ..Default::default()
+        });
+        session.world.get_player_mut().unwrap().inventory.wood = 5;
+
+        let state = session.get_state();
+        let tensor = state.to_tensor().unwrap();
+        let (h, w, c) = state.tensor_shape().unwrap();
+        assert_eq!((h, w), (7, 7));
+        assert_eq!(c, NUM_CHANNELS + SCALAR_FEATURES.len());
+        assert_eq!(tensor.len(), h * w * c);
+
+        // HWC layout: per-tile spatial channels first, then scalars
+        let view = state.view.as_ref().unwrap();
+        let spatial = view_tensor_u8(view);
+        let plane = h * w;
+        let wood_channel = NUM_CHANNELS + SCALAR_FEATURES.iter().position(|&n| n == "wood").unwrap();
+        let health_channel =
+            NUM_CHANNELS + SCALAR_FEATURES.iter().position(|&n| n == "health").unwrap();
+        for idx in 0..plane {
+            let base = idx * c;
+            assert_eq!(tensor[base + MATERIAL_CHANNEL], spatial[MATERIAL_CHANNEL * plane + idx]);
+            assert_eq!(tensor[base + wood_channel], 5);
+            assert_eq!(tensor[base + health_channel], 9);
+        }
+        assert_eq!(scalar_features(&state).len(), SCALAR_FEATURES.len());
+    }
+
+    #[test]
+    fn test_symbolic_observation_is_one_hot() {
+        let mut session = Session::new(SessionConfig {
+            world_size: (32, 32),
+            seed: Some(42),
+            view_radius: 3,
+            ..Default::default()
+        });
+        session.world.get_player_mut().unwrap().inventory.wood = 5;
+
+        let state = session.get_state();
+        let obs = symbolic_observation(&state).unwrap();
+        let view = state.view.as_ref().unwrap();
+        let size = view.size();
+        assert_eq!(obs.len(), symbolic_len(size));
+
+        // Every material id fits the tile-class block
+        assert!((crate::material::Material::Fence as usize + 1) < SYMBOLIC_TILE_CLASSES);
+
+        // Each tile sets exactly one tile class and one entity class
+        let spatial = view_tensor_u8(view);
+        let plane = size * size;
+        for idx in 0..plane {
+            let base = idx * SYMBOLIC_TILE_SLOTS;
+            let tiles = &obs[base..base + SYMBOLIC_TILE_CLASSES];
+            let entities =
+                &obs[base + SYMBOLIC_TILE_CLASSES..base + SYMBOLIC_TILE_SLOTS];
+            assert_eq!(tiles.iter().sum::<f32>(), 1.0);
+            assert_eq!(entities.iter().sum::<f32>(), 1.0);
+            let mat = spatial[MATERIAL_CHANNEL * plane + idx] as usize;
+            assert_eq!(tiles[mat], 1.0);
+        }
+
+        // The player's one-hot sits at the view center
+        let center = view.radius as usize * size + view.radius as usize;
+        assert_eq!(obs[center * SYMBOLIC_TILE_SLOTS + SYMBOLIC_TILE_CLASSES + 1], 1.0);
+
+        // Scalars follow, normalized by 10
+        let wood = SCALAR_FEATURES.iter().position(|&n| n == "wood").unwrap();
+        let scalar_base = plane * SYMBOLIC_TILE_SLOTS;
+        assert!((obs[scalar_base + wood] - 0.5).abs() < 1e-6);
+        assert!((obs[scalar_base] - 0.9).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_frame_stack_orders_and_resets() {
+        let mut session = Session::new(SessionConfig {
+            world_size: (32, 32),
+            seed: Some(42),
+            view_radius: 2,
+            ..Default::default()
+        });
+        let mut stack = FrameStack::new(3);
+
+        let first_view = session.get_state().view.unwrap();
+        let first = view_tensor_u8(&first_view);
+        let plane = first.len();
+        assert_eq!(
+            stack.stacked_shape(&first_view),
+            (3 * NUM_CHANNELS, 5, 5)
+        );
+
+        // The first push fills the whole stack with the first frame
+        let stacked = stack.push(&first_view);
+        assert_eq!(stacked.len(), 3 * plane);
+        assert_eq!(&stacked[..plane], &first[..]);
+        assert_eq!(&stacked[2 * plane..], &first[..]);
+
+        // After more steps the stack holds oldest..newest
+        session.step(crate::action::Action::MoveRight);
+        let second_view = session.get_state().view.unwrap();
+        let second = view_tensor_u8(&second_view);
+        let stacked = stack.push(&second_view);
+        assert_eq!(&stacked[..plane], &first[..]);
+        assert_eq!(&stacked[2 * plane..], &second[..]);
+
+        // Reset drops history: the next episode's first frame fills the
+        // stack instead of leaking the old frames
+        stack.reset();
+        session.reset();
+        let fresh_view = session.get_state().view.unwrap();
+        let fresh = view_tensor_u8(&fresh_view);
+        let stacked = stack.push(&fresh_view);
+        assert_eq!(&stacked[..plane], &fresh[..]);
+        assert_eq!(&stacked[plane..2 * plane], &fresh[..]);
+    }
+}
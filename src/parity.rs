@@ -7,6 +7,7 @@
 //! Data source: crafter/data.yaml
 
 #[cfg(test)]
+#[allow(clippy::assertions_on_constants)]
 mod tests {
     use crate::action::Action;
     use crate::achievement::Achievements;
@@ -160,7 +161,7 @@ mod tests {
         ];
 
         for (i, python_name) in python_materials.iter().enumerate() {
-            let material = Material::from_index(i as u8).expect(&format!("Material {} should exist", i));
+            let material = Material::from_index(i as u8).unwrap_or_else(|| panic!("Material {} should exist", i));
             let rust_name = format!("{:?}", material).to_lowercase();
             assert_eq!(
                 rust_name, *python_name,
@@ -0,0 +1,127 @@
+//! Platform-correct config and data directories
+//!
+//! One place for "where do crafter files live", shared by the TUI,
+//! recordings, and saves instead of each hand-rolling `$HOME/.config`:
+//!
+//! - Linux (and other unixes): XDG — `$XDG_CONFIG_HOME`/`$XDG_DATA_HOME`,
+//!   falling back to `~/.config` and `~/.local/share`
+//! - macOS: `~/Library/Application Support`
+//! - Windows: `%APPDATA%` for config, `%LOCALAPPDATA%` for data
+//!
+//! `CRAFTER_CONFIG_HOME` and `CRAFTER_DATA_HOME` override the base
+//! directories outright (handy for tests and sandboxes). A legacy
+//! `~/.config/<app>` directory keeps winning if it exists and the
+//! platform location does not, so existing installs are not orphaned.
+
+use std::path::PathBuf;
+
+/// The per-app configuration directory (profiles, rule configs)
+pub fn config_dir(app: &str) -> PathBuf {
+    if let Some(base) = std::env::var_os("CRAFTER_CONFIG_HOME") {
+        return PathBuf::from(base).join(app);
+    }
+    resolve_with_legacy(platform_config_base(), app)
+}
+
+/// The per-app data directory (recordings, saves, journals)
+pub fn data_dir(app: &str) -> PathBuf {
+    if let Some(base) = std::env::var_os("CRAFTER_DATA_HOME") {
+        return PathBuf::from(base).join(app);
+    }
+    resolve_with_legacy(platform_data_base(), app)
+}
+
+/// Prefer the platform directory, but fall back to an existing legacy
+/// `~/.config/<app>` so upgrades keep finding old files
+fn resolve_with_legacy(base: PathBuf, app: &str) -> PathBuf {
+    let preferred = base.join(app);
+    if preferred.exists() {
+        return preferred;
+    }
+    let legacy = legacy_config_base().join(app);
+    if legacy.exists() {
+        return legacy;
+    }
+    preferred
+}
+
+fn legacy_config_base() -> PathBuf {
+    home_dir().join(".config")
+}
+
+#[cfg(target_os = "macos")]
+fn platform_config_base() -> PathBuf {
+    home_dir().join("Library").join("Application Support")
+}
+
+#[cfg(target_os = "windows")]
+fn platform_config_base() -> PathBuf {
+    std::env::var_os("APPDATA")
+        .map(PathBuf::from)
+        .unwrap_or_else(|| home_dir().join("AppData").join("Roaming"))
+}
+
+#[cfg(not(any(target_os = "macos", target_os = "windows")))]
+fn platform_config_base() -> PathBuf {
+    std::env::var_os("XDG_CONFIG_HOME")
+        .map(PathBuf::from)
+        .unwrap_or_else(legacy_config_base)
+}
+
+#[cfg(target_os = "macos")]
+fn platform_data_base() -> PathBuf {
+    platform_config_base()
+}
+
+#[cfg(target_os = "windows")]
+fn platform_data_base() -> PathBuf {
+    std::env::var_os("LOCALAPPDATA")
+        .map(PathBuf::from)
+        .unwrap_or_else(platform_config_base)
+}
+
+#[cfg(not(any(target_os = "macos", target_os = "windows")))]
+fn platform_data_base() -> PathBuf {
+    std::env::var_os("XDG_DATA_HOME")
+        .map(PathBuf::from)
+        .unwrap_or_else(|| home_dir().join(".local").join("share"))
+}
+
+fn home_dir() -> PathBuf {
+    #[cfg(target_os = "windows")]
+    let var = "USERPROFILE";
+    #[cfg(not(target_os = "windows"))]
+    let var = "HOME";
+    std::env::var_os(var)
+        .map(PathBuf::from)
+        .unwrap_or_else(|| PathBuf::from("."))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_env_overrides_win() {
+        // Env mutation is process-global; exercise both overrides in one
+        // test to avoid racing parallel tests
+        std::env::set_var("CRAFTER_CONFIG_HOME", "/tmp/crafter-test-config");
+        std::env::set_var("CRAFTER_DATA_HOME", "/tmp/crafter-test-data");
+        assert_eq!(
+            config_dir("crafter"),
+            PathBuf::from("/tmp/crafter-test-config/crafter")
+        );
+        assert_eq!(
+            data_dir("crafter"),
+            PathBuf::from("/tmp/crafter-test-data/crafter")
+        );
+        std::env::remove_var("CRAFTER_CONFIG_HOME");
+        std::env::remove_var("CRAFTER_DATA_HOME");
+    }
+
+    #[test]
+    fn test_dirs_end_with_app_name() {
+        assert!(config_dir("crafter").ends_with("crafter"));
+        assert!(data_dir("mission-control").ends_with("mission-control"));
+    }
+}
@@ -0,0 +1,47 @@
+//! Platform shims so the core compiles on `wasm32-unknown-unknown`
+//!
+//! On native targets this re-exports the std types. The browser has no
+//! monotonic std clock, so on wasm [`Instant`] is a no-op stand-in and
+//! every measured duration is zero — wall-clock features (real-time
+//! mode, idle detection, play-time accounting) degrade gracefully, and
+//! wasm sessions should run in
+//! [`TimeMode::Logical`](crate::session::TimeMode::Logical), where the
+//! host drives every tick explicitly.
+
+#[cfg(not(target_arch = "wasm32"))]
+pub use std::time::Instant;
+
+#[cfg(target_arch = "wasm32")]
+pub use self::wasm::Instant;
+
+#[cfg(target_arch = "wasm32")]
+mod wasm {
+    use std::time::Duration;
+
+    /// Zero-duration stand-in for [`std::time::Instant`], which panics
+    /// at runtime on `wasm32-unknown-unknown`
+    #[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+    pub struct Instant;
+
+    impl Instant {
+        pub fn now() -> Self {
+            Instant
+        }
+
+        pub fn elapsed(&self) -> Duration {
+            Duration::ZERO
+        }
+
+        pub fn duration_since(&self, _earlier: Instant) -> Duration {
+            Duration::ZERO
+        }
+
+        pub fn checked_duration_since(&self, _earlier: Instant) -> Option<Duration> {
+            Some(Duration::ZERO)
+        }
+
+        pub fn saturating_duration_since(&self, _earlier: Instant) -> Duration {
+            Duration::ZERO
+        }
+    }
+}
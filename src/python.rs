@@ -0,0 +1,219 @@
+//! PyO3 bindings (requires the `python` feature)
+//!
+//! Exposes the engine to Python as the `crafter_core` extension module
+//! so it slots in where the Python Crafter env is used today: a
+//! discrete action space addressed by index, `step`/`reset`, and a
+//! `[C, H, W]` uint8 observation. Observations come back as raw bytes
+//! plus a shape tuple, which numpy ingests zero-copy:
+//!
+//! ```python
+//! import numpy as np
+//! import crafter_core
+//!
+//! env = crafter_core.Session(crafter_core.SessionConfig(seed=42))
+//! data, shape = env.observation()
+//! obs = np.frombuffer(data, dtype=np.uint8).reshape(shape)
+//! result = env.step(env.action_names().index("Do"))
+//! ```
+//!
+//! Build with `maturin build --features python` (or
+//! `cargo build --features python` and rename the cdylib to
+//! `crafter_core.so`).
+
+// The expanded #[pymethods] code trips useless_conversion on PyErr
+#![allow(clippy::useless_conversion)]
+
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+use pyo3::types::PyBytes;
+
+use crate::action::Action;
+use crate::config::SessionConfig;
+use crate::obs;
+use crate::session::{Session, StepResult};
+
+/// Python-facing session configuration
+#[pyclass(name = "SessionConfig")]
+#[derive(Clone)]
+pub struct PySessionConfig {
+    pub(crate) inner: SessionConfig,
+}
+
+#[pymethods]
+impl PySessionConfig {
+    /// Common knobs inline; everything else via `from_toml`
+    #[new]
+    #[pyo3(signature = (world_size=(64, 64), seed=None, view_radius=None, max_steps=None))]
+    fn new(
+        world_size: (u32, u32),
+        seed: Option<u64>,
+        view_radius: Option<u32>,
+        max_steps: Option<u32>,
+    ) -> Self {
+        let mut inner = SessionConfig {
+            world_size,
+            seed,
+            ..Default::default()
+        };
+        if let Some(radius) = view_radius {
+            inner.view_radius = radius;
+        }
+        inner.max_steps = max_steps;
+        Self { inner }
+    }
+
+    /// Parse a full config from TOML, same format as the config files
+    #[staticmethod]
+    fn from_toml(contents: &str) -> PyResult<Self> {
+        SessionConfig::from_toml_str(contents)
+            .map(|inner| Self { inner })
+            .map_err(|e| PyValueError::new_err(e.to_string()))
+    }
+
+    /// The config preset tuned for fast agent training
+    #[staticmethod]
+    fn fast_training() -> Self {
+        Self {
+            inner: SessionConfig::fast_training(),
+        }
+    }
+
+    fn __repr__(&self) -> String {
+        format!(
+            "SessionConfig(world_size={:?}, seed={:?})",
+            self.inner.world_size, self.inner.seed
+        )
+    }
+}
+
+/// Outcome of one step, mirroring [`StepResult`]
+#[pyclass(name = "StepResult")]
+pub struct PyStepResult {
+    #[pyo3(get)]
+    pub step: u64,
+    #[pyo3(get)]
+    pub reward: f32,
+    #[pyo3(get)]
+    pub done: bool,
+    #[pyo3(get)]
+    pub done_reason: Option<String>,
+    #[pyo3(get)]
+    pub newly_unlocked: Vec<String>,
+}
+
+impl From<StepResult> for PyStepResult {
+    fn from(result: StepResult) -> Self {
+        Self {
+            step: result.state.step,
+            reward: result.reward,
+            done: result.done,
+            done_reason: result.done_reason.map(|r| format!("{:?}", r)),
+            newly_unlocked: result.newly_unlocked,
+        }
+    }
+}
+
+#[pymethods]
+impl PyStepResult {
+    fn __repr__(&self) -> String {
+        format!(
+            "StepResult(step={}, reward={}, done={})",
+            self.step, self.reward, self.done
+        )
+    }
+}
+
+/// Python-facing game session with a discrete, index-addressed action
+/// space like the Python Crafter env
+#[pyclass(name = "Session", unsendable)]
+pub struct PySession {
+    session: Session,
+    actions: Vec<Action>,
+}
+
+#[pymethods]
+impl PySession {
+    #[new]
+    fn new(config: PySessionConfig) -> Self {
+        let actions = config.inner.action_profile.action_table().to_vec();
+        Self {
+            session: Session::new(config.inner),
+            actions,
+        }
+    }
+
+    /// Number of actions in the discrete action space
+    fn action_count(&self) -> usize {
+        self.actions.len()
+    }
+
+    /// Action names, index-aligned with `step`'s argument
+    fn action_names(&self) -> Vec<String> {
+        self.actions.iter().map(|a| format!("{:?}", a)).collect()
+    }
+
+    /// Step with the action at `action` in the action table
+    fn step(&mut self, action: usize) -> PyResult<PyStepResult> {
+        let action = *self.actions.get(action).ok_or_else(|| {
+            PyValueError::new_err(format!(
+                "action index {} out of range (action_count is {})",
+                action,
+                self.actions.len()
+            ))
+        })?;
+        Ok(self.session.step(action).into())
+    }
+
+    /// Start a fresh episode
+    fn reset(&mut self) {
+        self.session.reset();
+    }
+
+    /// The `[C, H, W]` uint8 observation as `(bytes, shape)`;
+    /// `np.frombuffer(data, dtype=np.uint8).reshape(shape)` wraps it
+    /// without a copy
+    fn observation<'py>(
+        &self,
+        py: Python<'py>,
+    ) -> PyResult<(Bound<'py, PyBytes>, (usize, usize, usize))> {
+        let state = self.session.get_state();
+        let view = state
+            .view
+            .as_ref()
+            .ok_or_else(|| PyValueError::new_err("session has no view configured"))?;
+        let tensor = obs::view_tensor_u8(view);
+        Ok((PyBytes::new_bound(py, &tensor), obs::view_tensor_shape(view)))
+    }
+
+    /// Current step number
+    fn step_count(&self) -> u64 {
+        self.session.timing.step
+    }
+
+    /// Episode number, starting at 1
+    fn episode(&self) -> u32 {
+        self.session.episode
+    }
+
+    /// Per-achievement unlock counts, keyed by achievement name
+    fn achievements(&self) -> std::collections::HashMap<String, u32> {
+        self.session.get_state().achievements.to_map()
+    }
+
+    fn __repr__(&self) -> String {
+        format!(
+            "Session(episode={}, step={})",
+            self.session.episode, self.session.timing.step
+        )
+    }
+}
+
+/// The `crafter_core` Python module
+#[pymodule]
+fn crafter_core(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    m.add_class::<PySessionConfig>()?;
+    m.add_class::<PySession>()?;
+    m.add_class::<PyStepResult>()?;
+    m.add("RULES_VERSION", crate::config::RULES_VERSION)?;
+    Ok(())
+}
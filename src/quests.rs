@@ -0,0 +1,392 @@
+//! Data-driven quest/event scripting
+//!
+//! Scenario designers describe story-like events in TOML — no Rust
+//! required. A [`QuestScript`] is a list of rules, each pairing one
+//! trigger (step reached, achievement unlocked, region entered) with a
+//! list of effects (spawn a mob, grant items, show a message):
+//!
+//! ```toml
+//! [[quests]]
+//! id = "ambush"
+//! trigger = { on = "enter_region", x = 10, y = 10, width = 4, height = 4 }
+//! effects = [
+//!     { kind = "show_message", text = "Zombies burst from the ground!" },
+//!     { kind = "spawn_mob", mob = "zombie" },
+//!     { kind = "spawn_mob", mob = "zombie" },
+//! ]
+//!
+//! [[quests]]
+//! id = "starter-kit"
+//! trigger = { on = "achievement", name = "place_table" }
+//! effects = [{ kind = "grant_item", item = "wood", count = 3 }]
+//! ```
+//!
+//! The host drives a [`QuestEngine`] by calling [`QuestEngine::tick`]
+//! after each session step; it evaluates triggers against the current
+//! state, applies due effects, and returns any messages to display.
+//! Rules fire once by default; set `once = false` to re-fire on every
+//! tick while the trigger holds.
+
+use crate::entity::{Cow, GameObject, Pig, Sheep, Skeleton, Zombie};
+use crate::inventory::{Inventory, MAX_INVENTORY_VALUE};
+use crate::session::Session;
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+
+/// Condition that makes a quest rule fire
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "on", rename_all = "snake_case")]
+pub enum QuestTrigger {
+    /// The session has reached (or passed) this step
+    Step { step: u64 },
+    /// The named achievement has been unlocked
+    Achievement { name: String },
+    /// The player stands inside the rectangle
+    EnterRegion { x: i32, y: i32, width: u32, height: u32 },
+}
+
+/// Mobs a quest can spawn
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum QuestMob {
+    Zombie,
+    Skeleton,
+    Cow,
+    Sheep,
+    Pig,
+}
+
+/// What happens when a rule fires
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum QuestEffect {
+    /// Spawn a mob at `pos`, or on a walkable tile near the player
+    SpawnMob {
+        mob: QuestMob,
+        pos: Option<(i32, i32)>,
+    },
+    /// Add `count` of an inventory item (resource, tool, or potion name
+    /// as it appears in [`Inventory`]); capped like normal pickups
+    GrantItem {
+        item: String,
+        #[serde(default = "default_count")]
+        count: u8,
+    },
+    /// Queue a message for the host frontend to display
+    ShowMessage { text: String },
+}
+
+fn default_count() -> u8 {
+    1
+}
+
+fn default_once() -> bool {
+    true
+}
+
+/// One trigger/effects pairing
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct QuestRule {
+    pub id: String,
+    pub trigger: QuestTrigger,
+    pub effects: Vec<QuestEffect>,
+    /// Fire a single time (default) or on every tick the trigger holds
+    #[serde(default = "default_once")]
+    pub once: bool,
+}
+
+/// A parsed quest script
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct QuestScript {
+    #[serde(default)]
+    pub quests: Vec<QuestRule>,
+}
+
+impl QuestScript {
+    pub fn from_toml_str(contents: &str) -> Result<Self, toml::de::Error> {
+        toml::from_str(contents)
+    }
+
+    pub fn load_from_path<P: AsRef<std::path::Path>>(path: P) -> std::io::Result<Self> {
+        let contents = std::fs::read_to_string(path)?;
+        Self::from_toml_str(&contents)
+            .map_err(|e| std::io::Error::new(std::io::ErrorKind::InvalidData, e))
+    }
+
+    /// Check the script for item names that would be ignored at runtime
+    pub fn validate(&self) -> Result<(), String> {
+        for rule in &self.quests {
+            for effect in &rule.effects {
+                if let QuestEffect::GrantItem { item, .. } = effect {
+                    let mut probe = Inventory::new();
+                    if !grant_item(&mut probe, item, 1) {
+                        return Err(format!("quest '{}': unknown item '{}'", rule.id, item));
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Runs a quest script against a session
+pub struct QuestEngine {
+    script: QuestScript,
+    fired: HashSet<String>,
+}
+
+impl QuestEngine {
+    pub fn new(script: QuestScript) -> Self {
+        Self {
+            script,
+            fired: HashSet::new(),
+        }
+    }
+
+    /// IDs of rules that have fired so far
+    pub fn fired(&self) -> &HashSet<String> {
+        &self.fired
+    }
+
+    /// Evaluate triggers against the session and apply due effects.
+    /// Call after each step; returns messages to display.
+    pub fn tick(&mut self, session: &mut Session) -> Vec<String> {
+        let state = session.get_state();
+        let step = state.step;
+        let player_pos = state.player_pos;
+        let mut messages = Vec::new();
+
+        let due: Vec<QuestRule> = self
+            .script
+            .quests
+            .iter()
+            .filter(|rule| !(rule.once && self.fired.contains(&rule.id)))
+            .filter(|rule| match &rule.trigger {
+                QuestTrigger::Step { step: at } => step >= *at,
+                QuestTrigger::Achievement { name } => {
+                    state.achievements.get(name).unwrap_or(0) > 0
+                }
+                QuestTrigger::EnterRegion {
+                    x,
+                    y,
+                    width,
+                    height,
+                } => {
+                    player_pos.0 >= *x
+                        && player_pos.0 < x + *width as i32
+                        && player_pos.1 >= *y
+                        && player_pos.1 < y + *height as i32
+                }
+            })
+            .cloned()
+            .collect();
+
+        for rule in due {
+            self.fired.insert(rule.id.clone());
+            for effect in &rule.effects {
+                match effect {
+                    QuestEffect::SpawnMob { mob, pos } => {
+                        let spawn_pos = pos
+                            .filter(|p| session.world.is_walkable(*p))
+                            .or_else(|| walkable_near(session, player_pos));
+                        if let Some(pos) = spawn_pos {
+                            session.world.add_object(match mob {
+                                QuestMob::Zombie => GameObject::Zombie(Zombie::new(pos)),
+                                QuestMob::Skeleton => GameObject::Skeleton(Skeleton::new(pos)),
+                                QuestMob::Cow => GameObject::Cow(Cow::new(pos)),
+                                QuestMob::Sheep => GameObject::Sheep(Sheep::new(pos)),
+                                QuestMob::Pig => GameObject::Pig(Pig::new(pos)),
+                            });
+                        }
+                    }
+                    QuestEffect::GrantItem { item, count } => {
+                        if let Some(player) = session.world.get_player_mut() {
+                            grant_item(&mut player.inventory, item, *count);
+                        }
+                    }
+                    QuestEffect::ShowMessage { text } => messages.push(text.clone()),
+                }
+            }
+        }
+        messages
+    }
+}
+
+/// First walkable tile near (but not on) a position, scanning outward
+fn walkable_near(session: &Session, center: (i32, i32)) -> Option<(i32, i32)> {
+    for radius in 2..=5i32 {
+        for dy in -radius..=radius {
+            for dx in -radius..=radius {
+                if dx.abs().max(dy.abs()) != radius {
+                    continue;
+                }
+                let pos = (center.0 + dx, center.1 + dy);
+                if session.world.is_walkable(pos) {
+                    return Some(pos);
+                }
+            }
+        }
+    }
+    None
+}
+
+/// Add to the named inventory slot, capped like normal pickups.
+/// Returns false for unknown item names.
+fn grant_item(inv: &mut Inventory, item: &str, count: u8) -> bool {
+    let cap = |slot: &mut u8| *slot = (*slot + count).min(MAX_INVENTORY_VALUE);
+    match item {
+        "wood" => cap(&mut inv.wood),
+        "stone" => cap(&mut inv.stone),
+        "coal" => cap(&mut inv.coal),
+        "iron" => cap(&mut inv.iron),
+        "diamond" => cap(&mut inv.diamond),
+        "sapphire" => cap(&mut inv.sapphire),
+        "ruby" => cap(&mut inv.ruby),
+        "sapling" => cap(&mut inv.sapling),
+        "wool" => cap(&mut inv.wool),
+        "food" => cap(&mut inv.food),
+        "drink" => cap(&mut inv.drink),
+        "energy" => cap(&mut inv.energy),
+        "health" => cap(&mut inv.health),
+        "wood_pickaxe" => cap(&mut inv.wood_pickaxe),
+        "stone_pickaxe" => cap(&mut inv.stone_pickaxe),
+        "iron_pickaxe" => cap(&mut inv.iron_pickaxe),
+        "diamond_pickaxe" => cap(&mut inv.diamond_pickaxe),
+        "wood_sword" => cap(&mut inv.wood_sword),
+        "stone_sword" => cap(&mut inv.stone_sword),
+        "iron_sword" => cap(&mut inv.iron_sword),
+        "diamond_sword" => cap(&mut inv.diamond_sword),
+        "bow" => cap(&mut inv.bow),
+        "arrows" => cap(&mut inv.arrows),
+        "spike_trap" => cap(&mut inv.spike_trap),
+        "door" => cap(&mut inv.door),
+        "fence" => cap(&mut inv.fence),
+        "potion_red" => cap(&mut inv.potion_red),
+        "potion_green" => cap(&mut inv.potion_green),
+        "potion_blue" => cap(&mut inv.potion_blue),
+        "potion_pink" => cap(&mut inv.potion_pink),
+        "potion_cyan" => cap(&mut inv.potion_cyan),
+        "potion_yellow" => cap(&mut inv.potion_yellow),
+        _ => return false,
+    }
+    true
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::action::Action;
+    use crate::config::SessionConfig;
+
+    fn test_session() -> Session {
+        Session::new(SessionConfig {
+            world_size: (32, 32),
+            seed: Some(42),
+            ..Default::default()
+        })
+    }
+
+    #[test]
+    fn test_step_trigger_grants_items_and_messages_once() {
+        let script = QuestScript::from_toml_str(
+            r#"
+            [[quests]]
+            id = "starter"
+            trigger = { on = "step", step = 2 }
+            effects = [
+                { kind = "grant_item", item = "wood", count = 3 },
+                { kind = "show_message", text = "A gift appears." },
+            ]
+            "#,
+        )
+        .unwrap();
+        script.validate().unwrap();
+
+        let mut session = test_session();
+        let mut engine = QuestEngine::new(script);
+
+        session.step(Action::Noop);
+        assert!(engine.tick(&mut session).is_empty());
+
+        session.step(Action::Noop);
+        let messages = engine.tick(&mut session);
+        assert_eq!(messages, vec!["A gift appears.".to_string()]);
+        assert_eq!(session.get_state().inventory.wood, 3);
+
+        // `once` rules never re-fire
+        session.step(Action::Noop);
+        assert!(engine.tick(&mut session).is_empty());
+        assert!(engine.fired().contains("starter"));
+    }
+
+    #[test]
+    fn test_achievement_trigger_spawns_mob() {
+        let script = QuestScript::from_toml_str(
+            r#"
+            [[quests]]
+            id = "wood-ambush"
+            trigger = { on = "achievement", name = "collect_wood" }
+            effects = [{ kind = "spawn_mob", mob = "cow" }]
+            "#,
+        )
+        .unwrap();
+
+        let mut session = test_session();
+        let mut engine = QuestEngine::new(script);
+
+        let cows_before = session
+            .world
+            .objects
+            .values()
+            .filter(|obj| matches!(obj, GameObject::Cow(_)))
+            .count();
+
+        // Not unlocked yet: nothing happens
+        engine.tick(&mut session);
+
+        // Force the unlock via a granted item plus direct achievement
+        if let Some(player) = session.world.get_player_mut() {
+            player.achievements.collect_wood = 1;
+        }
+        session.step(Action::Noop);
+        engine.tick(&mut session);
+
+        let cows_after = session
+            .world
+            .objects
+            .values()
+            .filter(|obj| matches!(obj, GameObject::Cow(_)))
+            .count();
+        assert_eq!(cows_after, cows_before + 1);
+    }
+
+    #[test]
+    fn test_enter_region_trigger_and_validation() {
+        let state_pos = test_session().get_state().player_pos;
+        let script = QuestScript::from_toml_str(&format!(
+            r#"
+            [[quests]]
+            id = "home"
+            trigger = {{ on = "enter_region", x = {}, y = {}, width = 1, height = 1 }}
+            effects = [{{ kind = "show_message", text = "Welcome home." }}]
+            "#,
+            state_pos.0, state_pos.1
+        ))
+        .unwrap();
+
+        let mut session = test_session();
+        let mut engine = QuestEngine::new(script);
+        assert_eq!(engine.tick(&mut session), vec!["Welcome home.".to_string()]);
+
+        let bad = QuestScript::from_toml_str(
+            r#"
+            [[quests]]
+            id = "bad"
+            trigger = { on = "step", step = 0 }
+            effects = [{ kind = "grant_item", item = "bogus" }]
+            "#,
+        )
+        .unwrap();
+        assert!(bad.validate().is_err());
+    }
+}
@@ -0,0 +1,179 @@
+//! Recipe registry for the classic tool recipes
+//!
+//! Crafting costs for the six classic tools live here as a `RecipeBook`.
+//! Normally the book holds the fixed Python Crafter costs, but with recipe
+//! mutation enabled the ingredient counts are resampled per episode within
+//! the bounds declared in this module, for testing agent adaptability. The
+//! sampled book is exposed through `GameState` so agents can observe the
+//! costs they are playing under.
+
+use crate::inventory::Inventory;
+use rand::Rng;
+use serde::{Deserialize, Serialize};
+
+/// Inclusive lower bound for a mutated ingredient count
+pub const MUTATION_MIN: u8 = 1;
+
+/// Inclusive upper bound for a mutated ingredient count
+pub const MUTATION_MAX: u8 = 3;
+
+/// Ingredient counts for one recipe. Ingredients a recipe never uses stay
+/// at zero, including under mutation.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RecipeCost {
+    pub wood: u8,
+    pub stone: u8,
+    pub coal: u8,
+    pub iron: u8,
+}
+
+impl RecipeCost {
+    /// Check whether the inventory can cover this cost
+    pub fn can_afford(&self, inv: &Inventory) -> bool {
+        inv.wood >= self.wood
+            && inv.stone >= self.stone
+            && inv.coal >= self.coal
+            && inv.iron >= self.iron
+    }
+
+    /// Consume the cost from the inventory. Returns false, leaving the
+    /// inventory untouched, when it cannot be covered.
+    pub fn consume(&self, inv: &mut Inventory) -> bool {
+        if !self.can_afford(inv) {
+            return false;
+        }
+        inv.wood -= self.wood;
+        inv.stone -= self.stone;
+        inv.coal -= self.coal;
+        inv.iron -= self.iron;
+        true
+    }
+
+    /// Resample every ingredient the classic recipe uses within the
+    /// mutation bounds; unused ingredients stay zero
+    fn mutated<R: Rng>(&self, rng: &mut R) -> RecipeCost {
+        let mut sample = |used: u8| {
+            if used == 0 {
+                0
+            } else {
+                rng.gen_range(MUTATION_MIN..=MUTATION_MAX)
+            }
+        };
+        RecipeCost {
+            wood: sample(self.wood),
+            stone: sample(self.stone),
+            coal: sample(self.coal),
+            iron: sample(self.iron),
+        }
+    }
+}
+
+/// The classic tool recipes, as fixed costs or mutated per episode
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RecipeBook {
+    pub wood_pickaxe: RecipeCost,
+    pub stone_pickaxe: RecipeCost,
+    pub iron_pickaxe: RecipeCost,
+    pub wood_sword: RecipeCost,
+    pub stone_sword: RecipeCost,
+    pub iron_sword: RecipeCost,
+}
+
+impl Default for RecipeBook {
+    /// The fixed Python Crafter costs
+    fn default() -> Self {
+        let wood_tool = RecipeCost {
+            wood: 1,
+            ..Default::default()
+        };
+        let stone_tool = RecipeCost {
+            wood: 1,
+            stone: 1,
+            ..Default::default()
+        };
+        let iron_tool = RecipeCost {
+            wood: 1,
+            coal: 1,
+            iron: 1,
+            ..Default::default()
+        };
+        Self {
+            wood_pickaxe: wood_tool,
+            stone_pickaxe: stone_tool,
+            iron_pickaxe: iron_tool,
+            wood_sword: wood_tool,
+            stone_sword: stone_tool,
+            iron_sword: iron_tool,
+        }
+    }
+}
+
+impl RecipeBook {
+    /// Sample a mutated book: each recipe keeps its classic ingredient
+    /// kinds but with counts drawn from the mutation bounds
+    pub fn mutated<R: Rng>(rng: &mut R) -> Self {
+        let classic = Self::default();
+        Self {
+            wood_pickaxe: classic.wood_pickaxe.mutated(rng),
+            stone_pickaxe: classic.stone_pickaxe.mutated(rng),
+            iron_pickaxe: classic.iron_pickaxe.mutated(rng),
+            wood_sword: classic.wood_sword.mutated(rng),
+            stone_sword: classic.stone_sword.mutated(rng),
+            iron_sword: classic.iron_sword.mutated(rng),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::SeedableRng;
+    use rand_chacha::ChaCha8Rng;
+
+    #[test]
+    fn test_classic_book_matches_fixed_costs() {
+        let book = RecipeBook::default();
+        assert_eq!(book.wood_pickaxe.wood, 1);
+        assert_eq!(book.wood_pickaxe.stone, 0);
+        assert_eq!(book.iron_sword.coal, 1);
+        assert_eq!(book.iron_sword.iron, 1);
+    }
+
+    #[test]
+    fn test_mutated_book_respects_bounds_and_ingredient_kinds() {
+        let mut rng = ChaCha8Rng::seed_from_u64(7);
+        for _ in 0..20 {
+            let book = RecipeBook::mutated(&mut rng);
+            for cost in [book.wood_pickaxe, book.wood_sword] {
+                assert!((MUTATION_MIN..=MUTATION_MAX).contains(&cost.wood));
+                assert_eq!(cost.stone, 0);
+                assert_eq!(cost.coal, 0);
+                assert_eq!(cost.iron, 0);
+            }
+            for cost in [book.iron_pickaxe, book.iron_sword] {
+                assert!((MUTATION_MIN..=MUTATION_MAX).contains(&cost.wood));
+                assert!((MUTATION_MIN..=MUTATION_MAX).contains(&cost.coal));
+                assert!((MUTATION_MIN..=MUTATION_MAX).contains(&cost.iron));
+                assert_eq!(cost.stone, 0);
+            }
+        }
+    }
+
+    #[test]
+    fn test_consume_is_all_or_nothing() {
+        let mut inv = Inventory::new();
+        inv.wood = 1;
+        let cost = RecipeCost {
+            wood: 1,
+            stone: 1,
+            ..Default::default()
+        };
+        assert!(!cost.consume(&mut inv));
+        assert_eq!(inv.wood, 1);
+
+        inv.stone = 2;
+        assert!(cost.consume(&mut inv));
+        assert_eq!(inv.wood, 0);
+        assert_eq!(inv.stone, 1);
+    }
+}
@@ -7,7 +7,9 @@
 
 use crate::action::Action;
 use crate::config::SessionConfig;
+use crate::material::Material;
 use crate::session::{GameState, Session, StepResult};
+use crate::world::WorldView;
 use serde::{Deserialize, Serialize};
 use std::fs::File;
 use std::io::{BufReader, BufWriter, Read, Write};
@@ -24,12 +26,135 @@ pub struct RecordedStep {
     pub reward: f32,
     /// Whether episode ended
     pub done: bool,
+    /// Whether the end was a truncation (step limit, manual reset)
+    /// rather than a termination (death); see `StepResult::truncated`.
+    /// Absent in old recordings, which conflated the two.
+    #[serde(default)]
+    pub truncated: bool,
     /// State before action (optional, can be large)
     #[serde(skip_serializing_if = "Option::is_none")]
     pub state_before: Option<GameState>,
     /// State after action (optional, can be large)
     #[serde(skip_serializing_if = "Option::is_none")]
     pub state_after: Option<GameState>,
+    /// View after action in compact form (see [`CompactView`]); set
+    /// instead of `state_after.view` when recording with `compact_views`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub compact_view: Option<CompactView>,
+    /// Wall-clock milliseconds between the previous recorded action and
+    /// this one (reaction time; long values are idle gaps). Only set
+    /// when recording with `record_timing`, e.g. real-time human play.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub wall_ms: Option<u64>,
+}
+
+impl RecordedStep {
+    /// The view after this step, decoding the compact form when that is
+    /// how it was recorded. This is what replay and dataset export should
+    /// use instead of reaching into `state_after` directly.
+    pub fn view(&self) -> Option<WorldView> {
+        if let Some(compact) = &self.compact_view {
+            return compact.decode();
+        }
+        self.state_after.as_ref().and_then(|s| s.view.clone())
+    }
+}
+
+/// A [`WorldView`] with its material grid stored as palette-indexed,
+/// run-length-encoded bytes (hex-encoded for JSON) instead of arrays of
+/// enum names. Cuts per-step view size roughly 10x in recordings.
+///
+/// Each run is two bytes: a count (1-255) and a tile byte, which is the
+/// material's palette index with the high bit set for out-of-bounds
+/// tiles. Objects and mining progress are sparse and stay as-is.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct CompactView {
+    pub center: crate::entity::Position,
+    pub radius: u32,
+    /// Hex-encoded RLE runs covering the view's tiles in row-major order
+    pub tiles: String,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub objects: Vec<(i32, i32, crate::entity::GameObject)>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub mining_progress: Vec<u8>,
+}
+
+/// High bit of a tile byte marks an out-of-bounds tile
+const TILE_OUT_OF_BOUNDS: u8 = 0x80;
+
+impl CompactView {
+    /// Encode a view into compact form
+    pub fn encode(view: &WorldView) -> Self {
+        let mut runs: Vec<(u8, u8)> = Vec::new();
+        for (idx, &material) in view.materials.iter().enumerate() {
+            let in_bounds = view.in_bounds.get(idx).copied().unwrap_or(true);
+            let tile = u8::from(material) | if in_bounds { 0 } else { TILE_OUT_OF_BOUNDS };
+            match runs.last_mut() {
+                Some((count, last)) if *last == tile && *count < u8::MAX => *count += 1,
+                _ => runs.push((1, tile)),
+            }
+        }
+        let mut tiles = String::with_capacity(runs.len() * 4);
+        for (count, tile) in runs {
+            tiles.push_str(&format!("{:02x}{:02x}", count, tile));
+        }
+        Self {
+            center: view.center,
+            radius: view.radius,
+            tiles,
+            objects: view.objects.clone(),
+            mining_progress: view.mining_progress.clone(),
+        }
+    }
+
+    /// Decode back into a full view. Returns `None` if the payload is
+    /// corrupt (bad hex, unknown palette index, or wrong tile count).
+    pub fn decode(&self) -> Option<WorldView> {
+        let expected = {
+            let size = (self.radius * 2 + 1) as usize;
+            size * size
+        };
+        let mut materials = Vec::with_capacity(expected);
+        let mut in_bounds = Vec::with_capacity(expected);
+        let bytes = self.tiles.as_bytes();
+        if !bytes.len().is_multiple_of(4) {
+            return None;
+        }
+        for run in bytes.chunks_exact(4) {
+            let run = std::str::from_utf8(run).ok()?;
+            let count = u8::from_str_radix(&run[..2], 16).ok()?;
+            let tile = u8::from_str_radix(&run[2..], 16).ok()?;
+            let material = Material::from_index(tile & !TILE_OUT_OF_BOUNDS)?;
+            for _ in 0..count {
+                materials.push(material);
+                in_bounds.push(tile & TILE_OUT_OF_BOUNDS == 0);
+            }
+        }
+        if materials.len() != expected {
+            return None;
+        }
+        Some(WorldView {
+            center: self.center,
+            radius: self.radius,
+            materials,
+            in_bounds,
+            objects: self.objects.clone(),
+            mining_progress: self.mining_progress.clone(),
+        })
+    }
+}
+
+/// Analysis note or bookmark attached to a recording at a specific step
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct Annotation {
+    /// Step the note refers to
+    pub step: u64,
+    /// When the note was written (Unix epoch seconds)
+    pub timestamp: u64,
+    /// Free-form note text (empty for plain bookmarks)
+    pub text: String,
+    /// Whether the replay scrubber should treat this as a jump target
+    pub bookmark: bool,
 }
 
 /// A complete recorded episode/trajectory
@@ -47,13 +172,30 @@ pub struct Recording {
     pub total_reward: f32,
     /// Whether states are included
     pub includes_states: bool,
+    /// Crate version that produced this recording (empty for recordings
+    /// made before versioning was embedded)
+    #[serde(default)]
+    pub crate_version: String,
+    /// Hash of the ruleset in effect when recording (see `compat`); 0 for
+    /// pre-versioning recordings
+    #[serde(default)]
+    pub ruleset_hash: u64,
+    /// Stable hash of this recording's own resolved config (see
+    /// [`crate::config::SessionConfig::resolved`]); 0 for recordings made
+    /// before it was embedded
+    #[serde(default)]
+    pub config_hash: u64,
     /// All recorded steps
     pub steps: Vec<RecordedStep>,
+    /// Analysis notes and bookmarks, kept sorted by step
+    #[serde(default)]
+    pub annotations: Vec<Annotation>,
 }
 
 impl Recording {
     /// Create a new empty recording
     pub fn new(config: SessionConfig, episode: u32) -> Self {
+        let config_hash = config.resolved().hash;
         Self {
             version: 1,
             config,
@@ -61,7 +203,11 @@ impl Recording {
             total_steps: 0,
             total_reward: 0.0,
             includes_states: false,
+            crate_version: crate::compat::CRATE_VERSION.to_string(),
+            ruleset_hash: crate::compat::ruleset_hash(),
+            config_hash,
             steps: Vec::new(),
+            annotations: Vec::new(),
         }
     }
 
@@ -111,6 +257,204 @@ impl Recording {
         self.steps.iter().map(|s| s.action).collect()
     }
 
+    /// Whether this recording carries wall-clock timing (human play
+    /// recorded with `record_timing`)
+    pub fn has_timing(&self) -> bool {
+        self.steps.iter().any(|s| s.wall_ms.is_some())
+    }
+
+    /// Per-step reaction times in milliseconds, for recordings made
+    /// with `record_timing`; skips steps without timing
+    pub fn wall_times_ms(&self) -> Vec<u64> {
+        self.steps.iter().filter_map(|s| s.wall_ms).collect()
+    }
+
+    /// Median reaction time in milliseconds, ignoring gaps of
+    /// `idle_threshold_ms` or longer so walk-away pauses don't skew the
+    /// human-behavior statistic
+    pub fn median_reaction_ms(&self, idle_threshold_ms: u64) -> Option<u64> {
+        let mut times: Vec<u64> = self
+            .steps
+            .iter()
+            .filter_map(|s| s.wall_ms)
+            .filter(|&ms| ms < idle_threshold_ms)
+            .collect();
+        if times.is_empty() {
+            return None;
+        }
+        times.sort_unstable();
+        Some(times[times.len() / 2])
+    }
+
+    /// Attach a timestamped text annotation at `step`
+    pub fn add_annotation(&mut self, step: u64, text: String) {
+        let annotation = Annotation {
+            step,
+            timestamp: unix_now(),
+            text,
+            bookmark: false,
+        };
+        let idx = self.annotations.partition_point(|a| a.step <= step);
+        self.annotations.insert(idx, annotation);
+    }
+
+    /// Toggle a bookmark at `step`; returns whether the bookmark is now set
+    pub fn toggle_bookmark(&mut self, step: u64) -> bool {
+        if let Some(idx) = self
+            .annotations
+            .iter()
+            .position(|a| a.bookmark && a.step == step)
+        {
+            self.annotations.remove(idx);
+            false
+        } else {
+            let annotation = Annotation {
+                step,
+                timestamp: unix_now(),
+                text: String::new(),
+                bookmark: true,
+            };
+            let idx = self.annotations.partition_point(|a| a.step <= step);
+            self.annotations.insert(idx, annotation);
+            true
+        }
+    }
+
+    /// All annotations attached to `step`
+    pub fn annotations_at(&self, step: u64) -> Vec<&Annotation> {
+        self.annotations.iter().filter(|a| a.step == step).collect()
+    }
+
+    /// Bookmarked steps in ascending order
+    pub fn bookmarks(&self) -> Vec<u64> {
+        self.annotations
+            .iter()
+            .filter(|a| a.bookmark)
+            .map(|a| a.step)
+            .collect()
+    }
+
+    /// The first bookmarked step strictly after `step`
+    pub fn next_bookmark(&self, step: u64) -> Option<u64> {
+        self.annotations
+            .iter()
+            .filter(|a| a.bookmark && a.step > step)
+            .map(|a| a.step)
+            .min()
+    }
+
+    /// The last bookmarked step strictly before `step`
+    pub fn prev_bookmark(&self, step: u64) -> Option<u64> {
+        self.annotations
+            .iter()
+            .filter(|a| a.bookmark && a.step < step)
+            .map(|a| a.step)
+            .max()
+    }
+
+    /// Shrink this recording in place for archiving or sharing.
+    ///
+    /// Actions, rewards, config (including the seed), and totals are
+    /// always preserved, so the trajectory stays replayable; only the
+    /// bulky per-step state payloads are thinned out according to
+    /// `options`. State-before snapshots are always dropped since a
+    /// keyframe's state-after is the next step's state-before.
+    pub fn compact(&mut self, options: &CompactOptions) {
+        let last_step = self.steps.last().map(|s| s.step);
+        for step in &mut self.steps {
+            step.state_before = None;
+            let keep = match options.keyframe_interval {
+                Some(interval) if interval > 0 => {
+                    step.step % interval == 0 || Some(step.step) == last_step
+                }
+                _ => false,
+            };
+            if !keep {
+                step.state_after = None;
+                step.compact_view = None;
+            } else {
+                if options.strip_views {
+                    step.compact_view = None;
+                }
+                if let Some(state) = &mut step.state_after {
+                    if options.strip_worlds {
+                        state.world = None;
+                    }
+                    if options.strip_views {
+                        state.view = None;
+                    }
+                }
+            }
+        }
+        if options.strip_annotations {
+            self.annotations.clear();
+        }
+        self.includes_states = self.steps.iter().any(|s| s.state_after.is_some());
+    }
+
+    /// Reward, vitals, and achievement unlocks over the episode.
+    ///
+    /// Vitals and unlock markers are filled from recorded state-after
+    /// snapshots and are `None`/empty for steps without one (e.g. after
+    /// `compact`); rewards come from the step log and are always present.
+    pub fn reward_curve(&self) -> Vec<RewardCurvePoint> {
+        let mut curve = Vec::with_capacity(self.steps.len());
+        let mut cumulative_reward = 0.0f32;
+        let mut prev_achievements = crate::achievement::Achievements::default();
+        for step in &self.steps {
+            cumulative_reward += step.reward;
+            let mut point = RewardCurvePoint {
+                step: step.step,
+                reward: step.reward,
+                cumulative_reward,
+                health: None,
+                food: None,
+                drink: None,
+                energy: None,
+                unlocked: Vec::new(),
+            };
+            if let Some(state) = &step.state_after {
+                point.health = Some(state.inventory.health);
+                point.food = Some(state.inventory.food);
+                point.drink = Some(state.inventory.drink);
+                point.energy = Some(state.inventory.energy);
+                point.unlocked = state
+                    .achievements
+                    .newly_unlocked_since(&prev_achievements)
+                    .iter()
+                    .map(|s| s.to_string())
+                    .collect();
+                point.unlocked.sort();
+                prev_achievements = state.achievements.clone();
+            }
+            curve.push(point);
+        }
+        curve
+    }
+
+    /// Export the reward curve as CSV for plotting and experiment
+    /// trackers. Unlock markers are `;`-joined; vitals columns are empty
+    /// when no state was recorded for the step.
+    pub fn reward_curve_csv(&self) -> String {
+        let mut csv =
+            String::from("step,reward,cumulative_reward,health,food,drink,energy,unlocked\n");
+        for point in self.reward_curve() {
+            let vital = |v: Option<u8>| v.map(|v| v.to_string()).unwrap_or_default();
+            csv.push_str(&format!(
+                "{},{},{},{},{},{},{},{}\n",
+                point.step,
+                point.reward,
+                point.cumulative_reward,
+                vital(point.health),
+                vital(point.food),
+                vital(point.drink),
+                vital(point.energy),
+                point.unlocked.join(";")
+            ));
+        }
+        csv
+    }
+
     /// Export as simple CSV (step, action, reward, done)
     pub fn to_csv(&self) -> String {
         let mut csv = String::from("step,action,reward,done\n");
@@ -124,26 +468,147 @@ impl Recording {
     }
 }
 
+/// One point on a recording's reward curve (see [`Recording::reward_curve`])
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct RewardCurvePoint {
+    /// Step number
+    pub step: u64,
+    /// Reward received at this step
+    pub reward: f32,
+    /// Reward accumulated up to and including this step
+    pub cumulative_reward: f32,
+    /// Vitals after this step, when a state was recorded
+    pub health: Option<u8>,
+    pub food: Option<u8>,
+    pub drink: Option<u8>,
+    pub energy: Option<u8>,
+    /// Achievements first unlocked at this step (requires recorded states)
+    pub unlocked: Vec<String>,
+}
+
+/// Options for [`Recording::compact`]
+#[derive(Clone, Debug)]
+pub struct CompactOptions {
+    /// Keep a state-after keyframe every N steps (the final step is always
+    /// kept as a keyframe); `None` drops every recorded state
+    pub keyframe_interval: Option<u64>,
+    /// Drop full-world snapshots from kept keyframes
+    pub strip_worlds: bool,
+    /// Drop rendered view windows from kept keyframes
+    pub strip_views: bool,
+    /// Drop annotations and bookmarks (they may contain analyst notes)
+    pub strip_annotations: bool,
+}
+
+impl Default for CompactOptions {
+    fn default() -> Self {
+        Self {
+            keyframe_interval: Some(100),
+            strip_worlds: true,
+            strip_views: true,
+            strip_annotations: false,
+        }
+    }
+}
+
+impl CompactOptions {
+    /// Maximum compaction for anonymized sharing: actions and seed only
+    pub fn anonymized() -> Self {
+        Self {
+            keyframe_interval: None,
+            strip_worlds: true,
+            strip_views: true,
+            strip_annotations: true,
+        }
+    }
+}
+
+/// Quality gate applied when converting recordings into training
+/// datasets, so low-quality demos can be excluded programmatically.
+///
+/// Criteria are conjunctive; `None` fields do not filter. Criteria that
+/// need recorded states (achievements, the lava check) reject
+/// recordings made without them — an unverifiable demo is treated as a
+/// bad one, not waved through.
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct DatasetFilter {
+    /// Keep episodes that unlocked at least this many achievements
+    pub min_achievements: Option<u32>,
+    /// Keep episodes that survived at least this many steps
+    pub min_survival_steps: Option<u64>,
+    /// Drop episodes that ended standing on lava within this many steps
+    /// — the classic "walked straight into lava" demo
+    pub exclude_lava_death_within: Option<u64>,
+}
+
+impl DatasetFilter {
+    pub fn passes(&self, recording: &Recording) -> bool {
+        self.rejection(recording).is_none()
+    }
+
+    /// Why the recording fails the filter, or `None` when it passes
+    pub fn rejection(&self, recording: &Recording) -> Option<String> {
+        if let Some(min) = self.min_achievements {
+            let unlocked = recording
+                .steps
+                .iter()
+                .rev()
+                .find_map(|s| s.state_after.as_ref())
+                .map(|s| s.achievements.total_unlocked())
+                .unwrap_or(0);
+            if unlocked < min {
+                return Some(format!("unlocked {} achievements, need {}", unlocked, min));
+            }
+        }
+        if let Some(min) = self.min_survival_steps {
+            if recording.total_steps < min {
+                return Some(format!(
+                    "survived {} steps, need {}",
+                    recording.total_steps, min
+                ));
+            }
+        }
+        if let Some(window) = self.exclude_lava_death_within {
+            if let Some(step) = recording.steps.iter().find(|s| s.done) {
+                if step.step < window && Self::ended_on_lava(step) {
+                    return Some(format!("lava death at step {}", step.step));
+                }
+            }
+        }
+        None
+    }
+
+    /// Keep only the recordings that pass
+    pub fn apply(&self, recordings: Vec<Recording>) -> Vec<Recording> {
+        recordings.into_iter().filter(|r| self.passes(r)).collect()
+    }
+
+    /// Whether the episode's final view shows the player on lava
+    fn ended_on_lava(step: &RecordedStep) -> bool {
+        step.view()
+            .map(|v| v.get_material(v.radius as i32, v.radius as i32) == Some(Material::Lava))
+            .unwrap_or(false)
+    }
+}
+
 /// Options for what to record
 #[derive(Clone, Debug)]
+#[derive(Default)]
 pub struct RecordingOptions {
     /// Record state before each action
     pub record_state_before: bool,
     /// Record state after each action
     pub record_state_after: bool,
+    /// Store per-step views as palette-indexed RLE bytes instead of full
+    /// `WorldView` JSON (see [`CompactView`])
+    pub compact_views: bool,
     /// Maximum steps to record (None = unlimited)
     pub max_steps: Option<u64>,
+    /// Store wall-clock gaps between actions in each step's `wall_ms`;
+    /// meaningful for real-time (human) play, noise for agent rollouts
+    pub record_timing: bool,
 }
 
-impl Default for RecordingOptions {
-    fn default() -> Self {
-        Self {
-            record_state_before: false,
-            record_state_after: false,
-            max_steps: None,
-        }
-    }
-}
 
 impl RecordingOptions {
     /// Record only actions and rewards (minimal size)
@@ -156,7 +621,17 @@ impl RecordingOptions {
         Self {
             record_state_before: true,
             record_state_after: true,
+            compact_views: false,
             max_steps: None,
+            record_timing: false,
+        }
+    }
+
+    /// Minimal recording plus wall-clock timing, for real-time human play
+    pub fn human() -> Self {
+        Self {
+            record_timing: true,
+            ..Self::default()
         }
     }
 }
@@ -166,26 +641,27 @@ pub struct RecordingSession {
     session: Session,
     recording: Recording,
     options: RecordingOptions,
+    /// When the previous recorded action arrived, for `record_timing`
+    last_input_at: crate::platform::Instant,
 }
 
 impl RecordingSession {
     /// Create a new recording session
     pub fn new(config: SessionConfig, options: RecordingOptions) -> Self {
-        let recording = Recording::new(config.clone(), 1);
-        Self {
-            session: Session::new(config),
-            recording,
-            options,
-        }
+        Self::from_session(Session::new(config), options)
     }
 
     /// Create a recording session from an existing session state
     pub fn from_session(session: Session, options: RecordingOptions) -> Self {
-        let recording = Recording::new(session.config.clone(), session.episode);
+        let mut recording = Recording::new(session.config.clone(), session.episode);
+        // Pin the resolved seed so recordings of random-seed runs still
+        // replay on the same map
+        recording.config.seed = Some(session.episode_seed());
         Self {
             session,
             recording,
             options,
+            last_input_at: crate::platform::Instant::now(),
         }
     }
 
@@ -199,6 +675,15 @@ impl RecordingSession {
             }
         }
 
+        let wall_ms = if self.options.record_timing {
+            let now = crate::platform::Instant::now();
+            let elapsed = now.duration_since(self.last_input_at).as_millis() as u64;
+            self.last_input_at = now;
+            Some(elapsed)
+        } else {
+            None
+        };
+
         let state_before = if self.options.record_state_before {
             Some(self.session.get_state())
         } else {
@@ -207,19 +692,30 @@ impl RecordingSession {
 
         let result = self.session.step(action);
 
-        let state_after = if self.options.record_state_after {
+        let mut state_after = if self.options.record_state_after {
             Some(result.state.clone())
         } else {
             None
         };
 
+        // Swap the bulky view out for its compact encoding
+        let mut compact_view = None;
+        if self.options.compact_views {
+            if let Some(view) = state_after.as_mut().and_then(|s| s.view.take()) {
+                compact_view = Some(CompactView::encode(&view));
+            }
+        }
+
         self.recording.steps.push(RecordedStep {
             step: self.recording.total_steps,
             action,
             reward: result.reward,
             done: result.done,
+            truncated: result.truncated,
             state_before,
             state_after,
+            compact_view,
+            wall_ms,
         });
 
         self.recording.total_steps += 1;
@@ -243,6 +739,7 @@ impl RecordingSession {
             Recording::new(self.session.config.clone(), next_episode),
         );
         self.session.reset();
+        self.last_input_at = crate::platform::Instant::now();
         old_recording
     }
 
@@ -260,12 +757,21 @@ impl RecordingSession {
     pub fn session(&self) -> &Session {
         &self.session
     }
+
+    /// Get mutable reference to underlying session (for debug commands
+    /// that mutate state outside of recorded steps)
+    pub fn session_mut(&mut self) -> &mut Session {
+        &mut self.session
+    }
 }
 
 /// Replay a recording deterministically
 pub struct ReplaySession {
     session: Session,
     actions: Vec<Action>,
+    /// Recorded wall-clock gap before each action, when the recording
+    /// has timing metadata
+    wall_ms: Vec<Option<u64>>,
     current_step: usize,
 }
 
@@ -275,10 +781,18 @@ impl ReplaySession {
         Self {
             session: Session::new(recording.config.clone()),
             actions: recording.actions(),
+            wall_ms: recording.steps.iter().map(|s| s.wall_ms).collect(),
             current_step: 0,
         }
     }
 
+    /// How long the human waited before the upcoming step, in
+    /// milliseconds; `None` without timing metadata or at the end.
+    /// Demo players sleep this long per step for human-paced replay.
+    pub fn next_step_wall_ms(&self) -> Option<u64> {
+        self.wall_ms.get(self.current_step).copied().flatten()
+    }
+
     /// Step through the replay
     pub fn step(&mut self) -> Option<StepResult> {
         if self.current_step >= self.actions.len() {
@@ -331,6 +845,146 @@ impl ReplaySession {
     pub fn session(&self) -> &Session {
         &self.session
     }
+
+    /// Seek to `target` steps into the replay. Seeking backwards restarts
+    /// the session from the recording's config (replays are deterministic)
+    /// and fast-forwards, so it costs `target` steps of simulation.
+    pub fn seek(&mut self, target: usize) {
+        let target = target.min(self.actions.len());
+        if target < self.current_step {
+            self.session = Session::new(self.session.config.clone());
+            self.current_step = 0;
+        }
+        while self.current_step < target {
+            if self.step().is_none() {
+                break;
+            }
+        }
+    }
+}
+
+fn unix_now() -> u64 {
+    std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(0)
+}
+
+/// The submitted action disagreed with the logged one at this point
+/// of an offline replay
+#[derive(Clone, Debug)]
+pub struct ActionMismatch {
+    /// Index of the recording within the [`OfflineEnv`]'s set
+    pub recording: usize,
+    /// Step number within that recording
+    pub step: u64,
+    pub logged: Action,
+    pub submitted: Action,
+}
+
+impl std::fmt::Display for ActionMismatch {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "recording {} step {}: logged {:?}, submitted {:?}",
+            self.recording, self.step, self.logged, self.submitted
+        )
+    }
+}
+
+impl std::error::Error for ActionMismatch {}
+
+/// Serves logged transitions from a set of recordings through a
+/// `step`-like interface, without simulating anything.
+///
+/// Useful for offline-RL debugging: a training loop can run against an
+/// `OfflineEnv` exactly as it would against a live session, but every
+/// transition comes verbatim from the dataset. Submitted actions are
+/// ignored by [`step`](OfflineEnv::step); use
+/// [`step_checked`](OfflineEnv::step_checked) to additionally assert
+/// they match the logged ones (a pipeline sanity check for replayed
+/// demonstrations). When one recording is exhausted the env moves to
+/// the next, mirroring auto-reset in a live batched env.
+pub struct OfflineEnv {
+    recordings: Vec<Recording>,
+    current: usize,
+    cursor: usize,
+}
+
+impl OfflineEnv {
+    pub fn new(recordings: Vec<Recording>) -> Self {
+        Self {
+            recordings,
+            current: 0,
+            cursor: 0,
+        }
+    }
+
+    /// Serve the next logged transition, ignoring `_action`. Returns
+    /// `None` once every recording is exhausted.
+    pub fn step(&mut self, _action: Action) -> Option<RecordedStep> {
+        loop {
+            let recording = self.recordings.get(self.current)?;
+            if let Some(step) = recording.steps.get(self.cursor) {
+                self.cursor += 1;
+                if self.cursor >= recording.steps.len() {
+                    self.current += 1;
+                    self.cursor = 0;
+                }
+                return Some(step.clone());
+            }
+            // Empty recording: skip it
+            self.current += 1;
+            self.cursor = 0;
+        }
+    }
+
+    /// Like [`step`](OfflineEnv::step), but errors if `action` differs
+    /// from the logged one instead of serving the transition
+    pub fn step_checked(&mut self, action: Action) -> Result<Option<RecordedStep>, ActionMismatch> {
+        if let Some(recording) = self.recordings.get(self.current) {
+            if let Some(logged) = recording.steps.get(self.cursor) {
+                if logged.action != action {
+                    return Err(ActionMismatch {
+                        recording: self.current,
+                        step: logged.step,
+                        logged: logged.action,
+                        submitted: action,
+                    });
+                }
+            }
+        }
+        Ok(self.step(action))
+    }
+
+    /// Rewind to the first transition of the first recording
+    pub fn reset(&mut self) {
+        self.current = 0;
+        self.cursor = 0;
+    }
+
+    /// Index of the recording the next transition comes from
+    pub fn current_recording(&self) -> usize {
+        self.current
+    }
+
+    /// Transitions left across all recordings
+    pub fn remaining(&self) -> usize {
+        let ahead: usize = self.recordings[self.current.min(self.recordings.len())..]
+            .iter()
+            .map(|r| r.steps.len())
+            .sum();
+        ahead.saturating_sub(self.cursor)
+    }
+
+    /// Total transitions across all recordings
+    pub fn total_steps(&self) -> usize {
+        self.recordings.iter().map(|r| r.steps.len()).sum()
+    }
+
+    pub fn recordings(&self) -> &[Recording] {
+        &self.recordings
+    }
 }
 
 #[cfg(test)]
@@ -388,4 +1042,353 @@ mod tests {
         assert!(recording.steps[0].state_before.is_some());
         assert!(recording.steps[0].state_after.is_some());
     }
+
+    #[test]
+    fn test_annotations_and_bookmarks() {
+        let config = SessionConfig {
+            world_size: (16, 16),
+            seed: Some(7),
+            ..Default::default()
+        };
+
+        let mut rec_session = RecordingSession::new(config, RecordingOptions::minimal());
+        for _ in 0..10 {
+            rec_session.step(Action::MoveRight);
+        }
+        let mut recording = rec_session.finish();
+
+        recording.add_annotation(3, "got stuck here".to_string());
+        assert!(recording.toggle_bookmark(2));
+        assert!(recording.toggle_bookmark(8));
+
+        assert_eq!(recording.bookmarks(), vec![2, 8]);
+        assert_eq!(recording.next_bookmark(2), Some(8));
+        assert_eq!(recording.prev_bookmark(8), Some(2));
+        assert_eq!(recording.next_bookmark(8), None);
+        assert_eq!(recording.annotations_at(3)[0].text, "got stuck here");
+
+        // Toggling off removes the bookmark but keeps the note
+        assert!(!recording.toggle_bookmark(2));
+        assert_eq!(recording.bookmarks(), vec![8]);
+        assert_eq!(recording.annotations_at(3).len(), 1);
+
+        // Annotations survive a save/load round trip
+        let temp_path = std::env::temp_dir().join("crafter_test_annotations.json");
+        recording.save_json(&temp_path).unwrap();
+        let loaded = Recording::load_json(&temp_path).unwrap();
+        assert_eq!(loaded.bookmarks(), vec![8]);
+        assert_eq!(loaded.annotations_at(3)[0].text, "got stuck here");
+        std::fs::remove_file(temp_path).ok();
+    }
+
+    #[test]
+    fn test_replay_seek_is_deterministic() {
+        let config = SessionConfig {
+            world_size: (16, 16),
+            seed: Some(99),
+            ..Default::default()
+        };
+
+        let mut rec_session = RecordingSession::new(config, RecordingOptions::minimal());
+        for _ in 0..6 {
+            rec_session.step(Action::MoveRight);
+        }
+        for _ in 0..6 {
+            rec_session.step(Action::MoveDown);
+        }
+        let recording = rec_session.finish();
+
+        let mut replay = ReplaySession::from_recording(&recording);
+        replay.seek(9);
+        assert_eq!(replay.current_step(), 9);
+        let forward_pos = replay.get_state().player_pos;
+
+        // Seeking backwards restarts and fast-forwards to the same state
+        replay.seek(4);
+        assert_eq!(replay.current_step(), 4);
+        replay.seek(9);
+        assert_eq!(replay.get_state().player_pos, forward_pos);
+    }
+
+    #[test]
+    fn test_reward_curve_tracks_cumulative_reward_and_unlocks() {
+        let config = SessionConfig {
+            world_size: (16, 16),
+            seed: Some(11),
+            ..Default::default()
+        };
+
+        let mut rec_session = RecordingSession::new(config, RecordingOptions::full());
+        // Stage a guaranteed unlock: face a tree and chop it
+        {
+            let session = rec_session.session_mut();
+            let pos = session.get_state().player_pos;
+            let facing = session.get_state().player_facing;
+            let target = (pos.0 + facing.0 as i32, pos.1 + facing.1 as i32);
+            session.world.set_material(target, crate::material::Material::Tree);
+        }
+        rec_session.step(Action::Do);
+        rec_session.step(Action::Noop);
+        let recording = rec_session.finish();
+
+        let curve = recording.reward_curve();
+        assert_eq!(curve.len(), 2);
+        assert!(curve[0].unlocked.contains(&"collect_wood".to_string()));
+        assert!(curve[1].unlocked.is_empty());
+        assert!(curve[0].health.is_some());
+        assert!(
+            (curve.last().unwrap().cumulative_reward - recording.total_reward).abs() < 1e-5
+        );
+
+        let csv = recording.reward_curve_csv();
+        assert!(csv.starts_with("step,reward,cumulative_reward"));
+        assert!(csv.contains("collect_wood"));
+        assert_eq!(csv.lines().count(), 3);
+    }
+
+    #[test]
+    fn test_compact_views_round_trip_and_shrink_recordings() {
+        let config = SessionConfig {
+            world_size: (32, 32),
+            seed: Some(42),
+            ..Default::default()
+        };
+
+        let mut full = RecordingSession::new(config.clone(), RecordingOptions::full());
+        let mut compact = RecordingSession::new(
+            config,
+            RecordingOptions {
+                compact_views: true,
+                ..RecordingOptions::full()
+            },
+        );
+        for _ in 0..20 {
+            full.step(Action::MoveRight);
+            compact.step(Action::MoveRight);
+        }
+        let full = full.finish();
+        let compact = compact.finish();
+
+        // The decoder reproduces the view the full recording kept
+        for (a, b) in full.steps.iter().zip(&compact.steps) {
+            let original = a.state_after.as_ref().unwrap().view.as_ref().unwrap();
+            assert!(b.state_after.as_ref().unwrap().view.is_none());
+            let decoded = b.view().expect("compact view should decode");
+            assert_eq!(decoded.center, original.center);
+            assert_eq!(decoded.radius, original.radius);
+            assert_eq!(decoded.materials, original.materials);
+            assert_eq!(decoded.in_bounds, original.in_bounds);
+        }
+
+        // And the tile grid shrinks ~10x against the enum-name arrays
+        let original = full.steps[0].state_after.as_ref().unwrap().view.as_ref().unwrap();
+        let grid_json = serde_json::to_vec(&original.materials).unwrap().len()
+            + serde_json::to_vec(&original.in_bounds).unwrap().len();
+        let tiles = compact.steps[0].compact_view.as_ref().unwrap().tiles.len();
+        assert!(
+            tiles * 10 <= grid_json,
+            "RLE tiles {} should be ~10x below grid JSON {}",
+            tiles,
+            grid_json
+        );
+
+        // Corrupt payloads decode to None rather than panicking
+        let mut broken = compact.steps[0].compact_view.clone().unwrap();
+        broken.tiles.truncate(3);
+        assert!(broken.decode().is_none());
+        broken.tiles = "01ff".to_string();
+        assert!(broken.decode().is_none());
+    }
+
+    #[test]
+    fn test_compact_keeps_keyframes_and_actions() {
+        let config = SessionConfig {
+            world_size: (16, 16),
+            seed: Some(5),
+            ..Default::default()
+        };
+
+        let mut rec_session = RecordingSession::new(config, RecordingOptions::full());
+        for _ in 0..10 {
+            rec_session.step(Action::MoveRight);
+        }
+        let mut recording = rec_session.finish();
+        recording.add_annotation(3, "human note".to_string());
+        let actions = recording.actions();
+
+        recording.compact(&CompactOptions {
+            keyframe_interval: Some(4),
+            strip_worlds: true,
+            strip_views: true,
+            strip_annotations: false,
+        });
+
+        // Actions, seed, and annotations survive
+        assert_eq!(recording.actions(), actions);
+        assert_eq!(recording.config.seed, Some(5));
+        assert_eq!(recording.annotations.len(), 1);
+
+        // Only keyframes (and the final step) keep a state, without views
+        for step in &recording.steps {
+            assert!(step.state_before.is_none());
+            let is_keyframe = step.step % 4 == 0 || step.step == 9;
+            assert_eq!(step.state_after.is_some(), is_keyframe);
+            if let Some(state) = &step.state_after {
+                assert!(state.view.is_none());
+                assert!(state.world.is_none());
+            }
+        }
+        assert!(recording.includes_states);
+    }
+
+    #[test]
+    fn test_compact_anonymized_strips_everything_but_trajectory() {
+        let config = SessionConfig {
+            world_size: (16, 16),
+            seed: Some(5),
+            ..Default::default()
+        };
+
+        let mut rec_session = RecordingSession::new(config, RecordingOptions::full());
+        for _ in 0..5 {
+            rec_session.step(Action::MoveDown);
+        }
+        let mut recording = rec_session.finish();
+        recording.add_annotation(1, "who wrote this".to_string());
+
+        recording.compact(&CompactOptions::anonymized());
+
+        assert!(recording.annotations.is_empty());
+        assert!(!recording.includes_states);
+        assert!(recording
+            .steps
+            .iter()
+            .all(|s| s.state_before.is_none() && s.state_after.is_none()));
+        assert_eq!(recording.actions().len(), 5);
+    }
+
+    #[test]
+    fn test_dataset_filter_screens_low_quality_demos() {
+        let config = SessionConfig {
+            world_size: (24, 24),
+            seed: Some(42),
+            ..Default::default()
+        };
+        let mut rec_session = RecordingSession::new(config, RecordingOptions::full());
+        for _ in 0..20 {
+            rec_session.step(Action::Noop);
+        }
+        let mut recording = rec_session.finish();
+
+        let lenient = DatasetFilter {
+            min_survival_steps: Some(10),
+            ..Default::default()
+        };
+        assert!(lenient.passes(&recording));
+
+        let strict = DatasetFilter {
+            min_survival_steps: Some(100),
+            ..Default::default()
+        };
+        assert_eq!(strict.apply(vec![recording.clone()]).len(), 0);
+
+        // An idle run unlocks nothing
+        let skilled = DatasetFilter {
+            min_achievements: Some(1),
+            ..Default::default()
+        };
+        assert!(skilled.rejection(&recording).is_some());
+
+        // Fake an early lava death on the final step
+        let last = recording.steps.last_mut().unwrap();
+        last.done = true;
+        let view = last.state_after.as_mut().and_then(|s| s.view.as_mut()).unwrap();
+        let center = (view.radius * (2 * view.radius + 1) + view.radius) as usize;
+        view.materials[center] = Material::Lava;
+
+        let no_lava = DatasetFilter {
+            exclude_lava_death_within: Some(100),
+            ..Default::default()
+        };
+        assert!(!no_lava.passes(&recording));
+        // Dying on lava late in a long run is not an early-death reject
+        let early_only = DatasetFilter {
+            exclude_lava_death_within: Some(10),
+            ..Default::default()
+        };
+        assert!(early_only.passes(&recording));
+    }
+
+    #[test]
+    fn test_human_recording_stores_reaction_times() {
+        let config = SessionConfig {
+            world_size: (24, 24),
+            seed: Some(42),
+            ..Default::default()
+        };
+        let mut recorder = RecordingSession::new(config.clone(), RecordingOptions::human());
+        recorder.step(Action::MoveRight);
+        std::thread::sleep(std::time::Duration::from_millis(10));
+        recorder.step(Action::MoveLeft);
+        let recording = recorder.finish();
+
+        assert!(recording.has_timing());
+        let times = recording.wall_times_ms();
+        assert_eq!(times.len(), 2);
+        // The deliberate pause shows up as the second step's gap
+        assert!(times[1] >= 10);
+        assert!(recording.median_reaction_ms(60_000).is_some());
+
+        // Replays expose the gap before each upcoming step for
+        // human-paced playback
+        let mut replay = ReplaySession::from_recording(&recording);
+        assert_eq!(replay.next_step_wall_ms(), Some(times[0]));
+        replay.step();
+        assert_eq!(replay.next_step_wall_ms(), Some(times[1]));
+
+        // Agent-style recordings carry no timing
+        let mut recorder = RecordingSession::new(config, RecordingOptions::minimal());
+        recorder.step(Action::Noop);
+        assert!(!recorder.finish().has_timing());
+    }
+
+    #[test]
+    fn test_offline_env_serves_logged_transitions() {
+        let config = SessionConfig {
+            world_size: (32, 32),
+            seed: Some(42),
+            ..Default::default()
+        };
+        let mut first = RecordingSession::new(config.clone(), RecordingOptions::default());
+        for _ in 0..3 {
+            first.step(Action::MoveRight);
+        }
+        let mut second = RecordingSession::new(config, RecordingOptions::default());
+        for _ in 0..2 {
+            second.step(Action::Noop);
+        }
+
+        let mut env = OfflineEnv::new(vec![first.finish(), second.finish()]);
+        assert_eq!(env.total_steps(), 5);
+
+        // Submitted actions are ignored; the logged transitions come
+        // back verbatim and the env rolls into the next recording
+        let served: Vec<RecordedStep> =
+            std::iter::from_fn(|| env.step(Action::Do)).collect();
+        assert_eq!(served.len(), 5);
+        assert_eq!(served[0].action, Action::MoveRight);
+        assert_eq!(served[3].action, Action::Noop);
+        assert!(env.step(Action::Do).is_none());
+        assert_eq!(env.remaining(), 0);
+
+        // Checked stepping flags the first divergence from the log
+        env.reset();
+        assert!(env.step_checked(Action::MoveRight).is_ok());
+        let err = env.step_checked(Action::Do).unwrap_err();
+        assert_eq!(err.recording, 0);
+        assert_eq!(err.logged, Action::MoveRight);
+        assert_eq!(err.submitted, Action::Do);
+        // A mismatch does not consume the transition
+        assert_eq!(env.remaining(), 4);
+    }
 }
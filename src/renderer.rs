@@ -392,16 +392,12 @@ impl Renderer for CompactJsonRenderer {
 }
 
 /// Semantic map renderer - produces a grid of material/object indices
+#[derive(Default)]
 pub struct SemanticRenderer {
     /// Size of the output grid (default: same as view)
     pub output_size: Option<(usize, usize)>,
 }
 
-impl Default for SemanticRenderer {
-    fn default() -> Self {
-        Self { output_size: None }
-    }
-}
 
 impl SemanticRenderer {
     pub fn new() -> Self {
@@ -440,6 +436,9 @@ impl SemanticRenderer {
                     crate::entity::CraftaxMobKind::Bat => 32,
                     crate::entity::CraftaxMobKind::Snail => 33,
                 },
+                GameObject::Sheep(_) => 34,
+                GameObject::Pig(_) => 35,
+                GameObject::EscortKnight(_) => 36,
             };
             object_types.insert((*x, *y), type_id);
         }
@@ -43,10 +43,30 @@ pub struct RewardConfig {
     /// Step penalty (encourages faster completion)
     pub step_penalty: f32,
 
+    /// Enable potential-based shaping: `gamma * phi(s') - phi(s)` where
+    /// `phi` is the negative weighted Manhattan distance to the nearest
+    /// visible tile of each not-yet-collected resource. Because the
+    /// shaping term telescopes over a trajectory, it provably leaves the
+    /// optimal policy unchanged (Ng et al., 1999).
+    #[serde(default)]
+    pub potential_shaping_enabled: bool,
+    /// Discount factor used in the potential difference — match your
+    /// training gamma for exact policy-invariance
+    #[serde(default = "default_potential_gamma")]
+    pub potential_gamma: f32,
+    /// Per-resource weights for the potential, keyed by resource name
+    /// (`wood`, `stone`, `coal`, `iron`, `diamond`); missing = 0
+    #[serde(default)]
+    pub potential_weights: HashMap<String, f32>,
+
     /// Custom reward multiplier
     pub global_scale: f32,
 }
 
+fn default_potential_gamma() -> f32 {
+    0.99
+}
+
 impl Default for RewardConfig {
     fn default() -> Self {
         let mut achievement_rewards = HashMap::new();
@@ -68,6 +88,9 @@ impl Default for RewardConfig {
             exploration_reward: 0.01,
             death_penalty: -1.0,
             step_penalty: 0.0,
+            potential_shaping_enabled: false,
+            potential_gamma: default_potential_gamma(),
+            potential_weights: HashMap::new(),
             global_scale: 1.0,
         }
     }
@@ -81,20 +104,23 @@ impl RewardConfig {
 
     /// Create a dense reward config (many reward signals)
     pub fn dense() -> Self {
-        let mut config = Self::default();
-        config.health_reward_enabled = true;
-        config.survival_reward_enabled = true;
-        config.inventory_reward_enabled = true;
-        config.exploration_reward_enabled = true;
-        config.step_penalty = -0.001;
-        config
+        Self {
+            health_reward_enabled: true,
+            survival_reward_enabled: true,
+            inventory_reward_enabled: true,
+            exploration_reward_enabled: true,
+            step_penalty: -0.001,
+            ..Self::default()
+        }
     }
 
     /// Create a config focused on exploration
     pub fn exploration_focused() -> Self {
-        let mut config = Self::default();
-        config.exploration_reward_enabled = true;
-        config.exploration_reward = 0.1;
+        let mut config = Self {
+            exploration_reward_enabled: true,
+            exploration_reward: 0.1,
+            ..Self::default()
+        };
 
         // Reduce achievement rewards
         for reward in config.achievement_rewards.values_mut() {
@@ -106,13 +132,14 @@ impl RewardConfig {
 
     /// Create a config focused on survival
     pub fn survival_focused() -> Self {
-        let mut config = Self::default();
-        config.health_reward_enabled = true;
-        config.health_reward_scale = 0.5;
-        config.survival_reward_enabled = true;
-        config.survival_reward_scale = 0.1;
-        config.death_penalty = -10.0;
-        config
+        Self {
+            health_reward_enabled: true,
+            health_reward_scale: 0.5,
+            survival_reward_enabled: true,
+            survival_reward_scale: 0.1,
+            death_penalty: -10.0,
+            ..Self::default()
+        }
     }
 
     /// Create a config focused on crafting progression
@@ -140,6 +167,20 @@ impl RewardConfig {
         config
     }
 
+    /// Create a config with potential-based shaping toward the resource
+    /// progression (wood, stone, coal, iron, diamond), equal weights
+    pub fn potential_shaped() -> Self {
+        let mut potential_weights = HashMap::new();
+        for name in ["wood", "stone", "coal", "iron", "diamond"] {
+            potential_weights.insert(name.to_string(), 1.0);
+        }
+        Self {
+            potential_shaping_enabled: true,
+            potential_weights,
+            ..Self::default()
+        }
+    }
+
     /// Set reward for a specific achievement
     pub fn set_achievement_reward(&mut self, name: &str, reward: f32) -> &mut Self {
         self.achievement_rewards.insert(name.to_string(), reward);
@@ -153,12 +194,31 @@ impl RewardConfig {
     }
 }
 
+/// User-supplied dense reward term
+///
+/// Shapers see the full pre- and post-step [`GameState`], so custom
+/// signals (distance-to-water, resource deltas, potential functions)
+/// can be injected without forking the crate. Register them with
+/// [`RewardCalculator::add_shaper`]; their outputs are summed into
+/// [`RewardComponents::shaped`] and scaled by `global_scale` like every
+/// other component.
+pub trait RewardShaper: Send {
+    /// Compute the shaping reward for one transition
+    fn shape(&mut self, before: &GameState, after: &GameState) -> f32;
+
+    /// Clear any per-episode state (called from [`RewardCalculator::reset`])
+    fn reset(&mut self) {}
+}
+
 /// Reward calculator that computes rewards based on state transitions
 pub struct RewardCalculator {
     config: RewardConfig,
     prev_achievements: Achievements,
     prev_inventory: Option<Inventory>,
     visited_positions: std::collections::HashSet<(i32, i32)>,
+    shapers: Vec<Box<dyn RewardShaper>>,
+    prev_state: Option<GameState>,
+    prev_potential: Option<f32>,
 }
 
 impl RewardCalculator {
@@ -169,18 +229,38 @@ impl RewardCalculator {
             prev_achievements: Achievements::default(),
             prev_inventory: None,
             visited_positions: std::collections::HashSet::new(),
+            shapers: Vec::new(),
+            prev_state: None,
+            prev_potential: None,
         }
     }
 
+    /// Register a custom reward shaper
+    pub fn add_shaper(&mut self, shaper: Box<dyn RewardShaper>) -> &mut Self {
+        self.shapers.push(shaper);
+        self
+    }
+
     /// Reset the calculator for a new episode
     pub fn reset(&mut self) {
         self.prev_achievements = Achievements::default();
         self.prev_inventory = None;
         self.visited_positions.clear();
+        self.prev_state = None;
+        self.prev_potential = None;
+        for shaper in &mut self.shapers {
+            shaper.reset();
+        }
     }
 
-    /// Calculate reward for a state transition
-    pub fn calculate(&mut self, state: &GameState, done: bool) -> RewardResult {
+    /// Calculate reward for a state transition.
+    ///
+    /// `terminated` means the episode ended in the environment (death).
+    /// A truncation — step limit or manual reset — must pass `false`
+    /// here, so terminal-only terms like the death penalty never leak
+    /// into episodes that value-based learners should bootstrap from
+    /// (see `StepResult::truncated`).
+    pub fn calculate(&mut self, state: &GameState, terminated: bool) -> RewardResult {
         let mut total_reward = 0.0;
         let mut components = RewardComponents::default();
 
@@ -223,13 +303,12 @@ impl RewardCalculator {
         }
 
         // Exploration rewards
-        if self.config.exploration_reward_enabled {
-            if !self.visited_positions.contains(&state.player_pos) {
+        if self.config.exploration_reward_enabled
+            && !self.visited_positions.contains(&state.player_pos) {
                 self.visited_positions.insert(state.player_pos);
                 components.exploration = self.config.exploration_reward;
                 total_reward += self.config.exploration_reward;
             }
-        }
 
         // Step penalty
         if self.config.step_penalty != 0.0 {
@@ -238,11 +317,35 @@ impl RewardCalculator {
         }
 
         // Death penalty
-        if done && !state.inventory.is_alive() {
+        if terminated && !state.inventory.is_alive() {
             components.death_penalty = self.config.death_penalty;
             total_reward += self.config.death_penalty;
         }
 
+        // Potential-based shaping
+        if self.config.potential_shaping_enabled {
+            let phi = self.potential(state);
+            if let Some(prev_phi) = self.prev_potential {
+                let shaping = self.config.potential_gamma * phi - prev_phi;
+                components.potential = shaping;
+                total_reward += shaping;
+            }
+            self.prev_potential = Some(phi);
+        }
+
+        // Custom shapers (need a previous state to form a transition)
+        if !self.shapers.is_empty() {
+            if let Some(prev_state) = self.prev_state.take() {
+                let mut shaped = 0.0;
+                for shaper in &mut self.shapers {
+                    shaped += shaper.shape(&prev_state, state);
+                }
+                components.shaped = shaped;
+                total_reward += shaped;
+            }
+            self.prev_state = Some(state.clone());
+        }
+
         // Apply global scale
         total_reward *= self.config.global_scale;
 
@@ -281,6 +384,52 @@ impl RewardCalculator {
         reward
     }
 
+    /// Potential `phi(s)`: for each weighted resource the player hasn't
+    /// collected yet, the negative Manhattan distance to its nearest
+    /// visible tile. Resources out of view count as just beyond the
+    /// farthest view corner, keeping the potential bounded.
+    fn potential(&self, state: &GameState) -> f32 {
+        use crate::material::Material;
+
+        let Some(view) = &state.view else {
+            return 0.0;
+        };
+        let size = view.size() as i32;
+        let radius = view.radius as i32;
+        let out_of_view = 2 * radius + 1;
+
+        let resources = [
+            (Material::Tree, "collect_wood", "wood"),
+            (Material::Stone, "collect_stone", "stone"),
+            (Material::Coal, "collect_coal", "coal"),
+            (Material::Iron, "collect_iron", "iron"),
+            (Material::Diamond, "collect_diamond", "diamond"),
+        ];
+
+        let mut phi = 0.0;
+        for (material, achievement, key) in resources {
+            let weight = self
+                .config
+                .potential_weights
+                .get(key)
+                .copied()
+                .unwrap_or(0.0);
+            if weight == 0.0 || state.achievements.get(achievement).unwrap_or(0) > 0 {
+                continue;
+            }
+            let mut nearest = out_of_view;
+            for y in 0..size {
+                for x in 0..size {
+                    if view.get_material(x, y) == Some(material) {
+                        nearest = nearest.min((x - radius).abs() + (y - radius).abs());
+                    }
+                }
+            }
+            phi -= weight * nearest as f32;
+        }
+        phi
+    }
+
     fn calculate_resource_delta(&self, current: &Inventory, prev: &Inventory) -> f32 {
         let mut delta = 0.0;
 
@@ -313,6 +462,10 @@ pub struct RewardComponents {
     pub exploration: f32,
     pub step_penalty: f32,
     pub death_penalty: f32,
+    /// Sum of all registered [`RewardShaper`] outputs
+    pub shaped: f32,
+    /// Potential-based shaping term `gamma * phi(s') - phi(s)`
+    pub potential: f32,
 }
 
 #[cfg(test)]
@@ -335,6 +488,8 @@ mod tests {
             daylight: 1.0,
             view: None,
             world: None,
+            recipes: crate::recipes::RecipeBook::default(),
+            seed: 0,
         };
 
         // No achievements = no reward
@@ -364,6 +519,8 @@ mod tests {
             daylight: 1.0,
             view: None,
             world: None,
+            recipes: crate::recipes::RecipeBook::default(),
+            seed: 0,
         };
 
         // First position = exploration reward
@@ -373,4 +530,111 @@ mod tests {
         // Step penalty
         assert!(result.components.step_penalty < 0.0);
     }
+
+    #[test]
+    fn test_potential_shaping_rewards_approaching_resources() {
+        use crate::material::Material;
+        use crate::world::WorldView;
+
+        let view_with_tree_at = |x: i32, y: i32| {
+            let mut materials = vec![Material::Grass; 9];
+            materials[(y * 3 + x) as usize] = Material::Tree;
+            WorldView {
+                center: (1, 1),
+                radius: 1,
+                materials,
+                in_bounds: vec![true; 9],
+                objects: Vec::new(),
+                mining_progress: Vec::new(),
+            }
+        };
+
+        let state_with_view = |view: WorldView| GameState {
+            step: 0,
+            episode: 1,
+            inventory: Inventory::default(),
+            achievements: Achievements::default(),
+            player_pos: (1, 1),
+            player_facing: (1, 0),
+            player_sleeping: false,
+            daylight: 1.0,
+            view: Some(view),
+            world: None,
+            recipes: crate::recipes::RecipeBook::default(),
+            seed: 0,
+        };
+
+        let mut calc = RewardCalculator::new(RewardConfig::potential_shaped());
+
+        // Tree at the view corner: Manhattan distance 2 from center
+        let far = state_with_view(view_with_tree_at(0, 0));
+        let result = calc.calculate(&far, false);
+        // First step has no previous potential, so no shaping yet
+        assert_eq!(result.components.potential, 0.0);
+
+        // Tree adjacent: distance 1 — moving closer is rewarded
+        let near = state_with_view(view_with_tree_at(1, 0));
+        let result = calc.calculate(&near, false);
+        assert!(result.components.potential > 0.0);
+
+        // Moving away again is penalized by (roughly) the same amount:
+        // the terms telescope, which is what keeps the policy unchanged
+        let result = calc.calculate(&far, false);
+        assert!(result.components.potential < 0.0);
+
+        // Once the resource is collected its term drops out
+        let mut collected = far.clone();
+        collected.achievements.collect_wood = 1;
+        calc.reset();
+        calc.calculate(&collected, false);
+        let result = calc.calculate(&collected, false);
+        // Identical states: shaping is (gamma - 1) * phi, and phi no
+        // longer includes the wood distance
+        let phi_without_wood = -4.0 * 3.0;
+        let expected = (0.99 - 1.0) * phi_without_wood;
+        assert!((result.components.potential - expected).abs() < 1e-4);
+    }
+
+    #[test]
+    fn test_custom_shaper_sees_pre_and_post_state() {
+        struct WoodDelta;
+
+        impl RewardShaper for WoodDelta {
+            fn shape(&mut self, before: &GameState, after: &GameState) -> f32 {
+                (after.inventory.wood as f32 - before.inventory.wood as f32) * 0.5
+            }
+        }
+
+        let mut calc = RewardCalculator::new(RewardConfig::sparse());
+        calc.add_shaper(Box::new(WoodDelta));
+
+        let mut state = GameState {
+            step: 0,
+            episode: 1,
+            inventory: Inventory::default(),
+            achievements: Achievements::default(),
+            player_pos: (0, 0),
+            player_facing: (1, 0),
+            player_sleeping: false,
+            daylight: 1.0,
+            view: None,
+            world: None,
+            recipes: crate::recipes::RecipeBook::default(),
+            seed: 0,
+        };
+
+        // First step has no previous state, so shapers contribute nothing
+        let result = calc.calculate(&state, false);
+        assert_eq!(result.components.shaped, 0.0);
+
+        state.inventory.wood = 3;
+        let result = calc.calculate(&state, false);
+        assert_eq!(result.components.shaped, 1.5);
+        assert_eq!(result.total, 1.5);
+
+        // Reset clears the stored transition state
+        calc.reset();
+        let result = calc.calculate(&state, false);
+        assert_eq!(result.components.shaped, 0.0);
+    }
 }
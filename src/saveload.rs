@@ -7,7 +7,6 @@ use crate::entity::GameObject;
 use crate::material::Material;
 use crate::session::{Session, SessionTiming};
 use crate::world::World;
-use rand_chacha::ChaCha8Rng;
 use serde::{Deserialize, Serialize};
 use std::fs::File;
 use std::io::{BufReader, BufWriter, Read, Write};
@@ -30,6 +29,12 @@ pub struct SaveData {
     pub step: u64,
     /// RNG state (for deterministic resumption)
     pub rng_state: [u8; 32],
+    /// Nights survived this episode (for night difficulty scaling)
+    #[serde(default)]
+    pub nights_survived: u32,
+    /// Tool recipe costs in effect (non-classic only under recipe mutation)
+    #[serde(default)]
+    pub recipes: crate::recipes::RecipeBook,
     /// World state
     pub world: WorldSaveData,
 }
@@ -49,6 +54,9 @@ pub struct WorldSaveData {
     pub daylight: f32,
     /// RNG seed for the world
     pub rng_seed: u64,
+    /// Named terrain regions (absent in old saves)
+    #[serde(default)]
+    pub regions: Vec<crate::world::NamedRegion>,
 }
 
 impl SaveData {
@@ -72,6 +80,8 @@ impl SaveData {
             episode: session.episode,
             step: session.timing.step,
             rng_state,
+            nights_survived: session.nights_survived,
+            recipes: session.recipes,
             world: WorldSaveData {
                 area: world.area,
                 materials: world.materials.clone(),
@@ -79,6 +89,7 @@ impl SaveData {
                 player_id: world.player_id,
                 daylight: world.daylight,
                 rng_seed: world.rng_seed,
+                regions: world.regions.clone(),
             },
         }
     }
@@ -165,6 +176,91 @@ impl SaveData {
     }
 }
 
+/// Result of re-running world generation against a save; see
+/// [`SaveData::verify_worldgen`]
+#[derive(Clone, Copy, Debug, Default)]
+pub struct WorldgenCheck {
+    /// Every tile matches pristine generation (an untouched save)
+    pub pristine: bool,
+    /// Tiles that differ from pristine generation but hold a material
+    /// normal play writes (paths, placed stations and defenses, toggled
+    /// doors, cleared trees)
+    pub changed_tiles: u32,
+    /// Tiles that differ and hold a material play cannot produce —
+    /// corruption, or worldgen drift across crate versions
+    pub suspect_tiles: u32,
+}
+
+impl WorldgenCheck {
+    /// Whether the saved terrain is consistent with this build's world
+    /// generation plus ordinary play
+    pub fn is_plausible(&self) -> bool {
+        self.suspect_tiles == 0
+    }
+}
+
+/// Materials ordinary play can write into a tile: mining leaves paths
+/// and clears trees to grass, stone and stations are placed, doors
+/// toggle between their two states
+fn play_writable(mat: Material) -> bool {
+    matches!(
+        mat,
+        Material::Grass
+            | Material::Path
+            | Material::Stone
+            | Material::Table
+            | Material::Furnace
+            | Material::Fence
+            | Material::DoorClosed
+            | Material::DoorOpen
+            | Material::SpikeTrap
+    )
+}
+
+impl SaveData {
+    /// Re-run world generation from the stored seed and compare the
+    /// saved terrain against it, flagging corrupted saves and
+    /// cross-version worldgen drift before the player invests more
+    /// time in them.
+    ///
+    /// Tiles touched by normal play are counted but expected; a tile
+    /// differing into a material play cannot produce marks the save as
+    /// implausible (see [`WorldgenCheck::is_plausible`]). Costs one
+    /// full world generation, so it is opt-in rather than part of
+    /// every load.
+    pub fn verify_worldgen(&self) -> WorldgenCheck {
+        let mut config = self.config.clone();
+        config.seed = Some(self.world.rng_seed);
+        config.world_size = self.world.area;
+        let mut generator = crate::worldgen::WorldGenerator::new(config);
+        let regenerated = generator.generate();
+
+        if regenerated.materials.len() != self.world.materials.len() {
+            return WorldgenCheck {
+                pristine: false,
+                changed_tiles: 0,
+                suspect_tiles: self.world.materials.len() as u32,
+            };
+        }
+
+        let mut check = WorldgenCheck {
+            pristine: true,
+            ..Default::default()
+        };
+        for (&saved, &generated) in self.world.materials.iter().zip(&regenerated.materials) {
+            if saved != generated {
+                check.pristine = false;
+                if play_writable(saved) {
+                    check.changed_tiles += 1;
+                } else {
+                    check.suspect_tiles += 1;
+                }
+            }
+        }
+        check
+    }
+}
+
 /// Extension trait for Session to add save/load functionality
 pub trait SessionSaveLoad {
     /// Save current session state to a file
@@ -204,14 +300,13 @@ impl SessionSaveLoad for Session {
 
 /// Reconstruct a session from save data
 fn session_from_save_data(save: SaveData) -> Session {
-    use rand::SeedableRng;
-
     // Create a new world with the same dimensions and seed
     let mut world = World::new(save.world.area.0, save.world.area.1, save.world.rng_seed);
 
     // Restore materials
     world.materials = save.world.materials;
     world.daylight = save.world.daylight;
+    world.regions = save.world.regions;
 
     // Clear default objects and restore saved ones
     world.objects.clear();
@@ -240,7 +335,20 @@ fn session_from_save_data(save: SaveData) -> Session {
 
     // Create RNG - use seed + step for reproducibility
     let seed = save.config.seed.unwrap_or(0);
-    let rng = ChaCha8Rng::seed_from_u64(seed.wrapping_add(save.step));
+    let rng =
+        crate::rng::SessionRng::from_seed_kind(save.config.rng_kind, seed.wrapping_add(save.step));
+    let rng_streams =
+        crate::rng::RngStreams::from_seed_kind(save.config.rng_kind, seed.wrapping_add(save.step));
+
+    let was_night = world.daylight < 0.5;
+
+    // Cached worlds are derived data; a restored session rebuilds its
+    // pool seeds and starts with a cold cache
+    let pool_seeds = if save.config.world_pool_size > 0 {
+        crate::worldgen::pool_seeds(seed, save.config.world_pool_size)
+    } else {
+        Vec::new()
+    };
 
     Session {
         config: save.config,
@@ -248,8 +356,28 @@ fn session_from_save_data(save: SaveData) -> Session {
         timing,
         episode: save.episode,
         rng,
+        rng_streams,
         last_player_action: None,
+        prev_action: None,
         prev_achievements,
+        nights_survived: save.nights_survived,
+        was_night,
+        horde: None,
+        last_horde_step: None,
+        escort: None,
+        escort_resolved: false,
+        pending_events: Vec::new(),
+        pending_sounds: Vec::new(),
+        recipes: save.recipes,
+        world_snapshot: std::cell::RefCell::new(None),
+        world_history: None,
+        curriculum: None,
+        reward_config: None,
+        episode_seed: save.world.rng_seed,
+        world_cache: crate::worldgen::WorldCache::new(),
+        pool_seeds,
+        best_nights_survived: save.nights_survived,
+        low_health_warned: false,
     }
 }
 
@@ -293,6 +421,43 @@ mod tests {
         std::fs::remove_file(temp_path).ok();
     }
 
+    #[test]
+    fn test_verify_worldgen_classifies_changes() {
+        let mut session = Session::new(SessionConfig {
+            world_size: (32, 32),
+            seed: Some(42),
+            ..Default::default()
+        });
+
+        // An untouched session regenerates exactly
+        let save = SaveData::from_session(&session, None);
+        let check = save.verify_worldgen();
+        assert!(check.pristine);
+        assert!(check.is_plausible());
+        assert_eq!((check.changed_tiles, check.suspect_tiles), (0, 0));
+
+        // Play-writable edits are expected, not suspect
+        let edit = match session.world.get_material((3, 3)) {
+            Some(Material::Path) => Material::Grass,
+            _ => Material::Path,
+        };
+        session.world.set_material((3, 3), edit);
+        let check = SaveData::from_session(&session, None).verify_worldgen();
+        assert!(!check.pristine);
+        assert!(check.is_plausible());
+        assert_eq!(check.changed_tiles, 1);
+
+        // A material play cannot produce flags the save
+        let mut corrupted = SaveData::from_session(&session, None);
+        corrupted.world.materials[0] = match corrupted.world.materials[0] {
+            Material::Lava => Material::Diamond,
+            _ => Material::Lava,
+        };
+        let check = corrupted.verify_worldgen();
+        assert!(!check.is_plausible());
+        assert_eq!(check.suspect_tiles, 1);
+    }
+
     #[test]
     fn test_binary_save_load() {
         let config = SessionConfig {
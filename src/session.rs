@@ -6,17 +6,20 @@ use crate::config::SessionConfig;
 use crate::entity::{Arrow, DamageSource, GameObject, Mob, Plant, Position};
 use crate::inventory::Inventory;
 use crate::material::Material;
+use crate::recipes::RecipeBook;
 use crate::world::{World, WorldView};
-use crate::worldgen::WorldGenerator;
+use crate::worldgen::{WorldCache, WorldGenerator};
 use rand::prelude::*;
-use rand_chacha::ChaCha8Rng;
+use crate::platform::Instant;
 use serde::{Deserialize, Serialize};
-use std::time::{Duration, Instant};
+use std::time::Duration;
 
 /// How the session handles time progression
 #[derive(Clone, Debug, Serialize, Deserialize)]
+#[derive(Default)]
 pub enum TimeMode {
     /// Game advances only on explicit step() calls
+    #[default]
     Logical,
 
     /// Game advances at a fixed real-time rate
@@ -32,12 +35,27 @@ pub enum TimeMode {
     },
 }
 
-impl Default for TimeMode {
-    fn default() -> Self {
-        TimeMode::Logical
-    }
+/// What a real-time session does when processing falls behind and more
+/// ticks are due than the per-update budget allows (see
+/// `max_ticks_per_update`). Whatever it does is reported through the
+/// next step's debug events instead of happening silently.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum LagPolicy {
+    /// Discard the backlog: the game clock jumps forward, losing the
+    /// ticks that should have happened
+    #[default]
+    DropTicks,
+    /// Keep the backlog and catch up over later updates: the game clock
+    /// slows down but no ticks are lost. The carried backlog is capped
+    /// at one budget's worth to avoid a death spiral.
+    SlowClock,
+    /// Process the whole backlog this update, ignoring the budget:
+    /// worst-case latency grows but the clock stays exact
+    BatchTicks,
 }
 
+
 /// Result of a single game step
 #[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct StepResult {
@@ -49,11 +67,34 @@ pub struct StepResult {
     pub done: bool,
     /// Reason for episode ending
     pub done_reason: Option<DoneReason>,
+    /// Whether the episode was cut off externally (step limit, manual
+    /// reset) rather than terminated by the game itself (death).
+    /// Value-based RL should bootstrap from the final state when this
+    /// is set; always false while `done` is false.
+    #[serde(default)]
+    pub truncated: bool,
     /// Achievements unlocked this step
     pub newly_unlocked: Vec<String>,
     /// Debug events for this step (before/after values for debugging)
     #[serde(default)]
     pub debug_events: Vec<String>,
+    /// Structured milestones raised this step, for UI toasts and server
+    /// notifications (see [`MilestoneEvent`])
+    #[serde(default)]
+    pub milestones: Vec<MilestoneEvent>,
+    /// Sound cues raised this step (see [`SoundEvent`])
+    #[serde(default)]
+    pub sounds: Vec<SoundEvent>,
+    /// The debug events classified by category and severity (see
+    /// [`crate::events::LogEntry`]); empty whenever `debug_events` is,
+    /// e.g. under `fast_mode`
+    #[serde(default)]
+    pub log: Vec<crate::events::LogEntry>,
+    /// Post-step legality of each action in the session's action
+    /// profile, index-aligned with its action table (see
+    /// [`Session::action_mask`]); empty under `fast_mode`
+    #[serde(default)]
+    pub action_mask: Vec<bool>,
 }
 
 /// Reason for episode ending
@@ -67,6 +108,81 @@ pub enum DoneReason {
     Reset,
 }
 
+/// A notable moment detected by the session itself, so UIs can show
+/// toasts and servers can push notifications without re-deriving game
+/// logic from debug strings
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub enum MilestoneEvent {
+    /// First iron collected this episode
+    FirstIron,
+    /// First diamond collected this episode
+    FirstDiamond,
+    /// A new best nights-survived count across this session's episodes
+    NightSurvivalRecord { nights: u32 },
+    /// Health dropped to a critical level; raised once per dip, re-armed
+    /// when health recovers
+    LowHealth { health: u8 },
+}
+
+/// A sound cue raised during a step (attack, mine, eat, ...). The core
+/// only emits these; clients decide how — or whether — to play them
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum SoundEvent {
+    /// Player struck a mob
+    Attack,
+    /// A mined block broke
+    Mine,
+    /// Player ate (cow, pig, or plant)
+    Eat,
+    /// Player reached a new level (Craftax XP)
+    LevelUp,
+    /// Dusk: daylight dropped below the night threshold
+    NightFalls,
+}
+
+impl std::fmt::Display for MilestoneEvent {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            MilestoneEvent::FirstIron => write!(f, "First iron collected"),
+            MilestoneEvent::FirstDiamond => write!(f, "First diamond collected"),
+            MilestoneEvent::NightSurvivalRecord { nights } => {
+                write!(f, "New record: {} night(s) survived", nights)
+            }
+            MilestoneEvent::LowHealth { health } => write!(f, "Low health: {}", health),
+        }
+    }
+}
+
+/// A transactional batch failed and the session was rolled back to the
+/// state it had before the batch; see [`Session::step_transaction`]
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct TransactionError {
+    /// Index of the action whose precondition failed, within the batch
+    pub failed_index: usize,
+    /// What did not hold (human-readable)
+    pub reason: String,
+}
+
+impl std::fmt::Display for TransactionError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "action {} failed: {} (session rolled back)",
+            self.failed_index, self.reason
+        )
+    }
+}
+
+/// Pre-step observations [`Session::action_failure`] judges an action by
+pub(crate) struct ActionProbe {
+    pos: Position,
+    /// The tile the action acts on: ahead in the move direction for
+    /// moves, the faced tile for placement
+    target: Position,
+    target_material: Option<Material>,
+    crafted: u32,
+}
+
 /// Current game state snapshot
 #[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct GameState {
@@ -88,8 +204,159 @@ pub struct GameState {
     pub daylight: f32,
     /// View around player (if not full world)
     pub view: Option<WorldView>,
-    /// Full world (if configured)
-    pub world: Option<World>,
+    /// Full world (if configured). Shared, not owned: the session
+    /// publishes one `Arc` snapshot per world change, so cloning a
+    /// `GameState` or reading the state twice in a tick no longer deep-
+    /// copies the world.
+    pub world: Option<std::sync::Arc<World>>,
+    /// Tool recipe costs in effect this episode (differs from the classic
+    /// book only under recipe mutation)
+    #[serde(default)]
+    pub recipes: RecipeBook,
+    /// The concrete seed this episode's world was generated from; see
+    /// [`Session::episode_seed`]
+    #[serde(default)]
+    pub seed: u64,
+}
+
+impl GameState {
+    /// Encode this state as a flat `[H, W, C]` u8 tensor for neural
+    /// network input: materials, entities and mining progress per tile,
+    /// plus inventory and vitals broadcast across the plane. `None`
+    /// without a view. See [`crate::obs::state_tensor_u8`].
+    pub fn to_tensor(&self) -> Option<Vec<u8>> {
+        crate::obs::state_tensor_u8(self)
+    }
+
+    /// Shape of [`to_tensor`](GameState::to_tensor)'s output:
+    /// `(height, width, channels)`
+    pub fn tensor_shape(&self) -> Option<(usize, usize, usize)> {
+        self.view.as_ref().map(crate::obs::state_tensor_shape)
+    }
+
+    /// Compute what changed since `prev`, for incremental UI updates
+    ///
+    /// Tile and entity changes are reported in view-local coordinates —
+    /// the cells a renderer actually repaints — so a moving player
+    /// naturally produces a large delta (the whole viewport shifts)
+    /// while an idle frame produces a near-empty one. If either state
+    /// lacks a view, or the view sizes differ, every tile of the new
+    /// view is reported.
+    pub fn diff(&self, prev: &GameState) -> StateDelta {
+        let mut delta = StateDelta::default();
+
+        if self.player_pos != prev.player_pos {
+            delta.player_moved = Some(self.player_pos);
+        }
+        if self.player_facing != prev.player_facing {
+            delta.player_facing = Some(self.player_facing);
+        }
+
+        // Inventory and vitals, keyed by the stable scalar-feature names
+        let prev_feats = crate::obs::scalar_features(prev);
+        let new_feats = crate::obs::scalar_features(self);
+        for (name, (new, old)) in crate::obs::SCALAR_FEATURES
+            .iter()
+            .zip(new_feats.iter().zip(prev_feats.iter()))
+        {
+            let change = *new as i32 - *old as i32;
+            if change != 0 {
+                // The first four scalar features are the vitals
+                if matches!(*name, "health" | "food" | "drink" | "energy") {
+                    delta.vitals.push((name, change));
+                } else {
+                    delta.inventory.push((name, change));
+                }
+            }
+        }
+
+        let Some(view) = &self.view else {
+            return delta;
+        };
+        let size = view.size() as i32;
+
+        // Tiles: compare per view cell when the previous view lines up,
+        // otherwise report everything
+        let comparable = prev
+            .view
+            .as_ref()
+            .filter(|p| p.radius == view.radius)
+            .is_some();
+        for y in 0..size {
+            for x in 0..size {
+                let material = view.get_material(x, y).unwrap_or_default();
+                let changed = if comparable {
+                    let p = prev.view.as_ref().unwrap();
+                    p.get_material(x, y) != Some(material)
+                        || p.get_mining_progress(x, y) != view.get_mining_progress(x, y)
+                } else {
+                    true
+                };
+                if changed {
+                    delta.tiles.push((x, y, material));
+                }
+            }
+        }
+
+        // Entities: cells where occupancy changed (appeared, left, moved,
+        // or a different kind of object is now there)
+        let occupant = |v: &WorldView, x: i32, y: i32| {
+            v.objects
+                .iter()
+                .find(|(ox, oy, _)| *ox == x && *oy == y)
+                .map(|(_, _, obj)| obj.clone())
+        };
+        let prev_view = if comparable { prev.view.as_ref() } else { None };
+        for y in 0..size {
+            for x in 0..size {
+                let new_obj = occupant(view, x, y);
+                let old_obj = prev_view.and_then(|p| occupant(p, x, y));
+                let same_kind = match (&new_obj, &old_obj) {
+                    (None, None) => true,
+                    (Some(a), Some(b)) => a.kind_name() == b.kind_name(),
+                    _ => false,
+                };
+                if !same_kind {
+                    delta.entities.push((x, y, new_obj));
+                }
+            }
+        }
+
+        delta
+    }
+}
+
+/// Incremental difference between two [`GameState`] snapshots, from
+/// [`GameState::diff`]. Coordinates are view-local.
+#[derive(Clone, Debug, Default)]
+pub struct StateDelta {
+    /// View cells whose material or mining progress changed, with the
+    /// new material
+    pub tiles: Vec<(i32, i32, Material)>,
+    /// View cells whose entity occupancy changed: the object now there,
+    /// or `None` for a vacated cell
+    pub entities: Vec<(i32, i32, Option<GameObject>)>,
+    /// Inventory counts that changed: `(item name, signed change)`,
+    /// named as in [`crate::obs::SCALAR_FEATURES`]
+    pub inventory: Vec<(&'static str, i32)>,
+    /// Vitals that changed: `(stat name, signed change)`
+    pub vitals: Vec<(&'static str, i32)>,
+    /// New player position, if the player moved
+    pub player_moved: Option<Position>,
+    /// New facing direction, if it changed
+    pub player_facing: Option<(i8, i8)>,
+}
+
+impl StateDelta {
+    /// True when nothing changed — the frame can be skipped entirely
+    pub fn is_empty(&self) -> bool {
+        self.tiles.is_empty()
+            && self.entities.is_empty()
+            && self.inventory.is_empty()
+            && self.vitals.is_empty()
+            && self.player_moved.is_none()
+            && self.player_facing.is_none()
+    }
 }
 
 /// Session timing state
@@ -100,7 +367,20 @@ pub struct SessionTiming {
     pub last_tick_at: Option<Instant>,
     pub tick_accumulator: Duration,
     pub paused: bool,
+    /// Wall-clock time spent paused, closed out on resume; see
+    /// [`Session::elapsed_play_time`] for the pause-aware clock
     pub total_pause_duration: Duration,
+    /// When the current pause began, if paused
+    pub paused_since: Option<Instant>,
+    /// Sub-nanosecond remainder carried between ticks so tick rates
+    /// that don't divide a second evenly (see [`TickInterval`]) never
+    /// drift
+    pub tick_frac_nanos: u64,
+    /// When the last non-Noop action arrived, for idle detection
+    pub last_real_input_at: Instant,
+    /// Whether the current pause was triggered by idle detection
+    /// (`idle_pause_secs`) rather than [`Session::set_paused`]
+    pub idle_paused: bool,
 }
 
 impl SessionTiming {
@@ -112,8 +392,51 @@ impl SessionTiming {
             tick_accumulator: Duration::ZERO,
             paused: false,
             total_pause_duration: Duration::ZERO,
+            paused_since: None,
+            tick_frac_nanos: 0,
+            last_real_input_at: Instant::now(),
+            idle_paused: false,
+        }
+    }
+}
+
+/// Exact tick interval as integer nanoseconds plus a fractional
+/// remainder, replacing `Duration::from_secs_f32(1.0 / tps)`.
+///
+/// The tick rate is snapped to milli-tick precision (`round(tps * 1000)`
+/// ticks per 1000 seconds) and the interval kept as a rational number of
+/// nanoseconds. Ticks whose exact length isn't a whole nanosecond carry
+/// the remainder forward, so N ticks always consume exactly N intervals
+/// of real time — no accumulator drift at high tick rates, and timing
+/// that reproduces exactly in tests.
+#[derive(Clone, Copy, Debug)]
+pub(crate) struct TickInterval {
+    /// Whole nanoseconds per tick
+    whole_ns: u64,
+    /// Leftover nanoseconds per tick, as `frac_num / frac_den`
+    frac_num: u64,
+    frac_den: u64,
+}
+
+impl TickInterval {
+    pub(crate) fn from_tps(ticks_per_second: f32) -> Self {
+        // Milli-tick precision covers every sane rate exactly (10.0,
+        // 59.94, 0.5, ...) while keeping the arithmetic in u64 range
+        let ticks_per_1000s = ((ticks_per_second * 1000.0).round() as u64).max(1);
+        const NANOS_PER_1000S: u64 = 1_000_000_000_000;
+        Self {
+            whole_ns: NANOS_PER_1000S / ticks_per_1000s,
+            frac_num: NANOS_PER_1000S % ticks_per_1000s,
+            frac_den: ticks_per_1000s,
         }
     }
+
+    /// Length of the next tick in nanoseconds given the carried
+    /// remainder, and the remainder to carry if that tick is taken
+    pub(crate) fn advance(&self, frac_nanos: u64) -> (u64, u64) {
+        let frac = frac_nanos + self.frac_num;
+        (self.whole_ns + frac / self.frac_den, frac % self.frac_den)
+    }
 }
 
 impl Default for SessionTiming {
@@ -122,6 +445,42 @@ impl Default for SessionTiming {
     }
 }
 
+/// Lifecycle of a scheduled horde event
+#[derive(Clone, Copy, Debug)]
+pub(crate) struct HordeState {
+    /// Step at which the wave spawns
+    pub spawn_at: u64,
+    /// Step at which surviving the horde is credited
+    pub resolve_at: u64,
+    /// Whether the wave has been spawned yet
+    pub spawned: bool,
+}
+
+/// Progress of the knight escort mission
+#[derive(Clone, Copy, Debug)]
+pub(crate) struct EscortState {
+    /// Object id of the friendly knight
+    pub knight_id: u32,
+    /// Where the knight must be delivered
+    pub village_pos: Position,
+    /// Step at which the mission fails if the knight has not arrived
+    pub deadline: u64,
+}
+
+/// Live mob population snapshot used by spawn cap enforcement
+#[derive(Clone, Copy, Debug, Default)]
+struct MobCensus {
+    total: u32,
+    zombies: u32,
+    cows: u32,
+    sheep: u32,
+    pigs: u32,
+    craftax_hostiles: u32,
+    craftax_passives: u32,
+    hostiles_near_player: u32,
+    passives_near_player: u32,
+}
+
 /// A game session
 pub struct Session {
     /// Session configuration
@@ -132,49 +491,322 @@ pub struct Session {
     pub timing: SessionTiming,
     /// Current episode number
     pub episode: u32,
-    /// RNG for game logic
-    pub(crate) rng: ChaCha8Rng,
+    /// RNG for game logic not owned by a named substream
+    pub(crate) rng: crate::rng::SessionRng,
+    /// Named per-subsystem substreams (mob AI, spawning, loot), so
+    /// toggling one system does not shift the sequences of the others
+    pub(crate) rng_streams: crate::rng::RngStreams,
     /// Last player action (for real-time mode)
     pub(crate) last_player_action: Option<Action>,
+    /// The action the previous step actually executed, consulted by
+    /// sticky actions (`config.sticky_action_prob`)
+    pub(crate) prev_action: Option<Action>,
     /// Previous achievements (for reward calculation)
     pub(crate) prev_achievements: Achievements,
+    /// Number of nights the player has survived this episode
+    pub(crate) nights_survived: u32,
+    /// Whether the previous tick was during night (for night counting)
+    pub(crate) was_night: bool,
+    /// Currently scheduled or active horde event
+    pub(crate) horde: Option<HordeState>,
+    /// Step at which the last horde resolved (for the minimum interval)
+    pub(crate) last_horde_step: Option<u64>,
+    /// Currently active escort mission
+    pub(crate) escort: Option<EscortState>,
+    /// Whether the escort mission has already succeeded or failed
+    pub(crate) escort_resolved: bool,
+    /// Events queued while processing the player action (e.g. fortune bonus
+    /// drops), drained into the step's debug event stream
+    pub(crate) pending_events: Vec<String>,
+    /// Sound cues queued while processing the tick, drained into the
+    /// step result (see [`SoundEvent`])
+    pub(crate) pending_sounds: Vec<SoundEvent>,
+    /// The tool recipe costs in effect this episode (classic unless recipe
+    /// mutation is enabled)
+    pub recipes: RecipeBook,
+    /// Cached full-world snapshot keyed by the world's change counter,
+    /// only populated under `full_world_state`
+    pub(crate) world_snapshot: std::cell::RefCell<Option<(u64, std::sync::Arc<World>)>>,
+    /// Keyframe-compressed terrain history, recorded after every step
+    /// when enabled via [`Session::enable_world_history`]
+    pub world_history: Option<crate::history::WorldHistory>,
+    /// Curriculum consulted on every [`reset`](Session::reset), set via
+    /// [`Session::set_curriculum`]
+    pub(crate) curriculum: Option<crate::curriculum::CurriculumScheduler>,
+    /// Per-achievement reward weights, set via
+    /// [`Session::set_reward_config`]; `None` keeps the classic flat +1
+    pub(crate) reward_config: Option<crate::rewards::RewardConfig>,
+    /// The concrete seed this episode's world was generated from, even
+    /// when the config left the seed random; see
+    /// [`episode_seed`](Session::episode_seed)
+    pub(crate) episode_seed: u64,
+    /// Pristine generated worlds reused across resets instead of
+    /// re-running generation; see [`WorldCache`]
+    pub(crate) world_cache: WorldCache,
+    /// Episode seeds cycled round-robin when
+    /// [`world_pool_size`](SessionConfig::world_pool_size) is set;
+    /// empty otherwise
+    pub(crate) pool_seeds: Vec<u64>,
+    /// Best nights-survived count across this session's episodes, for
+    /// the night-survival-record milestone
+    pub(crate) best_nights_survived: u32,
+    /// A low-health milestone is pending recovery; re-armed when health
+    /// rises back above the warning threshold
+    pub(crate) low_health_warned: bool,
 }
 
 impl Session {
     /// Create a new game session
     pub fn new(config: SessionConfig) -> Self {
         let seed = config.seed.unwrap_or_else(|| rand::thread_rng().gen());
-        let mut generator = WorldGenerator::new(config.clone());
-        let world = generator.generate();
+        let mut world_cache = WorldCache::new();
+        let pool_seeds = if config.world_pool_size > 0 {
+            crate::worldgen::pool_seeds(seed, config.world_pool_size)
+        } else {
+            Vec::new()
+        };
+        // Warm the whole pool up front so every later reset is a clone
+        for &pool_seed in &pool_seeds {
+            world_cache.get_or_generate(&config, pool_seed);
+        }
+        let world = if config.seed.is_some() || !pool_seeds.is_empty() {
+            world_cache.get_or_generate(&config, seed)
+        } else {
+            // One-off random seeds would only grow the cache; generate
+            // directly. Pinning the resolved seed keeps the world and
+            // the session RNGs agreeing even when the config left it
+            // random.
+            let mut gen_config = config.clone();
+            gen_config.seed = Some(seed);
+            WorldGenerator::new(gen_config).generate()
+        };
 
         let prev_achievements = world
             .get_player()
             .map(|p| p.achievements.clone())
             .unwrap_or_default();
 
+        let rng_kind = config.rng_kind;
+        let mut rng = crate::rng::SessionRng::from_seed_kind(rng_kind, seed);
+        let recipes = if config.recipe_mutation_enabled {
+            RecipeBook::mutated(&mut rng)
+        } else {
+            RecipeBook::default()
+        };
+
         Self {
             config,
             world,
             timing: SessionTiming::new(),
             episode: 1,
-            rng: ChaCha8Rng::seed_from_u64(seed),
+            rng,
+            rng_streams: crate::rng::RngStreams::from_seed_kind(rng_kind, seed),
             last_player_action: None,
+            prev_action: None,
             prev_achievements,
+            nights_survived: 0,
+            was_night: false,
+            horde: None,
+            last_horde_step: None,
+            escort: None,
+            escort_resolved: false,
+            pending_events: Vec::new(),
+            pending_sounds: Vec::new(),
+            recipes,
+            world_snapshot: std::cell::RefCell::new(None),
+            world_history: None,
+            curriculum: None,
+            reward_config: None,
+            episode_seed: seed,
+            world_cache,
+            pool_seeds,
+            best_nights_survived: 0,
+            low_health_warned: false,
         }
     }
 
+    /// Attach a curriculum consulted on every [`reset`](Session::reset)
+    pub fn set_curriculum(&mut self, curriculum: crate::curriculum::CurriculumScheduler) {
+        self.curriculum = Some(curriculum);
+    }
+
+    /// The attached curriculum, if any
+    pub fn curriculum(&self) -> Option<&crate::curriculum::CurriculumScheduler> {
+        self.curriculum.as_ref()
+    }
+
+    /// Weight achievement rewards by name instead of the classic flat +1.
+    ///
+    /// First unlocks earn `achievement_rewards[name]` and repeat unlocks
+    /// `achievement_repeat_rewards[name]` per extra count, matching
+    /// [`RewardCalculator`](crate::rewards::RewardCalculator) semantics:
+    /// names missing from a map earn nothing. Useful for emphasizing
+    /// late-game achievements like `collect_diamond` during training.
+    pub fn set_reward_config(&mut self, config: crate::rewards::RewardConfig) {
+        self.reward_config = Some(config);
+    }
+
+    /// The attached reward weights, if any
+    pub fn reward_config(&self) -> Option<&crate::rewards::RewardConfig> {
+        self.reward_config.as_ref()
+    }
+
     /// Reset the session to a new episode
     pub fn reset(&mut self) {
-        let _seed = self.config.seed.unwrap_or_else(|| self.rng.gen());
-        let mut generator = WorldGenerator::new(self.config.clone());
-        self.world = generator.generate();
+        // Let the curriculum mutate the config before the new world is
+        // generated; it sees the upcoming episode number and how the
+        // outgoing episode performed
+        let mut curriculum_events = Vec::new();
+        if let Some(mut curriculum) = self.curriculum.take() {
+            let unlocked = self
+                .world
+                .get_player()
+                .map(|p| p.achievements.total_unlocked())
+                .unwrap_or(0);
+            for name in curriculum.advance(self.episode + 1, unlocked, &mut self.config) {
+                curriculum_events.push(format!("curriculum stage applied: {}", name));
+            }
+            self.curriculum = Some(curriculum);
+        }
+
+        // Resolve this episode's concrete seed: a pool cycles its
+        // pre-generated seeds round-robin, while random runs draw from
+        // the session RNG so the whole session replays from the first
+        // episode's seed
+        let seed = if self.pool_seeds.is_empty() {
+            self.config.seed.unwrap_or_else(|| self.rng.gen())
+        } else {
+            self.pool_seeds[self.episode as usize % self.pool_seeds.len()]
+        };
+        let world = if self.config.seed.is_some() || !self.pool_seeds.is_empty() {
+            // Fixed-seed and pooled resets hit the cache after the
+            // first generation
+            self.world_cache.get_or_generate(&self.config, seed)
+        } else {
+            let mut gen_config = self.config.clone();
+            gen_config.seed = Some(seed);
+            WorldGenerator::new(gen_config).generate()
+        };
+        self.begin_episode(seed, world);
+        self.pending_events.append(&mut curriculum_events);
+    }
+
+    /// Reset to a new episode on a clone of `template`'s pristine
+    /// world, skipping generation entirely — the fast path for
+    /// fixed-seed training loops that reuse one map. The curriculum is
+    /// not consulted: the template decides the world, and generation
+    /// settings it would mutate are never read.
+    pub fn reset_from_template(&mut self, template: &crate::worldgen::WorldTemplate) {
+        self.begin_episode(template.seed(), template.world().clone());
+    }
+
+    /// Episode bookkeeping shared by every reset flavor: installs the
+    /// new world and seed and rolls all per-episode state
+    fn begin_episode(&mut self, seed: u64, world: World) {
+        self.episode_seed = seed;
+        self.world = world;
         self.timing = SessionTiming::new();
         self.episode += 1;
+        self.nights_survived = 0;
+        self.was_night = false;
+        self.horde = None;
+        self.last_horde_step = None;
+        self.escort = None;
+        self.escort_resolved = false;
+        self.prev_action = None;
+        // best_nights_survived deliberately carries across episodes
+        self.low_health_warned = false;
+        self.pending_events.clear();
+        self.pending_sounds.clear();
+        self.recipes = if self.config.recipe_mutation_enabled {
+            RecipeBook::mutated(&mut self.rng)
+        } else {
+            RecipeBook::default()
+        };
         self.prev_achievements = self
             .world
             .get_player()
             .map(|p| p.achievements.clone())
             .unwrap_or_default();
+        // A history carries over across episodes by restarting on the
+        // fresh world
+        if let Some(history) = &self.world_history {
+            let interval = history.keyframe_interval();
+            self.world_history = Some(crate::history::WorldHistory::new(&self.world, 0, interval));
+        }
+    }
+
+    /// Start recording keyframe-compressed terrain history from the
+    /// current step; see [`crate::history::WorldHistory`]
+    pub fn enable_world_history(&mut self, keyframe_interval: u32) {
+        self.world_history = Some(crate::history::WorldHistory::new(
+            &self.world,
+            self.timing.step,
+            keyframe_interval,
+        ));
+    }
+
+    /// The fully-resolved rules this session is playing under, with
+    /// their stable hash; see [`SessionConfig::resolved`]
+    pub fn effective_config(&self) -> crate::config::ResolvedConfig {
+        self.config.resolved()
+    }
+
+    /// The concrete seed this episode's world was generated from. Equal
+    /// to `config.seed` when one was fixed; for random runs this is the
+    /// resolved value, so "random seed" episodes can still be
+    /// reproduced.
+    pub fn episode_seed(&self) -> u64 {
+        self.episode_seed
+    }
+
+    /// The session's world cache, for inspecting reset throughput:
+    /// [`WorldCache::hits`] counts resets that skipped generation
+    pub fn world_cache(&self) -> &WorldCache {
+        &self.world_cache
+    }
+
+    /// A shell command that regenerates this episode's world: the
+    /// `play` binary with the resolved seed and world size pinned.
+    /// Paste it from a bug report to land in the same map; the full
+    /// trajectory additionally needs the episode's actions (see
+    /// [`Recording`](crate::recording::Recording)).
+    pub fn repro_command(&self) -> String {
+        format!(
+            "cargo run --bin play -- --seed {} --world-size {}x{}",
+            self.episode_seed, self.config.world_size.0, self.config.world_size.1
+        )
+    }
+
+    /// Stable digest of the current game state (terrain, objects,
+    /// inventory) plus the step and episode counters, for determinism
+    /// checks in CI and across platforms; see [`World::state_hash`]
+    pub fn state_hash(&self) -> u64 {
+        use std::hash::Hasher;
+        let mut hasher = crate::compat::fnv1a();
+        hasher.write_u64(self.world.state_hash());
+        hasher.write_u64(self.timing.step);
+        hasher.write_u32(self.episode);
+        hasher.finish()
+    }
+
+    /// Render the current full state to a PNG on disk, with the HUD
+    /// (status bars) always drawn so screenshots are self-describing
+    /// regardless of the renderer config's own setting. Requires the
+    /// `png` feature.
+    #[cfg(feature = "png")]
+    pub fn screenshot(
+        &self,
+        path: &str,
+        renderer_config: crate::image_renderer::ImageRendererConfig,
+    ) -> Result<(), image::ImageError> {
+        let renderer = crate::image_renderer::ImageRenderer::new(
+            crate::image_renderer::ImageRendererConfig {
+                show_status_bars: true,
+                ..renderer_config
+            },
+        );
+        renderer.save_png(&self.get_state(), path)
     }
 
     /// Get the current game state
@@ -192,15 +824,95 @@ impl Session {
             daylight: self.world.daylight,
             view: player.map(|p| self.world.get_view(p.pos, self.config.view_radius)),
             world: if self.config.full_world_state {
-                Some(self.world.clone())
+                Some(self.shared_world_snapshot())
             } else {
                 None
             },
+            recipes: self.recipes,
+            seed: self.episode_seed,
         }
     }
 
-    /// Advance the game by one tick
+    /// The current world as a shared snapshot, re-cloned only when the
+    /// world's change counter has moved since the last call
+    fn shared_world_snapshot(&self) -> std::sync::Arc<World> {
+        let mut cache = self.world_snapshot.borrow_mut();
+        if let Some((version, snapshot)) = cache.as_ref() {
+            if *version == self.world.version() {
+                return snapshot.clone();
+            }
+        }
+        let snapshot = std::sync::Arc::new(self.world.clone());
+        *cache = Some((self.world.version(), snapshot.clone()));
+        snapshot
+    }
+
+    /// Lightweight state for `fast_mode` step results: scalar fields only,
+    /// no view grid and no world copy. Callers that need a full
+    /// observation under `fast_mode` ask for one via `get_state`.
+    fn get_state_delta(&self) -> GameState {
+        let player = self.world.get_player();
+
+        GameState {
+            step: self.timing.step,
+            episode: self.episode,
+            inventory: player.map(|p| p.inventory.clone()).unwrap_or_default(),
+            achievements: player.map(|p| p.achievements.clone()).unwrap_or_default(),
+            player_pos: player.map(|p| p.pos).unwrap_or((0, 0)),
+            player_facing: player.map(|p| p.facing).unwrap_or((0, 1)),
+            player_sleeping: player.map(|p| p.sleeping).unwrap_or(false),
+            daylight: self.world.daylight,
+            view: None,
+            world: None,
+            recipes: self.recipes,
+            seed: self.episode_seed,
+        }
+    }
+
+    /// Advance the game by one tick — or by `config.action_repeat`
+    /// ticks of the same action (Atari-style frame skip), summing
+    /// rewards, concatenating events, and returning the final state.
+    /// With `sticky_action_prob` set, the supplied action is first
+    /// swapped for the previous step's with that probability, drawn
+    /// from the seeded session RNG.
     pub fn step(&mut self, action: Action) -> StepResult {
+        let action = self.resolve_sticky_action(action);
+        let mut result = self.step_tick(action);
+        for _ in 1..self.config.action_repeat.max(1) {
+            if result.done {
+                break;
+            }
+            let tick = self.step_tick(action);
+            result.state = tick.state;
+            result.reward += tick.reward;
+            result.done = tick.done;
+            result.done_reason = tick.done_reason;
+            result.truncated = tick.truncated;
+            result.newly_unlocked.extend(tick.newly_unlocked);
+            result.debug_events.extend(tick.debug_events);
+            result.milestones.extend(tick.milestones);
+            result.sounds.extend(tick.sounds);
+            result.log.extend(tick.log);
+            result.action_mask = tick.action_mask;
+        }
+        result
+    }
+
+    /// Swap `action` for the previous step's with probability
+    /// `sticky_action_prob`. No RNG draw happens while the feature is
+    /// off, so default configs keep their exact sequences.
+    fn resolve_sticky_action(&mut self, action: Action) -> Action {
+        let prob = self.config.sticky_action_prob;
+        let chosen = match self.prev_action {
+            Some(prev) if prob > 0.0 && self.rng.gen::<f32>() < prob => prev,
+            _ => action,
+        };
+        self.prev_action = Some(chosen);
+        chosen
+    }
+
+    /// One tick of [`step`](Session::step), before action repeat
+    fn step_tick(&mut self, action: Action) -> StepResult {
         self.timing.step += 1;
         self.timing.last_tick_at = Some(Instant::now());
 
@@ -212,12 +924,259 @@ impl Session {
             .unwrap_or_default();
 
         // Process the tick
-        self.process_tick(action)
+        let result = self.process_tick(action);
+        if let Some(history) = self.world_history.as_mut() {
+            history.record(self.timing.step, &self.world);
+        }
+        result
+    }
+
+    /// Craft up to `count` units of one recipe with a single world tick.
+    ///
+    /// The world advances exactly once, as if `action` had been issued
+    /// normally; any units beyond the first are crafted in the same tick
+    /// while materials, stations, and the per-slot cap hold out. Scripted
+    /// agents use this to collapse long `Make*` sequences into one step.
+    /// Non-crafting actions fall back to a plain `step`.
+    pub fn step_craft_n(&mut self, action: Action, count: u8) -> StepResult {
+        if count > 1 && action.is_crafting() {
+            // Extra units are crafted up front so their achievements and
+            // events land in the same step's accounting
+            for _ in 1..count {
+                let before = self.crafted_count(action);
+                self.process_player_action(action);
+                if self.crafted_count(action) <= before {
+                    break;
+                }
+            }
+        }
+        self.step(action)
+    }
+
+    /// Run a batch of actions as a transaction: all of them apply, or
+    /// none of them do.
+    ///
+    /// Each action's precondition is checked after its step — a move
+    /// must actually move (or open a door), a craft must produce its
+    /// item, a placement must change the faced tile. On the first
+    /// failure the session is restored to its pre-batch state and the
+    /// failing index is reported, so callers replaying a planned path
+    /// never leave the session half-executed. Context-dependent actions
+    /// (`Do`, `Sleep`, `Noop`, combat) have no checkable precondition
+    /// and always pass. An episode ending mid-batch is not a failure:
+    /// the batch is truncated and the results so far are returned.
+    pub fn step_transaction(&mut self, actions: &[Action]) -> Result<Vec<StepResult>, TransactionError> {
+        let checkpoint = crate::saveload::SaveData::from_session(self, None);
+        let mut results = Vec::with_capacity(actions.len());
+        for (index, &action) in actions.iter().enumerate() {
+            let probe = self.action_probe(action);
+            let result = self.step(action);
+            let done = result.done;
+            results.push(result);
+            if done {
+                break;
+            }
+            if let Some(reason) = self.action_failure(action, &probe) {
+                *self = checkpoint.into_session();
+                return Err(TransactionError {
+                    failed_index: index,
+                    reason,
+                });
+            }
+        }
+        Ok(results)
+    }
+
+    /// Capture what `action` is about to act on, for `action_failure`
+    pub(crate) fn action_probe(&self, action: Action) -> ActionProbe {
+        let (pos, facing) = self
+            .world
+            .get_player()
+            .map(|p| (p.pos, p.facing))
+            .unwrap_or(((0, 0), (0, 1)));
+        let (dx, dy) = action
+            .movement_delta()
+            .unwrap_or((facing.0 as i32, facing.1 as i32));
+        let target = (pos.0 + dx, pos.1 + dy);
+        ActionProbe {
+            pos,
+            target,
+            target_material: self.world.get_material(target),
+            crafted: self.crafted_count(action),
+        }
+    }
+
+    /// Why `action` failed given its pre-step probe, or `None` when it
+    /// succeeded or has no checkable precondition
+    pub(crate) fn action_failure(&self, action: Action, probe: &ActionProbe) -> Option<String> {
+        match action {
+            Action::MoveLeft | Action::MoveRight | Action::MoveUp | Action::MoveDown => {
+                let pos = self.world.get_player().map(|p| p.pos)?;
+                if pos != probe.pos {
+                    return None;
+                }
+                // Walking into a closed door opens it instead of moving
+                if probe.target_material == Some(Material::DoorClosed)
+                    && self.world.get_material(probe.target) == Some(Material::DoorOpen)
+                {
+                    return None;
+                }
+                Some(format!(
+                    "move blocked at ({}, {})",
+                    probe.target.0, probe.target.1
+                ))
+            }
+            _ if action.is_crafting() => {
+                if self.crafted_count(action) > probe.crafted {
+                    None
+                } else {
+                    Some(format!("{:?} preconditions not met", action))
+                }
+            }
+            Action::PlaceStone
+            | Action::PlaceTable
+            | Action::PlaceFurnace
+            | Action::PlaceSpikeTrap
+            | Action::PlaceDoor
+            | Action::PlaceFence => {
+                if self.world.get_material(probe.target) != probe.target_material {
+                    None
+                } else {
+                    Some(format!(
+                        "cannot place at ({}, {})",
+                        probe.target.0, probe.target.1
+                    ))
+                }
+            }
+            Action::PlacePlant => {
+                if self.world.get_object_at(probe.target).is_some() {
+                    None
+                } else {
+                    Some(format!(
+                        "cannot plant at ({}, {})",
+                        probe.target.0, probe.target.1
+                    ))
+                }
+            }
+            _ => None,
+        }
+    }
+
+    /// How many units of a crafting action's output the player holds, used
+    /// by `step_craft_n` to detect when a craft stops succeeding
+    pub(crate) fn crafted_count(&self, action: Action) -> u32 {
+        let inv = match self.world.get_player() {
+            Some(p) => &p.inventory,
+            None => return 0,
+        };
+        match action {
+            Action::MakeWoodPickaxe => inv.wood_pickaxe as u32,
+            Action::MakeStonePickaxe => inv.stone_pickaxe as u32,
+            Action::MakeIronPickaxe => inv.iron_pickaxe as u32,
+            Action::MakeWoodSword => inv.wood_sword as u32,
+            Action::MakeStoneSword => inv.stone_sword as u32,
+            Action::MakeIronSword => inv.iron_sword as u32,
+            Action::MakeDiamondPickaxe => inv.diamond_pickaxe as u32,
+            Action::MakeDiamondSword => inv.diamond_sword as u32,
+            Action::MakeIronArmor | Action::MakeDiamondArmor => {
+                inv.armor_helmet as u32
+                    + inv.armor_chestplate as u32
+                    + inv.armor_leggings as u32
+                    + inv.armor_boots as u32
+            }
+            Action::MakeBow => inv.bow as u32,
+            Action::MakeArrow => inv.arrows as u32,
+            Action::MakeSpikeTrap => inv.spike_trap as u32,
+            Action::MakeDoor => inv.door as u32,
+            Action::MakeFence => inv.fence as u32,
+            _ => 0,
+        }
+    }
+
+    /// Move the player to `pos`, bypassing movement rules. Debug helper
+    /// for consoles and scripted scenario setup; not recorded as a step.
+    pub fn debug_teleport(&mut self, pos: Position) -> Result<(), String> {
+        if !self.world.in_bounds(pos) {
+            return Err(format!("({}, {}) is out of bounds", pos.0, pos.1));
+        }
+        if !self.world.is_walkable(pos) {
+            return Err(format!("({}, {}) is not walkable", pos.0, pos.1));
+        }
+        let player_id = self.world.player_id;
+        if self.world.move_object(player_id, pos) {
+            Ok(())
+        } else {
+            Err("player could not be moved".to_string())
+        }
+    }
+
+    /// Add `count` of a named item to the player's inventory, subject to
+    /// the usual per-slot cap. Debug helper; accepts the same item names
+    /// the inventory uses (wood, stone, ..., wood_pickaxe, potion_red, ...).
+    pub fn debug_give(&mut self, item: &str, count: u8) -> Result<(), String> {
+        let cap = crate::inventory::MAX_INVENTORY_VALUE;
+        let player = match self.world.get_player_mut() {
+            Some(p) => p,
+            None => return Err("no player in world".to_string()),
+        };
+        let inv = &mut player.inventory;
+        match item {
+            "wood" => inv.add_wood(count),
+            "stone" => inv.add_stone(count),
+            "coal" => inv.add_coal(count),
+            "iron" => inv.add_iron(count),
+            "diamond" => inv.add_diamond(count),
+            "sapphire" => inv.add_sapphire(count),
+            "ruby" => inv.add_ruby(count),
+            "sapling" => inv.add_sapling(count),
+            "wool" => inv.add_wool(count),
+            "fence" => inv.add_fence(count),
+            "arrows" | "arrow" => inv.add_arrows(count),
+            "food" => inv.add_food(count),
+            "drink" => inv.add_drink(count),
+            "energy" => inv.add_energy(count),
+            "health" => inv.add_health(count),
+            "potion_red" => inv.add_potion_red(count),
+            "potion_green" => inv.add_potion_green(count),
+            "potion_blue" => inv.add_potion_blue(count),
+            "potion_pink" => inv.add_potion_pink(count),
+            "potion_cyan" => inv.add_potion_cyan(count),
+            "potion_yellow" => inv.add_potion_yellow(count),
+            "wood_pickaxe" => inv.wood_pickaxe = inv.wood_pickaxe.saturating_add(count).min(cap),
+            "stone_pickaxe" => inv.stone_pickaxe = inv.stone_pickaxe.saturating_add(count).min(cap),
+            "iron_pickaxe" => inv.iron_pickaxe = inv.iron_pickaxe.saturating_add(count).min(cap),
+            "diamond_pickaxe" => {
+                inv.diamond_pickaxe = inv.diamond_pickaxe.saturating_add(count).min(cap)
+            }
+            "wood_sword" => inv.wood_sword = inv.wood_sword.saturating_add(count).min(cap),
+            "stone_sword" => inv.stone_sword = inv.stone_sword.saturating_add(count).min(cap),
+            "iron_sword" => inv.iron_sword = inv.iron_sword.saturating_add(count).min(cap),
+            "diamond_sword" => inv.diamond_sword = inv.diamond_sword.saturating_add(count).min(cap),
+            "bow" => inv.bow = inv.bow.saturating_add(count).min(cap),
+            _ => return Err(format!("unknown item '{}'", item)),
+        }
+        Ok(())
+    }
+
+    /// Restart the episode with a new seed. Debug helper; equivalent to
+    /// setting `config.seed` and calling `reset`.
+    pub fn debug_reseed(&mut self, seed: u64) {
+        self.config.seed = Some(seed);
+        self.rng = crate::rng::SessionRng::from_seed_kind(self.config.rng_kind, seed);
+        self.rng_streams = crate::rng::RngStreams::from_seed_kind(self.config.rng_kind, seed);
+        self.reset();
     }
 
-    /// Set player action for next tick (real-time mode)
+    /// Set player action for next tick (real-time mode). Real (non-Noop)
+    /// input feeds idle detection and resumes an idle-paused session.
     pub fn set_action(&mut self, action: Action) {
         self.last_player_action = Some(action);
+        if action != Action::Noop {
+            self.timing.last_real_input_at = Instant::now();
+            if self.timing.idle_paused {
+                self.set_paused(false);
+            }
+        }
     }
 
     /// Update for real-time mode
@@ -230,29 +1189,53 @@ impl Session {
             | TimeMode::Hybrid {
                 ticks_per_second, ..
             } => {
+                // Idle detection: a player sending nothing but Noops for
+                // long enough is AFK, not playing slowly
+                if !self.timing.paused {
+                    if let Some(idle_secs) = self.config.idle_pause_secs {
+                        if self.timing.last_real_input_at.elapsed().as_secs_f32() >= idle_secs {
+                            self.timing.paused = true;
+                            self.timing.idle_paused = true;
+                            self.timing.paused_since = Some(Instant::now());
+                            self.pending_events
+                                .push(format!("auto-paused after {:.0}s idle", idle_secs));
+                        }
+                    }
+                }
                 if self.timing.paused {
-                    self.timing.total_pause_duration += delta;
+                    // Pause time is measured from paused_since and
+                    // closed out on resume, not accumulated per update
                     return vec![];
                 }
 
-                let tick_duration = Duration::from_secs_f32(1.0 / ticks_per_second);
+                let interval = TickInterval::from_tps(*ticks_per_second);
                 self.timing.tick_accumulator += delta;
 
                 let mut results = Vec::new();
-                const MAX_TICKS_PER_UPDATE: u32 = 10;
+                let budget = self.config.max_ticks_per_update.unwrap_or(10).max(1);
+                // Even batching needs a hard stop so a huge delta (or a
+                // tiny tick) can't wedge the update loop
+                let cap = match self.config.lag_policy {
+                    LagPolicy::BatchTicks => budget.saturating_mul(100),
+                    _ => budget,
+                };
                 let mut ticks_this_update = 0;
 
-                while self.timing.tick_accumulator >= tick_duration
-                    && ticks_this_update < MAX_TICKS_PER_UPDATE
-                {
+                while ticks_this_update < cap {
+                    let (tick_ns, next_frac) = interval.advance(self.timing.tick_frac_nanos);
+                    let tick_duration = Duration::from_nanos(tick_ns);
+                    if self.timing.tick_accumulator < tick_duration {
+                        break;
+                    }
                     self.timing.tick_accumulator -= tick_duration;
+                    self.timing.tick_frac_nanos = next_frac;
                     let action = self.last_player_action.unwrap_or(Action::Noop);
                     results.push(self.step(action));
                     ticks_this_update += 1;
                 }
 
-                if ticks_this_update >= MAX_TICKS_PER_UPDATE {
-                    self.timing.tick_accumulator = Duration::ZERO;
+                if ticks_this_update >= budget {
+                    self.apply_lag_policy(interval, ticks_this_update, budget);
                 }
 
                 results
@@ -260,35 +1243,204 @@ impl Session {
         }
     }
 
+    /// Handle time still owed after an update hit the tick budget,
+    /// per the configured [`LagPolicy`]; reports what was done through
+    /// the pending event stream
+    fn apply_lag_policy(&mut self, interval: TickInterval, ticks_taken: u32, budget: u32) {
+        let (tick_ns, _) = interval.advance(self.timing.tick_frac_nanos);
+        let backlog = self.timing.tick_accumulator;
+        if backlog < Duration::from_nanos(tick_ns) {
+            return;
+        }
+        match self.config.lag_policy {
+            LagPolicy::DropTicks => {
+                self.timing.tick_accumulator = Duration::ZERO;
+                self.timing.tick_frac_nanos = 0;
+                self.pending_events.push(format!(
+                    "lag: dropped {}ms backlog after {} ticks (drop_ticks)",
+                    backlog.as_millis(),
+                    ticks_taken
+                ));
+            }
+            LagPolicy::SlowClock => {
+                let carry_cap = Duration::from_nanos(tick_ns.saturating_mul(budget as u64));
+                let dropped = backlog.saturating_sub(carry_cap);
+                if !dropped.is_zero() {
+                    self.timing.tick_accumulator = carry_cap;
+                }
+                self.pending_events.push(format!(
+                    "lag: carrying {}ms backlog (slow_clock{})",
+                    self.timing.tick_accumulator.as_millis(),
+                    if dropped.is_zero() {
+                        String::new()
+                    } else {
+                        format!(", dropped {}ms over cap", dropped.as_millis())
+                    }
+                ));
+            }
+            LagPolicy::BatchTicks => {
+                // Only reachable at the safety cap; note the overrun
+                self.pending_events.push(format!(
+                    "lag: batched {} ticks, {}ms still pending (batch_ticks)",
+                    ticks_taken,
+                    backlog.as_millis()
+                ));
+            }
+        }
+    }
+
     /// Pause/unpause the session
     pub fn set_paused(&mut self, paused: bool) {
         if self.timing.paused && !paused {
             self.timing.tick_accumulator = Duration::ZERO;
+            self.timing.tick_frac_nanos = 0;
+            // Don't immediately re-trigger idle detection on resume
+            self.timing.last_real_input_at = Instant::now();
+            // Close out the pause interval into the running total
+            if let Some(since) = self.timing.paused_since.take() {
+                self.timing.total_pause_duration += since.elapsed();
+            }
+        } else if !self.timing.paused && paused {
+            self.timing.paused_since = Some(Instant::now());
         }
         self.timing.paused = paused;
+        self.timing.idle_paused = false;
     }
 
-    /// Process one game tick
-    fn process_tick(&mut self, action: Action) -> StepResult {
-        let mut debug_events = Vec::new();
-
-        // Capture state before action for debugging
-        let (drink_before, food_before, _energy_before, sleeping_before, health_before) = self
-            .world
-            .get_player()
-            .map(|p| {
-                (
-                    p.inventory.drink,
-                    p.inventory.food,
-                    p.inventory.energy,
-                    p.sleeping,
-                    p.inventory.health,
-                )
-            })
-            .unwrap_or((0, 0, 0, false, 0));
+    /// Wall-clock time this episode has actually been playing: time
+    /// since the episode started minus every paused interval, including
+    /// a pause still in progress
+    pub fn elapsed_play_time(&self) -> Duration {
+        let mut paused = self.timing.total_pause_duration;
+        if let Some(since) = self.timing.paused_since {
+            paused += since.elapsed();
+        }
+        self.timing.created_at.elapsed().saturating_sub(paused)
+    }
+
+    /// Ticks per second actually achieved this episode, measured
+    /// against [`elapsed_play_time`](Session::elapsed_play_time) so
+    /// pauses don't drag the number down. This is what a UI Hz readout
+    /// should display.
+    pub fn achieved_tps(&self) -> f64 {
+        let secs = self.elapsed_play_time().as_secs_f64();
+        if secs <= 0.0 {
+            return 0.0;
+        }
+        self.timing.step as f64 / secs
+    }
+
+    /// Whether the session auto-paused from idle detection (see
+    /// `idle_pause_secs`); cleared on resume
+    pub fn is_idle_paused(&self) -> bool {
+        self.timing.idle_paused
+    }
+
+    /// Whether `action` can have an effect in the current state, from
+    /// inventory, table/furnace adjacency, and the facing tile. Moves
+    /// and context-sensitive actions (`Do`, `Sleep`, `Noop`) are always
+    /// legal; crafting and placement are legal only when their
+    /// preconditions hold, so agents can mask out guaranteed no-ops.
+    pub fn action_legal(&self, action: Action) -> bool {
+        let Some(player) = self.world.get_player() else {
+            return false;
+        };
+        let inv = &player.inventory;
+        let near_table = self.world.has_adjacent_table(player.pos);
+        let near_furnace = self.world.has_adjacent_furnace(player.pos);
+        let target = (
+            player.pos.0 + player.facing.0 as i32,
+            player.pos.1 + player.facing.1 as i32,
+        );
+        // Placement needs open grass in front, matching process_place
+        let target_open = self.world.get_material(target) == Some(Material::Grass)
+            && self.world.get_object_at(target).is_none();
+
+        match action {
+            Action::Noop | Action::Do | Action::Sleep => true,
+            Action::MoveLeft | Action::MoveRight | Action::MoveUp | Action::MoveDown => true,
+            Action::PlaceStone => target_open && inv.stone >= 1,
+            Action::PlaceTable => target_open && inv.wood >= 2,
+            Action::PlaceFurnace => target_open && inv.stone >= 4,
+            Action::PlacePlant => target_open && inv.sapling >= 1,
+            Action::PlaceSpikeTrap => target_open && inv.spike_trap >= 1,
+            Action::PlaceDoor => target_open && inv.door >= 1,
+            Action::PlaceFence => target_open && inv.fence >= 1,
+            Action::MakeWoodPickaxe => near_table && self.recipes.wood_pickaxe.can_afford(inv),
+            Action::MakeStonePickaxe => near_table && self.recipes.stone_pickaxe.can_afford(inv),
+            Action::MakeIronPickaxe => {
+                near_table && near_furnace && self.recipes.iron_pickaxe.can_afford(inv)
+            }
+            Action::MakeWoodSword => near_table && self.recipes.wood_sword.can_afford(inv),
+            Action::MakeStoneSword => near_table && self.recipes.stone_sword.can_afford(inv),
+            Action::MakeIronSword => {
+                near_table && near_furnace && self.recipes.iron_sword.can_afford(inv)
+            }
+            Action::MakeDiamondPickaxe => near_table && inv.can_craft_diamond_pickaxe(),
+            Action::MakeDiamondSword => near_table && inv.can_craft_diamond_sword(),
+            Action::MakeIronArmor => near_table && near_furnace && inv.iron >= 3 && inv.coal >= 3,
+            Action::MakeDiamondArmor => near_table && inv.diamond >= 3,
+            Action::MakeBow => near_table && inv.can_craft_bow(),
+            Action::MakeArrow => near_table && inv.can_craft_arrow(),
+            Action::MakeSpikeTrap => near_table && inv.can_craft_spike_trap(),
+            Action::MakeDoor => near_table && inv.can_craft_door(),
+            Action::MakeFence => near_table && inv.can_craft_fence(),
+            Action::ShootArrow => inv.bow >= 1 && inv.arrows >= 1,
+            Action::DrinkPotionRed => inv.potion_red >= 1,
+            Action::DrinkPotionGreen => inv.potion_green >= 1,
+            Action::DrinkPotionBlue => inv.potion_blue >= 1,
+            Action::DrinkPotionPink => inv.potion_pink >= 1,
+            Action::DrinkPotionCyan => inv.potion_cyan >= 1,
+            Action::DrinkPotionYellow => inv.potion_yellow >= 1,
+        }
+    }
+
+    /// Legality of every action in the session's action profile,
+    /// index-aligned with the profile's action table
+    pub fn action_mask(&self) -> Vec<bool> {
+        self.config
+            .action_profile
+            .action_table()
+            .iter()
+            .map(|&a| self.action_legal(a))
+            .collect()
+    }
+
+    /// The actions in the session's action profile that are legal now
+    pub fn legal_actions(&self) -> Vec<Action> {
+        self.config
+            .action_profile
+            .action_table()
+            .iter()
+            .copied()
+            .filter(|&a| self.action_legal(a))
+            .collect()
+    }
+
+    /// Process one game tick
+    fn process_tick(&mut self, action: Action) -> StepResult {
+        let mut debug_events = Vec::new();
+        // Throughput mode: per-step debug strings are formatting-heavy,
+        // so skip building them entirely
+        let collect_events = !self.config.fast_mode;
+
+        // Capture state before action for debugging
+        let (drink_before, food_before, _energy_before, sleeping_before, health_before) = self
+            .world
+            .get_player()
+            .map(|p| {
+                (
+                    p.inventory.drink,
+                    p.inventory.food,
+                    p.inventory.energy,
+                    p.sleeping,
+                    p.inventory.health,
+                )
+            })
+            .unwrap_or((0, 0, 0, false, 0));
 
         // Capture action context for debug events
-        let action_event = if action != Action::Noop {
+        let action_event = if collect_events && action != Action::Noop {
             let mut desc = format!("ACTION: {:?}", action);
             if action == Action::Do {
                 if let Some(player) = self.world.get_player() {
@@ -301,6 +1453,8 @@ impl Session {
                     } else if let Some(obj) = self.world.get_object_at(facing_pos) {
                         match obj {
                             GameObject::Cow(_) => "cow",
+                            GameObject::Sheep(_) => "sheep",
+                            GameObject::Pig(_) => "pig",
                             GameObject::Zombie(_) => "zombie",
                             GameObject::Skeleton(_) => "skeleton",
                             GameObject::CraftaxMob(mob) => match mob.kind {
@@ -315,6 +1469,7 @@ impl Session {
                             GameObject::Arrow(_) => "arrow",
                             GameObject::Plant(_) => "plant",
                             GameObject::Player(_) => "player",
+                            GameObject::EscortKnight(_) => "escort_knight",
                         }
                         .to_string()
                     } else if let Some(mat) = self.world.get_material(facing_pos) {
@@ -334,6 +1489,10 @@ impl Session {
                             Material::Sapphire => "sapphire",
                             Material::Ruby => "ruby",
                             Material::Chest => "chest",
+                            Material::SpikeTrap => "spike_trap",
+                            Material::DoorClosed => "door_closed",
+                            Material::DoorOpen => "door_open",
+                            Material::Fence => "fence",
                         }
                         .to_string()
                     } else {
@@ -351,6 +1510,15 @@ impl Session {
         if self.config.day_night_cycle {
             self.world
                 .update_daylight(self.timing.step, self.config.day_cycle_period);
+
+            // Count a survived night when daylight returns after a night
+            let is_night = self.world.daylight < 0.5;
+            if self.was_night && !is_night {
+                self.nights_survived += 1;
+            } else if !self.was_night && is_night {
+                self.pending_sounds.push(SoundEvent::NightFalls);
+            }
+            self.was_night = is_night;
         }
 
         // Process player action
@@ -360,13 +1528,16 @@ impl Session {
             debug_events.push(event);
         }
 
+        // Events queued while processing the action (e.g. fortune drops)
+        debug_events.append(&mut self.pending_events);
+
         // Capture state after action (before life stats update)
         let (drink_after_action, food_after_action, energy_after_action) = self.world.get_player()
             .map(|p| (p.inventory.drink, p.inventory.food, p.inventory.energy))
             .unwrap_or((0, 0, 0));
 
         // Debug: log if drink changed from action (e.g., drinking water)
-        if drink_after_action != drink_before {
+        if collect_events && drink_after_action != drink_before {
             debug_events.push(format!(
                 "DRINK: {} -> {} (from action {:?})",
                 drink_before, drink_after_action, action
@@ -374,7 +1545,7 @@ impl Session {
         }
 
         // Debug: log if food changed from action (e.g., eating cow)
-        if food_after_action != food_before {
+        if collect_events && food_after_action != food_before {
             debug_events.push(format!(
                 "FOOD: {} -> {} (from action {:?})",
                 food_before, food_after_action, action
@@ -398,13 +1569,34 @@ impl Session {
             }
         }
 
+        // Burn damage-over-time from fireball hits: 1 damage every 3 ticks
+        // while the burn lasts. Burns bypass armor.
+        if let Some(player) = self.world.get_player_mut() {
+            if player.burn_ticks > 0 {
+                player.burn_ticks -= 1;
+                if player.burn_ticks % 3 == 0 {
+                    Session::apply_player_damage_with_reduction(
+                        player,
+                        DamageSource::Burn,
+                        1.0,
+                        1.0,
+                        0.0,
+                        self.config.health_enabled,
+                    );
+                    if player.sleeping {
+                        player.wake_up();
+                    }
+                }
+            }
+        }
+
         // Capture state after life stats update
         let (drink_after_stats, energy_after_stats) = self.world.get_player()
             .map(|p| (p.inventory.drink, p.inventory.energy))
             .unwrap_or((0, 0));
 
         // Debug: log if energy changed from sleeping
-        if sleeping_before && energy_after_stats != energy_after_action {
+        if collect_events && sleeping_before && energy_after_stats != energy_after_action {
             debug_events.push(format!(
                 "ENERGY (sleeping): {} -> {} (from life_stats)",
                 energy_after_action, energy_after_stats
@@ -412,7 +1604,7 @@ impl Session {
         }
 
         // Debug: log if drink changed from life stats (thirst)
-        if drink_after_stats != drink_after_action {
+        if collect_events && drink_after_stats != drink_after_action {
             debug_events.push(format!(
                 "DRINK (thirst): {} -> {} (from life_stats)",
                 drink_after_action, drink_after_stats
@@ -428,20 +1620,31 @@ impl Session {
         // Process plants
         self.process_plants();
 
+        // Process spike traps
+        self.process_traps();
+
         // Spawn/despawn mobs
         self.spawn_despawn_mobs();
 
+        // Process horde events
+        self.process_horde(&mut debug_events);
+
+        // Process the escort mission
+        self.process_escort(&mut debug_events);
+
         // Log damage taken this tick with a cause when available.
-        if let Some(player) = self.world.get_player() {
-            if player.inventory.health < health_before {
-                let cause = player
-                    .last_damage_source
-                    .map(|source| source.label())
-                    .unwrap_or("unknown");
-                debug_events.push(format!(
-                    "DAMAGE: {} -> {} (cause: {})",
-                    health_before, player.inventory.health, cause
-                ));
+        if collect_events {
+            if let Some(player) = self.world.get_player() {
+                if player.inventory.health < health_before {
+                    let cause = player
+                        .last_damage_source
+                        .map(|source| source.label())
+                        .unwrap_or("unknown");
+                    debug_events.push(format!(
+                        "DAMAGE: {} -> {} (cause: {})",
+                        health_before, player.inventory.health, cause
+                    ));
+                }
             }
         }
 
@@ -460,18 +1663,75 @@ impl Session {
         // Calculate rewards
         let (reward, newly_unlocked) = self.calculate_rewards();
 
+        let milestones = self.collect_milestones(&newly_unlocked);
+
+        let log = debug_events
+            .iter()
+            .map(|e| crate::events::LogEntry::classify(e))
+            .collect();
+
+        let truncated = done && !matches!(done_reason, Some(DoneReason::Death));
+
         StepResult {
-            state: self.get_state(),
+            state: if self.config.fast_mode {
+                self.get_state_delta()
+            } else {
+                self.get_state()
+            },
             reward,
             done,
             done_reason,
+            truncated,
             newly_unlocked,
             debug_events,
+            milestones,
+            sounds: std::mem::take(&mut self.pending_sounds),
+            log,
+            action_mask: if self.config.fast_mode {
+                Vec::new()
+            } else {
+                self.action_mask()
+            },
+        }
+    }
+
+    /// Detect structured milestones for this step; see [`MilestoneEvent`]
+    fn collect_milestones(&mut self, newly_unlocked: &[String]) -> Vec<MilestoneEvent> {
+        const LOW_HEALTH_THRESHOLD: u8 = 3;
+
+        let mut milestones = Vec::new();
+        for name in newly_unlocked {
+            match name.as_str() {
+                "collect_iron" => milestones.push(MilestoneEvent::FirstIron),
+                "collect_diamond" => milestones.push(MilestoneEvent::FirstDiamond),
+                _ => {}
+            }
+        }
+        if self.nights_survived > self.best_nights_survived {
+            self.best_nights_survived = self.nights_survived;
+            milestones.push(MilestoneEvent::NightSurvivalRecord {
+                nights: self.nights_survived,
+            });
+        }
+        if let Some(player) = self.world.get_player() {
+            let health = player.inventory.health;
+            if health > LOW_HEALTH_THRESHOLD {
+                self.low_health_warned = false;
+            } else if health > 0 && !self.low_health_warned {
+                self.low_health_warned = true;
+                milestones.push(MilestoneEvent::LowHealth { health });
+            }
         }
+        milestones
     }
 
     /// Process player action
-    fn process_player_action(&mut self, action: Action) {
+    pub(crate) fn process_player_action(&mut self, action: Action) {
+        // Actions outside the configured profile are ignored like a noop
+        if !self.config.action_profile.contains(action) {
+            return;
+        }
+
         // Wake up if sleeping and any action
         if let Some(player) = self.world.get_player_mut() {
             if player.sleeping && action != Action::Noop && action != Action::Sleep {
@@ -516,6 +1776,12 @@ impl Session {
             Action::DrinkPotionPink => self.process_drink_potion(crate::craftax::loot::PotionKind::Pink),
             Action::DrinkPotionCyan => self.process_drink_potion(crate::craftax::loot::PotionKind::Cyan),
             Action::DrinkPotionYellow => self.process_drink_potion(crate::craftax::loot::PotionKind::Yellow),
+            Action::MakeSpikeTrap => self.process_craft_spike_trap(),
+            Action::MakeDoor => self.process_craft_door(),
+            Action::MakeFence => self.process_craft_fence(),
+            Action::PlaceSpikeTrap => self.process_place(Material::SpikeTrap),
+            Action::PlaceDoor => self.process_place(Material::DoorClosed),
+            Action::PlaceFence => self.process_place(Material::Fence),
         }
     }
 
@@ -535,20 +1801,26 @@ impl Session {
                 }
             };
 
+            // A closed door opens instead of being walked through; mobs have
+            // no such handling, so doors keep them out
+            if should_move && self.world.get_material(new_pos) == Some(Material::DoorClosed) {
+                self.world.set_material(new_pos, Material::DoorOpen);
+                return;
+            }
+
             // Now check walkable and move (separate borrow)
             if should_move && self.world.is_walkable(new_pos) {
                 self.world.move_object(player_id, new_pos);
 
                 // Check for lava death (player dies instantly on lava)
                 if let Some(mat) = self.world.get_material(new_pos) {
-                    if mat.is_deadly() {
-                        if self.config.health_enabled {
+                    if mat.is_deadly()
+                        && self.config.health_enabled {
                             if let Some(player) = self.world.get_player_mut() {
                                 player.last_damage_source = Some(DamageSource::Lava);
                                 player.inventory.health = 0;
                             }
                         }
-                    }
                 }
             }
         }
@@ -618,6 +1890,7 @@ impl Session {
                 }
                 player.inventory.level = next_level;
                 player.inventory.stat_points = player.inventory.stat_points.saturating_add(1);
+                self.pending_sounds.push(SoundEvent::LevelUp);
                 if self.config.craftax.achievements_enabled {
                     player.achievements.reach_level += 1;
                 }
@@ -638,7 +1911,8 @@ impl Session {
                     player.achievements.defeat_knight_archer += 1;
                 }
                 crate::entity::CraftaxMobKind::Troll => player.achievements.defeat_troll += 1,
-                _ => {}
+                crate::entity::CraftaxMobKind::Bat => player.achievements.defeat_bat += 1,
+                crate::entity::CraftaxMobKind::Snail => player.achievements.defeat_snail += 1,
             }
         }
     }
@@ -654,8 +1928,8 @@ impl Session {
         }
         let attempts = 6;
         for _ in 0..attempts {
-            let angle: f32 = self.rng.gen::<f32>() * std::f32::consts::TAU;
-            let dist: f32 = min_dist + self.rng.gen::<f32>() * (max_dist - min_dist);
+            let angle: f32 = self.rng_streams.spawning.gen::<f32>() * std::f32::consts::TAU;
+            let dist: f32 = min_dist + self.rng_streams.spawning.gen::<f32>() * (max_dist - min_dist);
             let pos = (
                 player_pos.0 + (angle.cos() * dist) as i32,
                 player_pos.1 + (angle.sin() * dist) as i32,
@@ -674,17 +1948,33 @@ impl Session {
             None => return,
         };
 
+        if matches!(
+            obj,
+            GameObject::Cow(_)
+                | GameObject::Sheep(_)
+                | GameObject::Pig(_)
+                | GameObject::Zombie(_)
+                | GameObject::Skeleton(_)
+                | GameObject::CraftaxMob(_)
+                | GameObject::EscortKnight(_)
+        ) {
+            self.pending_sounds.push(SoundEvent::Attack);
+        }
+
         match obj {
             GameObject::Cow(mut cow) => {
                 let damage =
                     (player.attack_damage() as f32 * self.config.player_damage_mult).max(0.0)
                         as u8;
                 if !cow.take_damage(damage) {
-                    // Cow died - gives 6 food (matching Python Crafter)
+                    // Cow died - gives food (rules table: 6, matching
+                    // Python Crafter)
+                    let food = self.config.rules.cow_food;
                     self.world.remove_object(obj_id);
                     if let Some(p) = self.world.get_player_mut() {
-                        p.inventory.add_food(6);
+                        p.inventory.add_food(food);
                         p.achievements.eat_cow += 1;
+                        self.pending_sounds.push(SoundEvent::Eat);
                     }
                 } else {
                     // Update cow health
@@ -693,6 +1983,44 @@ impl Session {
                     }
                 }
             }
+            GameObject::Sheep(mut sheep) => {
+                let damage =
+                    (player.attack_damage() as f32 * self.config.player_damage_mult).max(0.0)
+                        as u8;
+                if !sheep.take_damage(damage) {
+                    // Sheep died - drops wool plus a little food
+                    let food = self.config.rules.sheep_food;
+                    self.world.remove_object(obj_id);
+                    if let Some(p) = self.world.get_player_mut() {
+                        p.inventory.add_wool(1);
+                        p.inventory.add_food(food);
+                        p.achievements.collect_wool += 1;
+                    }
+                } else {
+                    if let Some(GameObject::Sheep(s)) = self.world.get_object_mut(obj_id) {
+                        s.health = sheep.health;
+                    }
+                }
+            }
+            GameObject::Pig(mut pig) => {
+                let damage =
+                    (player.attack_damage() as f32 * self.config.player_damage_mult).max(0.0)
+                        as u8;
+                if !pig.take_damage(damage) {
+                    // Pig died - gives more food than a cow
+                    let food = self.config.rules.pig_food;
+                    self.world.remove_object(obj_id);
+                    if let Some(p) = self.world.get_player_mut() {
+                        p.inventory.add_food(food);
+                        p.achievements.eat_pig += 1;
+                        self.pending_sounds.push(SoundEvent::Eat);
+                    }
+                } else {
+                    if let Some(GameObject::Pig(pg)) = self.world.get_object_mut(obj_id) {
+                        pg.health = pig.health;
+                    }
+                }
+            }
             GameObject::Zombie(mut zombie) => {
                 let damage =
                     (player.attack_damage() as f32 * self.config.player_damage_mult).max(0.0)
@@ -733,6 +2061,7 @@ impl Session {
                 let damage =
                     (player.attack_damage() as f32 * self.config.player_damage_mult).max(0.0)
                         as u8;
+                let damage = crate::craftax::mobs::stats(mob.kind).absorb(damage);
                 if !mob.take_damage(damage) {
                     self.world.remove_object(obj_id);
                     self.grant_xp(3);
@@ -741,20 +2070,112 @@ impl Session {
                     m.health = mob.health;
                 }
             }
-            GameObject::Plant(plant) => {
-                if plant.is_ripe() {
-                    // Ripe plant gives 4 food (matching Python Crafter)
+            GameObject::EscortKnight(mut knight) => {
+                // Friendly fire: the knight can be hurt, but killing it just
+                // fails the escort mission
+                let damage =
+                    (player.attack_damage() as f32 * self.config.player_damage_mult).max(0.0)
+                        as u8;
+                if !knight.take_damage(damage) {
+                    self.world.remove_object(obj_id);
+                } else if let Some(GameObject::EscortKnight(k)) =
+                    self.world.get_object_mut(obj_id)
+                {
+                    k.health = knight.health;
+                }
+            }
+            GameObject::Plant(plant)
+                if plant.is_ripe() => {
+                    // Ripe plant gives food (rules table: 4, matching
+                    // Python Crafter)
+                    let food = self.config.rules.plant_food;
                     self.world.remove_object(obj_id);
                     if let Some(p) = self.world.get_player_mut() {
-                        p.inventory.add_food(4);
+                        p.inventory.add_food(food);
                         p.achievements.eat_plant += 1;
+                        self.pending_sounds.push(SoundEvent::Eat);
                     }
                 }
-            }
             _ => {}
         }
     }
 
+    /// Land one mining hit on a tile. Returns true when the tile breaks:
+    /// always when multi-hit mining is disabled, otherwise once enough hits
+    /// have accumulated for the material's hardness. Pickaxe tiers above the
+    /// material's requirement shave hits off, so better tools mine faster
+    /// rather than just unlocking more ores.
+    fn mining_hit_breaks(&mut self, pos: Position, mat: Material, pickaxe_tier: u8) -> bool {
+        if !self.config.mining.enabled {
+            self.pending_sounds.push(SoundEvent::Mine);
+            return true;
+        }
+        let required = mat.required_pickaxe_tier().unwrap_or(0);
+        let spare_tiers = pickaxe_tier.saturating_sub(required);
+        let bonus = spare_tiers.saturating_mul(self.config.mining.tier_bonus);
+        let hardness = (mat.hardness() as f32 * self.config.mining.hardness_mult).round() as u8;
+        let needed = hardness.saturating_sub(bonus).max(1);
+        // Progress is cleared by set_material when the tile finally changes
+        let breaks = self.world.add_mining_progress(pos) >= needed;
+        if breaks {
+            self.pending_sounds.push(SoundEvent::Mine);
+        }
+        breaks
+    }
+
+    /// When the carry limit is on, check whether another pickup would push
+    /// the player past the cap. A blocked pickup leaves the tile untouched
+    /// and queues an event so agents can tell why `Do` did nothing.
+    fn pickup_blocked_by_capacity(&mut self) -> bool {
+        if !self.config.carry.enabled {
+            return false;
+        }
+        let total = self
+            .world
+            .get_player()
+            .map(|p| p.inventory.total_carried())
+            .unwrap_or(0);
+        if total >= self.config.carry.max_total_items {
+            self.pending_events
+                .push("CAPACITY: carrying too much, pickup failed".to_string());
+            true
+        } else {
+            false
+        }
+    }
+
+    /// Roll for a fortune bonus unit. Each pickaxe tier above the material's
+    /// required tier adds the per-material chance; a hit queues a debug event
+    /// and yields one extra unit on top of the normal drop.
+    fn roll_fortune_bonus(&mut self, mat: Material, pickaxe_tier: u8) -> u8 {
+        if !self.config.fortune.enabled {
+            return 0;
+        }
+        let required = mat.required_pickaxe_tier().unwrap_or(0);
+        let spare_tiers = pickaxe_tier.saturating_sub(required);
+        if spare_tiers == 0 {
+            return 0;
+        }
+        let (per_tier, name) = match mat {
+            Material::Tree => (self.config.fortune.wood_chance, "wood"),
+            Material::Stone => (self.config.fortune.stone_chance, "stone"),
+            Material::Coal => (self.config.fortune.coal_chance, "coal"),
+            Material::Iron => (self.config.fortune.iron_chance, "iron"),
+            Material::Diamond => (self.config.fortune.diamond_chance, "diamond"),
+            Material::Sapphire => (self.config.fortune.sapphire_chance, "sapphire"),
+            Material::Ruby => (self.config.fortune.ruby_chance, "ruby"),
+            _ => return 0,
+        };
+        let chance = (per_tier * spare_tiers as f32).min(1.0);
+        if chance > 0.0 && self.rng_streams.loot.gen::<f32>() < chance {
+            self.pending_events
+                .push(format!("FORTUNE: rich vein yielded bonus {}", name));
+            1
+        } else {
+            0
+        }
+    }
+
     /// Interact with terrain
     fn interact_with_terrain(
         &mut self,
@@ -764,60 +2185,115 @@ impl Session {
     ) {
         match mat {
             Material::Tree => {
+                if self.pickup_blocked_by_capacity() {
+                    return;
+                }
+                if !self.mining_hit_breaks(pos, mat, player.inventory.best_pickaxe_tier()) {
+                    return;
+                }
                 // Python Crafter: trees only give wood (1), NOT saplings
                 // Saplings come from grass with 10% probability
                 self.world.set_material(pos, Material::Grass);
+                let bonus = self.roll_fortune_bonus(mat, player.inventory.best_pickaxe_tier());
                 if let Some(p) = self.world.get_player_mut() {
-                    p.inventory.add_wood(1);
-                    p.achievements.collect_wood += 1;
+                    p.inventory.add_wood(1 + bonus);
+                    p.achievements.collect_wood += 1 + bonus as u32;
                 }
             }
-            Material::Stone => {
-                if player.inventory.best_pickaxe_tier() >= 1 {
+            Material::Stone
+                if player.inventory.best_pickaxe_tier() >= 1 => {
+                    if self.pickup_blocked_by_capacity() {
+                        return;
+                    }
+                    if !self.mining_hit_breaks(pos, mat, player.inventory.best_pickaxe_tier()) {
+                        return;
+                    }
                     self.world.set_material(pos, Material::Path);
+                    let bonus = self.roll_fortune_bonus(mat, player.inventory.best_pickaxe_tier());
                     if let Some(p) = self.world.get_player_mut() {
-                        p.inventory.add_stone(1);
-                        p.achievements.collect_stone += 1;
+                        p.inventory.add_stone(1 + bonus);
+                        p.achievements.collect_stone += 1 + bonus as u32;
                     }
                 }
-            }
-            Material::Coal => {
-                if player.inventory.best_pickaxe_tier() >= 1 {
+            Material::Coal
+                if player.inventory.best_pickaxe_tier() >= 1 => {
+                    if self.pickup_blocked_by_capacity() {
+                        return;
+                    }
+                    if !self.mining_hit_breaks(pos, mat, player.inventory.best_pickaxe_tier()) {
+                        return;
+                    }
                     self.world.set_material(pos, Material::Path);
+                    let bonus = self.roll_fortune_bonus(mat, player.inventory.best_pickaxe_tier());
                     if let Some(p) = self.world.get_player_mut() {
-                        p.inventory.add_coal(1);
-                        p.achievements.collect_coal += 1;
+                        p.inventory.add_coal(1 + bonus);
+                        p.achievements.collect_coal += 1 + bonus as u32;
                     }
                 }
-            }
-            Material::Iron => {
-                if player.inventory.best_pickaxe_tier() >= 2 {
+            Material::Iron
+                if player.inventory.best_pickaxe_tier() >= 2 => {
+                    if self.pickup_blocked_by_capacity() {
+                        return;
+                    }
+                    if !self.mining_hit_breaks(pos, mat, player.inventory.best_pickaxe_tier()) {
+                        return;
+                    }
                     self.world.set_material(pos, Material::Path);
+                    let bonus = self.roll_fortune_bonus(mat, player.inventory.best_pickaxe_tier());
                     if let Some(p) = self.world.get_player_mut() {
-                        p.inventory.add_iron(1);
-                        p.achievements.collect_iron += 1;
+                        p.inventory.add_iron(1 + bonus);
+                        p.achievements.collect_iron += 1 + bonus as u32;
                     }
                 }
-            }
-            Material::Diamond => {
-                if player.inventory.best_pickaxe_tier() >= 3 {
+            Material::Diamond
+                if player.inventory.best_pickaxe_tier() >= 3 => {
+                    if self.pickup_blocked_by_capacity() {
+                        return;
+                    }
+                    if !self.mining_hit_breaks(pos, mat, player.inventory.best_pickaxe_tier()) {
+                        return;
+                    }
                     self.world.set_material(pos, Material::Path);
+                    let bonus = self.roll_fortune_bonus(mat, player.inventory.best_pickaxe_tier());
                     if let Some(p) = self.world.get_player_mut() {
-                        p.inventory.add_diamond(1);
-                        p.achievements.collect_diamond += 1;
+                        p.inventory.add_diamond(1 + bonus);
+                        p.achievements.collect_diamond += 1 + bonus as u32;
                     }
                 }
+            Material::DoorClosed => {
+                self.world.set_material(pos, Material::DoorOpen);
+            }
+            // Only close an empty doorway
+            Material::DoorOpen if self.world.get_object_at(pos).is_none() => {
+                self.world.set_material(pos, Material::DoorClosed);
+            }
+            Material::Fence => {
+                if self.pickup_blocked_by_capacity() {
+                    return;
+                }
+                // Fences come back as items rather than raw wood
+                self.world.set_material(pos, Material::Grass);
+                if let Some(p) = self.world.get_player_mut() {
+                    p.inventory.add_fence(1);
+                }
             }
             Material::Sapphire => {
                 if !self.config.craftax.enabled || !self.config.craftax.items_enabled {
                     return;
                 }
                 if player.inventory.best_pickaxe_tier() >= 4 {
+                    if self.pickup_blocked_by_capacity() {
+                        return;
+                    }
+                    if !self.mining_hit_breaks(pos, mat, player.inventory.best_pickaxe_tier()) {
+                        return;
+                    }
                     self.world.set_material(pos, Material::Path);
+                    let bonus = self.roll_fortune_bonus(mat, player.inventory.best_pickaxe_tier());
                     if let Some(p) = self.world.get_player_mut() {
-                        p.inventory.add_sapphire(1);
+                        p.inventory.add_sapphire(1 + bonus);
                         if self.config.craftax.achievements_enabled {
-                            p.achievements.collect_sapphire += 1;
+                            p.achievements.collect_sapphire += 1 + bonus as u32;
                         }
                     }
                 }
@@ -827,11 +2303,18 @@ impl Session {
                     return;
                 }
                 if player.inventory.best_pickaxe_tier() >= 4 {
+                    if self.pickup_blocked_by_capacity() {
+                        return;
+                    }
+                    if !self.mining_hit_breaks(pos, mat, player.inventory.best_pickaxe_tier()) {
+                        return;
+                    }
                     self.world.set_material(pos, Material::Path);
+                    let bonus = self.roll_fortune_bonus(mat, player.inventory.best_pickaxe_tier());
                     if let Some(p) = self.world.get_player_mut() {
-                        p.inventory.add_ruby(1);
+                        p.inventory.add_ruby(1 + bonus);
                         if self.config.craftax.achievements_enabled {
-                            p.achievements.collect_ruby += 1;
+                            p.achievements.collect_ruby += 1 + bonus as u32;
                         }
                     }
                 }
@@ -844,7 +2327,7 @@ impl Session {
                     return;
                 }
                 self.world.set_material(pos, Material::Path);
-                let loot = crate::craftax::loot::roll_chest_loot(&mut self.rng, &self.config.craftax.loot);
+                let loot = crate::craftax::loot::roll_chest_loot(&mut self.rng_streams.loot, &self.config.craftax.loot);
                 if let Some(p) = self.world.get_player_mut() {
                     if self.config.craftax.achievements_enabled {
                         p.achievements.open_chest += 1;
@@ -904,15 +2387,17 @@ impl Session {
                     p.achievements.collect_drink += 1;
                 }
             }
-            Material::Grass => {
+            Material::Grass
                 // 10% chance to get sapling from grass (matching Python Crafter)
-                if self.rng.gen::<f32>() < 0.1 {
+                if self.rng_streams.loot.gen::<f32>() < 0.1 => {
+                    if self.pickup_blocked_by_capacity() {
+                        return;
+                    }
                     if let Some(p) = self.world.get_player_mut() {
                         p.inventory.add_sapling(1);
                         p.achievements.collect_sapling += 1;
                     }
                 }
-            }
             _ => {}
         }
     }
@@ -941,30 +2426,24 @@ impl Session {
         let should_place = {
             if let Some(p) = self.world.get_player_mut() {
                 match mat {
-                    Material::Stone => {
-                        if p.inventory.use_stone() {
+                    Material::Stone
+                        if p.inventory.use_stone() => {
                             p.achievements.place_stone += 1;
                             true
-                        } else {
-                            false
                         }
-                    }
-                    Material::Table => {
-                        if p.inventory.use_wood_for_table() {
+                    Material::Table
+                        if p.inventory.use_wood_for_table() => {
                             p.achievements.place_table += 1;
                             true
-                        } else {
-                            false
                         }
-                    }
-                    Material::Furnace => {
-                        if p.inventory.use_stone_for_furnace() {
+                    Material::Furnace
+                        if p.inventory.use_stone_for_furnace() => {
                             p.achievements.place_furnace += 1;
                             true
-                        } else {
-                            false
                         }
-                    }
+                    Material::SpikeTrap if p.inventory.use_spike_trap() => true,
+                    Material::DoorClosed if p.inventory.use_door() => true,
+                    Material::Fence if p.inventory.use_fence() => true,
                     _ => false,
                 }
             } else {
@@ -1025,8 +2504,9 @@ impl Session {
             return;
         }
 
+        let cost = self.recipes.wood_pickaxe;
         if let Some(p) = self.world.get_player_mut() {
-            if p.inventory.craft_wood_pickaxe() {
+            if p.inventory.craft_wood_pickaxe_with(&cost) {
                 p.achievements.make_wood_pickaxe += 1;
             }
         }
@@ -1042,8 +2522,9 @@ impl Session {
             return;
         }
 
+        let cost = self.recipes.stone_pickaxe;
         if let Some(p) = self.world.get_player_mut() {
-            if p.inventory.craft_stone_pickaxe() {
+            if p.inventory.craft_stone_pickaxe_with(&cost) {
                 p.achievements.make_stone_pickaxe += 1;
             }
         }
@@ -1062,8 +2543,9 @@ impl Session {
             return;
         }
 
+        let cost = self.recipes.iron_pickaxe;
         if let Some(p) = self.world.get_player_mut() {
-            if p.inventory.craft_iron_pickaxe() {
+            if p.inventory.craft_iron_pickaxe_with(&cost) {
                 p.achievements.make_iron_pickaxe += 1;
             }
         }
@@ -1079,8 +2561,9 @@ impl Session {
             return;
         }
 
+        let cost = self.recipes.wood_sword;
         if let Some(p) = self.world.get_player_mut() {
-            if p.inventory.craft_wood_sword() {
+            if p.inventory.craft_wood_sword_with(&cost) {
                 p.achievements.make_wood_sword += 1;
             }
         }
@@ -1096,8 +2579,9 @@ impl Session {
             return;
         }
 
+        let cost = self.recipes.stone_sword;
         if let Some(p) = self.world.get_player_mut() {
-            if p.inventory.craft_stone_sword() {
+            if p.inventory.craft_stone_sword_with(&cost) {
                 p.achievements.make_stone_sword += 1;
             }
         }
@@ -1116,8 +2600,9 @@ impl Session {
             return;
         }
 
+        let cost = self.recipes.iron_sword;
         if let Some(p) = self.world.get_player_mut() {
-            if p.inventory.craft_iron_sword() {
+            if p.inventory.craft_iron_sword_with(&cost) {
                 p.achievements.make_iron_sword += 1;
             }
         }
@@ -1137,11 +2622,10 @@ impl Session {
         }
 
         if let Some(p) = self.world.get_player_mut() {
-            if p.inventory.craft_diamond_pickaxe() {
-                if self.config.craftax.achievements_enabled {
+            if p.inventory.craft_diamond_pickaxe()
+                && self.config.craftax.achievements_enabled {
                     p.achievements.make_diamond_pickaxe += 1;
                 }
-            }
         }
     }
 
@@ -1159,11 +2643,10 @@ impl Session {
         }
 
         if let Some(p) = self.world.get_player_mut() {
-            if p.inventory.craft_diamond_sword() {
-                if self.config.craftax.achievements_enabled {
+            if p.inventory.craft_diamond_sword()
+                && self.config.craftax.achievements_enabled {
                     p.achievements.make_diamond_sword += 1;
                 }
-            }
         }
     }
 
@@ -1184,11 +2667,10 @@ impl Session {
         }
 
         if let Some(p) = self.world.get_player_mut() {
-            if p.inventory.craft_iron_armor() {
-                if self.config.craftax.achievements_enabled {
+            if p.inventory.craft_iron_armor()
+                && self.config.craftax.achievements_enabled {
                     p.achievements.make_iron_armor += 1;
                 }
-            }
         }
     }
 
@@ -1206,11 +2688,10 @@ impl Session {
         }
 
         if let Some(p) = self.world.get_player_mut() {
-            if p.inventory.craft_diamond_armor() {
-                if self.config.craftax.achievements_enabled {
+            if p.inventory.craft_diamond_armor()
+                && self.config.craftax.achievements_enabled {
                     p.achievements.make_diamond_armor += 1;
                 }
-            }
         }
     }
 
@@ -1228,11 +2709,10 @@ impl Session {
         }
 
         if let Some(p) = self.world.get_player_mut() {
-            if p.inventory.craft_bow() {
-                if self.config.craftax.achievements_enabled {
+            if p.inventory.craft_bow()
+                && self.config.craftax.achievements_enabled {
                     p.achievements.make_bow += 1;
                 }
-            }
         }
     }
 
@@ -1250,11 +2730,55 @@ impl Session {
         }
 
         if let Some(p) = self.world.get_player_mut() {
-            if p.inventory.craft_arrow() {
-                if self.config.craftax.achievements_enabled {
+            if p.inventory.craft_arrow()
+                && self.config.craftax.achievements_enabled {
                     p.achievements.make_arrow += 1;
                 }
-            }
+        }
+    }
+
+    fn process_craft_spike_trap(&mut self) {
+        let has_table = self
+            .world
+            .get_player()
+            .map(|p| self.world.has_adjacent_table(p.pos))
+            .unwrap_or(false);
+        if !has_table {
+            return;
+        }
+
+        if let Some(p) = self.world.get_player_mut() {
+            p.inventory.craft_spike_trap();
+        }
+    }
+
+    fn process_craft_door(&mut self) {
+        let has_table = self
+            .world
+            .get_player()
+            .map(|p| self.world.has_adjacent_table(p.pos))
+            .unwrap_or(false);
+        if !has_table {
+            return;
+        }
+
+        if let Some(p) = self.world.get_player_mut() {
+            p.inventory.craft_door();
+        }
+    }
+
+    fn process_craft_fence(&mut self) {
+        let has_table = self
+            .world
+            .get_player()
+            .map(|p| self.world.has_adjacent_table(p.pos))
+            .unwrap_or(false);
+        if !has_table {
+            return;
+        }
+
+        if let Some(p) = self.world.get_player_mut() {
+            p.inventory.craft_fence();
         }
     }
 
@@ -1275,10 +2799,14 @@ impl Session {
             return;
         }
 
+        let achievements_enabled = self.config.craftax.achievements_enabled;
         if let Some(p) = self.world.get_player_mut() {
             if p.inventory.arrows > 0 {
                 p.inventory.arrows -= 1;
             }
+            if achievements_enabled {
+                p.achievements.fire_bow += 1;
+            }
         }
 
         let arrow_pos = (
@@ -1342,11 +2870,10 @@ impl Session {
                 }
                 _ => false,
             };
-            if consumed {
-                if self.config.craftax.achievements_enabled {
+            if consumed
+                && self.config.craftax.achievements_enabled {
                     p.achievements.drink_potion += 1;
                 }
-            }
         }
     }
 
@@ -1364,6 +2891,8 @@ impl Session {
                 if matches!(
                     obj,
                     GameObject::Cow(_)
+                        | GameObject::Sheep(_)
+                        | GameObject::Pig(_)
                         | GameObject::Zombie(_)
                         | GameObject::Skeleton(_)
                         | GameObject::CraftaxMob(_)
@@ -1383,7 +2912,15 @@ impl Session {
 
             match obj {
                 GameObject::Cow(cow) => {
-                    self.process_cow_ai(id, cow);
+                    self.process_passive_wander(id, cow.pos, 0.5);
+                }
+                GameObject::Sheep(sheep) => {
+                    let move_chance = self.config.wildlife.sheep_move_chance;
+                    self.process_passive_wander(id, sheep.pos, move_chance);
+                }
+                GameObject::Pig(pig) => {
+                    let move_chance = self.config.wildlife.pig_move_chance;
+                    self.process_passive_wander(id, pig.pos, move_chance);
                 }
                 GameObject::Zombie(zombie) => {
                     if let Some(player_pos) = player_pos {
@@ -1408,15 +2945,21 @@ impl Session {
         }
     }
 
-    /// Cow AI - random movement
-    fn process_cow_ai(&mut self, id: u32, cow: crate::entity::Cow) {
-        if self.rng.gen::<f32>() < 0.5 {
+    /// Passive animal AI - random wandering with a per-species move chance
+    fn process_passive_wander(&mut self, id: u32, pos: Position, move_chance: f32) {
+        if self.rng_streams.mob_ai.gen::<f32>() >= move_chance {
             return; // Don't move every tick
         }
 
         let directions = [(0, 1), (0, -1), (1, 0), (-1, 0)];
-        let dir = directions[self.rng.gen_range(0..4)];
-        let new_pos = (cow.pos.0 + dir.0, cow.pos.1 + dir.1);
+        let dir = directions[self.rng_streams.mob_ai.gen_range(0..4)];
+        let new_pos = (pos.0 + dir.0, pos.1 + dir.1);
+
+        // Wildlife never wanders onto spike traps; hostiles chasing the
+        // player can still blunder into them
+        if self.world.get_material(new_pos) == Some(Material::SpikeTrap) {
+            return;
+        }
 
         if self.world.is_walkable(new_pos) && self.world.get_object_at(new_pos).is_none() {
             self.world.move_object(id, new_pos);
@@ -1438,9 +2981,9 @@ impl Session {
             (zombie.pos.0 - player_pos.0).abs() + (zombie.pos.1 - player_pos.1).abs();
 
         // First: movement (chase or random)
-        if dist <= 8 && self.rng.gen::<f32>() < 0.9 {
+        if dist <= 8 && self.rng_streams.mob_ai.gen::<f32>() < 0.9 {
             // Move towards player (80% long-axis, 20% short-axis like Python)
-            let long_axis = self.rng.gen::<f32>() < 0.8;
+            let long_axis = self.rng_streams.mob_ai.gen::<f32>() < 0.8;
             let (dx, dy) = self.toward_direction(zombie.pos, player_pos, long_axis);
 
             let new_pos = (zombie.pos.0 + dx, zombie.pos.1 + dy);
@@ -1450,7 +2993,7 @@ impl Session {
         } else {
             // Random movement when not chasing (matching Python Crafter)
             let directions = [(0, 1), (0, -1), (1, 0), (-1, 0)];
-            let dir = directions[self.rng.gen_range(0..4)];
+            let dir = directions[self.rng_streams.mob_ai.gen_range(0..4)];
             let new_pos = (zombie.pos.0 + dir.0, zombie.pos.1 + dir.1);
             if self.world.is_walkable(new_pos) && self.world.get_object_at(new_pos).is_none() {
                 self.world.move_object(id, new_pos);
@@ -1468,8 +3011,13 @@ impl Session {
             if zombie.cooldown > 0 {
                 zombie.cooldown -= 1;
             } else {
-                let base_damage = 2.0 * self.config.zombie_damage_mult;
-                let sleep_mult = if player_sleeping { 3.5 } else { 1.0 };
+                let base_damage =
+                    self.config.rules.zombie_base_damage * self.config.zombie_damage_mult;
+                let sleep_mult = if player_sleeping {
+                    self.config.rules.zombie_sleep_multiplier
+                } else {
+                    1.0
+                };
 
                 if let Some(player) = self.world.get_player_mut() {
                     let reduction = if self.config.craftax.enabled && self.config.craftax.combat_enabled {
@@ -1535,7 +3083,7 @@ impl Session {
         // Priority 1: Retreat if player too close (dist <= 3)
         if dist <= 3 {
             // Calculate direction away from player (60% long-axis, 40% short-axis)
-            let long_axis = self.rng.gen::<f32>() < 0.6;
+            let long_axis = self.rng_streams.mob_ai.gen::<f32>() < 0.6;
             let (dx, dy) = self.toward_direction(skeleton.pos, player_pos, long_axis);
             let (dx, dy) = (-dx, -dy);
 
@@ -1551,7 +3099,7 @@ impl Session {
         }
 
         // Priority 2: Shoot at 50% probability when in range (dist <= 5)
-        if dist <= 5 && skeleton.can_shoot() && self.rng.gen::<f32>() < 0.5 {
+        if dist <= 5 && skeleton.can_shoot() && self.rng_streams.mob_ai.gen::<f32>() < 0.5 {
             let (dx, dy) = self.toward_direction(skeleton.pos, player_pos, true);
             let dx = dx as i8;
             let dy = dy as i8;
@@ -1571,19 +3119,19 @@ impl Session {
             )));
             skeleton.reset_reload();
         // Priority 3: Chase at 30% probability when in range (dist <= 8)
-        } else if dist <= 8 && self.rng.gen::<f32>() < 0.3 {
+        } else if dist <= 8 && self.rng_streams.mob_ai.gen::<f32>() < 0.3 {
             // Move toward player (60% long-axis, 40% short-axis)
-            let long_axis = self.rng.gen::<f32>() < 0.6;
+            let long_axis = self.rng_streams.mob_ai.gen::<f32>() < 0.6;
             let (dx, dy) = self.toward_direction(skeleton.pos, player_pos, long_axis);
 
             let new_pos = (skeleton.pos.0 + dx, skeleton.pos.1 + dy);
             if self.world.is_walkable(new_pos) && self.world.get_object_at(new_pos).is_none() {
                 self.world.move_object(id, new_pos);
             }
-        } else if self.rng.gen::<f32>() < 0.2 {
+        } else if self.rng_streams.mob_ai.gen::<f32>() < 0.2 {
             // Random movement when idle (matching Python Crafter)
             let directions = [(0, 1), (0, -1), (1, 0), (-1, 0)];
-            let dir = directions[self.rng.gen_range(0..4)];
+            let dir = directions[self.rng_streams.mob_ai.gen_range(0..4)];
             let new_pos = (skeleton.pos.0 + dir.0, skeleton.pos.1 + dir.1);
             if self.world.is_walkable(new_pos) && self.world.get_object_at(new_pos).is_none() {
                 self.world.move_object(id, new_pos);
@@ -1612,23 +3160,49 @@ impl Session {
         }
 
         if mob.is_passive() {
-            let move_chance = match mob.kind {
-                crate::entity::CraftaxMobKind::Bat => 0.6,
-                crate::entity::CraftaxMobKind::Snail => 0.3,
-                _ => 0.4,
-            };
-            if self.rng.gen::<f32>() < move_chance {
-                let directions = [(0, 1), (0, -1), (1, 0), (-1, 0)];
-                let dir = directions[self.rng.gen_range(0..4)];
-                let new_pos = (mob.pos.0 + dir.0, mob.pos.1 + dir.1);
-                let walkable = match mob.kind {
-                    crate::entity::CraftaxMobKind::Bat => {
-                        self.world.in_bounds(new_pos) && self.world.get_object_at(new_pos).is_none()
-                    }
-                    _ => self.world.is_walkable(new_pos) && self.world.get_object_at(new_pos).is_none(),
-                };
-                if walkable {
-                    self.world.move_object(id, new_pos);
+            match mob.kind {
+                // Erratic flight: bats move often, occasionally swoop two
+                // tiles, and ignore terrain entirely - they just won't land
+                // on lava or another object.
+                crate::entity::CraftaxMobKind::Bat => {
+                    if self.rng_streams.mob_ai.gen::<f32>() < 0.7 {
+                        let directions = [(0, 1), (0, -1), (1, 0), (-1, 0)];
+                        let dir = directions[self.rng_streams.mob_ai.gen_range(0..4)];
+                        let step = if self.rng_streams.mob_ai.gen::<f32>() < 0.3 { 2 } else { 1 };
+                        let new_pos = (mob.pos.0 + dir.0 * step, mob.pos.1 + dir.1 * step);
+                        let can_land = self.world.in_bounds(new_pos)
+                            && self.world.get_material(new_pos) != Some(Material::Lava)
+                            && self.world.get_object_at(new_pos).is_none();
+                        if can_land {
+                            self.world.move_object(id, new_pos);
+                        }
+                    }
+                }
+                // Snails creep along the ground and rarely bother moving;
+                // their shell armor is applied when they take damage.
+                crate::entity::CraftaxMobKind::Snail => {
+                    if self.rng_streams.mob_ai.gen::<f32>() < 0.2 {
+                        let directions = [(0, 1), (0, -1), (1, 0), (-1, 0)];
+                        let dir = directions[self.rng_streams.mob_ai.gen_range(0..4)];
+                        let new_pos = (mob.pos.0 + dir.0, mob.pos.1 + dir.1);
+                        if self.world.is_walkable(new_pos)
+                            && self.world.get_object_at(new_pos).is_none()
+                        {
+                            self.world.move_object(id, new_pos);
+                        }
+                    }
+                }
+                _ => {
+                    if self.rng_streams.mob_ai.gen::<f32>() < 0.4 {
+                        let directions = [(0, 1), (0, -1), (1, 0), (-1, 0)];
+                        let dir = directions[self.rng_streams.mob_ai.gen_range(0..4)];
+                        let new_pos = (mob.pos.0 + dir.0, mob.pos.1 + dir.1);
+                        if self.world.is_walkable(new_pos)
+                            && self.world.get_object_at(new_pos).is_none()
+                        {
+                            self.world.move_object(id, new_pos);
+                        }
+                    }
                 }
             }
         } else {
@@ -1642,12 +3216,14 @@ impl Session {
                         crate::entity::ProjectileKind::Fireball
                         | crate::entity::ProjectileKind::Iceball => DamageSource::CraftaxMagic,
                     };
-                    let arrow = Arrow::with_stats(
+                    let arrow = Arrow::with_flight(
                         arrow_pos,
                         (dx as i8, dy as i8),
                         stats.projectile,
                         stats.ranged_damage,
                         source,
+                        stats.projectile_speed,
+                        stats.projectile_pierce,
                     );
                     self.world.add_object(GameObject::Arrow(arrow));
                     mob.cooldown = stats.cooldown;
@@ -1681,8 +3257,8 @@ impl Session {
 
             if !attacked {
                 let flee = stats.is_ranged() && dist <= 2;
-                let move_toward = dist <= 8 && self.rng.gen::<f32>() < 0.6;
-                let move_random = self.rng.gen::<f32>() < 0.2;
+                let move_toward = dist <= 8 && self.rng_streams.mob_ai.gen::<f32>() < 0.6;
+                let move_random = self.rng_streams.mob_ai.gen::<f32>() < 0.2;
                 let (dx, dy) = if flee {
                     let (dx, dy) = self.toward_direction(mob.pos, player_pos, true);
                     (-dx, -dy)
@@ -1690,7 +3266,7 @@ impl Session {
                     self.toward_direction(mob.pos, player_pos, true)
                 } else if move_random {
                     let directions = [(0, 1), (0, -1), (1, 0), (-1, 0)];
-                    directions[self.rng.gen_range(0..4)]
+                    directions[self.rng_streams.mob_ai.gen_range(0..4)]
                 } else {
                     (0, 0)
                 };
@@ -1714,6 +3290,9 @@ impl Session {
     /// - Arrows deal 2 damage to any object they hit (player, cow, zombie, skeleton)
     /// - Arrows destroy Table/Furnace, converting them to path
     /// - Arrows can travel through water and lava
+    ///
+    /// Projectiles with `speed > 1` advance multiple tiles per tick, checking
+    /// for hits at every tile along the way.
     fn process_arrows(&mut self) {
         let arrow_ids: Vec<u32> = self
             .world
@@ -1729,136 +3308,184 @@ impl Session {
             .collect();
 
         for id in arrow_ids {
-            let arrow = match self.world.get_object(id) {
-                Some(GameObject::Arrow(a)) => a.clone(),
+            let speed = match self.world.get_object(id) {
+                Some(GameObject::Arrow(a)) => a.speed.max(1),
                 _ => continue,
             };
-
-            let next_pos = arrow.next_position();
-
-            // Check if arrow hits player
-            if let Some(player) = self.world.get_player() {
-                if next_pos == player.pos {
-                    if let Some(p) = self.world.get_player_mut() {
-                        let reduction = if self.config.craftax.enabled && self.config.craftax.combat_enabled {
-                            p.inventory.armor_reduction()
-                        } else {
-                            0.0
-                        };
-                        Session::apply_player_damage_with_reduction(
-                            p,
-                            arrow.source,
-                            arrow.damage as f32,
-                            1.0,
-                            reduction,
-                            self.config.health_enabled,
-                        );
-                        if p.sleeping {
-                            p.wake_up();
-                        }
-                    }
-                    self.world.remove_object(id);
-                    continue;
+            for _ in 0..speed {
+                if !self.advance_arrow(id) {
+                    break;
                 }
             }
+        }
+    }
 
-            // Check if arrow hits a mob (matching Python Crafter: arrows damage any object)
-            if let Some(target_id) = self.world.get_object_id_at(next_pos) {
-                let mut remove_target = false;
-                let arrow_damage = arrow.damage;
-                let mut grant_xp_amount: Option<u32> = None;
-                let mut craftax_kill: Option<crate::entity::CraftaxMobKind> = None;
+    /// Advance an arrow by one tile, resolving any hit. Returns false once
+    /// the arrow has been removed from the world.
+    fn advance_arrow(&mut self, id: u32) -> bool {
+        let arrow = match self.world.get_object(id) {
+            Some(GameObject::Arrow(a)) => a.clone(),
+            _ => return false,
+        };
 
-                if let Some(obj) = self.world.get_object_mut(target_id) {
-                    match obj {
-                        GameObject::Cow(cow) => {
-                            if cow.health > arrow_damage {
-                                cow.health -= arrow_damage;
-                            } else {
-                                remove_target = true;
-                            }
+        let next_pos = arrow.next_position();
+
+        // Check if arrow hits player
+        if let Some(player) = self.world.get_player() {
+            if next_pos == player.pos {
+                if let Some(p) = self.world.get_player_mut() {
+                    let reduction = if self.config.craftax.enabled && self.config.craftax.combat_enabled {
+                        p.inventory.armor_reduction()
+                    } else {
+                        0.0
+                    };
+                    Session::apply_player_damage_with_reduction(
+                        p,
+                        arrow.source,
+                        arrow.damage as f32,
+                        1.0,
+                        reduction,
+                        self.config.health_enabled,
+                    );
+                    // Fireballs set the player on fire for a few ticks
+                    if matches!(arrow.kind, crate::entity::ProjectileKind::Fireball) {
+                        p.burn_ticks = 6;
+                    }
+                    if p.sleeping {
+                        p.wake_up();
+                    }
+                }
+                self.world.remove_object(id);
+                return false;
+            }
+        }
+
+        // Check if arrow hits a mob (matching Python Crafter: arrows damage any object)
+        if let Some(target_id) = self.world.get_object_id_at(next_pos) {
+            let mut remove_target = false;
+            let arrow_damage = arrow.damage;
+            let mut grant_xp_amount: Option<u32> = None;
+            let mut craftax_kill: Option<crate::entity::CraftaxMobKind> = None;
+
+            if let Some(obj) = self.world.get_object_mut(target_id) {
+                match obj {
+                    GameObject::Cow(cow) => {
+                        if cow.health > arrow_damage {
+                            cow.health -= arrow_damage;
+                        } else {
+                            remove_target = true;
                         }
-                        GameObject::Zombie(zombie) => {
-                            if zombie.health > arrow_damage {
-                                zombie.health -= arrow_damage;
-                            } else {
-                                remove_target = true;
-                                if matches!(arrow.source, DamageSource::PlayerArrow) {
-                                    grant_xp_amount = Some(2);
-                                }
-                            }
+                    }
+                    GameObject::Sheep(sheep) => {
+                        if sheep.health > arrow_damage {
+                            sheep.health -= arrow_damage;
+                        } else {
+                            remove_target = true;
+                        }
+                    }
+                    GameObject::Pig(pig) => {
+                        if pig.health > arrow_damage {
+                            pig.health -= arrow_damage;
+                        } else {
+                            remove_target = true;
                         }
-                        GameObject::Skeleton(skeleton) => {
-                            if skeleton.health > arrow_damage {
-                                skeleton.health -= arrow_damage;
-                            } else {
-                                remove_target = true;
-                                if matches!(arrow.source, DamageSource::PlayerArrow) {
-                                    grant_xp_amount = Some(2);
-                                }
+                    }
+                    GameObject::Zombie(zombie) => {
+                        if zombie.health > arrow_damage {
+                            zombie.health -= arrow_damage;
+                        } else {
+                            remove_target = true;
+                            if matches!(arrow.source, DamageSource::PlayerArrow) {
+                                grant_xp_amount = Some(2);
                             }
                         }
-                        GameObject::Plant(plant) => {
-                            if plant.health > arrow_damage {
-                                plant.health -= arrow_damage;
-                            } else {
-                                remove_target = true;
+                    }
+                    GameObject::Skeleton(skeleton) => {
+                        if skeleton.health > arrow_damage {
+                            skeleton.health -= arrow_damage;
+                        } else {
+                            remove_target = true;
+                            if matches!(arrow.source, DamageSource::PlayerArrow) {
+                                grant_xp_amount = Some(2);
                             }
                         }
-                        GameObject::CraftaxMob(mob) => {
-                            if mob.health > arrow_damage {
-                                mob.health -= arrow_damage;
-                            } else {
-                                remove_target = true;
-                                if matches!(arrow.source, DamageSource::PlayerArrow) {
-                                    grant_xp_amount = Some(3);
-                                    craftax_kill = Some(mob.kind);
-                                }
+                    }
+                    GameObject::Plant(plant) => {
+                        if plant.health > arrow_damage {
+                            plant.health -= arrow_damage;
+                        } else {
+                            remove_target = true;
+                        }
+                    }
+                    GameObject::EscortKnight(knight) => {
+                        if knight.health > arrow_damage {
+                            knight.health -= arrow_damage;
+                        } else {
+                            remove_target = true;
+                        }
+                    }
+                    GameObject::CraftaxMob(mob) => {
+                        let arrow_damage = crate::craftax::mobs::stats(mob.kind).absorb(arrow_damage);
+                        if mob.health > arrow_damage {
+                            mob.health -= arrow_damage;
+                        } else {
+                            remove_target = true;
+                            if matches!(arrow.source, DamageSource::PlayerArrow) {
+                                grant_xp_amount = Some(3);
+                                craftax_kill = Some(mob.kind);
                             }
                         }
-                        _ => {}
                     }
+                    _ => {}
                 }
+            }
 
-                if remove_target {
-                    self.world.remove_object(target_id);
-                }
-                self.world.remove_object(id);
-                if let Some(amount) = grant_xp_amount {
-                    self.grant_xp(amount);
-                }
-                if let Some(kind) = craftax_kill {
-                    self.record_craftax_kill(kind);
-                }
-                continue;
+            if remove_target {
+                self.world.remove_object(target_id);
+            }
+            if let Some(amount) = grant_xp_amount {
+                self.grant_xp(amount);
+            }
+            if let Some(kind) = craftax_kill {
+                self.record_craftax_kill(kind);
             }
 
-            // Check if arrow goes out of bounds
-            if !self.world.in_bounds(next_pos) {
-                self.world.remove_object(id);
-                continue;
+            // Piercing projectiles fly on through a target they killed;
+            // anything else (or a surviving target) stops the arrow.
+            if arrow.pierce && remove_target {
+                self.world.move_object(id, next_pos);
+                return true;
             }
+            self.world.remove_object(id);
+            return false;
+        }
 
-            // Check material at next position
-            if let Some(mat) = self.world.get_material(next_pos) {
-                // Arrow destroys Table/Furnace, converting to path (matching Python Crafter)
-                if mat == Material::Table || mat == Material::Furnace {
-                    self.world.set_material(next_pos, Material::Path);
-                    self.world.remove_object(id);
-                    continue;
-                }
+        // Check if arrow goes out of bounds
+        if !self.world.in_bounds(next_pos) {
+            self.world.remove_object(id);
+            return false;
+        }
 
-                // Arrow can travel through walkable tiles plus water and lava
-                let can_pass = mat.is_walkable() || mat == Material::Water || mat == Material::Lava;
-                if !can_pass {
-                    self.world.remove_object(id);
-                    continue;
-                }
+        // Check material at next position
+        if let Some(mat) = self.world.get_material(next_pos) {
+            // Arrow destroys Table/Furnace, converting to path (matching Python Crafter)
+            if mat == Material::Table || mat == Material::Furnace {
+                self.world.set_material(next_pos, Material::Path);
+                self.world.remove_object(id);
+                return false;
             }
 
-            // Move arrow
-            self.world.move_object(id, next_pos);
+            // Arrow can travel through walkable tiles plus water and lava
+            let can_pass = mat.is_walkable() || mat == Material::Water || mat == Material::Lava;
+            if !can_pass {
+                self.world.remove_object(id);
+                return false;
+            }
         }
+
+        // Move arrow
+        self.world.move_object(id, next_pos);
+        true
     }
 
     /// Process plants - matching Python Crafter behavior:
@@ -1891,10 +3518,14 @@ impl Session {
             for (dx, dy) in directions {
                 let adj_pos = (plant_pos.0 + dx, plant_pos.1 + dy);
                 if let Some(obj) = self.world.get_object_at(adj_pos) {
-                    // Zombies, skeletons, and cows damage plants
+                    // Zombies, skeletons, and grazing livestock damage plants
                     if matches!(
                         obj,
-                        GameObject::Zombie(_) | GameObject::Skeleton(_) | GameObject::Cow(_)
+                        GameObject::Zombie(_)
+                            | GameObject::Skeleton(_)
+                            | GameObject::Cow(_)
+                            | GameObject::Sheep(_)
+                            | GameObject::Pig(_)
                     ) || matches!(obj, GameObject::CraftaxMob(m) if m.is_hostile())
                     {
                         take_damage = true;
@@ -1937,6 +3568,149 @@ impl Session {
         }
     }
 
+    /// Damage mobs standing on spike traps (1 per tick). The player placed
+    /// the traps and knows where they are, so only mobs are hurt.
+    fn process_traps(&mut self) {
+        let trapped: Vec<u32> = self
+            .world
+            .objects
+            .iter()
+            .filter_map(|(&id, obj)| {
+                let on_trap =
+                    self.world.get_material(obj.position()) == Some(Material::SpikeTrap);
+                if !on_trap {
+                    return None;
+                }
+                match obj {
+                    GameObject::Cow(_)
+                    | GameObject::Sheep(_)
+                    | GameObject::Pig(_)
+                    | GameObject::Zombie(_)
+                    | GameObject::Skeleton(_)
+                    | GameObject::CraftaxMob(_)
+                    | GameObject::EscortKnight(_) => Some(id),
+                    _ => None,
+                }
+            })
+            .collect();
+
+        for id in trapped {
+            let dead = match self.world.get_object_mut(id) {
+                Some(GameObject::Cow(m)) => !m.take_damage(1),
+                Some(GameObject::Sheep(m)) => !m.take_damage(1),
+                Some(GameObject::Pig(m)) => !m.take_damage(1),
+                Some(GameObject::Zombie(m)) => !m.take_damage(1),
+                Some(GameObject::Skeleton(m)) => !m.take_damage(1),
+                Some(GameObject::CraftaxMob(m)) => !m.take_damage(1),
+                Some(GameObject::EscortKnight(m)) => !m.take_damage(1),
+                _ => false,
+            };
+            if dead {
+                self.world.remove_object(id);
+            }
+        }
+    }
+
+    /// Count the live mob population, split by kind and by proximity to the
+    /// player, so spawn rolls can be bounded by the configured caps.
+    fn mob_census(&self, player_pos: Position) -> MobCensus {
+        let radius = self.config.spawn_balance.density_radius;
+        let mut census = MobCensus::default();
+
+        for obj in self.world.objects.values() {
+            let near = {
+                let pos = obj.position();
+                (pos.0 - player_pos.0).abs() + (pos.1 - player_pos.1).abs() <= radius
+            };
+            match obj {
+                GameObject::Cow(_) => {
+                    census.total += 1;
+                    census.cows += 1;
+                    if near {
+                        census.passives_near_player += 1;
+                    }
+                }
+                GameObject::Sheep(_) => {
+                    census.total += 1;
+                    census.sheep += 1;
+                    if near {
+                        census.passives_near_player += 1;
+                    }
+                }
+                GameObject::Pig(_) => {
+                    census.total += 1;
+                    census.pigs += 1;
+                    if near {
+                        census.passives_near_player += 1;
+                    }
+                }
+                GameObject::Zombie(_) | GameObject::Skeleton(_) => {
+                    census.total += 1;
+                    if matches!(obj, GameObject::Zombie(_)) {
+                        census.zombies += 1;
+                    }
+                    if near {
+                        census.hostiles_near_player += 1;
+                    }
+                }
+                GameObject::CraftaxMob(mob) => {
+                    census.total += 1;
+                    if mob.is_hostile() {
+                        census.craftax_hostiles += 1;
+                        if near {
+                            census.hostiles_near_player += 1;
+                        }
+                    } else {
+                        census.craftax_passives += 1;
+                        if near {
+                            census.passives_near_player += 1;
+                        }
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        census
+    }
+
+    /// Whether a hostile spawn is allowed under the caps and light rules
+    fn hostile_spawn_allowed(&self, census: &MobCensus) -> bool {
+        let balance = &self.config.spawn_balance;
+        self.world.daylight < balance.hostile_max_light
+            && census.total < balance.global_mob_cap
+            && census.hostiles_near_player < balance.hostiles_near_player_target
+    }
+
+    /// Whether a passive spawn is allowed under the caps and light rules
+    fn passive_spawn_allowed(&self, census: &MobCensus) -> bool {
+        let balance = &self.config.spawn_balance;
+        self.world.daylight >= balance.passive_min_light
+            && census.total < balance.global_mob_cap
+            && census.passives_near_player < balance.passives_near_player_target
+    }
+
+    /// Spawn rate multiplier from night-based difficulty scaling
+    fn night_spawn_multiplier(&self) -> f32 {
+        let scaling = &self.config.night_scaling;
+        if !scaling.enabled {
+            return 1.0;
+        }
+        (1.0 + self.nights_survived as f32 * scaling.spawn_rate_per_night)
+            .min(scaling.max_spawn_multiplier)
+    }
+
+    /// Hostile mob health with the night-based difficulty bonus applied
+    fn scaled_hostile_health(&self, base: u8) -> u8 {
+        let scaling = &self.config.night_scaling;
+        if !scaling.enabled {
+            return base;
+        }
+        let bonus = ((self.nights_survived as f32 * scaling.health_per_night) as u8)
+            .min(scaling.max_bonus_health);
+        base.saturating_add(bonus)
+    }
+
     /// Spawn and despawn mobs
     fn spawn_despawn_mobs(&mut self) {
         let player_pos = match self.world.get_player() {
@@ -1952,20 +3726,22 @@ impl Session {
             .filter_map(|(&id, obj)| {
                 let pos = obj.position();
                 let dist = (pos.0 - player_pos.0).abs() + (pos.1 - player_pos.1).abs();
-                if dist > 30 {
+                if dist > self.config.rules.mob_despawn_distance {
                     match obj {
-                        GameObject::Cow(_) if self.rng.gen::<f32>() < self.config.cow_despawn_rate => {
+                        GameObject::Cow(_) | GameObject::Sheep(_) | GameObject::Pig(_)
+                            if self.rng_streams.spawning.gen::<f32>() < self.config.cow_despawn_rate =>
+                        {
                             Some(id)
                         }
                         GameObject::Zombie(_)
-                            if self.rng.gen::<f32>() < self.config.zombie_despawn_rate =>
+                            if self.rng_streams.spawning.gen::<f32>() < self.config.zombie_despawn_rate =>
                         {
                             Some(id)
                         }
                         GameObject::CraftaxMob(mob)
                             if self.config.craftax.enabled
                                 && self.config.craftax.mobs_enabled
-                                && self.rng.gen::<f32>()
+                                && self.rng_streams.spawning.gen::<f32>()
                                     < if mob.is_hostile() {
                                         self.config.zombie_despawn_rate
                                     } else {
@@ -1986,30 +3762,39 @@ impl Session {
             self.world.remove_object(id);
         }
 
-        // Spawn new mobs at night
-        if self.world.daylight < 0.5 {
-            // Zombie spawn
-            if self.rng.gen::<f32>() < self.config.zombie_spawn_rate * 0.01 {
-                let angle: f32 = self.rng.gen::<f32>() * std::f32::consts::TAU;
-                let dist: f32 = 15.0 + self.rng.gen::<f32>() * 10.0;
-                let spawn_pos = (
-                    player_pos.0 + (angle.cos() * dist) as i32,
-                    player_pos.1 + (angle.sin() * dist) as i32,
-                );
+        // Take a census after despawning so spawn rolls below respect the caps
+        let mut census = self.mob_census(player_pos);
 
-                if self.world.is_walkable(spawn_pos) && self.world.get_object_at(spawn_pos).is_none()
-                {
-                    self.world.add_object(GameObject::Zombie(
-                        crate::entity::Zombie::with_health(spawn_pos, self.config.zombie_health),
-                    ));
-                }
+        // Zombie spawn (night only, bounded by caps and density targets)
+        if census.zombies < self.config.spawn_balance.zombie_cap
+            && self.hostile_spawn_allowed(&census)
+            && self.rng_streams.spawning.gen::<f32>()
+                < self.config.zombie_spawn_rate * 0.01 * self.night_spawn_multiplier()
+        {
+            let angle: f32 = self.rng_streams.spawning.gen::<f32>() * std::f32::consts::TAU;
+            let dist: f32 = 15.0 + self.rng_streams.spawning.gen::<f32>() * 10.0;
+            let spawn_pos = (
+                player_pos.0 + (angle.cos() * dist) as i32,
+                player_pos.1 + (angle.sin() * dist) as i32,
+            );
+
+            if self.world.is_walkable(spawn_pos) && self.world.get_object_at(spawn_pos).is_none() {
+                let health = self.scaled_hostile_health(self.config.zombie_health);
+                self.world.add_object(GameObject::Zombie(
+                    crate::entity::Zombie::with_health(spawn_pos, health),
+                ));
+                census.total += 1;
+                census.zombies += 1;
             }
         }
 
-        // Cow spawn (any time)
-        if self.rng.gen::<f32>() < self.config.cow_spawn_rate * 0.1 {
-            let angle: f32 = self.rng.gen::<f32>() * std::f32::consts::TAU;
-            let dist: f32 = 10.0 + self.rng.gen::<f32>() * 15.0;
+        // Cow spawn (any time, bounded by caps and density targets)
+        if census.cows < self.config.spawn_balance.cow_cap
+            && self.passive_spawn_allowed(&census)
+            && self.rng_streams.spawning.gen::<f32>() < self.config.cow_spawn_rate * 0.1
+        {
+            let angle: f32 = self.rng_streams.spawning.gen::<f32>() * std::f32::consts::TAU;
+            let dist: f32 = 10.0 + self.rng_streams.spawning.gen::<f32>() * 15.0;
             let spawn_pos = (
                 player_pos.0 + (angle.cos() * dist) as i32,
                 player_pos.1 + (angle.sin() * dist) as i32,
@@ -2020,6 +3805,47 @@ impl Session {
                     spawn_pos,
                     self.config.cow_health,
                 )));
+                census.total += 1;
+                census.cows += 1;
+            }
+        }
+
+        // Sheep spawn (grass only, bounded by caps and density targets)
+        if census.sheep < self.config.wildlife.sheep_cap
+            && self.passive_spawn_allowed(&census)
+            && self.rng_streams.spawning.gen::<f32>() < self.config.wildlife.sheep_spawn_rate * 0.1
+        {
+            if let Some(pos) = self.random_spawn_near_player(player_pos, 10.0, 25.0) {
+                if self.world.get_material(pos) == Some(Material::Grass)
+                    && self.world.get_object_at(pos).is_none()
+                {
+                    self.world.add_object(GameObject::Sheep(
+                        crate::entity::Sheep::with_health(pos, self.config.wildlife.sheep_health),
+                    ));
+                    census.total += 1;
+                    census.sheep += 1;
+                }
+            }
+        }
+
+        // Pig spawn (grass or sand, bounded by caps and density targets)
+        if census.pigs < self.config.wildlife.pig_cap
+            && self.passive_spawn_allowed(&census)
+            && self.rng_streams.spawning.gen::<f32>() < self.config.wildlife.pig_spawn_rate * 0.1
+        {
+            if let Some(pos) = self.random_spawn_near_player(player_pos, 10.0, 25.0) {
+                if matches!(
+                    self.world.get_material(pos),
+                    Some(Material::Grass) | Some(Material::Sand)
+                ) && self.world.get_object_at(pos).is_none()
+                {
+                    self.world.add_object(GameObject::Pig(crate::entity::Pig::with_health(
+                        pos,
+                        self.config.wildlife.pig_health,
+                    )));
+                    census.total += 1;
+                    census.pigs += 1;
+                }
             }
         }
 
@@ -2028,7 +3854,9 @@ impl Session {
         }
 
         // Craftax hostile spawns (night-time bias)
-        if self.world.daylight < 0.5 {
+        if census.craftax_hostiles < self.config.spawn_balance.craftax_hostile_cap
+            && self.hostile_spawn_allowed(&census)
+        {
             let hostile_spawns = [
                 (crate::entity::CraftaxMobKind::OrcSoldier, 0.01, self.config.craftax.spawn.orc_soldier_density),
                 (crate::entity::CraftaxMobKind::OrcMage, 0.008, self.config.craftax.spawn.orc_mage_density),
@@ -2042,20 +3870,31 @@ impl Session {
             ];
 
             for (kind, base_rate, density) in hostile_spawns {
-                if self.rng.gen::<f32>() < base_rate * density {
+                if census.craftax_hostiles >= self.config.spawn_balance.craftax_hostile_cap
+                    || !self.hostile_spawn_allowed(&census)
+                {
+                    break;
+                }
+                if self.rng_streams.spawning.gen::<f32>() < base_rate * density * self.night_spawn_multiplier() {
                     if let Some(pos) = self.random_spawn_near_player(player_pos, 12.0, 20.0) {
                         if self.world.is_walkable(pos) && self.world.get_object_at(pos).is_none() {
                             let stats = crate::craftax::mobs::stats(kind);
-                            let mob = crate::entity::CraftaxMob::new(kind, pos, stats.health);
+                            let health = self.scaled_hostile_health(stats.health);
+                            let mob = crate::entity::CraftaxMob::new(kind, pos, health);
                             self.world.add_object(GameObject::CraftaxMob(mob));
+                            census.total += 1;
+                            census.craftax_hostiles += 1;
                         }
                     }
                 }
             }
         }
 
-        // Craftax passive spawns (any time)
-        if self.rng.gen::<f32>() < 0.02 * self.config.craftax.spawn.snail_density {
+        // Craftax passive spawns (any time, bounded by caps and density targets)
+        if census.craftax_passives < self.config.spawn_balance.craftax_passive_cap
+            && self.passive_spawn_allowed(&census)
+            && self.rng_streams.spawning.gen::<f32>() < 0.02 * self.config.craftax.spawn.snail_density
+        {
             if let Some(pos) = self.random_spawn_near_player(player_pos, 8.0, 16.0) {
                 if self.world.get_material(pos) == Some(Material::Grass)
                     && self.world.get_object_at(pos).is_none()
@@ -2064,11 +3903,16 @@ impl Session {
                     let mob =
                         crate::entity::CraftaxMob::new(crate::entity::CraftaxMobKind::Snail, pos, stats.health);
                     self.world.add_object(GameObject::CraftaxMob(mob));
+                    census.total += 1;
+                    census.craftax_passives += 1;
                 }
             }
         }
 
-        if self.rng.gen::<f32>() < 0.02 * self.config.craftax.spawn.bat_density {
+        if census.craftax_passives < self.config.spawn_balance.craftax_passive_cap
+            && self.passive_spawn_allowed(&census)
+            && self.rng_streams.spawning.gen::<f32>() < 0.02 * self.config.craftax.spawn.bat_density
+        {
             if let Some(pos) = self.random_spawn_near_player(player_pos, 8.0, 16.0) {
                 if self.world.get_material(pos) == Some(Material::Path)
                     && self.world.get_object_at(pos).is_none()
@@ -2082,77 +3926,1033 @@ impl Session {
         }
     }
 
-    /// Check for game over conditions
-    fn check_done(&self) -> (bool, Option<DoneReason>) {
-        // Check player death
-        if let Some(player) = self.world.get_player() {
-            if self.config.health_enabled && !player.is_alive() {
-                return (true, Some(DoneReason::Death));
-            }
-        } else {
-            return (true, Some(DoneReason::Death));
-        }
+    /// Schedule, spawn, and resolve horde events.
+    ///
+    /// A horde is announced via the debug event stream `warning_ticks` ahead
+    /// of the wave, then spawns `wave_size` zombies in a ring around the
+    /// player. Surviving until `resolve_at` earns `survive_horde`.
+    fn process_horde(&mut self, debug_events: &mut Vec<String>) {
+        if !self.config.horde.enabled {
+            return;
+        }
+
+        let step = self.timing.step;
+        let player_pos = match self.world.get_player() {
+            Some(p) => p.pos,
+            None => return,
+        };
+
+        if let Some(mut state) = self.horde {
+            if !state.spawned && step >= state.spawn_at {
+                let wave_size = self.config.horde.wave_size;
+                let health = self.scaled_hostile_health(self.config.zombie_health);
+                let mut spawned = 0;
+                for _ in 0..wave_size * 3 {
+                    if spawned >= wave_size {
+                        break;
+                    }
+                    if let Some(pos) = self.random_spawn_near_player(player_pos, 6.0, 10.0) {
+                        if self.world.is_walkable(pos) && self.world.get_object_at(pos).is_none() {
+                            self.world.add_object(GameObject::Zombie(
+                                crate::entity::Zombie::with_health(pos, health),
+                            ));
+                            spawned += 1;
+                        }
+                    }
+                }
+                debug_events.push(format!("HORDE: the horde has arrived ({} zombies)", spawned));
+                state.spawned = true;
+                self.horde = Some(state);
+            } else if state.spawned && step >= state.resolve_at {
+                if let Some(player) = self.world.get_player_mut() {
+                    if player.is_alive() {
+                        player.achievements.survive_horde += 1;
+                        debug_events.push("HORDE: survived the horde".to_string());
+                    }
+                }
+                self.horde = None;
+                self.last_horde_step = Some(step);
+            }
+        } else {
+            // Only schedule at night, and not too soon after the last horde
+            let is_night = self.world.daylight < 0.5;
+            let interval_ok = self
+                .last_horde_step
+                .map(|last| step.saturating_sub(last) >= self.config.horde.min_interval as u64)
+                .unwrap_or(true);
+            if is_night && interval_ok && self.rng_streams.spawning.gen::<f32>() < self.config.horde.trigger_chance
+            {
+                let spawn_at = step + self.config.horde.warning_ticks as u64;
+                self.horde = Some(HordeState {
+                    spawn_at,
+                    resolve_at: spawn_at + self.config.horde.survive_ticks as u64,
+                    spawned: false,
+                });
+                debug_events.push(format!(
+                    "HORDE: zombies are converging on you (arriving in {} ticks)",
+                    self.config.horde.warning_ticks
+                ));
+            }
+        }
+    }
+
+    /// Run the knight escort mission: spawn the knight, have it follow the
+    /// player while fighting off zombies, and resolve success or failure.
+    ///
+    /// The knight spawns next to the player at `start_step` together with a
+    /// village site `village_distance` away. It follows the player, strikes
+    /// adjacent zombies, and is mauled by zombies standing next to it.
+    /// Getting the knight within 2 tiles of the village before the deadline
+    /// earns `escort_knight`; the mission fails if the knight dies or the
+    /// time limit runs out.
+    fn process_escort(&mut self, debug_events: &mut Vec<String>) {
+        if !self.config.escort.enabled || self.escort_resolved {
+            return;
+        }
+
+        let step = self.timing.step;
+        let player_pos = match self.world.get_player() {
+            Some(p) => p.pos,
+            None => return,
+        };
+
+        let state = match self.escort {
+            Some(state) => state,
+            None => {
+                if step < self.config.escort.start_step {
+                    return;
+                }
+                // Spawn the knight next to the player; retry next tick if no
+                // free tile comes up
+                let spawn = match self.random_spawn_near_player(player_pos, 2.0, 4.0) {
+                    Some(pos)
+                        if self.world.is_walkable(pos)
+                            && self.world.get_object_at(pos).is_none() =>
+                    {
+                        pos
+                    }
+                    _ => return,
+                };
+                let village_pos = self.pick_village_site(spawn);
+                let knight_id = self.world.add_object(GameObject::EscortKnight(
+                    crate::entity::EscortKnight::with_health(
+                        spawn,
+                        self.config.escort.knight_health,
+                    ),
+                ));
+                self.escort = Some(EscortState {
+                    knight_id,
+                    village_pos,
+                    deadline: step + self.config.escort.time_limit as u64,
+                });
+                debug_events.push(format!(
+                    "ESCORT: a wounded knight joins you; lead them to the village at {:?}",
+                    village_pos
+                ));
+                return;
+            }
+        };
+
+        let mut knight = match self.world.get_object(state.knight_id) {
+            Some(GameObject::EscortKnight(k)) => k.clone(),
+            _ => {
+                // Failure: the knight has died
+                debug_events.push("ESCORT: the knight has fallen; mission failed".to_string());
+                self.escort = None;
+                self.escort_resolved = true;
+                return;
+            }
+        };
+
+        // Fight: strike one adjacent zombie, otherwise march
+        let adjacent_zombie = self.world.objects.iter().find_map(|(&zid, obj)| match obj {
+            GameObject::Zombie(z)
+                if (z.pos.0 - knight.pos.0).abs() + (z.pos.1 - knight.pos.1).abs() <= 1 =>
+            {
+                Some((zid, z.clone()))
+            }
+            _ => None,
+        });
+        if let Some((zid, mut zombie)) = adjacent_zombie {
+            if knight.cooldown > 0 {
+                knight.cooldown -= 1;
+            } else {
+                knight.cooldown = 2;
+                if !zombie.take_damage(self.config.escort.knight_damage) {
+                    self.world.remove_object(zid);
+                    debug_events.push("ESCORT: the knight cut down a zombie".to_string());
+                } else if let Some(GameObject::Zombie(z)) = self.world.get_object_mut(zid) {
+                    z.health = zombie.health;
+                }
+            }
+        } else {
+            // March: head for the village once it is close, otherwise follow
+            // the player (80% long-axis like the other mobs)
+            let to_village = (knight.pos.0 - state.village_pos.0).abs()
+                + (knight.pos.1 - state.village_pos.1).abs();
+            let target = if to_village <= 8 {
+                state.village_pos
+            } else {
+                player_pos
+            };
+            let dist = (knight.pos.0 - target.0).abs() + (knight.pos.1 - target.1).abs();
+            if dist > 1 {
+                let long_axis = self.rng_streams.mob_ai.gen::<f32>() < 0.8;
+                let (dx, dy) = self.toward_direction(knight.pos, target, long_axis);
+                let new_pos = (knight.pos.0 + dx, knight.pos.1 + dy);
+                if self.world.is_walkable(new_pos) && self.world.get_object_at(new_pos).is_none()
+                {
+                    self.world.move_object(state.knight_id, new_pos);
+                    knight.pos = new_pos;
+                }
+            }
+        }
+
+        // Zombies maul the knight when adjacent
+        let maulers = self
+            .world
+            .objects
+            .values()
+            .filter(|obj| {
+                matches!(obj, GameObject::Zombie(z)
+                    if (z.pos.0 - knight.pos.0).abs() + (z.pos.1 - knight.pos.1).abs() <= 1)
+            })
+            .count() as u8;
+        if maulers > 0 && !knight.take_damage(maulers) {
+            self.world.remove_object(state.knight_id);
+            debug_events.push("ESCORT: the knight has fallen; mission failed".to_string());
+            self.escort = None;
+            self.escort_resolved = true;
+            return;
+        }
+
+        // Success: the knight reached the village
+        let to_village = (knight.pos.0 - state.village_pos.0).abs()
+            + (knight.pos.1 - state.village_pos.1).abs();
+        if to_village <= 2 {
+            self.world.remove_object(state.knight_id);
+            if let Some(player) = self.world.get_player_mut() {
+                player.achievements.escort_knight += 1;
+            }
+            debug_events.push("ESCORT: the knight reached the village safely".to_string());
+            self.escort = None;
+            self.escort_resolved = true;
+            return;
+        }
+
+        // Failure: out of time
+        if step >= state.deadline {
+            self.world.remove_object(state.knight_id);
+            debug_events.push("ESCORT: the knight gave up waiting; mission failed".to_string());
+            self.escort = None;
+            self.escort_resolved = true;
+            return;
+        }
+
+        // Write back the knight's health and attack cooldown
+        if let Some(GameObject::EscortKnight(k)) = self.world.get_object_mut(state.knight_id) {
+            k.health = knight.health;
+            k.cooldown = knight.cooldown;
+        }
+    }
+
+    /// Pick a walkable village site roughly `village_distance` away from the
+    /// knight's spawn, clamped to the world interior.
+    fn pick_village_site(&mut self, from: Position) -> Position {
+        let (width, height) = self.config.world_size;
+        let (max_x, max_y) = (width as i32 - 2, height as i32 - 2);
+        let distance = self.config.escort.village_distance;
+        for _ in 0..32 {
+            let angle = self.rng_streams.spawning.gen::<f32>() * std::f32::consts::TAU;
+            let pos = (
+                (from.0 as f32 + angle.cos() * distance).round() as i32,
+                (from.1 as f32 + angle.sin() * distance).round() as i32,
+            );
+            let pos = (pos.0.clamp(1, max_x), pos.1.clamp(1, max_y));
+            if self.world.is_walkable(pos) {
+                return pos;
+            }
+        }
+        (
+            from.0.clamp(1, max_x),
+            (from.1 + distance as i32).clamp(1, max_y),
+        )
+    }
+
+    /// Check for game over conditions
+    fn check_done(&self) -> (bool, Option<DoneReason>) {
+        // Check player death
+        if let Some(player) = self.world.get_player() {
+            if self.config.health_enabled && !player.is_alive() {
+                return (true, Some(DoneReason::Death));
+            }
+        } else {
+            return (true, Some(DoneReason::Death));
+        }
+
+        // Check max steps
+        if let Some(max_steps) = self.config.max_steps {
+            if self.timing.step >= max_steps as u64 {
+                return (true, Some(DoneReason::MaxSteps));
+            }
+        }
+
+        (false, None)
+    }
+
+    /// Calculate rewards from achievements
+    fn calculate_rewards(&self) -> (f32, Vec<String>) {
+        let current = self
+            .world
+            .get_player()
+            .map(|p| &p.achievements)
+            .unwrap_or(&self.prev_achievements);
+        self.achievement_reward(current, &self.prev_achievements)
+    }
+
+    /// Reward and unlock names for one player's achievement delta; shared
+    /// by the single-player path and [`crate::multiplayer`]
+    pub(crate) fn achievement_reward(
+        &self,
+        current: &Achievements,
+        previous: &Achievements,
+    ) -> (f32, Vec<String>) {
+        let mut reward = 0.0;
+        let mut newly_unlocked = Vec::new();
+
+        // Compare counter arrays by canonical index instead of per-name
+        // string lookups; the hot path allocates only on an actual unlock
+        let curr = current.counters();
+        let prev = previous.counters();
+        let craftax_range = if self.config.craftax.enabled && self.config.craftax.achievements_enabled
+        {
+            0..0
+        } else {
+            // Craftax names sit between the classic and extended tables
+            let start = Achievements::all_names().len();
+            start..start + Achievements::craftax_names().len()
+        };
+
+        for i in 0..Achievements::COUNT {
+            if craftax_range.contains(&i) {
+                continue;
+            }
+            if curr[i] > prev[i] {
+                match &self.reward_config {
+                    None => reward += 1.0,
+                    Some(config) => {
+                        let name = Achievements::NAMES[i];
+                        if prev[i] == 0 {
+                            if let Some(&r) = config.achievement_rewards.get(name) {
+                                reward += r;
+                            }
+                        }
+                        if let Some(&r) = config.achievement_repeat_rewards.get(name) {
+                            reward += r * (curr[i] - prev[i].max(1)) as f32;
+                        }
+                    }
+                }
+                if prev[i] == 0 {
+                    newly_unlocked.push(Achievements::NAMES[i].to_string());
+                }
+            }
+        }
+
+        (reward, newly_unlocked)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_session_creation() {
+        let config = SessionConfig {
+            world_size: (32, 32),
+            seed: Some(42),
+            ..Default::default()
+        };
+
+        let session = Session::new(config);
+        let state = session.get_state();
+
+        assert_eq!(state.step, 0);
+        assert_eq!(state.episode, 1);
+        assert!(state.inventory.is_alive());
+    }
+
+    #[test]
+    fn test_fast_mode_skips_debug_strings_and_views() {
+        let config = SessionConfig {
+            world_size: (32, 32),
+            seed: Some(42),
+            fast_mode: true,
+            ..Default::default()
+        };
+
+        let mut session = Session::new(config);
+        let result = session.step(Action::Do);
+
+        assert!(result.debug_events.is_empty());
+        assert!(result.state.view.is_none());
+        assert!(result.state.world.is_none());
+        // Full observations stay available on demand
+        assert!(session.get_state().view.is_some());
+    }
+
+    #[test]
+    fn test_full_world_state_shares_snapshots() {
+        let config = SessionConfig {
+            world_size: (32, 32),
+            seed: Some(42),
+            full_world_state: true,
+            ..Default::default()
+        };
+
+        let mut session = Session::new(config);
+        let a = session.get_state().world.unwrap();
+        let b = session.get_state().world.unwrap();
+        // No world change between reads: both states share one snapshot
+        assert!(std::sync::Arc::ptr_eq(&a, &b));
+
+        let c = session.step(Action::MoveRight).state.world.unwrap();
+        assert!(!std::sync::Arc::ptr_eq(&a, &c));
+        assert_eq!(c.get_player().unwrap().pos.0, a.get_player().unwrap().pos.0 + 1);
+    }
+
+    #[test]
+    fn test_fast_mode_matches_normal_trajectory() {
+        let fast = SessionConfig {
+            world_size: (32, 32),
+            seed: Some(42),
+            fast_mode: true,
+            ..Default::default()
+        };
+        let normal = SessionConfig {
+            fast_mode: false,
+            ..fast.clone()
+        };
+
+        let mut fast_session = Session::new(fast);
+        let mut normal_session = Session::new(normal);
+        for _ in 0..50 {
+            let a = fast_session.step(Action::MoveRight);
+            let b = normal_session.step(Action::MoveRight);
+            assert_eq!(a.state.player_pos, b.state.player_pos);
+            assert_eq!(a.reward, b.reward);
+            assert_eq!(a.newly_unlocked, b.newly_unlocked);
+        }
+    }
+
+    #[test]
+    fn test_night_scaling_multipliers() {
+        let config = SessionConfig {
+            world_size: (32, 32),
+            seed: Some(42),
+            night_scaling: crate::config::NightScalingConfig {
+                enabled: true,
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+
+        let mut session = Session::new(config);
+        assert_eq!(session.night_spawn_multiplier(), 1.0);
+        assert_eq!(session.scaled_hostile_health(5), 5);
+
+        session.nights_survived = 4;
+        assert_eq!(session.night_spawn_multiplier(), 1.0 + 4.0 * 0.15);
+        assert_eq!(session.scaled_hostile_health(5), 7);
+
+        // Scaling is bounded by the configured maximums
+        session.nights_survived = 100;
+        assert_eq!(session.night_spawn_multiplier(), 3.0);
+        assert_eq!(session.scaled_hostile_health(5), 11);
+
+        // Disabled scaling leaves rates and health untouched
+        session.config.night_scaling.enabled = false;
+        assert_eq!(session.night_spawn_multiplier(), 1.0);
+        assert_eq!(session.scaled_hostile_health(5), 5);
+    }
+
+    #[test]
+    fn test_nights_survived_counter() {
+        let config = SessionConfig {
+            world_size: (32, 32),
+            seed: Some(42),
+            day_cycle_period: 100,
+            health_enabled: false,
+            hunger_enabled: false,
+            thirst_enabled: false,
+            fatigue_enabled: false,
+            ..Default::default()
+        };
+
+        let mut session = Session::new(config);
+        // Two full day cycles pass at least one complete night each
+        for _ in 0..200 {
+            session.step(Action::Noop);
+        }
+        assert!(
+            session.nights_survived >= 1,
+            "expected at least one survived night after two day cycles"
+        );
+    }
+
+    #[test]
+    fn test_horde_spawns_wave_and_grants_achievement() {
+        let config = SessionConfig {
+            world_size: (32, 32),
+            seed: Some(42),
+            health_enabled: false,
+            horde: crate::config::HordeConfig {
+                enabled: true,
+                wave_size: 4,
+                survive_ticks: 5,
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+
+        let mut session = Session::new(config);
+        let count_zombies = |session: &Session| {
+            session
+                .world
+                .objects
+                .values()
+                .filter(|obj| matches!(obj, GameObject::Zombie(_)))
+                .count()
+        };
+        let before = count_zombies(&session);
+
+        // Force a horde that spawns on the next step
+        session.horde = Some(HordeState {
+            spawn_at: session.timing.step + 1,
+            resolve_at: session.timing.step + 6,
+            spawned: false,
+        });
+
+        let result = session.step(Action::Noop);
+        assert!(count_zombies(&session) > before, "horde wave should spawn zombies");
+        assert!(
+            result.debug_events.iter().any(|e| e.starts_with("HORDE")),
+            "horde arrival should be announced"
+        );
+
+        // Survive until the horde resolves
+        for _ in 0..10 {
+            session.step(Action::Noop);
+        }
+        let achievements = session.get_state().achievements;
+        assert_eq!(achievements.survive_horde, 1);
+    }
+
+    #[test]
+    fn test_craft_and_place_defensive_structures() {
+        let config = SessionConfig::default();
+        let mut session = Session::new(config);
+
+        let (px, py) = session.get_state().player_pos;
+        session.world.set_material((px - 1, py), Material::Table);
+        session.world.set_material((px + 1, py), Material::Grass);
+        if let Some(obj_id) = session.world.get_object_id_at((px + 1, py)) {
+            session.world.remove_object(obj_id);
+        }
+        if let Some(player) = session.world.get_player_mut() {
+            player.facing = (1, 0);
+            player.inventory.wood = 5;
+            player.inventory.stone = 2;
+        }
+
+        session.step(Action::MakeSpikeTrap);
+        session.step(Action::MakeDoor);
+        session.step(Action::MakeFence);
+        let inv = session.get_state().inventory;
+        assert_eq!(inv.spike_trap, 1);
+        assert_eq!(inv.door, 1);
+        assert_eq!(inv.fence, 1);
+        assert_eq!(inv.wood, 1, "recipes should consume 1+2+1 wood");
+        assert_eq!(inv.stone, 1, "the trap should consume 1 stone");
+
+        // Fences place on grass and can be collected back as items
+        session.step(Action::PlaceFence);
+        assert_eq!(session.world.get_material((px + 1, py)), Some(Material::Fence));
+        session.step(Action::Do);
+        assert_eq!(session.world.get_material((px + 1, py)), Some(Material::Grass));
+        assert_eq!(session.get_state().inventory.fence, 1);
+
+        // A closed door opens when the player walks into it, consuming the move
+        session.step(Action::PlaceDoor);
+        assert_eq!(
+            session.world.get_material((px + 1, py)),
+            Some(Material::DoorClosed)
+        );
+        session.step(Action::MoveRight);
+        assert_eq!(
+            session.world.get_material((px + 1, py)),
+            Some(Material::DoorOpen)
+        );
+        assert_eq!(session.get_state().player_pos, (px, py));
+        session.step(Action::MoveRight);
+        assert_eq!(session.get_state().player_pos, (px + 1, py));
+    }
+
+    #[test]
+    fn test_spike_trap_damages_mobs() {
+        let config = SessionConfig::default();
+        let mut session = Session::new(config);
+
+        session.world.set_material((5, 5), Material::SpikeTrap);
+        let zombie_id = session
+            .world
+            .add_object(GameObject::Zombie(crate::entity::Zombie::with_health((5, 5), 2)));
+
+        session.process_traps();
+        assert!(
+            matches!(
+                session.world.get_object(zombie_id),
+                Some(GameObject::Zombie(z)) if z.health == 1
+            ),
+            "the trap should deal 1 damage per tick"
+        );
+        session.process_traps();
+        assert!(
+            session.world.get_object(zombie_id).is_none(),
+            "the trap should finish the zombie off"
+        );
+    }
+
+    #[test]
+    fn test_escort_mission_success() {
+        let config = SessionConfig {
+            world_size: (32, 32),
+            seed: Some(42),
+            health_enabled: false,
+            escort: crate::config::EscortConfig {
+                enabled: true,
+                start_step: 0,
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        let mut session = Session::new(config);
+
+        // Spawning retries until a free tile turns up near the player
+        for _ in 0..20 {
+            session.step(Action::Noop);
+            if session.escort.is_some() {
+                break;
+            }
+        }
+        let state = session.escort.expect("the knight should spawn");
+        assert!(
+            matches!(
+                session.world.get_object(state.knight_id),
+                Some(GameObject::EscortKnight(_))
+            ),
+            "the knight should be in the world"
+        );
+
+        // Teleport the village next to the knight to force a delivery
+        let knight_pos = session.world.get_object(state.knight_id).unwrap().position();
+        session.escort = Some(EscortState {
+            village_pos: knight_pos,
+            ..state
+        });
+
+        let result = session.step(Action::Noop);
+        assert_eq!(session.get_state().achievements.escort_knight, 1);
+        assert!(
+            session.world.get_object(state.knight_id).is_none(),
+            "the knight should walk into the village"
+        );
+        assert!(
+            result.debug_events.iter().any(|e| e.contains("village safely")),
+            "delivery should be announced"
+        );
+        assert!(session.escort_resolved, "the mission should not restart");
+    }
+
+    #[test]
+    fn test_escort_mission_fails_when_knight_dies() {
+        let config = SessionConfig {
+            world_size: (32, 32),
+            seed: Some(7),
+            health_enabled: false,
+            escort: crate::config::EscortConfig {
+                enabled: true,
+                start_step: 0,
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        let mut session = Session::new(config);
+
+        for _ in 0..20 {
+            session.step(Action::Noop);
+            if session.escort.is_some() {
+                break;
+            }
+        }
+        let state = session.escort.expect("the knight should spawn");
+
+        session.world.remove_object(state.knight_id);
+        let result = session.step(Action::Noop);
+        assert!(
+            result.debug_events.iter().any(|e| e.contains("mission failed")),
+            "the failure should be announced"
+        );
+        assert!(session.escort_resolved);
+        assert_eq!(session.get_state().achievements.escort_knight, 0);
+    }
+
+    #[test]
+    fn test_fortune_bonus_requires_spare_tiers() {
+        let config = SessionConfig {
+            fortune: crate::config::FortuneConfig {
+                enabled: true,
+                coal_chance: 1.0,
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        let mut session = Session::new(config);
+
+        let (px, py) = session.get_state().player_pos;
+        let target = (px + 1, py);
+        session.world.set_material(target, Material::Coal);
+        if let Some(obj_id) = session.world.get_object_id_at(target) {
+            session.world.remove_object(obj_id);
+        }
+        if let Some(player) = session.world.get_player_mut() {
+            player.facing = (1, 0);
+            player.inventory.wood_pickaxe = 1;
+        }
+
+        // A wood pickaxe is exactly the required tier: no bonus roll at all
+        session.step(Action::Do);
+        assert_eq!(session.get_state().inventory.coal, 1);
+
+        // An iron pickaxe is two tiers ahead, and with a guaranteed chance
+        // the vein pays out double
+        session.world.set_material(target, Material::Coal);
+        if let Some(player) = session.world.get_player_mut() {
+            player.inventory.iron_pickaxe = 1;
+        }
+        let result = session.step(Action::Do);
+        assert_eq!(session.get_state().inventory.coal, 3);
+        assert_eq!(session.get_state().achievements.collect_coal, 3);
+        assert!(
+            result.debug_events.iter().any(|e| e.starts_with("FORTUNE")),
+            "the bonus drop should be announced"
+        );
+    }
+
+    #[test]
+    fn test_action_profiles_define_stable_indexing() {
+        use crate::action::ActionProfile;
+
+        assert_eq!(ActionProfile::Minimal.num_actions(), 7);
+        assert_eq!(ActionProfile::Classic.num_actions(), 17);
+        assert_eq!(ActionProfile::CraftaxFull.num_actions(), Action::all().len());
+
+        // Profile-local indices are positions in the profile's own list
+        assert_eq!(ActionProfile::Classic.index_of(Action::Do), Some(5));
+        assert_eq!(
+            ActionProfile::Classic.action_from_index(5),
+            Some(Action::Do)
+        );
+        assert_eq!(ActionProfile::Minimal.index_of(Action::PlaceStone), None);
+        assert!(!ActionProfile::Classic.contains(Action::MakeFence));
+        assert!(ActionProfile::CraftaxFull.contains(Action::MakeFence));
+    }
+
+    #[test]
+    fn test_out_of_profile_actions_are_ignored() {
+        let config = SessionConfig {
+            action_profile: crate::action::ActionProfile::Classic,
+            ..Default::default()
+        };
+        let mut session = Session::new(config);
+
+        let (px, py) = session.get_state().player_pos;
+        session.world.set_material((px - 1, py), Material::Table);
+        if let Some(player) = session.world.get_player_mut() {
+            player.inventory.wood = 3;
+        }
+
+        // MakeFence is outside the classic profile, so nothing happens
+        session.step(Action::MakeFence);
+        let state = session.get_state();
+        assert_eq!(state.inventory.fence, 0);
+        assert_eq!(state.inventory.wood, 3);
+
+        // Classic actions still work as usual
+        session.step(Action::MakeWoodPickaxe);
+        assert_eq!(session.get_state().inventory.wood_pickaxe, 1);
+    }
+
+    #[test]
+    fn test_debug_console_helpers() {
+        let config = SessionConfig {
+            world_size: (16, 16),
+            seed: Some(42),
+            ..Default::default()
+        };
+        let mut session = Session::new(config);
+
+        assert!(session.debug_give("wood", 5).is_ok());
+        assert_eq!(session.get_state().inventory.wood, 5);
+        assert!(session.debug_give("wood_pickaxe", 1).is_ok());
+        assert_eq!(session.get_state().inventory.wood_pickaxe, 1);
+        assert!(session.debug_give("bogus", 1).is_err());
+
+        // Out-of-bounds teleports are rejected; in-bounds walkable tiles work
+        assert!(session.debug_teleport((-1, 0)).is_err());
+        let target = (0..16)
+            .flat_map(|y| (0..16).map(move |x| (x, y)))
+            .find(|&pos| session.world.is_walkable(pos))
+            .unwrap();
+        assert!(session.debug_teleport(target).is_ok());
+        assert_eq!(session.get_state().player_pos, target);
+
+        // Reseeding restarts the episode deterministically
+        session.debug_reseed(7);
+        assert_eq!(session.config.seed, Some(7));
+        assert_eq!(session.get_state().step, 0);
+        assert_eq!(session.get_state().inventory.wood, 0);
+    }
+
+    #[test]
+    fn test_recipe_mutation_is_sampled_and_enforced() {
+        let config = SessionConfig {
+            seed: Some(42),
+            recipe_mutation_enabled: true,
+            ..Default::default()
+        };
+        let mut session = Session::new(config);
+
+        // The sampled book is observable through the game state
+        let cost = session.recipes.wood_pickaxe;
+        assert_eq!(session.get_state().recipes, session.recipes);
+        assert!((1..=3).contains(&cost.wood));
+        assert_eq!(cost.stone, 0);
+
+        let (px, py) = session.get_state().player_pos;
+        session.world.set_material((px - 1, py), Material::Table);
+        if let Some(player) = session.world.get_player_mut() {
+            player.inventory.wood = cost.wood;
+        }
+
+        // Crafting consumes exactly the mutated cost
+        session.step(Action::MakeWoodPickaxe);
+        let state = session.get_state();
+        assert_eq!(state.inventory.wood_pickaxe, 1);
+        assert_eq!(state.inventory.wood, 0);
+    }
+
+    #[test]
+    fn test_fire_bow_achievement_is_wired() {
+        let config = SessionConfig {
+            craftax: crate::config::CraftaxConfig {
+                enabled: true,
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        let mut session = Session::new(config);
+
+        if let Some(player) = session.world.get_player_mut() {
+            player.inventory.bow = 1;
+            player.inventory.arrows = 2;
+        }
+
+        let result = session.step(Action::ShootArrow);
+        let state = session.get_state();
+        assert_eq!(state.achievements.fire_bow, 1);
+        assert!(
+            result.newly_unlocked.iter().any(|n| n == "fire_bow"),
+            "firing the bow should unlock and reward the achievement"
+        );
+        // The full map export carries the Craftax counts too
+        assert_eq!(state.achievements.to_map().get("fire_bow"), Some(&1));
+    }
+
+    #[test]
+    fn test_step_craft_n_crafts_multiple_in_one_tick() {
+        let config = SessionConfig::default();
+        let mut session = Session::new(config);
+
+        let (px, py) = session.get_state().player_pos;
+        session.world.set_material((px - 1, py), Material::Table);
+        if let Some(player) = session.world.get_player_mut() {
+            player.inventory.wood = 3;
+        }
+
+        // Asking for five fences with three wood crafts three, all within a
+        // single world tick
+        let step_before = session.get_state().step;
+        session.step_craft_n(Action::MakeFence, 5);
+        let state = session.get_state();
+        assert_eq!(state.step, step_before + 1);
+        assert_eq!(state.inventory.fence, 3);
+        assert_eq!(state.inventory.wood, 0);
+    }
+
+    #[test]
+    fn test_carry_limit_blocks_pickups() {
+        let config = SessionConfig {
+            carry: crate::config::CarryConfig {
+                enabled: true,
+                max_total_items: 3,
+            },
+            ..Default::default()
+        };
+        let mut session = Session::new(config);
+
+        let (px, py) = session.get_state().player_pos;
+        let target = (px + 1, py);
+        session.world.set_material(target, Material::Tree);
+        if let Some(obj_id) = session.world.get_object_id_at(target) {
+            session.world.remove_object(obj_id);
+        }
+        if let Some(player) = session.world.get_player_mut() {
+            player.facing = (1, 0);
+            player.inventory.wood = 2;
+        }
+
+        // Room for one more item: the chop goes through
+        session.step(Action::Do);
+        assert_eq!(session.get_state().inventory.wood, 3);
+
+        // At the cap the tree stays put and the refusal is announced
+        session.world.set_material(target, Material::Tree);
+        let result = session.step(Action::Do);
+        assert_eq!(session.world.get_material(target), Some(Material::Tree));
+        assert_eq!(session.get_state().inventory.wood, 3);
+        assert!(
+            result.debug_events.iter().any(|e| e.starts_with("CAPACITY")),
+            "the blocked pickup should be announced"
+        );
+    }
+
+    #[test]
+    fn test_multi_hit_mining_accumulates_progress() {
+        let config = SessionConfig {
+            mining: crate::config::MiningConfig {
+                enabled: true,
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        let mut session = Session::new(config);
 
-        // Check max steps
-        if let Some(max_steps) = self.config.max_steps {
-            if self.timing.step >= max_steps as u64 {
-                return (true, Some(DoneReason::MaxSteps));
-            }
+        let (px, py) = session.get_state().player_pos;
+        let target = (px + 1, py);
+        session.world.set_material(target, Material::Tree);
+        if let Some(obj_id) = session.world.get_object_id_at(target) {
+            session.world.remove_object(obj_id);
+        }
+        if let Some(player) = session.world.get_player_mut() {
+            player.facing = (1, 0);
         }
 
-        (false, None)
-    }
-
-    /// Calculate rewards from achievements
-    fn calculate_rewards(&self) -> (f32, Vec<String>) {
-        let current = self
-            .world
-            .get_player()
-            .map(|p| &p.achievements)
-            .unwrap_or(&self.prev_achievements);
+        // Trees have hardness 2, so the first hit only leaves a crack
+        session.step(Action::Do);
+        assert_eq!(session.world.get_material(target), Some(Material::Tree));
+        assert_eq!(session.world.get_mining_progress(target), 1);
+        assert_eq!(session.get_state().inventory.wood, 0);
 
-        let mut reward = 0.0;
-        let mut newly_unlocked = Vec::new();
+        session.step(Action::Do);
+        assert_eq!(session.world.get_material(target), Some(Material::Grass));
+        assert_eq!(session.world.get_mining_progress(target), 0);
+        assert_eq!(session.get_state().inventory.wood, 1);
+    }
 
-        let names = if self.config.craftax.enabled && self.config.craftax.achievements_enabled {
-            Achievements::all_names_with_craftax()
-        } else {
-            Achievements::all_names().to_vec()
+    #[test]
+    fn test_better_pickaxe_mines_in_fewer_hits() {
+        let config = SessionConfig {
+            mining: crate::config::MiningConfig {
+                enabled: true,
+                ..Default::default()
+            },
+            ..Default::default()
         };
+        let mut session = Session::new(config);
 
-        for name in names {
-            let curr = current.get(name).unwrap_or(0);
-            let prev = self.prev_achievements.get(name).unwrap_or(0);
-            if curr > prev {
-                reward += 1.0;
-                if prev == 0 {
-                    newly_unlocked.push(name.to_string());
-                }
-            }
+        let (px, py) = session.get_state().player_pos;
+        let target = (px + 1, py);
+        session.world.set_material(target, Material::Stone);
+        if let Some(obj_id) = session.world.get_object_id_at(target) {
+            session.world.remove_object(obj_id);
+        }
+        if let Some(player) = session.world.get_player_mut() {
+            player.facing = (1, 0);
+            player.inventory.wood_pickaxe = 1;
         }
 
-        (reward, newly_unlocked)
-    }
-}
+        // Stone has hardness 3 and a wood pickaxe is exactly the required
+        // tier, so it takes all three hits
+        session.step(Action::Do);
+        session.step(Action::Do);
+        assert_eq!(session.world.get_material(target), Some(Material::Stone));
+        session.step(Action::Do);
+        assert_eq!(session.world.get_material(target), Some(Material::Path));
+        assert_eq!(session.get_state().inventory.stone, 1);
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+        // An iron pickaxe is two tiers above, which shaves the next stone
+        // down to a single hit
+        session.world.set_material(target, Material::Stone);
+        if let Some(player) = session.world.get_player_mut() {
+            player.inventory.iron_pickaxe = 1;
+        }
+        session.step(Action::Do);
+        assert_eq!(session.world.get_material(target), Some(Material::Path));
+        assert_eq!(session.get_state().inventory.stone, 2);
+    }
 
     #[test]
-    fn test_session_creation() {
+    fn test_spawn_caps_limit_mob_population() {
         let config = SessionConfig {
             world_size: (32, 32),
             seed: Some(42),
+            spawn_balance: crate::config::SpawnBalanceConfig {
+                global_mob_cap: 0,
+                ..Default::default()
+            },
             ..Default::default()
         };
 
-        let session = Session::new(config);
-        let state = session.get_state();
+        let mut session = Session::new(config);
+        let count_mobs = |session: &Session| {
+            session
+                .world
+                .objects
+                .values()
+                .filter(|obj| {
+                    matches!(
+                        obj,
+                        GameObject::Cow(_)
+                            | GameObject::Zombie(_)
+                            | GameObject::Skeleton(_)
+                            | GameObject::CraftaxMob(_)
+                    )
+                })
+                .count()
+        };
 
-        assert_eq!(state.step, 0);
-        assert_eq!(state.episode, 1);
-        assert!(state.inventory.is_alive());
+        let initial = count_mobs(&session);
+        for _ in 0..300 {
+            session.step(Action::Noop);
+        }
+
+        // With a zero global cap no mob may spawn at runtime; despawns and
+        // deaths can only shrink the worldgen population.
+        assert!(
+            count_mobs(&session) <= initial,
+            "mob population grew despite a zero global cap"
+        );
     }
 
     #[test]
@@ -2243,6 +5043,10 @@ mod tests {
                                 Material::Path => '_',
                                 Material::Table => '+',
                                 Material::Furnace => 'F',
+                                Material::SpikeTrap => '^',
+                                Material::DoorClosed => 'D',
+                                Material::DoorOpen => '/',
+                                Material::Fence => '=',
                             });
                         }
                     } else {
@@ -2404,7 +5208,7 @@ mod tests {
             println!("Action: {:?} (explore)", action);
             if do_step(&mut session, action, &mut step) { break; }
 
-            if step % 20 == 0 {
+            if step.is_multiple_of(20) {
                 show_state(&session, step);
             }
         }
@@ -2425,307 +5229,41 @@ mod tests {
 
     #[test]
     fn test_play_game_ai() {
-        use crate::material::Material;
-        use crate::entity::GameObject;
-
+        // The hand-rolled heuristic loop that used to live here is now
+        // [`crate::policy::SurvivalPolicy`] driven through the standard
+        // harness in [`crate::eval::evaluate`]; this stays as the
+        // "an agent can play a whole episode" smoke test
         let config = SessionConfig {
             world_size: (64, 64),
-            seed: Some(2024),  // Good spawn location
+            seed: Some(2024),
             max_steps: Some(10000),
             ..Default::default()
         };
+        let factory: &crate::eval::PolicyFactory =
+            &|| Box::new(crate::policy::SurvivalPolicy::new());
+        let report = crate::eval::evaluate(
+            "survival",
+            factory,
+            &config,
+            &[2024],
+            &crate::eval::EvaluateOptions {
+                max_steps: 10_000,
+                ..Default::default()
+            },
+        );
 
-        let mut session = Session::new(config);
-        let mut total_reward = 0.0;
-        let mut achievements_unlocked: Vec<String> = vec![];
-
-        println!("\n{}", "=".repeat(60));
-        println!("=== AI PLAYING CRAFTER (seed: 2024) ===\n");
-
-        // Simple AI strategy
-        fn choose_action(state: &GameState, world: &World) -> Action {
-            let inv = &state.inventory;
-            let pos = state.player_pos;
-            let facing = state.player_facing;
-            let facing_pos = (pos.0 + facing.0 as i32, pos.1 + facing.1 as i32);
-
-            // Priority 1: Critical survival - drink if thirsty
-            if inv.drink <= 5 {
-                // Check if facing water - drink immediately
-                if world.get_material(facing_pos) == Some(Material::Water) {
-                    return Action::Do;
-                }
-                // Look for water nearby (adjacent first)
-                for (dx, dy) in [(-1,0), (1,0), (0,-1), (0,1)] {
-                    let check = (pos.0 + dx, pos.1 + dy);
-                    if world.get_material(check) == Some(Material::Water) {
-                        return match (dx, dy) {
-                            (-1, 0) => Action::MoveLeft,
-                            (1, 0) => Action::MoveRight,
-                            (0, -1) => Action::MoveUp,
-                            (0, 1) => Action::MoveDown,
-                            _ => Action::Noop,
-                        };
-                    }
-                }
-                // Search wider for water if very thirsty
-                if inv.drink <= 3 {
-                    for radius in 2i32..=20 {
-                        for dy in -radius..=radius {
-                            for dx in -radius..=radius {
-                                if dx.abs() == radius || dy.abs() == radius {
-                                    let check = (pos.0 + dx, pos.1 + dy);
-                                    if world.get_material(check) == Some(Material::Water) {
-                                        if dx < 0 { return Action::MoveLeft; }
-                                        if dx > 0 { return Action::MoveRight; }
-                                        if dy < 0 { return Action::MoveUp; }
-                                        if dy > 0 { return Action::MoveDown; }
-                                    }
-                                }
-                            }
-                        }
-                    }
-                }
-            }
-
-            // Priority 2: Eat if hungry and cow nearby
-            if inv.food <= 4 {
-                for obj in world.objects.values() {
-                    if let GameObject::Cow(cow) = obj {
-                        let dist = (cow.pos.0 - pos.0).abs() + (cow.pos.1 - pos.1).abs();
-                        if dist <= 1 && facing_pos == cow.pos {
-                            return Action::Do; // Attack cow
-                        }
-                        if dist <= 5 {
-                            // Move toward cow
-                            let dx = (cow.pos.0 - pos.0).signum();
-                            let dy = (cow.pos.1 - pos.1).signum();
-                            if dx < 0 { return Action::MoveLeft; }
-                            if dx > 0 { return Action::MoveRight; }
-                            if dy < 0 { return Action::MoveUp; }
-                            if dy > 0 { return Action::MoveDown; }
-                        }
-                    }
-                }
-            }
-
-            // Priority 3: Sleep if energy low and safe
-            if inv.energy <= 2 && !state.player_sleeping {
-                // Check no zombies nearby
-                let mut safe = true;
-                for obj in world.objects.values() {
-                    if let GameObject::Zombie(z) = obj {
-                        let dist = (z.pos.0 - pos.0).abs() + (z.pos.1 - pos.1).abs();
-                        if dist < 10 { safe = false; break; }
-                    }
-                }
-                if safe {
-                    return Action::Sleep;
-                }
-            }
-
-            // Priority 4: Attack adjacent enemy (only if we have a sword)
-            if inv.best_sword_tier() > 0 {
-                for obj in world.objects.values() {
-                    match obj {
-                        GameObject::Zombie(z) if z.pos == facing_pos => return Action::Do,
-                        GameObject::Skeleton(s) if s.pos == facing_pos => return Action::Do,
-                        _ => {}
-                    }
-                }
-            }
-
-            // Priority 4b: Flee from VERY close zombie if no sword
-            if inv.best_sword_tier() == 0 {
-                for obj in world.objects.values() {
-                    if let GameObject::Zombie(z) = obj {
-                        let dist = (z.pos.0 - pos.0).abs() + (z.pos.1 - pos.1).abs();
-                        if dist <= 2 {
-                            let dx = pos.0 - z.pos.0;
-                            let dy = pos.1 - z.pos.1;
-                            if dx.abs() >= dy.abs() {
-                                if dx >= 0 { return Action::MoveRight; }
-                                else { return Action::MoveLeft; }
-                            } else {
-                                if dy >= 0 { return Action::MoveDown; }
-                                else { return Action::MoveUp; }
-                            }
-                        }
-                    }
-                }
-            }
-
-            // Priority 5: Craft tools if near table
-            let near_table = [(-1,0),(1,0),(0,-1),(0,1),(0,0)].iter().any(|(dx,dy)| {
-                world.get_material((pos.0+dx, pos.1+dy)) == Some(Material::Table)
-            });
-            let near_furnace = [(-1,0),(1,0),(0,-1),(0,1),(0,0)].iter().any(|(dx,dy)| {
-                world.get_material((pos.0+dx, pos.1+dy)) == Some(Material::Furnace)
-            });
-
-            if near_table {
-                // Prioritize sword for survival, then pickaxe for resources
-                if inv.wood_sword == 0 && inv.wood >= 1 { return Action::MakeWoodSword; }
-                if inv.wood_pickaxe == 0 && inv.wood >= 1 { return Action::MakeWoodPickaxe; }
-                if inv.stone_sword == 0 && inv.wood >= 1 && inv.stone >= 1 { return Action::MakeStoneSword; }
-                if inv.stone_pickaxe == 0 && inv.wood >= 1 && inv.stone >= 1 { return Action::MakeStonePickaxe; }
-                if near_furnace {
-                    if inv.iron_sword == 0 && inv.wood >= 1 && inv.coal >= 1 && inv.iron >= 1 { return Action::MakeIronSword; }
-                    if inv.iron_pickaxe == 0 && inv.wood >= 1 && inv.coal >= 1 && inv.iron >= 1 { return Action::MakeIronPickaxe; }
-                }
-            }
-
-            // Priority 6: Place table if we have enough wood (need 2 for table + 2 for pickaxe+sword)
-            if inv.wood >= 4 && !near_table {
-                if world.get_material(facing_pos).map(|m| m.is_walkable()).unwrap_or(false) {
-                    return Action::PlaceTable;
-                }
-            }
-
-            // Priority 7: Place furnace if we have stone
-            if inv.stone >= 5 && near_table && !near_furnace {
-                if world.get_material(facing_pos).map(|m| m.is_walkable()).unwrap_or(false) {
-                    return Action::PlaceFurnace;
-                }
-            }
-
-            // Priority 7b: Hunt nearby zombies if we have a sword
-            if inv.best_sword_tier() > 0 {
-                for obj in world.objects.values() {
-                    if let GameObject::Zombie(z) = obj {
-                        let dist = (z.pos.0 - pos.0).abs() + (z.pos.1 - pos.1).abs();
-                        if dist <= 4 {
-                            let dx = z.pos.0 - pos.0;
-                            let dy = z.pos.1 - pos.1;
-                            if dx < 0 { return Action::MoveLeft; }
-                            if dx > 0 { return Action::MoveRight; }
-                            if dy < 0 { return Action::MoveUp; }
-                            if dy > 0 { return Action::MoveDown; }
-                        }
-                    }
-                }
-            }
-
-            // Priority 8: Collect resources from adjacent tiles (turn to face, then collect)
-            for (dx, dy) in [(-1i32, 0i32), (1, 0), (0, -1), (0, 1)] {
-                let adj = (pos.0 + dx, pos.1 + dy);
-                if let Some(mat) = world.get_material(adj) {
-                    let can_collect = match mat {
-                        Material::Tree if inv.wood < 9 => true,
-                        Material::Stone if inv.stone < 9 && inv.wood_pickaxe > 0 => true,
-                        Material::Coal if inv.coal < 9 && inv.wood_pickaxe > 0 => true,
-                        Material::Iron if inv.iron < 9 && inv.stone_pickaxe > 0 => true,
-                        Material::Diamond if inv.diamond < 9 && inv.iron_pickaxe > 0 => true,
-                        Material::Grass if inv.sapling < 3 => true,
-                        _ => false,
-                    };
-                    if can_collect {
-                        // If facing it, collect; otherwise turn to face it
-                        if facing_pos == adj {
-                            return Action::Do;
-                        } else {
-                            // Turn to face the resource
-                            return match (dx, dy) {
-                                (-1, 0) => Action::MoveLeft,
-                                (1, 0) => Action::MoveRight,
-                                (0, -1) => Action::MoveUp,
-                                (0, 1) => Action::MoveDown,
-                                _ => Action::Noop,
-                            };
-                        }
-                    }
-                }
-            }
-
-            // Priority 9: Move toward resources
-            // Find nearest tree if we need wood
-            if inv.wood < 5 {
-                for dy in -10..=10 {
-                    for dx in -10..=10 {
-                        let check = (pos.0 + dx, pos.1 + dy);
-                        if world.get_material(check) == Some(Material::Tree) {
-                            if dx < 0 { return Action::MoveLeft; }
-                            if dx > 0 { return Action::MoveRight; }
-                            if dy < 0 { return Action::MoveUp; }
-                            if dy > 0 { return Action::MoveDown; }
-                        }
-                    }
-                }
-            }
-
-            // Find stone if we have pickaxe
-            if inv.stone < 5 && inv.wood_pickaxe > 0 {
-                for dy in -15..=15 {
-                    for dx in -15..=15 {
-                        let check = (pos.0 + dx, pos.1 + dy);
-                        if world.get_material(check) == Some(Material::Stone) {
-                            if dx < 0 { return Action::MoveLeft; }
-                            if dx > 0 { return Action::MoveRight; }
-                            if dy < 0 { return Action::MoveUp; }
-                            if dy > 0 { return Action::MoveDown; }
-                        }
-                    }
-                }
-            }
-
-            // Random exploration
-            match (pos.0 + pos.1) % 4 {
-                0 => Action::MoveLeft,
-                1 => Action::MoveRight,
-                2 => Action::MoveUp,
-                _ => Action::MoveDown,
-            }
-        }
-
-        // Play the game
-        let mut last_print_step = 0;
-        loop {
-            let state = session.get_state();
-            let action = choose_action(&state, &session.world);
-
-            // Debug: show action taken every 20 steps
-            if state.step % 20 == 0 {
-                println!("Step {}: pos={:?} facing={:?} action={:?} wood={}",
-                    state.step, state.player_pos, state.player_facing, action, state.inventory.wood);
-            }
-
-            let result = session.step(action);
-
-            total_reward += result.reward;
-            for ach in &result.newly_unlocked {
-                println!("Step {}: Achievement unlocked: {}", result.state.step, ach);
-                achievements_unlocked.push(ach.clone());
-            }
-
-            // Print status every 500 steps
-            if result.state.step - last_print_step >= 500 {
-                last_print_step = result.state.step;
-                let inv = &result.state.inventory;
-                println!("Step {}: HP:{} Food:{} Drink:{} Energy:{} | Wood:{} Stone:{} Coal:{} Iron:{} Diamond:{}",
-                    result.state.step, inv.health, inv.food, inv.drink, inv.energy,
-                    inv.wood, inv.stone, inv.coal, inv.iron, inv.diamond);
-            }
+        assert_eq!(report.episodes.len(), 1);
+        let episode = &report.episodes[0];
+        assert!(episode.steps > 0);
+        assert_eq!(episode.reward_curve.len() as u64, episode.steps);
 
-            if result.done {
-                println!("\n=== GAME OVER at step {} ===", result.state.step);
-                println!("Reason: {:?}", result.done_reason);
-                println!("Total reward: {}", total_reward);
-                println!("Achievements ({}):", achievements_unlocked.len());
-                for ach in &achievements_unlocked {
-                    println!("  - {}", ach);
-                }
-                let inv = &result.state.inventory;
-                println!("\nFinal inventory:");
-                println!("  Vitals: HP:{} Food:{} Drink:{} Energy:{}", inv.health, inv.food, inv.drink, inv.energy);
-                println!("  Resources: Wood:{} Stone:{} Coal:{} Iron:{} Diamond:{}", inv.wood, inv.stone, inv.coal, inv.iron, inv.diamond);
-                println!("  Tools: WoodPick:{} StonePick:{} IronPick:{} WoodSword:{} StoneSword:{} IronSword:{}",
-                    inv.wood_pickaxe, inv.stone_pickaxe, inv.iron_pickaxe, inv.wood_sword, inv.stone_sword, inv.iron_sword);
-                break;
-            }
+        println!("\n=== AI PLAYING CRAFTER (seed: 2024) ===");
+        println!("Steps: {}  Reward: {:.2}  Score: {:.2}", episode.steps, episode.reward, report.score);
+        println!("Achievements ({}):", episode.unlocked.len());
+        for name in &episode.unlocked {
+            println!("  - {}", name);
         }
-
-        println!("\nAchievement count: {}", achievements_unlocked.len());
-        // Don't assert - just report results
+        // Don't assert on performance - just report results
     }
 
     #[test]
@@ -3215,35 +5753,216 @@ mod mechanics_tests {
         session.step(Action::Sleep);
         assert!(session.get_state().player_sleeping, "Should be sleeping");
 
-        // Any action should wake up
-        session.step(Action::MoveRight);
-        assert!(!session.get_state().player_sleeping, "Should wake up on action");
+        // Any action should wake up
+        session.step(Action::MoveRight);
+        assert!(!session.get_state().player_sleeping, "Should wake up on action");
+    }
+
+    // ==================== COMBAT ====================
+
+    #[test]
+    fn test_attack_cow_unarmed() {
+        let config = SessionConfig::default();
+        let mut session = Session::new(config);
+
+        let player_pos = session.get_state().player_pos;
+        let cow_pos = (player_pos.0 + 1, player_pos.1);
+        let cow_id = session.world.add_object(GameObject::Cow(Cow::new(cow_pos)));
+
+        if let Some(player) = session.world.get_player_mut() {
+            player.facing = (1, 0);
+            player.inventory.food = 2;
+        }
+
+        // Attack 3 times (cow has 3 health, unarmed does 1 damage)
+        for _ in 0..3 {
+            session.world.move_object(cow_id, cow_pos);
+            session.step(Action::Do);
+        }
+
+        assert!(session.world.get_object(cow_id).is_none(), "Cow should be dead");
+        assert_eq!(session.get_state().inventory.food, 8, "Should gain 6 food from cow");
+    }
+
+    #[test]
+    fn test_sheep_and_pig_drops() {
+        let config = SessionConfig::default();
+        let mut session = Session::new(config);
+
+        let player_pos = session.get_state().player_pos;
+        let sheep_pos = (player_pos.0 + 1, player_pos.1);
+        let sheep_id = session
+            .world
+            .add_object(GameObject::Sheep(crate::entity::Sheep::new(sheep_pos)));
+
+        if let Some(player) = session.world.get_player_mut() {
+            player.facing = (1, 0);
+            player.inventory.food = 0;
+        }
+
+        // Attack 3 times (sheep has 3 health, unarmed does 1 damage)
+        for _ in 0..3 {
+            session.world.move_object(sheep_id, sheep_pos);
+            session.step(Action::Do);
+        }
+
+        let state = session.get_state();
+        assert!(session.world.get_object(sheep_id).is_none(), "Sheep should be dead");
+        assert_eq!(state.inventory.wool, 1, "Should gain 1 wool from sheep");
+        assert!(state.achievements.collect_wool > 0, "Should have collect_wool achievement");
+
+        let food_before = state.inventory.food;
+        let pig_pos = (player_pos.0 + 1, player_pos.1);
+        let pig_id = session
+            .world
+            .add_object(GameObject::Pig(crate::entity::Pig::new(pig_pos)));
+
+        // Attack 4 times (pig has 4 health)
+        for _ in 0..4 {
+            session.world.move_object(pig_id, pig_pos);
+            session.step(Action::Do);
+        }
+
+        let state = session.get_state();
+        assert!(session.world.get_object(pig_id).is_none(), "Pig should be dead");
+        assert!(
+            state.inventory.food > food_before,
+            "Should gain food from pig"
+        );
+        assert!(state.achievements.eat_pig > 0, "Should have eat_pig achievement");
+    }
+
+    #[test]
+    fn test_snail_armor_and_wildlife_kill_achievements() {
+        let config = SessionConfig {
+            craftax: crate::config::CraftaxConfig {
+                enabled: true,
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        let mut session = Session::new(config);
+
+        let player_pos = session.get_state().player_pos;
+        let mob_pos = (player_pos.0 + 1, player_pos.1);
+        let snail_id = session.world.add_object(GameObject::CraftaxMob(
+            crate::entity::CraftaxMob::new(crate::entity::CraftaxMobKind::Snail, mob_pos, 3),
+        ));
+
+        if let Some(player) = session.world.get_player_mut() {
+            player.facing = (1, 0);
+            player.inventory.wood_sword = 1; // 2 damage, snail armor absorbs 1
+        }
+
+        // Effective damage is 1 per hit, so the snail survives two swings
+        for _ in 0..2 {
+            session.world.move_object(snail_id, mob_pos);
+            session.step(Action::Do);
+        }
+        assert!(
+            session.world.get_object(snail_id).is_some(),
+            "Armored snail should survive two wood sword hits"
+        );
+
+        session.world.move_object(snail_id, mob_pos);
+        session.step(Action::Do);
+        assert!(session.world.get_object(snail_id).is_none(), "Snail should be dead");
+        assert!(
+            session.get_state().achievements.defeat_snail > 0,
+            "Should have defeat_snail achievement"
+        );
+
+        let bat_id = session.world.add_object(GameObject::CraftaxMob(
+            crate::entity::CraftaxMob::new(crate::entity::CraftaxMobKind::Bat, mob_pos, 2),
+        ));
+        session.world.move_object(bat_id, mob_pos);
+        session.step(Action::Do);
+        assert!(session.world.get_object(bat_id).is_none(), "Bat should be dead");
+        assert!(
+            session.get_state().achievements.defeat_bat > 0,
+            "Should have defeat_bat achievement"
+        );
     }
 
-    // ==================== COMBAT ====================
-
     #[test]
-    fn test_attack_cow_unarmed() {
+    fn test_fireball_applies_burn() {
         let config = SessionConfig::default();
         let mut session = Session::new(config);
 
         let player_pos = session.get_state().player_pos;
-        let cow_pos = (player_pos.0 + 1, player_pos.1);
-        let cow_id = session.world.add_object(GameObject::Cow(Cow::new(cow_pos)));
+        let fireball = crate::entity::Arrow::with_flight(
+            (player_pos.0 - 1, player_pos.1),
+            (1, 0),
+            crate::entity::ProjectileKind::Fireball,
+            3,
+            DamageSource::CraftaxMagic,
+            1,
+            false,
+        );
+        session.world.add_object(GameObject::Arrow(fireball));
 
-        if let Some(player) = session.world.get_player_mut() {
-            player.facing = (1, 0);
-            player.inventory.food = 2;
+        let health_before = session.world.get_player().unwrap().inventory.health;
+        session.process_arrows();
+
+        let player = session.world.get_player().unwrap();
+        assert_eq!(player.inventory.health, health_before - 3, "Fireball should deal 3 damage");
+        assert_eq!(player.burn_ticks, 6, "Fireball hit should ignite the player");
+
+        // Burn deals 1 damage every 3 ticks for 6 ticks (2 total)
+        let health_after_hit = player.inventory.health;
+        for _ in 0..6 {
+            session.step(Action::Noop);
         }
+        let player = session.world.get_player().unwrap();
+        assert_eq!(player.burn_ticks, 0, "Burn should wear off after 6 ticks");
+        assert_eq!(
+            player.inventory.health,
+            health_after_hit - 2,
+            "Burn should deal 2 damage over its duration"
+        );
+    }
 
-        // Attack 3 times (cow has 3 health, unarmed does 1 damage)
-        for _ in 0..3 {
-            session.world.move_object(cow_id, cow_pos);
-            session.step(Action::Do);
+    #[test]
+    fn test_piercing_arrow_kills_mobs_in_a_line() {
+        let config = SessionConfig::default();
+        let mut session = Session::new(config);
+
+        // Lay out a clear lane away from the player so mob AI cannot interfere
+        for x in 5..=8 {
+            session.world.set_material((x, 5), Material::Grass);
         }
+        let first = session.world.add_object(GameObject::Zombie(Zombie::new((6, 5))));
+        let second = session.world.add_object(GameObject::Zombie(Zombie::new((7, 5))));
 
-        assert!(session.world.get_object(cow_id).is_none(), "Cow should be dead");
-        assert_eq!(session.get_state().inventory.food, 8, "Should gain 6 food from cow");
+        let arrow = crate::entity::Arrow::with_flight(
+            (5, 5),
+            (1, 0),
+            crate::entity::ProjectileKind::Arrow,
+            10,
+            DamageSource::PlayerArrow,
+            2,
+            true,
+        );
+        let arrow_id = session.world.add_object(GameObject::Arrow(arrow));
+
+        session.process_arrows();
+        assert!(session.world.get_object(first).is_none(), "First zombie should be dead");
+        assert!(session.world.get_object(second).is_none(), "Second zombie should be dead");
+        assert!(
+            session.world.get_object(arrow_id).is_some(),
+            "Piercing arrow should keep flying after killing both"
+        );
+
+        // A surviving target still stops a piercing arrow
+        let tough = session
+            .world
+            .add_object(GameObject::Zombie(Zombie::with_health((8, 5), 50)));
+        session.process_arrows();
+        assert!(session.world.get_object(tough).is_some(), "Tough zombie should survive");
+        assert!(
+            session.world.get_object(arrow_id).is_none(),
+            "Arrow should lodge in a target it fails to kill"
+        );
     }
 
     #[test]
@@ -3878,6 +6597,8 @@ mod mechanics_tests {
             if result.done {
                 done = true;
                 assert!(matches!(result.done_reason, Some(DoneReason::Death)));
+                // Death terminates; it is not a truncation
+                assert!(!result.truncated);
                 break;
             }
         }
@@ -3900,6 +6621,8 @@ mod mechanics_tests {
             if result.done {
                 done = true;
                 assert!(matches!(result.done_reason, Some(DoneReason::MaxSteps)));
+                // The step limit truncates rather than terminates
+                assert!(result.truncated);
                 break;
             }
         }
@@ -3907,6 +6630,230 @@ mod mechanics_tests {
         assert!(done, "Game should end at max steps");
     }
 
+    #[test]
+    fn test_milestone_events() {
+        let config = SessionConfig {
+            world_size: (32, 32),
+            seed: Some(42),
+            ..Default::default()
+        };
+        let mut session = Session::new(config);
+
+        // A nights-survived count above the session best raises a record
+        // milestone exactly once
+        session.nights_survived = 1;
+        let result = session.step(Action::Noop);
+        assert!(result
+            .milestones
+            .contains(&MilestoneEvent::NightSurvivalRecord { nights: 1 }));
+        let result = session.step(Action::Noop);
+        assert!(!result
+            .milestones
+            .iter()
+            .any(|m| matches!(m, MilestoneEvent::NightSurvivalRecord { .. })));
+
+        // Low health warns once per dip, not every step
+        session.world.get_player_mut().unwrap().inventory.health = 2;
+        let result = session.step(Action::Noop);
+        assert!(result
+            .milestones
+            .iter()
+            .any(|m| matches!(m, MilestoneEvent::LowHealth { .. })));
+        let result = session.step(Action::Noop);
+        assert!(!result
+            .milestones
+            .iter()
+            .any(|m| matches!(m, MilestoneEvent::LowHealth { .. })));
+    }
+
+    #[test]
+    fn test_state_hash_is_deterministic() {
+        let config = SessionConfig {
+            world_size: (32, 32),
+            seed: Some(7),
+            ..Default::default()
+        };
+        let mut a = Session::new(config.clone());
+        let mut b = Session::new(config);
+        assert_eq!(a.state_hash(), b.state_hash());
+
+        for action in [Action::MoveRight, Action::Do, Action::MoveDown] {
+            a.step(action);
+            b.step(action);
+        }
+        assert_eq!(a.state_hash(), b.state_hash());
+
+        // A different seed or further play changes the digest
+        let c = Session::new(SessionConfig {
+            world_size: (32, 32),
+            seed: Some(8),
+            ..Default::default()
+        });
+        assert_ne!(a.state_hash(), c.state_hash());
+        let before = b.state_hash();
+        b.step(Action::MoveLeft);
+        assert_ne!(before, b.state_hash());
+    }
+
+    #[test]
+    fn test_action_repeat_advances_multiple_ticks() {
+        let mut session = Session::new(SessionConfig {
+            world_size: (32, 32),
+            seed: Some(7),
+            action_repeat: 4,
+            ..Default::default()
+        });
+        let result = session.step(Action::Noop);
+        assert_eq!(result.state.step, 4);
+        assert_eq!(session.timing.step, 4);
+
+        // The episode ending cuts a repeat short
+        let mut short = Session::new(SessionConfig {
+            world_size: (32, 32),
+            seed: Some(7),
+            max_steps: Some(2),
+            action_repeat: 4,
+            ..Default::default()
+        });
+        let result = short.step(Action::Noop);
+        assert!(result.done);
+        assert_eq!(result.state.step, 2);
+    }
+
+    #[test]
+    fn test_sticky_actions_repeat_the_previous_action() {
+        let mut session = Session::new(SessionConfig {
+            world_size: (32, 32),
+            seed: Some(7),
+            sticky_action_prob: 1.0,
+            ..Default::default()
+        });
+        // The first step has no previous action to stick to
+        session.step(Action::MoveRight);
+        assert_eq!(session.prev_action, Some(Action::MoveRight));
+        // With probability 1 every later step repeats the previous one
+        session.step(Action::MoveLeft);
+        assert_eq!(session.prev_action, Some(Action::MoveRight));
+
+        // Off by default: the supplied action always executes
+        let mut plain = Session::new(SessionConfig {
+            world_size: (32, 32),
+            seed: Some(7),
+            ..Default::default()
+        });
+        plain.step(Action::MoveRight);
+        plain.step(Action::MoveLeft);
+        assert_eq!(plain.prev_action, Some(Action::MoveLeft));
+    }
+
+    #[test]
+    fn test_episode_seed_is_resolved_and_reproducible() {
+        let fixed = Session::new(SessionConfig {
+            world_size: (32, 32),
+            seed: Some(42),
+            ..Default::default()
+        });
+        assert_eq!(fixed.episode_seed(), 42);
+        assert_eq!(fixed.get_state().seed, 42);
+        assert!(fixed.repro_command().contains("--seed 42"));
+        assert!(fixed.repro_command().contains("--world-size 32x32"));
+
+        // A random-seed session records the concrete seed it drew;
+        // pinning that seed regenerates the identical world
+        let random = Session::new(SessionConfig {
+            world_size: (32, 32),
+            seed: None,
+            ..Default::default()
+        });
+        let replayed = Session::new(SessionConfig {
+            world_size: (32, 32),
+            seed: Some(random.episode_seed()),
+            ..Default::default()
+        });
+        assert_eq!(random.world.state_hash(), replayed.world.state_hash());
+
+        // Recordings embed the resolved seed, not `None`
+        let rec = crate::recording::RecordingSession::new(
+            SessionConfig {
+                world_size: (32, 32),
+                seed: None,
+                ..Default::default()
+            },
+            crate::recording::RecordingOptions::minimal(),
+        );
+        assert_eq!(
+            rec.recording().config.seed,
+            Some(rec.session().episode_seed())
+        );
+    }
+
+    #[test]
+    fn test_world_pool_cycles_round_robin_from_cache() {
+        let mut session = Session::new(SessionConfig {
+            world_size: (32, 32),
+            seed: Some(42),
+            world_pool_size: 3,
+            ..Default::default()
+        });
+        // The whole pool is pre-generated; episode 1 cloned from it
+        assert_eq!(session.world_cache().len(), 3);
+        assert_eq!(session.world_cache().misses(), 3);
+
+        let mut hashes = vec![session.world.state_hash()];
+        for _ in 0..3 {
+            session.reset();
+            hashes.push(session.world.state_hash());
+        }
+        // Three pool worlds, then back to the first
+        assert_ne!(hashes[0], hashes[1]);
+        assert_ne!(hashes[1], hashes[2]);
+        assert_eq!(hashes[0], hashes[3]);
+        // Every reset was a clone, never a regeneration
+        assert_eq!(session.world_cache().misses(), 3);
+        assert_eq!(session.world_cache().hits(), 4);
+
+        // A fixed seed without a pool also stops regenerating: the
+        // second reset clones the first reset's world
+        let mut fixed = Session::new(SessionConfig {
+            world_size: (32, 32),
+            seed: Some(7),
+            ..Default::default()
+        });
+        fixed.reset();
+        fixed.reset();
+        assert_eq!(fixed.world_cache().misses(), 1);
+        assert_eq!(fixed.world_cache().hits(), 2);
+    }
+
+    #[test]
+    fn test_reset_from_template_clones_the_pristine_world() {
+        let config = SessionConfig {
+            world_size: (32, 32),
+            seed: Some(42),
+            ..Default::default()
+        };
+        let template = crate::worldgen::WorldTemplate::generate(&config);
+        assert_eq!(template.seed(), 42);
+
+        // A templated reset lands on the template's map regardless of
+        // the session's own seed, with episode state rolled as usual
+        let mut session = Session::new(SessionConfig {
+            seed: Some(7),
+            ..config
+        });
+        session.step(Action::MoveRight);
+        session.reset_from_template(&template);
+        assert_eq!(session.episode, 2);
+        assert_eq!(session.episode_seed(), 42);
+        assert_eq!(session.timing.step, 0);
+        assert_eq!(session.world.state_hash(), template.world().state_hash());
+
+        // Dirtying the clone leaves the shared template pristine
+        session.world.set_material((0, 0), Material::Lava);
+        session.reset_from_template(&template);
+        assert_eq!(session.world.state_hash(), template.world().state_hash());
+    }
+
     // ==================== INTEGRATION TESTS ====================
 
     #[test]
@@ -3989,6 +6936,366 @@ mod mechanics_tests {
         assert_eq!(session.get_state().inventory.food, 8, "Should gain 6 food from cow");
     }
 
+    #[test]
+    fn test_rules_table_overrides_balance_constants() {
+        let config = SessionConfig::from_toml_str(
+            "world_size = [32, 32]\nseed = 54321\n\n[rules]\ncow_food = 3\n",
+        )
+        .unwrap();
+        assert_eq!(config.rules.version, crate::config::RULES_VERSION);
+        assert_eq!(config.rules.cow_food, 3);
+        // Untouched entries keep the built-in values
+        assert_eq!(config.rules.plant_food, 4);
+        assert_eq!(config.rules.mob_despawn_distance, 30);
+
+        let mut session = Session::new(config);
+        if let Some(player) = session.world.get_player_mut() {
+            player.inventory.food = 2;
+        }
+        let player_pos = session.get_state().player_pos;
+        let cow_pos = (player_pos.0 + 1, player_pos.1);
+        let cow_id = session.world.add_object(GameObject::Cow(Cow::new(cow_pos)));
+        if let Some(player) = session.world.get_player_mut() {
+            player.facing = (1, 0);
+        }
+
+        for _ in 0..5 {
+            if session.world.get_object(cow_id).is_some() {
+                session.world.move_object(cow_id, cow_pos);
+            }
+            session.step(Action::Do);
+        }
+
+        assert_eq!(
+            session.get_state().inventory.food,
+            5,
+            "Overridden rules should grant 3 food from the cow"
+        );
+    }
+
+    #[test]
+    fn test_step_transaction_rolls_back_on_blocked_move() {
+        let config = SessionConfig {
+            world_size: (32, 32),
+            seed: Some(7),
+            ..Default::default()
+        };
+        let mut session = Session::new(config);
+        let start = session.get_state().player_pos;
+
+        // Clear the first tile so the opening move succeeds, then wall
+        // off the second so the batch fails partway through
+        session.world.set_material((start.0 + 1, start.1), Material::Grass);
+        session.world.set_material((start.0 + 2, start.1), Material::Stone);
+        assert!(session.world.is_walkable((start.0 + 1, start.1)));
+        let step_before = session.get_state().step;
+
+        let err = session
+            .step_transaction(&[Action::MoveRight, Action::MoveRight, Action::Do])
+            .unwrap_err();
+        assert_eq!(err.failed_index, 1);
+        assert_eq!(
+            session.get_state().player_pos,
+            start,
+            "rollback should undo the first move"
+        );
+        assert_eq!(session.get_state().step, step_before);
+    }
+
+    #[test]
+    fn test_step_transaction_applies_when_preconditions_hold() {
+        let config = SessionConfig {
+            world_size: (32, 32),
+            seed: Some(7),
+            ..Default::default()
+        };
+        let mut session = Session::new(config);
+        let start = session.get_state().player_pos;
+        session.world.set_material((start.0 + 1, start.1), Material::Grass);
+
+        let results = session
+            .step_transaction(&[Action::MoveRight, Action::Noop])
+            .unwrap();
+        assert_eq!(results.len(), 2);
+        assert_eq!(session.get_state().player_pos, (start.0 + 1, start.1));
+    }
+
+    #[test]
+    fn test_action_mask_gates_crafting_and_placement() {
+        let config = SessionConfig {
+            world_size: (32, 32),
+            seed: Some(42),
+            ..Default::default()
+        };
+        let mut session = Session::new(config);
+
+        // Without wood or a table, crafting a pickaxe is a no-op
+        assert!(!session.action_legal(Action::MakeWoodPickaxe));
+        assert!(session.action_legal(Action::Noop));
+        assert!(session.action_legal(Action::MoveLeft));
+
+        let pos = session.get_state().player_pos;
+        session.world.set_material((pos.0 + 1, pos.1), Material::Table);
+        session.world.get_player_mut().unwrap().inventory.wood = 5;
+        assert!(session.action_legal(Action::MakeWoodPickaxe));
+        // Iron tools also need a furnace and iron
+        assert!(!session.action_legal(Action::MakeIronPickaxe));
+
+        // Placement needs open grass in front
+        session.world.get_player_mut().unwrap().inventory.stone = 4;
+        let facing = session.world.get_player().unwrap().facing;
+        let target = (pos.0 + facing.0 as i32, pos.1 + facing.1 as i32);
+        session.world.set_material(target, Material::Grass);
+        assert!(session.action_legal(Action::PlaceStone));
+        session.world.set_material(target, Material::Stone);
+        assert!(!session.action_legal(Action::PlaceStone));
+
+        // The step result carries the mask, aligned with the table
+        let table = session.config.action_profile.action_table().to_vec();
+        let result = session.step(Action::Noop);
+        assert_eq!(result.action_mask.len(), table.len());
+        let legal = session.legal_actions();
+        assert!(legal.contains(&Action::Noop));
+        for (action, legal_now) in table.iter().zip(&result.action_mask) {
+            assert_eq!(session.action_legal(*action), *legal_now);
+        }
+    }
+
+    #[test]
+    fn test_tick_interval_carries_exact_remainders() {
+        // 30 tps doesn't divide a second in whole nanoseconds; the
+        // carried remainder must make 30 ticks consume exactly 1s
+        let interval = TickInterval::from_tps(30.0);
+        let mut frac = 0;
+        let mut consumed: u64 = 0;
+        for _ in 0..30 {
+            let (tick_ns, next_frac) = interval.advance(frac);
+            consumed += tick_ns;
+            frac = next_frac;
+        }
+        assert_eq!(consumed, 1_000_000_000);
+        assert_eq!(frac, 0);
+
+        // And 3000 ticks consume exactly 100s — no drift at scale
+        let mut frac = 0;
+        let mut consumed: u64 = 0;
+        for _ in 0..3000 {
+            let (tick_ns, next_frac) = interval.advance(frac);
+            consumed += tick_ns;
+            frac = next_frac;
+        }
+        assert_eq!(consumed, 100_000_000_000);
+    }
+
+    #[test]
+    fn test_realtime_updates_are_exactly_reproducible() {
+        let config = SessionConfig {
+            world_size: (32, 32),
+            seed: Some(42),
+            time_mode: TimeMode::RealTime {
+                ticks_per_second: 30.0,
+                pause_on_disconnect: false,
+            },
+            ..Default::default()
+        };
+        let mut session = Session::new(config);
+
+        // Ten exact 100ms updates at 30 tps: 3 ticks each, and the
+        // accumulator lands back on exactly zero
+        for _ in 0..10 {
+            let results = session.update(Duration::from_millis(100));
+            assert_eq!(results.len(), 3);
+        }
+        assert_eq!(session.timing.step, 30);
+        assert_eq!(session.timing.tick_accumulator, Duration::ZERO);
+        assert_eq!(session.timing.tick_frac_nanos, 0);
+    }
+
+    #[test]
+    fn test_elapsed_play_time_excludes_pauses() {
+        let config = SessionConfig {
+            world_size: (32, 32),
+            seed: Some(42),
+            ..Default::default()
+        };
+        let mut session = Session::new(config);
+        for _ in 0..5 {
+            session.step(Action::Noop);
+        }
+
+        // An open pause freezes the play clock
+        session.set_paused(true);
+        let frozen = session.elapsed_play_time();
+        std::thread::sleep(Duration::from_millis(20));
+        let during = session.elapsed_play_time();
+        assert!(during < frozen + Duration::from_millis(15));
+
+        // Resume closes the interval into the running total
+        session.set_paused(false);
+        assert!(session.timing.total_pause_duration >= Duration::from_millis(20));
+        assert!(session.elapsed_play_time() + Duration::from_millis(15) < session.timing.created_at.elapsed());
+
+        // Achieved tps is steps over play time, nonzero once stepped
+        assert!(session.achieved_tps() > 0.0);
+    }
+
+    #[test]
+    fn test_lag_policies_handle_tick_backlog() {
+        let realtime = |lag_policy| SessionConfig {
+            world_size: (32, 32),
+            seed: Some(42),
+            time_mode: TimeMode::RealTime {
+                ticks_per_second: 10.0,
+                pause_on_disconnect: false,
+            },
+            lag_policy,
+            ..Default::default()
+        };
+
+        // drop_ticks: the backlog beyond the budget is discarded, and
+        // the drop is reported instead of happening silently
+        let mut session = Session::new(realtime(LagPolicy::DropTicks));
+        let results = session.update(Duration::from_secs(5));
+        assert_eq!(results.len(), 10);
+        assert_eq!(session.timing.tick_accumulator, Duration::ZERO);
+        let result = session.step(Action::Noop);
+        assert!(result.debug_events.iter().any(|e| e.contains("drop_ticks")));
+
+        // slow_clock: up to a budget's worth of backlog is carried, so
+        // later updates catch up without being handed more time
+        let mut session = Session::new(realtime(LagPolicy::SlowClock));
+        let results = session.update(Duration::from_secs(5));
+        assert_eq!(results.len(), 10);
+        assert_eq!(session.timing.tick_accumulator, Duration::from_secs(1));
+        let results = session.update(Duration::ZERO);
+        assert_eq!(results.len(), 10);
+
+        // batch_ticks: the whole backlog is processed in one update
+        let mut session = Session::new(realtime(LagPolicy::BatchTicks));
+        let results = session.update(Duration::from_secs(5));
+        assert_eq!(results.len(), 50);
+        assert_eq!(session.timing.tick_accumulator, Duration::ZERO);
+    }
+
+    #[test]
+    fn test_idle_detection_auto_pauses_and_resumes_on_input() {
+        let config = SessionConfig {
+            world_size: (32, 32),
+            seed: Some(42),
+            time_mode: TimeMode::RealTime {
+                ticks_per_second: 10.0,
+                pause_on_disconnect: false,
+            },
+            // Zero threshold: any update without real input is idle
+            idle_pause_secs: Some(0.0),
+            ..Default::default()
+        };
+        let mut session = Session::new(config);
+
+        // With no real input yet, the first update auto-pauses instead
+        // of ticking noops into the episode
+        let results = session.update(Duration::from_millis(200));
+        assert!(results.is_empty());
+        assert!(session.timing.paused);
+        assert!(session.is_idle_paused());
+        let step_before = session.timing.step;
+
+        // Paused updates accumulate pause time, not steps
+        session.update(Duration::from_millis(200));
+        assert_eq!(session.timing.step, step_before);
+
+        // Real input resumes; the queued event surfaces on the next step
+        session.set_action(Action::MoveRight);
+        assert!(!session.timing.paused);
+        assert!(!session.is_idle_paused());
+        let result = session.step(Action::MoveRight);
+        assert!(result
+            .debug_events
+            .iter()
+            .any(|e| e.contains("auto-paused")));
+    }
+
+    #[test]
+    fn test_state_diff_reports_incremental_changes() {
+        let config = SessionConfig {
+            world_size: (32, 32),
+            seed: Some(42),
+            view_radius: 3,
+            ..Default::default()
+        };
+        let mut session = Session::new(config);
+        let before = session.get_state();
+
+        // Identical snapshots diff to nothing
+        assert!(before.diff(&before).is_empty());
+
+        // A synthetic transition: gained wood, lost food, tile mutated
+        let mut after = before.clone();
+        after.inventory.wood = 2;
+        after.inventory.food = after.inventory.food.saturating_sub(1);
+        let view = after.view.as_mut().unwrap();
+        let center = view.size() * view.size() / 2;
+        view.materials[center] = Material::Furnace;
+
+        let delta = after.diff(&before);
+        assert!(!delta.is_empty());
+        assert_eq!(delta.inventory, vec![("wood", 2)]);
+        assert_eq!(delta.vitals, vec![("food", -1)]);
+        let radius = after.view.as_ref().unwrap().radius as i32;
+        assert_eq!(delta.tiles, vec![(radius, radius, Material::Furnace)]);
+        assert!(delta.player_moved.is_none());
+
+        // A real step diffs cleanly against the previous snapshot
+        session.step(Action::MoveRight);
+        let stepped = session.get_state();
+        let delta = stepped.diff(&before);
+        if stepped.player_pos != before.player_pos {
+            assert_eq!(delta.player_moved, Some(stepped.player_pos));
+        }
+    }
+
+    #[test]
+    fn test_weighted_achievement_rewards() {
+        let config = SessionConfig {
+            world_size: (32, 32),
+            seed: Some(42),
+            ..Default::default()
+        };
+        let mut session = Session::new(config);
+
+        let prev = Achievements::default();
+        let curr = Achievements {
+            collect_wood: 1,
+            ..Default::default()
+        };
+
+        // Without a reward config: classic flat +1
+        let (reward, unlocked) = session.achievement_reward(&curr, &prev);
+        assert_eq!(reward, 1.0);
+        assert_eq!(unlocked, vec!["collect_wood".to_string()]);
+
+        // With weights: the configured value replaces the flat +1
+        let mut reward_config = crate::rewards::RewardConfig::default();
+        reward_config.set_achievement_reward("collect_wood", 5.0);
+        reward_config.set_achievement_repeat_reward("collect_wood", 0.5);
+        session.set_reward_config(reward_config);
+        let (reward, _) = session.achievement_reward(&curr, &prev);
+        assert_eq!(reward, 5.0);
+
+        // Repeat unlocks earn the repeat weight per extra count
+        let prev_repeat = Achievements {
+            collect_wood: 1,
+            ..Default::default()
+        };
+        let curr_repeat = Achievements {
+            collect_wood: 3,
+            ..Default::default()
+        };
+        let (reward, unlocked) = session.achievement_reward(&curr_repeat, &prev_repeat);
+        assert_eq!(reward, 1.0);
+        assert!(unlocked.is_empty());
+    }
+
     #[test]
     fn test_full_game_sleep_energy() {
         let config = SessionConfig {
@@ -0,0 +1,201 @@
+//! Zero-copy observation export over shared memory (requires the `shm`
+//! feature)
+//!
+//! High-frame-rate consumers (Python bindings, external viewers) should
+//! not pay for serializing megabytes of frame or observation data per
+//! step. [`ShmWriter`] maps a file — put it on tmpfs, e.g. `/dev/shm` —
+//! and republishes a payload region in place under a seqlock, so the
+//! Rust side does one memcpy and readers in any language map the same
+//! pages and copy nothing.
+//!
+//! Layout: a 64-byte header (magic, capacity, sequence, payload length,
+//! all little-endian u64) followed by the payload region. The sequence
+//! number is odd while a write is in flight and even once the payload is
+//! consistent; readers retry when they observe an odd or changed
+//! sequence. A Python reader is a few lines:
+//!
+//! ```text
+//! m = mmap.mmap(os.open(path, os.O_RDONLY), 0, prot=mmap.PROT_READ)
+//! seq, length = struct.unpack_from("<QQ", m, 16)
+//! frame = np.frombuffer(m, np.uint8, count=length, offset=64)
+//! ```
+
+use memmap2::{Mmap, MmapMut};
+use std::fs::OpenOptions;
+use std::path::Path;
+use std::sync::atomic::{fence, Ordering};
+
+/// Identifies a crafter shared-memory channel (and its layout version)
+const MAGIC: u64 = 0x4352_5348_4d31; // "CRSHM1"
+
+/// Header size in bytes; the payload region starts here
+pub const HEADER_LEN: usize = 64;
+
+const MAGIC_OFFSET: usize = 0;
+const CAPACITY_OFFSET: usize = 8;
+const SEQ_OFFSET: usize = 16;
+const LEN_OFFSET: usize = 24;
+
+fn read_u64(buf: &[u8], offset: usize) -> u64 {
+    u64::from_le_bytes(buf[offset..offset + 8].try_into().unwrap())
+}
+
+fn write_u64(buf: &mut [u8], offset: usize, value: u64) {
+    buf[offset..offset + 8].copy_from_slice(&value.to_le_bytes());
+}
+
+/// Publishing side of a shared-memory channel
+pub struct ShmWriter {
+    map: MmapMut,
+    capacity: usize,
+    seq: u64,
+}
+
+impl ShmWriter {
+    /// Create (or truncate) the channel file with room for payloads up
+    /// to `capacity` bytes
+    pub fn create<P: AsRef<Path>>(path: P, capacity: usize) -> std::io::Result<Self> {
+        let file = OpenOptions::new()
+            .read(true)
+            .write(true)
+            .create(true)
+            .truncate(true)
+            .open(path)?;
+        file.set_len((HEADER_LEN + capacity) as u64)?;
+        // Safety: the map is backed by a file we own for the writer's
+        // lifetime; concurrent readers handle torn state via the seqlock
+        let mut map = unsafe { MmapMut::map_mut(&file)? };
+        write_u64(&mut map, MAGIC_OFFSET, MAGIC);
+        write_u64(&mut map, CAPACITY_OFFSET, capacity as u64);
+        write_u64(&mut map, SEQ_OFFSET, 0);
+        write_u64(&mut map, LEN_OFFSET, 0);
+        Ok(Self {
+            map,
+            capacity,
+            seq: 0,
+        })
+    }
+
+    /// Maximum payload size this channel can carry
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// Publish a new payload in place. Returns the sequence number the
+    /// payload was published under.
+    pub fn publish(&mut self, payload: &[u8]) -> std::io::Result<u64> {
+        if payload.len() > self.capacity {
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                format!(
+                    "payload of {} bytes exceeds channel capacity {}",
+                    payload.len(),
+                    self.capacity
+                ),
+            ));
+        }
+        // Mark the region inconsistent (odd), copy, then mark consistent
+        self.seq += 1;
+        write_u64(&mut self.map, SEQ_OFFSET, self.seq);
+        fence(Ordering::SeqCst);
+        write_u64(&mut self.map, LEN_OFFSET, payload.len() as u64);
+        self.map[HEADER_LEN..HEADER_LEN + payload.len()].copy_from_slice(payload);
+        fence(Ordering::SeqCst);
+        self.seq += 1;
+        write_u64(&mut self.map, SEQ_OFFSET, self.seq);
+        Ok(self.seq)
+    }
+}
+
+/// Consuming side of a shared-memory channel. Mostly useful for tests
+/// and Rust-side tooling; Python readers map the file directly.
+pub struct ShmReader {
+    map: Mmap,
+}
+
+impl ShmReader {
+    /// Map an existing channel file
+    pub fn open<P: AsRef<Path>>(path: P) -> std::io::Result<Self> {
+        let file = OpenOptions::new().read(true).open(path)?;
+        // Safety: read-only map; torn state is handled via the seqlock
+        let map = unsafe { Mmap::map(&file)? };
+        if map.len() < HEADER_LEN || read_u64(&map, MAGIC_OFFSET) != MAGIC {
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::InvalidData,
+                "not a crafter shared-memory channel",
+            ));
+        }
+        Ok(Self { map })
+    }
+
+    /// Sequence number of the most recent publish (odd while a write is
+    /// in flight)
+    pub fn sequence(&self) -> u64 {
+        read_u64(&self.map, SEQ_OFFSET)
+    }
+
+    /// Copy the latest consistent payload into `out`, returning its
+    /// sequence number, or `None` when nothing has been published yet.
+    /// Retries while the writer is mid-publish.
+    pub fn read_latest(&self, out: &mut Vec<u8>) -> Option<u64> {
+        loop {
+            let before = self.sequence();
+            if before == 0 {
+                return None;
+            }
+            if before % 2 == 1 {
+                std::hint::spin_loop();
+                continue;
+            }
+            fence(Ordering::SeqCst);
+            let len = read_u64(&self.map, LEN_OFFSET) as usize;
+            out.clear();
+            out.extend_from_slice(&self.map[HEADER_LEN..HEADER_LEN + len]);
+            fence(Ordering::SeqCst);
+            if self.sequence() == before {
+                return Some(before);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_publish_and_read_roundtrip() {
+        let path = std::env::temp_dir().join("crafter_test_shm_roundtrip");
+        let mut writer = ShmWriter::create(&path, 64).unwrap();
+        let reader = ShmReader::open(&path).unwrap();
+
+        let mut out = Vec::new();
+        assert_eq!(reader.read_latest(&mut out), None);
+
+        writer.publish(b"first frame").unwrap();
+        assert_eq!(reader.read_latest(&mut out), Some(2));
+        assert_eq!(out, b"first frame");
+
+        writer.publish(b"second, longer frame").unwrap();
+        assert_eq!(reader.read_latest(&mut out), Some(4));
+        assert_eq!(out, b"second, longer frame");
+
+        std::fs::remove_file(path).ok();
+    }
+
+    #[test]
+    fn test_publish_rejects_oversized_payload() {
+        let path = std::env::temp_dir().join("crafter_test_shm_oversize");
+        let mut writer = ShmWriter::create(&path, 8).unwrap();
+        assert!(writer.publish(b"way more than eight bytes").is_err());
+        std::fs::remove_file(path).ok();
+    }
+
+    #[test]
+    fn test_open_rejects_foreign_file() {
+        let path = std::env::temp_dir().join("crafter_test_shm_foreign");
+        std::fs::write(&path, vec![0u8; 128]).unwrap();
+        assert!(ShmReader::open(&path).is_err());
+        std::fs::remove_file(path).ok();
+    }
+}
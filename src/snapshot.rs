@@ -7,9 +7,13 @@
 use crate::action::Action;
 use crate::entity::GameObject;
 use crate::material::Material;
-use crate::session::{DoneReason, Session, StepResult};
+use crate::saveload::SaveData;
+use crate::session::{DoneReason, Session, StepResult, TransactionError};
 use crate::SessionConfig;
 use std::collections::HashMap;
+use std::path::PathBuf;
+use crate::platform::Instant;
+use std::time::Duration;
 use uuid::Uuid;
 
 /// Snapshot request (mirrors mc_api::CrafterSnapshotRequest)
@@ -22,6 +26,11 @@ pub struct SnapshotRequest {
     pub config_name: Option<String>,
     pub config_path: Option<String>,
     pub config_toml: Option<String>,
+    /// Execute the action batch as a transaction: if any action's
+    /// precondition fails the session rolls back to its pre-batch state
+    /// and the response carries a `transaction_failed` error with the
+    /// failing index
+    pub transactional: bool,
 }
 
 /// Action enum (mirrors mc_api::CrafterAction)
@@ -57,6 +66,16 @@ pub enum SnapshotAction {
     DrinkPotionPink,
     DrinkPotionCyan,
     DrinkPotionYellow,
+    MakeSpikeTrap,
+    MakeDoor,
+    MakeFence,
+    PlaceSpikeTrap,
+    PlaceDoor,
+    PlaceFence,
+    /// Craft several units of one recipe in a single step (parsed from
+    /// `<craft_action>*<count>`, e.g. `make_arrow*5`); see
+    /// `Session::step_craft_n`
+    CraftN { action: Action, count: u8 },
 }
 
 impl SnapshotAction {
@@ -92,9 +111,17 @@ impl SnapshotAction {
             Self::DrinkPotionPink => Action::DrinkPotionPink,
             Self::DrinkPotionCyan => Action::DrinkPotionCyan,
             Self::DrinkPotionYellow => Action::DrinkPotionYellow,
+            Self::MakeSpikeTrap => Action::MakeSpikeTrap,
+            Self::MakeDoor => Action::MakeDoor,
+            Self::MakeFence => Action::MakeFence,
+            Self::PlaceSpikeTrap => Action::PlaceSpikeTrap,
+            Self::PlaceDoor => Action::PlaceDoor,
+            Self::PlaceFence => Action::PlaceFence,
+            Self::CraftN { action, .. } => action,
         }
     }
 
+    #[allow(clippy::should_implement_trait)]
     pub fn from_str(s: &str) -> Option<Self> {
         match s {
             "noop" => Some(Self::Noop),
@@ -127,7 +154,67 @@ impl SnapshotAction {
             "potion_pink" | "drink_potion_pink" => Some(Self::DrinkPotionPink),
             "potion_cyan" | "drink_potion_cyan" => Some(Self::DrinkPotionCyan),
             "potion_yellow" | "drink_potion_yellow" => Some(Self::DrinkPotionYellow),
-            _ => None,
+            "trap" | "make_spike_trap" => Some(Self::MakeSpikeTrap),
+            "door" | "make_door" => Some(Self::MakeDoor),
+            "fence" | "make_fence" => Some(Self::MakeFence),
+            "place_trap" | "place_spike_trap" => Some(Self::PlaceSpikeTrap),
+            "place_door" => Some(Self::PlaceDoor),
+            "place_fence" => Some(Self::PlaceFence),
+            _ => {
+                // A `*<count>` suffix on a crafting action crafts several
+                // units in one step, e.g. "make_arrow*5"
+                let (name, count) = s.split_once('*')?;
+                let count: u8 = count.parse().ok().filter(|&c| c >= 1)?;
+                let action = Self::from_str(name)?.to_action();
+                if action.is_crafting() {
+                    Some(Self::CraftN { action, count })
+                } else {
+                    None
+                }
+            }
+        }
+    }
+}
+
+impl From<Action> for SnapshotAction {
+    fn from(action: Action) -> Self {
+        match action {
+            Action::Noop => Self::Noop,
+            Action::MoveLeft => Self::MoveLeft,
+            Action::MoveRight => Self::MoveRight,
+            Action::MoveUp => Self::MoveUp,
+            Action::MoveDown => Self::MoveDown,
+            Action::Do => Self::Do,
+            Action::Sleep => Self::Sleep,
+            Action::PlaceStone => Self::PlaceStone,
+            Action::PlaceTable => Self::PlaceTable,
+            Action::PlaceFurnace => Self::PlaceFurnace,
+            Action::PlacePlant => Self::PlacePlant,
+            Action::MakeWoodPickaxe => Self::MakeWoodPickaxe,
+            Action::MakeStonePickaxe => Self::MakeStonePickaxe,
+            Action::MakeIronPickaxe => Self::MakeIronPickaxe,
+            Action::MakeWoodSword => Self::MakeWoodSword,
+            Action::MakeStoneSword => Self::MakeStoneSword,
+            Action::MakeIronSword => Self::MakeIronSword,
+            Action::MakeDiamondPickaxe => Self::MakeDiamondPickaxe,
+            Action::MakeDiamondSword => Self::MakeDiamondSword,
+            Action::MakeIronArmor => Self::MakeIronArmor,
+            Action::MakeDiamondArmor => Self::MakeDiamondArmor,
+            Action::MakeBow => Self::MakeBow,
+            Action::MakeArrow => Self::MakeArrow,
+            Action::ShootArrow => Self::ShootArrow,
+            Action::DrinkPotionRed => Self::DrinkPotionRed,
+            Action::DrinkPotionGreen => Self::DrinkPotionGreen,
+            Action::DrinkPotionBlue => Self::DrinkPotionBlue,
+            Action::DrinkPotionPink => Self::DrinkPotionPink,
+            Action::DrinkPotionCyan => Self::DrinkPotionCyan,
+            Action::DrinkPotionYellow => Self::DrinkPotionYellow,
+            Action::MakeSpikeTrap => Self::MakeSpikeTrap,
+            Action::MakeDoor => Self::MakeDoor,
+            Action::MakeFence => Self::MakeFence,
+            Action::PlaceSpikeTrap => Self::PlaceSpikeTrap,
+            Action::PlaceDoor => Self::PlaceDoor,
+            Action::PlaceFence => Self::PlaceFence,
         }
     }
 }
@@ -141,7 +228,7 @@ pub struct SnapshotEntity {
 }
 
 /// Player stats
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Default)]
 pub struct SnapshotStats {
     pub health: i32,
     pub food: i32,
@@ -150,7 +237,7 @@ pub struct SnapshotStats {
 }
 
 /// Player inventory
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Default)]
 pub struct SnapshotInventory {
     pub wood: i32,
     pub stone: i32,
@@ -192,6 +279,61 @@ pub struct SnapshotLine {
     pub value: String,
 }
 
+/// Structured error carried in [`SnapshotResponse::error`].
+///
+/// `code` is stable across releases so non-Rust clients can branch on
+/// it; `message` is human-readable detail and may change freely.
+#[derive(Debug, Clone)]
+pub struct SnapshotError {
+    pub code: &'static str,
+    pub message: String,
+    /// For `transaction_failed`: index of the batch action that failed
+    pub failed_index: Option<usize>,
+}
+
+impl SnapshotError {
+    /// The request named a session that is neither live nor checkpointed
+    pub const UNKNOWN_SESSION: &'static str = "unknown_session";
+    /// The request's config (TOML, path, or name) failed to load
+    pub const INVALID_CONFIG: &'static str = "invalid_config";
+    /// An action token did not parse (used by wire-protocol hosts)
+    pub const INVALID_ACTION: &'static str = "invalid_action";
+    /// A transactional batch failed and the session was rolled back
+    pub const TRANSACTION_FAILED: &'static str = "transaction_failed";
+
+    pub fn unknown_session(id: &str) -> Self {
+        Self {
+            code: Self::UNKNOWN_SESSION,
+            message: format!("no session with id '{}'", id),
+            failed_index: None,
+        }
+    }
+
+    pub fn invalid_config(detail: impl std::fmt::Display) -> Self {
+        Self {
+            code: Self::INVALID_CONFIG,
+            message: format!("config failed to load: {}", detail),
+            failed_index: None,
+        }
+    }
+
+    pub fn invalid_action(token: &str) -> Self {
+        Self {
+            code: Self::INVALID_ACTION,
+            message: format!("unknown action '{}'", token),
+            failed_index: None,
+        }
+    }
+
+    pub fn transaction_failed(error: &crate::session::TransactionError) -> Self {
+        Self {
+            code: Self::TRANSACTION_FAILED,
+            message: error.to_string(),
+            failed_index: Some(error.failed_index),
+        }
+    }
+}
+
 /// Snapshot response
 #[derive(Debug, Clone)]
 pub struct SnapshotResponse {
@@ -211,12 +353,63 @@ pub struct SnapshotResponse {
     pub reward: f32,
     pub available_actions: Vec<String>,
     pub hints: Vec<String>,
+    /// Run attribution copied from the session config
+    pub run_id: Option<String>,
+    pub labels: HashMap<String, String>,
+    /// The fully-resolved config the session is actually playing under,
+    /// after request overrides (seed, view size, inline TOML) are applied;
+    /// `None` only on error responses
+    pub effective_config: Option<SessionConfig>,
+    /// Set when the request could not be served (see [`SnapshotError`]);
+    /// the rest of the response is empty in that case
+    pub error: Option<SnapshotError>,
+}
+
+impl SnapshotResponse {
+    /// An otherwise-empty response carrying a structured error
+    pub fn from_error(session_id: String, error: SnapshotError) -> Self {
+        Self {
+            session_id,
+            step: 0,
+            done: false,
+            done_reason: None,
+            player_pos: (0, 0),
+            player_facing: (0, 0),
+            stats: SnapshotStats::default(),
+            inventory: SnapshotInventory::default(),
+            map_lines: Vec::new(),
+            map_legend: Vec::new(),
+            entities: Vec::new(),
+            achievements: Vec::new(),
+            newly_unlocked: Vec::new(),
+            reward: 0.0,
+            available_actions: Vec::new(),
+            hints: Vec::new(),
+            run_id: None,
+            labels: HashMap::new(),
+            effective_config: None,
+            error: Some(error),
+        }
+    }
+}
+
+/// Policy for spilling idle sessions to disk. Sessions untouched for
+/// longer than `idle_timeout` are checkpointed into `checkpoint_dir`
+/// and dropped from memory on [`SnapshotManager::evict_idle`]; a later
+/// request for the same session ID restores them transparently.
+#[derive(Debug, Clone)]
+pub struct IdlePolicy {
+    pub idle_timeout: Duration,
+    pub checkpoint_dir: PathBuf,
 }
 
 /// Manager for Crafter game sessions
 pub struct SnapshotManager {
     sessions: HashMap<String, Session>,
     default_config: SessionConfig,
+    idle_policy: Option<IdlePolicy>,
+    /// When each live session last served a request
+    last_touched: HashMap<String, Instant>,
 }
 
 impl Default for SnapshotManager {
@@ -234,6 +427,8 @@ impl SnapshotManager {
                 view_radius: 4, // 4 = 9x9 grid
                 ..Default::default()
             },
+            idle_policy: None,
+            last_touched: HashMap::new(),
         }
     }
 
@@ -242,49 +437,94 @@ impl SnapshotManager {
         // Convert view_size to view_radius (view_size = 2*radius + 1)
         let view_radius = request.view_size.map(|s| (s - 1) / 2).unwrap_or(4);
 
-        // Get or create session
-        let (session_id, session) = if let Some(ref id) = request.session_id {
-            if let Some(s) = self.sessions.get_mut(id) {
-                (id.clone(), s)
-            } else {
-                // Session not found, create new
+        // Get, restore, or create the session
+        let session_id = match request.session_id {
+            Some(ref id) if self.sessions.contains_key(id) => id.clone(),
+            // Not in memory: an idle-evicted session may be on disk
+            Some(ref id) if self.restore_checkpoint(id) => id.clone(),
+            // Naming a session that does not exist is an error, not an
+            // implicit new game
+            Some(ref id) => {
+                return SnapshotResponse::from_error(
+                    id.clone(),
+                    SnapshotError::unknown_session(id),
+                );
+            }
+            None => {
                 let new_id = Uuid::new_v4().to_string();
-                let config = self.resolve_request_config(&request, view_radius);
+                let config = match self.resolve_request_config(&request, view_radius) {
+                    Ok(config) => config,
+                    Err(error) => return SnapshotResponse::from_error(String::new(), error),
+                };
                 self.sessions.insert(new_id.clone(), Session::new(config));
-                (new_id.clone(), self.sessions.get_mut(&new_id).unwrap())
+                new_id
             }
-        } else {
-            // Create new session
-            let new_id = Uuid::new_v4().to_string();
-            let config = self.resolve_request_config(&request, view_radius);
-            self.sessions.insert(new_id.clone(), Session::new(config));
-            (new_id.clone(), self.sessions.get_mut(&new_id).unwrap())
         };
+        self.last_touched.insert(session_id.clone(), Instant::now());
+        let session = self.sessions.get_mut(&session_id).unwrap();
 
-        // Execute actions
+        // Execute actions; transactional batches checkpoint up front so
+        // a mid-batch precondition failure can roll everything back
+        let mut checkpoint = (request.transactional && !request.actions.is_empty())
+            .then(|| SaveData::from_session(session, None));
         let mut last_result: Option<StepResult> = None;
         let mut all_newly_unlocked = Vec::new();
         let mut total_reward = 0.0;
+        let mut transaction_error: Option<TransactionError> = None;
 
-        for action in request.actions {
-            let result = session.step(action.to_action());
+        for (index, action) in request.actions.into_iter().enumerate() {
+            let (result, failure) = match action {
+                SnapshotAction::CraftN { action, count } => {
+                    let before = session.crafted_count(action);
+                    let result = session.step_craft_n(action, count);
+                    // The macro succeeds only if every unit was crafted
+                    let failure = (session.crafted_count(action) < before + count as u32)
+                        .then(|| format!("{:?} preconditions not met", action));
+                    (result, failure)
+                }
+                other => {
+                    let action = other.to_action();
+                    let probe = session.action_probe(action);
+                    let result = session.step(action);
+                    let failure = session.action_failure(action, &probe);
+                    (result, failure)
+                }
+            };
             total_reward += result.reward;
             all_newly_unlocked.extend(result.newly_unlocked.clone());
             let done = result.done;
             last_result = Some(result);
             if done {
+                // Episode ending mid-batch is not a precondition failure
                 break;
             }
+            if let Some(reason) = failure {
+                if let Some(checkpoint) = checkpoint.take() {
+                    *session = checkpoint.into_session();
+                    transaction_error = Some(TransactionError {
+                        failed_index: index,
+                        reason,
+                    });
+                    break;
+                }
+            }
         }
 
         // Drop the mutable borrow
         let _ = session;
-        
+
         // Get an immutable borrow for building response
         let session = self.sessions.get(&session_id).unwrap();
 
         // Build response from current state
-        self.build_response(session_id, session, last_result, all_newly_unlocked, total_reward)
+        if let Some(error) = transaction_error {
+            // The batch was rolled back: report the pre-batch state
+            let mut response = self.build_response(session_id, session, None, Vec::new(), 0.0);
+            response.error = Some(SnapshotError::transaction_failed(&error));
+            response
+        } else {
+            self.build_response(session_id, session, last_result, all_newly_unlocked, total_reward)
+        }
     }
 
     fn build_response(
@@ -333,6 +573,10 @@ impl SnapshotManager {
                     Some(Material::Sand) => ':',
                     Some(Material::Lava) => '%',
                     Some(Material::Path) => '_',
+                    Some(Material::SpikeTrap) => '^',
+                    Some(Material::DoorClosed) => 'D',
+                    Some(Material::DoorOpen) => '/',
+                    Some(Material::Fence) => '=',
                     None => ' ',
                 };
                 row.push(ch);
@@ -346,8 +590,8 @@ impl SnapshotManager {
         for obj in session.world.objects.values() {
             match obj {
                 GameObject::Cow(c) => {
-                    let dist = ((c.pos.0 - state.player_pos.0).abs()
-                        + (c.pos.1 - state.player_pos.1).abs()) as i32;
+                    let dist = (c.pos.0 - state.player_pos.0).abs()
+                        + (c.pos.1 - state.player_pos.1).abs();
                     if dist <= view_size {
                         entities.push(SnapshotEntity {
                             kind: "cow".to_string(),
@@ -356,9 +600,31 @@ impl SnapshotManager {
                         });
                     }
                 }
+                GameObject::Sheep(s) => {
+                    let dist = (s.pos.0 - state.player_pos.0).abs()
+                        + (s.pos.1 - state.player_pos.1).abs();
+                    if dist <= view_size {
+                        entities.push(SnapshotEntity {
+                            kind: "sheep".to_string(),
+                            pos: s.pos,
+                            health: Some(s.health as i32),
+                        });
+                    }
+                }
+                GameObject::Pig(p) => {
+                    let dist = (p.pos.0 - state.player_pos.0).abs()
+                        + (p.pos.1 - state.player_pos.1).abs();
+                    if dist <= view_size {
+                        entities.push(SnapshotEntity {
+                            kind: "pig".to_string(),
+                            pos: p.pos,
+                            health: Some(p.health as i32),
+                        });
+                    }
+                }
                 GameObject::Zombie(z) => {
-                    let dist = ((z.pos.0 - state.player_pos.0).abs()
-                        + (z.pos.1 - state.player_pos.1).abs()) as i32;
+                    let dist = (z.pos.0 - state.player_pos.0).abs()
+                        + (z.pos.1 - state.player_pos.1).abs();
                     if dist <= view_size {
                         entities.push(SnapshotEntity {
                             kind: "zombie".to_string(),
@@ -368,8 +634,8 @@ impl SnapshotManager {
                     }
                 }
                 GameObject::Skeleton(s) => {
-                    let dist = ((s.pos.0 - state.player_pos.0).abs()
-                        + (s.pos.1 - state.player_pos.1).abs()) as i32;
+                    let dist = (s.pos.0 - state.player_pos.0).abs()
+                        + (s.pos.1 - state.player_pos.1).abs();
                     if dist <= view_size {
                         entities.push(SnapshotEntity {
                             kind: "skeleton".to_string(),
@@ -379,8 +645,8 @@ impl SnapshotManager {
                     }
                 }
                 GameObject::CraftaxMob(m) => {
-                    let dist = ((m.pos.0 - state.player_pos.0).abs()
-                        + (m.pos.1 - state.player_pos.1).abs()) as i32;
+                    let dist = (m.pos.0 - state.player_pos.0).abs()
+                        + (m.pos.1 - state.player_pos.1).abs();
                     if dist <= view_size {
                         let kind = match m.kind {
                             crate::entity::CraftaxMobKind::OrcSoldier => "orc_soldier",
@@ -398,6 +664,17 @@ impl SnapshotManager {
                         });
                     }
                 }
+                GameObject::EscortKnight(k) => {
+                    let dist = (k.pos.0 - state.player_pos.0).abs()
+                        + (k.pos.1 - state.player_pos.1).abs();
+                    if dist <= view_size {
+                        entities.push(SnapshotEntity {
+                            kind: "escort_knight".to_string(),
+                            pos: k.pos,
+                            health: Some(k.health as i32),
+                        });
+                    }
+                }
                 _ => {}
             }
         }
@@ -459,6 +736,7 @@ impl SnapshotManager {
             if ach.drink_potion > 0 { achievements.push("drink_potion".to_string()); }
             if ach.gain_xp > 0 { achievements.push("gain_xp".to_string()); }
             if ach.reach_level > 0 { achievements.push("reach_level".to_string()); }
+            if ach.fire_bow > 0 { achievements.push("fire_bow".to_string()); }
         }
 
         // Available actions
@@ -479,6 +757,12 @@ impl SnapshotManager {
             "make_wood_sword".to_string(),
             "make_stone_sword".to_string(),
             "make_iron_sword".to_string(),
+            "make_spike_trap".to_string(),
+            "make_door".to_string(),
+            "make_fence".to_string(),
+            "place_spike_trap".to_string(),
+            "place_door".to_string(),
+            "place_fence".to_string(),
         ];
         if session.config.craftax.enabled && session.config.craftax.items_enabled {
             available_actions.extend([
@@ -561,6 +845,8 @@ impl SnapshotManager {
             step: state.step,
             done,
             done_reason,
+            run_id: session.config.run_id.clone(),
+            labels: session.config.labels.clone(),
             player_pos: state.player_pos,
             player_facing: state.player_facing,
             stats: SnapshotStats {
@@ -610,6 +896,8 @@ impl SnapshotManager {
             reward,
             available_actions,
             hints,
+            effective_config: Some(session.config.clone()),
+            error: None,
         }
     }
 
@@ -618,8 +906,84 @@ impl SnapshotManager {
         self.sessions.get(id)
     }
 
+    /// Insert a session under an explicit ID (e.g. one restored from a
+    /// save), replacing any session already stored under that ID
+    pub fn insert_session(&mut self, id: String, session: Session) {
+        self.last_touched.insert(id.clone(), Instant::now());
+        self.sessions.insert(id, session);
+    }
+
+    /// Enable (or disable, with `None`) idle checkpointing; see
+    /// [`IdlePolicy`]
+    pub fn set_idle_policy(&mut self, policy: Option<IdlePolicy>) {
+        self.idle_policy = policy;
+    }
+
+    /// Checkpoint and drop every session idle for longer than the
+    /// policy's timeout, returning the evicted IDs. Sessions whose
+    /// checkpoint cannot be written stay in memory. No-op without a
+    /// policy.
+    pub fn evict_idle(&mut self) -> Vec<String> {
+        let Some(policy) = self.idle_policy.clone() else {
+            return Vec::new();
+        };
+        let now = Instant::now();
+        let idle: Vec<String> = self
+            .last_touched
+            .iter()
+            .filter(|(id, touched)| {
+                now.duration_since(**touched) >= policy.idle_timeout
+                    && self.sessions.contains_key(*id)
+            })
+            .map(|(id, _)| id.clone())
+            .collect();
+
+        let mut evicted = Vec::new();
+        for id in idle {
+            let Some(session) = self.sessions.get(&id) else { continue };
+            if std::fs::create_dir_all(&policy.checkpoint_dir).is_err() {
+                continue;
+            }
+            let save = SaveData::from_session(session, Some(id.clone()));
+            if save.save_binary(checkpoint_path(&policy.checkpoint_dir, &id)).is_ok() {
+                self.sessions.remove(&id);
+                self.last_touched.remove(&id);
+                evicted.push(id);
+            }
+        }
+        evicted
+    }
+
+    /// Try to bring an evicted session back from its checkpoint
+    fn restore_checkpoint(&mut self, id: &str) -> bool {
+        let Some(policy) = &self.idle_policy else {
+            return false;
+        };
+        let path = checkpoint_path(&policy.checkpoint_dir, id);
+        match SaveData::load_binary(&path) {
+            Ok(save) => {
+                self.sessions.insert(id.to_string(), save.into_session());
+                true
+            }
+            Err(_) => false,
+        }
+    }
+
+    /// Replace the config used for sessions created after this call;
+    /// sessions already running keep the config they started with
+    pub fn set_default_config(&mut self, config: SessionConfig) {
+        self.default_config = config;
+    }
+
+    /// The config new sessions are created with when the request does not
+    /// name one
+    pub fn default_config(&self) -> &SessionConfig {
+        &self.default_config
+    }
+
     /// Remove a session
     pub fn remove_session(&mut self, id: &str) -> Option<Session> {
+        self.last_touched.remove(id);
         self.sessions.remove(id)
     }
 
@@ -628,13 +992,17 @@ impl SnapshotManager {
         self.sessions.keys().cloned().collect()
     }
 
-    fn resolve_request_config(&self, request: &SnapshotRequest, view_radius: u32) -> SessionConfig {
+    fn resolve_request_config(
+        &self,
+        request: &SnapshotRequest,
+        view_radius: u32,
+    ) -> Result<SessionConfig, SnapshotError> {
         let mut config = if let Some(toml) = &request.config_toml {
-            SessionConfig::from_toml_str(toml).unwrap_or_else(|_| self.default_config.clone())
+            SessionConfig::from_toml_str(toml).map_err(SnapshotError::invalid_config)?
         } else if let Some(path) = &request.config_path {
-            SessionConfig::load_from_path(path).unwrap_or_else(|_| self.default_config.clone())
+            SessionConfig::load_from_path(path).map_err(SnapshotError::invalid_config)?
         } else if let Some(name) = &request.config_name {
-            SessionConfig::load_named(name).unwrap_or_else(|_| self.default_config.clone())
+            SessionConfig::load_named(name).map_err(SnapshotError::invalid_config)?
         } else {
             self.default_config.clone()
         };
@@ -643,10 +1011,19 @@ impl SnapshotManager {
             config.seed = Some(seed);
         }
         config.view_radius = view_radius;
-        config
+        Ok(config)
     }
 }
 
+/// Session IDs are UUIDs, but keep checkpoint filenames safe regardless
+fn checkpoint_path(dir: &std::path::Path, id: &str) -> PathBuf {
+    let safe: String = id
+        .chars()
+        .map(|c| if c.is_ascii_alphanumeric() || c == '-' { c } else { '_' })
+        .collect();
+    dir.join(format!("{}.crafter", safe))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -662,6 +1039,7 @@ mod tests {
             config_name: None,
             config_path: None,
             config_toml: None,
+            transactional: false,
         };
 
         let response = manager.process(request);
@@ -690,6 +1068,7 @@ mod tests {
             config_name: None,
             config_path: None,
             config_toml: None,
+            transactional: false,
         };
 
         let response = manager.process(request);
@@ -710,6 +1089,7 @@ mod tests {
             config_name: None,
             config_path: None,
             config_toml: None,
+            transactional: false,
         };
         let response1 = manager.process(request1);
         let session_id = response1.session_id.clone();
@@ -723,10 +1103,310 @@ mod tests {
             config_name: None,
             config_path: None,
             config_toml: None,
+            transactional: false,
         };
         let response2 = manager.process(request2);
 
         assert_eq!(response2.session_id, session_id);
         assert_eq!(response2.step, 3); // 1 + 2 more
     }
+
+    #[test]
+    fn test_response_echoes_run_attribution() {
+        let mut manager = SnapshotManager::new();
+        let request = SnapshotRequest {
+            session_id: None,
+            seed: Some(42),
+            actions: vec![],
+            view_size: None,
+            config_name: None,
+            config_path: None,
+            config_toml: Some(
+                "run_id = \"exp-01\"\n[labels]\nsweep = \"lr\"\n".to_string(),
+            ),
+            transactional: false,
+        };
+
+        let response = manager.process(request);
+        assert_eq!(response.run_id.as_deref(), Some("exp-01"));
+        assert_eq!(response.labels.get("sweep").map(String::as_str), Some("lr"));
+    }
+
+    #[test]
+    fn test_idle_eviction_checkpoints_and_restores_transparently() {
+        let dir = std::env::temp_dir().join("crafter_test_idle_evict");
+        std::fs::remove_dir_all(&dir).ok();
+
+        let mut manager = SnapshotManager::new();
+        manager.set_idle_policy(Some(IdlePolicy {
+            idle_timeout: Duration::ZERO,
+            checkpoint_dir: dir.clone(),
+        }));
+
+        let response = manager.process(SnapshotRequest {
+            session_id: None,
+            seed: Some(42),
+            actions: vec![SnapshotAction::MoveRight, SnapshotAction::MoveRight],
+            view_size: None,
+            config_name: None,
+            config_path: None,
+            config_toml: None,
+            transactional: false,
+        });
+        let session_id = response.session_id.clone();
+        assert_eq!(response.step, 2);
+
+        // Zero timeout: the session is immediately idle
+        let evicted = manager.evict_idle();
+        assert_eq!(evicted, vec![session_id.clone()]);
+        assert!(manager.session_ids().is_empty());
+        assert!(checkpoint_path(&dir, &session_id).exists());
+
+        // A request for the evicted ID restores it where it left off
+        let resumed = manager.process(SnapshotRequest {
+            session_id: Some(session_id.clone()),
+            seed: None,
+            actions: vec![SnapshotAction::MoveRight],
+            view_size: None,
+            config_name: None,
+            config_path: None,
+            config_toml: None,
+            transactional: false,
+        });
+        assert_eq!(resumed.session_id, session_id);
+        assert_eq!(resumed.step, 3);
+
+        std::fs::remove_dir_all(&dir).ok();
+    }
+
+    #[test]
+    fn test_evict_idle_without_policy_is_noop() {
+        let mut manager = SnapshotManager::new();
+        manager.process(SnapshotRequest {
+            session_id: None,
+            seed: Some(42),
+            actions: vec![],
+            view_size: None,
+            config_name: None,
+            config_path: None,
+            config_toml: None,
+            transactional: false,
+        });
+        assert!(manager.evict_idle().is_empty());
+        assert_eq!(manager.session_ids().len(), 1);
+    }
+
+    #[test]
+    fn test_set_default_config_applies_to_new_sessions_only() {
+        let mut manager = SnapshotManager::new();
+        let old = manager.process(SnapshotRequest {
+            session_id: None,
+            seed: Some(42),
+            actions: vec![],
+            view_size: None,
+            config_name: None,
+            config_path: None,
+            config_toml: None,
+            transactional: false,
+        });
+
+        let mut config = manager.default_config().clone();
+        config.run_id = Some("reloaded".to_string());
+        manager.set_default_config(config);
+
+        // The existing session keeps the config it started with
+        let resumed = manager.process(SnapshotRequest {
+            session_id: Some(old.session_id.clone()),
+            seed: None,
+            actions: vec![],
+            view_size: None,
+            config_name: None,
+            config_path: None,
+            config_toml: None,
+            transactional: false,
+        });
+        assert_eq!(resumed.run_id, None);
+
+        // New sessions pick up the replacement
+        let fresh = manager.process(SnapshotRequest {
+            session_id: None,
+            seed: Some(7),
+            actions: vec![],
+            view_size: None,
+            config_name: None,
+            config_path: None,
+            config_toml: None,
+            transactional: false,
+        });
+        assert_eq!(fresh.run_id.as_deref(), Some("reloaded"));
+    }
+
+    #[test]
+    fn test_unknown_session_is_an_error_not_a_new_game() {
+        let mut manager = SnapshotManager::new();
+        let response = manager.process(SnapshotRequest {
+            session_id: Some("no-such-session".to_string()),
+            seed: None,
+            actions: vec![SnapshotAction::MoveRight],
+            view_size: None,
+            config_name: None,
+            config_path: None,
+            config_toml: None,
+            transactional: false,
+        });
+
+        let error = response.error.expect("should carry an error");
+        assert_eq!(error.code, SnapshotError::UNKNOWN_SESSION);
+        assert_eq!(response.session_id, "no-such-session");
+        // No session was implicitly created
+        assert!(manager.session_ids().is_empty());
+    }
+
+    #[test]
+    fn test_invalid_config_toml_is_an_error() {
+        let mut manager = SnapshotManager::new();
+        let response = manager.process(SnapshotRequest {
+            session_id: None,
+            seed: Some(1),
+            actions: vec![],
+            view_size: None,
+            config_name: None,
+            config_path: None,
+            config_toml: Some("world_size = \"not a tuple\"".to_string()),
+            transactional: false,
+        });
+
+        let error = response.error.expect("should carry an error");
+        assert_eq!(error.code, SnapshotError::INVALID_CONFIG);
+        assert!(manager.session_ids().is_empty());
+
+        // A healthy request reports no error
+        let ok = manager.process(SnapshotRequest {
+            session_id: None,
+            seed: Some(1),
+            actions: vec![],
+            view_size: None,
+            config_name: None,
+            config_path: None,
+            config_toml: None,
+            transactional: false,
+        });
+        assert!(ok.error.is_none());
+    }
+
+    #[test]
+    fn test_inline_config_toml_is_applied_and_echoed() {
+        let mut manager = SnapshotManager::new();
+        let response = manager.process(SnapshotRequest {
+            session_id: None,
+            seed: Some(9),
+            actions: vec![],
+            view_size: Some(5),
+            config_name: None,
+            config_path: None,
+            config_toml: Some("fast_mode = true\nworld_size = [24, 24]\n".to_string()),
+            transactional: false,
+        });
+
+        assert!(response.error.is_none());
+        let config = response.effective_config.expect("should echo the config");
+        assert!(config.fast_mode);
+        assert_eq!(config.world_size, (24, 24));
+        // Request-level overrides are reflected in the echo
+        assert_eq!(config.seed, Some(9));
+        assert_eq!(config.view_radius, 2);
+    }
+
+    #[test]
+    fn test_invalid_config_toml_reports_the_line() {
+        let mut manager = SnapshotManager::new();
+        let response = manager.process(SnapshotRequest {
+            session_id: None,
+            seed: None,
+            actions: vec![],
+            view_size: None,
+            config_name: None,
+            config_path: None,
+            config_toml: Some("world_size = [24, 24]\nfast_mode = yes\n".to_string()),
+            transactional: false,
+        });
+
+        let error = response.error.expect("should carry an error");
+        assert_eq!(error.code, SnapshotError::INVALID_CONFIG);
+        assert!(
+            error.message.contains("line 2"),
+            "error should point at the offending line: {}",
+            error.message
+        );
+    }
+
+    #[test]
+    fn test_transactional_batch_rolls_back_on_failure() {
+        let mut manager = SnapshotManager::new();
+        let start = manager.process(SnapshotRequest {
+            session_id: None,
+            seed: Some(42),
+            actions: vec![],
+            view_size: None,
+            config_name: None,
+            config_path: None,
+            config_toml: None,
+            transactional: false,
+        });
+        let session_id = start.session_id.clone();
+
+        // Crafting an iron sword with an empty inventory always fails
+        let response = manager.process(SnapshotRequest {
+            session_id: Some(session_id.clone()),
+            seed: None,
+            actions: vec![SnapshotAction::Noop, SnapshotAction::MakeIronSword],
+            view_size: None,
+            config_name: None,
+            config_path: None,
+            config_toml: None,
+            transactional: true,
+        });
+        let error = response.error.expect("batch should fail");
+        assert_eq!(error.code, SnapshotError::TRANSACTION_FAILED);
+        assert_eq!(error.failed_index, Some(1));
+        assert_eq!(response.step, 0, "the noop before the failure rolls back too");
+        assert_eq!(response.reward, 0.0);
+
+        // The rolled-back session is still live and steps normally
+        let after = manager.process(SnapshotRequest {
+            session_id: Some(session_id),
+            seed: None,
+            actions: vec![SnapshotAction::Noop],
+            view_size: None,
+            config_name: None,
+            config_path: None,
+            config_toml: None,
+            transactional: true,
+        });
+        assert!(after.error.is_none());
+        assert_eq!(after.step, 1);
+    }
+
+    #[test]
+    fn test_craft_n_parsing() {
+        assert!(matches!(
+            SnapshotAction::from_str("make_arrow*5"),
+            Some(SnapshotAction::CraftN {
+                action: Action::MakeArrow,
+                count: 5
+            })
+        ));
+        assert!(matches!(
+            SnapshotAction::from_str("fence*2"),
+            Some(SnapshotAction::CraftN {
+                action: Action::MakeFence,
+                count: 2
+            })
+        ));
+        // Only crafting actions take a count, and it must be a positive number
+        assert!(SnapshotAction::from_str("move_right*3").is_none());
+        assert!(SnapshotAction::from_str("make_arrow*0").is_none());
+        assert!(SnapshotAction::from_str("make_arrow*").is_none());
+        assert!(SnapshotAction::from_str("bogus*2").is_none());
+    }
 }
@@ -0,0 +1,244 @@
+//! Episode statistics and the official Crafter score
+//!
+//! [`EpisodeStats`] accumulates [`StepResult`]s (or whole recordings)
+//! across episodes and reports per-achievement success rates, mean
+//! episode length, a death-cause histogram, and the official Crafter
+//! score: the geometric-mean aggregate
+//! `exp(mean(ln(1 + s_i))) - 1` over the 22 classic achievements,
+//! with each success rate `s_i` expressed as a percentage.
+
+use std::collections::{HashMap, HashSet};
+
+use crate::achievement::Achievements;
+use crate::recording::{Recording, ReplaySession};
+use crate::session::{DoneReason, StepResult};
+
+/// Prefix of the debug event the session emits on death
+const DEATH_CAUSE_PREFIX: &str = "Death cause: ";
+
+/// Accumulates per-episode statistics across many episodes
+#[derive(Clone, Debug, Default)]
+pub struct EpisodeStats {
+    /// Completed episodes
+    episodes: u32,
+    /// Steps across completed episodes
+    total_steps: u64,
+    /// Steps in the episode currently being accumulated
+    current_steps: u64,
+    /// Achievements unlocked in the current episode
+    current_unlocked: HashSet<String>,
+    /// Episodes in which each achievement was unlocked at least once
+    unlock_episodes: HashMap<String, u32>,
+    /// Deaths by cause label (zombie, lava, starvation, ...)
+    death_causes: HashMap<String, u32>,
+    /// Classified events by category across all fed steps
+    event_categories: HashMap<crate::events::EventCategory, u32>,
+}
+
+impl EpisodeStats {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Feed one step; a `done` step closes the current episode
+    pub fn record_step(&mut self, result: &StepResult) {
+        self.current_steps += 1;
+        for name in &result.newly_unlocked {
+            self.current_unlocked.insert(name.clone());
+        }
+        for entry in &result.log {
+            *self.event_categories.entry(entry.category).or_insert(0) += 1;
+        }
+        if result.done {
+            if matches!(result.done_reason, Some(DoneReason::Death)) {
+                let cause = result
+                    .debug_events
+                    .iter()
+                    .find_map(|e| e.strip_prefix(DEATH_CAUSE_PREFIX))
+                    .unwrap_or("unknown");
+                *self.death_causes.entry(cause.to_string()).or_insert(0) += 1;
+            }
+            self.finish_episode();
+        }
+    }
+
+    /// Feed a whole recording as one episode by replaying it. Like all
+    /// replays this reproduces the original run exactly only when the
+    /// recording was made with a fixed seed.
+    pub fn record_recording(&mut self, recording: &Recording) {
+        let mut replay = ReplaySession::from_recording(recording);
+        let mut ended = false;
+        while let Some(result) = replay.step() {
+            ended = result.done;
+            self.record_step(&result);
+        }
+        if !ended && self.current_steps > 0 {
+            self.finish_episode();
+        }
+    }
+
+    /// Close the current episode without a `done` step (e.g. a run cut
+    /// short); a no-op when no steps have been accumulated
+    pub fn finish_episode(&mut self) {
+        if self.current_steps == 0 && self.current_unlocked.is_empty() {
+            return;
+        }
+        self.episodes += 1;
+        self.total_steps += self.current_steps;
+        self.current_steps = 0;
+        for name in self.current_unlocked.drain() {
+            *self.unlock_episodes.entry(name).or_insert(0) += 1;
+        }
+    }
+
+    /// Completed episodes
+    pub fn episodes(&self) -> u32 {
+        self.episodes
+    }
+
+    /// Mean length of completed episodes in steps
+    pub fn mean_episode_length(&self) -> f32 {
+        if self.episodes == 0 {
+            return 0.0;
+        }
+        self.total_steps as f32 / self.episodes as f32
+    }
+
+    /// Fraction of completed episodes in which `name` was unlocked
+    pub fn success_rate(&self, name: &str) -> f32 {
+        if self.episodes == 0 {
+            return 0.0;
+        }
+        let unlocked = self.unlock_episodes.get(name).copied().unwrap_or(0);
+        unlocked as f32 / self.episodes as f32
+    }
+
+    /// Success rates for the 22 classic achievements, in canonical order
+    pub fn success_rates(&self) -> Vec<(&'static str, f32)> {
+        Achievements::all_names()
+            .iter()
+            .map(|&name| (name, self.success_rate(name)))
+            .collect()
+    }
+
+    /// Deaths by cause label, e.g. `zombie` or `starvation`
+    pub fn death_causes(&self) -> &HashMap<String, u32> {
+        &self.death_causes
+    }
+
+    /// Classified events by category across all fed steps (see
+    /// [`crate::events::EventCategory`])
+    pub fn event_categories(&self) -> &HashMap<crate::events::EventCategory, u32> {
+        &self.event_categories
+    }
+
+    /// The official Crafter score over the 22 classic achievements; see
+    /// [`crafter_score`]
+    pub fn score(&self) -> f32 {
+        if self.episodes == 0 {
+            return 0.0;
+        }
+        let rates: Vec<f32> = Achievements::all_names()
+            .iter()
+            .map(|&name| self.success_rate(name))
+            .collect();
+        crafter_score(&rates)
+    }
+}
+
+/// The official Crafter score aggregate over per-achievement success
+/// rates in `0..=1`: `exp(mean(ln(1 + s_i))) - 1` with rates expressed
+/// as percentages, so a policy that unlocks everything every episode
+/// scores 100. Shared by [`EpisodeStats`] and the evaluation harness in
+/// [`crate::eval`].
+pub fn crafter_score(success_rates: &[f32]) -> f32 {
+    if success_rates.is_empty() {
+        return 0.0;
+    }
+    let mean_log: f32 = success_rates
+        .iter()
+        .map(|&rate| (1.0 + 100.0 * rate).ln())
+        .sum::<f32>()
+        / success_rates.len() as f32;
+    mean_log.exp() - 1.0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::SessionConfig;
+    use crate::session::Session;
+
+    fn done_result(
+        newly_unlocked: Vec<String>,
+        reason: DoneReason,
+        debug_events: Vec<String>,
+    ) -> StepResult {
+        let state = Session::new(SessionConfig {
+            world_size: (16, 16),
+            seed: Some(1),
+            ..Default::default()
+        })
+        .get_state();
+        let truncated = !matches!(reason, DoneReason::Death);
+        StepResult {
+            state,
+            reward: 0.0,
+            done: true,
+            done_reason: Some(reason),
+            truncated,
+            newly_unlocked,
+            debug_events,
+            milestones: Vec::new(),
+            sounds: Vec::new(),
+            log: Vec::new(),
+            action_mask: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn test_success_rates_and_score() {
+        let mut stats = EpisodeStats::new();
+        stats.record_step(&done_result(
+            vec!["collect_wood".to_string()],
+            DoneReason::MaxSteps,
+            Vec::new(),
+        ));
+        stats.record_step(&done_result(Vec::new(), DoneReason::MaxSteps, Vec::new()));
+
+        assert_eq!(stats.episodes(), 2);
+        assert!((stats.success_rate("collect_wood") - 0.5).abs() < 1e-6);
+        assert_eq!(stats.success_rate("collect_stone"), 0.0);
+
+        // One achievement at 50%, the other 21 at 0%
+        let expected = ((1.0f32 + 50.0).ln() / 22.0).exp() - 1.0;
+        assert!((stats.score() - expected).abs() < 1e-4);
+    }
+
+    #[test]
+    fn test_death_causes_and_episode_length() {
+        let mut stats = EpisodeStats::new();
+        stats.record_step(&done_result(
+            Vec::new(),
+            DoneReason::Death,
+            vec!["Death cause: zombie".to_string()],
+        ));
+        // Two-step episode ending in an uncaused death
+        stats.record_step(&done_result(Vec::new(), DoneReason::MaxSteps, Vec::new()));
+
+        assert_eq!(stats.death_causes().get("zombie"), Some(&1));
+        assert!((stats.mean_episode_length() - 1.0).abs() < 1e-6);
+
+        // A partial episode counts once finished explicitly
+        let mut open = EpisodeStats::new();
+        let mut result = done_result(Vec::new(), DoneReason::MaxSteps, Vec::new());
+        result.done = false;
+        result.done_reason = None;
+        open.record_step(&result);
+        open.record_step(&result);
+        assert_eq!(open.episodes(), 0);
+        open.finish_episode();
+        assert_eq!(open.episodes(), 1);
+        assert!((open.mean_episode_length() - 2.0).abs() < 1e-6);
+    }
+}
@@ -0,0 +1,227 @@
+//! Experiment tracker integration hooks (requires the `telemetry` feature)
+//!
+//! A [`MetricsSink`] receives scalar metrics and per-episode summaries
+//! from rollout and evaluation code. The crate ships small reference
+//! sinks (stdout, JSONL files); bridging to W&B, MLflow, or similar is a
+//! matter of implementing the trait in the consuming crate, so this
+//! crate never depends on tracker clients.
+
+use crate::recording::Recording;
+use serde::{Deserialize, Serialize};
+use std::fs::File;
+use std::io::{BufWriter, Write};
+use std::path::Path;
+
+/// Summary metrics for one finished episode
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct EpisodeMetrics {
+    /// Episode number
+    pub episode: u32,
+    /// Seed the episode ran under, if fixed
+    pub seed: Option<u64>,
+    /// Steps taken
+    pub steps: u64,
+    /// Total reward accumulated
+    pub total_reward: f32,
+    /// Distinct achievements unlocked
+    pub unique_achievements: u32,
+    /// Run attribution copied from the session config
+    pub run_id: Option<String>,
+    pub labels: std::collections::HashMap<String, String>,
+}
+
+impl EpisodeMetrics {
+    /// Summarize a finished recording
+    pub fn from_recording(recording: &Recording) -> Self {
+        let unique_achievements = recording
+            .steps
+            .iter()
+            .rev()
+            .find_map(|s| s.state_after.as_ref())
+            .map(|state| {
+                state
+                    .achievements
+                    .to_map()
+                    .values()
+                    .filter(|&&count| count > 0)
+                    .count() as u32
+            })
+            .unwrap_or(0);
+        Self {
+            episode: recording.episode,
+            seed: recording.config.seed,
+            steps: recording.total_steps,
+            total_reward: recording.total_reward,
+            unique_achievements,
+            run_id: recording.config.run_id.clone(),
+            labels: recording.config.labels.clone(),
+        }
+    }
+}
+
+/// Destination for training/eval metrics. Implementations should be
+/// cheap to call per step; batching and upload policy are theirs.
+pub trait MetricsSink {
+    /// Record a named scalar at a global step
+    fn log_scalar(&mut self, name: &str, step: u64, value: f64);
+
+    /// Record a finished episode's summary
+    fn log_episode(&mut self, metrics: &EpisodeMetrics);
+
+    /// Flush any buffered output (no-op by default)
+    fn flush(&mut self) {}
+}
+
+/// Reference sink that prints metrics to stdout, one line each
+#[derive(Default)]
+pub struct StdoutSink;
+
+impl MetricsSink for StdoutSink {
+    fn log_scalar(&mut self, name: &str, step: u64, value: f64) {
+        println!("metric {} step={} value={}", name, step, value);
+    }
+
+    fn log_episode(&mut self, metrics: &EpisodeMetrics) {
+        println!(
+            "episode {} steps={} reward={} achievements={}",
+            metrics.episode, metrics.steps, metrics.total_reward, metrics.unique_achievements
+        );
+    }
+}
+
+/// Reference sink that appends metrics as JSON lines, the interchange
+/// format most trackers can ingest directly
+pub struct JsonlSink {
+    writer: BufWriter<File>,
+}
+
+impl JsonlSink {
+    /// Append to (or create) a JSONL file at `path`
+    pub fn new<P: AsRef<Path>>(path: P) -> std::io::Result<Self> {
+        let file = File::options().create(true).append(true).open(path)?;
+        Ok(Self {
+            writer: BufWriter::new(file),
+        })
+    }
+
+    fn write_line(&mut self, value: serde_json::Value) {
+        if let Ok(line) = serde_json::to_string(&value) {
+            let _ = writeln!(self.writer, "{}", line);
+        }
+    }
+}
+
+impl MetricsSink for JsonlSink {
+    fn log_scalar(&mut self, name: &str, step: u64, value: f64) {
+        self.write_line(serde_json::json!({
+            "kind": "scalar",
+            "name": name,
+            "step": step,
+            "value": value,
+        }));
+    }
+
+    fn log_episode(&mut self, metrics: &EpisodeMetrics) {
+        if let Ok(value) = serde_json::to_value(metrics) {
+            self.write_line(serde_json::json!({
+                "kind": "episode",
+                "metrics": value,
+            }));
+        }
+    }
+
+    fn flush(&mut self) {
+        let _ = self.writer.flush();
+    }
+}
+
+/// Fan a metrics stream out to several sinks at once
+#[derive(Default)]
+pub struct MultiSink {
+    sinks: Vec<Box<dyn MetricsSink>>,
+}
+
+impl MultiSink {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn push(&mut self, sink: Box<dyn MetricsSink>) {
+        self.sinks.push(sink);
+    }
+}
+
+impl MetricsSink for MultiSink {
+    fn log_scalar(&mut self, name: &str, step: u64, value: f64) {
+        for sink in &mut self.sinks {
+            sink.log_scalar(name, step, value);
+        }
+    }
+
+    fn log_episode(&mut self, metrics: &EpisodeMetrics) {
+        for sink in &mut self.sinks {
+            sink.log_episode(metrics);
+        }
+    }
+
+    fn flush(&mut self) {
+        for sink in &mut self.sinks {
+            sink.flush();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::action::Action;
+    use crate::config::SessionConfig;
+    use crate::recording::{RecordingOptions, RecordingSession};
+
+    #[test]
+    fn test_episode_metrics_from_recording() {
+        let config = SessionConfig {
+            world_size: (16, 16),
+            seed: Some(3),
+            ..Default::default()
+        };
+        let mut rec_session = RecordingSession::new(config, RecordingOptions::full());
+        rec_session.step(Action::MoveRight);
+        rec_session.step(Action::MoveDown);
+        let recording = rec_session.finish();
+
+        let metrics = EpisodeMetrics::from_recording(&recording);
+        assert_eq!(metrics.episode, 1);
+        assert_eq!(metrics.seed, Some(3));
+        assert_eq!(metrics.steps, 2);
+        assert!((metrics.total_reward - recording.total_reward).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_jsonl_sink_writes_parseable_lines() {
+        let path = std::env::temp_dir().join("crafter_test_metrics.jsonl");
+        std::fs::remove_file(&path).ok();
+        {
+            let mut sink = JsonlSink::new(&path).unwrap();
+            sink.log_scalar("reward", 10, 1.5);
+            sink.log_episode(&EpisodeMetrics {
+                episode: 1,
+                seed: Some(7),
+                steps: 100,
+                total_reward: 2.0,
+                unique_achievements: 3,
+                run_id: Some("run-7".to_string()),
+                labels: std::collections::HashMap::new(),
+            });
+            sink.flush();
+        }
+        let contents = std::fs::read_to_string(&path).unwrap();
+        let lines: Vec<_> = contents.lines().collect();
+        assert_eq!(lines.len(), 2);
+        for line in &lines {
+            let value: serde_json::Value = serde_json::from_str(line).unwrap();
+            assert!(value.get("kind").is_some());
+        }
+        std::fs::remove_file(path).ok();
+    }
+}
@@ -0,0 +1,132 @@
+//! wasm-bindgen bindings (requires the `wasm` feature)
+//!
+//! Exposes the engine to JavaScript for browser demos and JS agents:
+//! a discrete action space addressed by index, `step` returning a JSON
+//! transition, and `render_rgba` producing pixels ready for a canvas
+//! `ImageData`. Sessions run in logical time — the browser drives every
+//! tick from its own `requestAnimationFrame` loop, since wasm has no
+//! monotonic std clock (see [`crate::platform`]).
+//!
+//! ```javascript
+//! const session = new WasmSession(42n, 64, 4);
+//! const result = JSON.parse(session.step(session.action_index("Do")));
+//! const pixels = session.render_rgba(10);
+//! ctx.putImageData(new ImageData(
+//!     new Uint8ClampedArray(pixels.buffer),
+//!     session.render_width(10),
+//!     session.render_height(10),
+//! ), 0, 0);
+//! ```
+//!
+//! Build the cdylib with
+//! `wasm-pack build --features wasm` (binaries are not wasm-aware; use
+//! `--lib` with plain cargo).
+
+use wasm_bindgen::prelude::*;
+
+use crate::config::SessionConfig;
+use crate::image_renderer::{ImageRenderer, ImageRendererConfig};
+use crate::session::{Session, TimeMode};
+
+/// A logical-time game session driven from JavaScript
+#[wasm_bindgen]
+pub struct WasmSession {
+    session: Session,
+}
+
+#[wasm_bindgen]
+impl WasmSession {
+    /// Create a session with a fixed seed, square world and view radius
+    #[wasm_bindgen(constructor)]
+    pub fn new(seed: u64, world_size: u32, view_radius: u32) -> WasmSession {
+        let size = world_size.clamp(16, 256);
+        let config = SessionConfig {
+            seed: Some(seed),
+            world_size: (size, size),
+            view_radius: view_radius.clamp(1, 12),
+            time_mode: TimeMode::Logical,
+            ..Default::default()
+        };
+        WasmSession {
+            session: Session::new(config),
+        }
+    }
+
+    /// Number of actions; indices map to [`action_name`](Self::action_name)
+    pub fn num_actions(&self) -> u32 {
+        self.session.config.action_profile.num_actions() as u32
+    }
+
+    /// Name of the action at `index`, empty if out of range
+    pub fn action_name(&self, index: u32) -> String {
+        self.session
+            .config
+            .action_profile
+            .action_from_index(index as usize)
+            .map(|a| format!("{:?}", a))
+            .unwrap_or_default()
+    }
+
+    /// Index of the named action, or -1 if unknown
+    pub fn action_index(&self, name: &str) -> i32 {
+        let profile = self.session.config.action_profile;
+        profile
+            .action_table()
+            .iter()
+            .position(|a| format!("{:?}", a) == name)
+            .map(|i| i as i32)
+            .unwrap_or(-1)
+    }
+
+    /// Advance one tick with the action at `index` and return the full
+    /// [`StepResult`](crate::session::StepResult) as JSON
+    pub fn step(&mut self, index: u32) -> String {
+        let action = self
+            .session
+            .config
+            .action_profile
+            .action_from_index(index as usize)
+            .unwrap_or(crate::action::Action::Noop);
+        let result = self.session.step(action);
+        serde_json::to_string(&result).unwrap_or_else(|_| "{}".to_string())
+    }
+
+    /// Start a fresh episode
+    pub fn reset(&mut self) {
+        self.session.reset();
+    }
+
+    /// Current [`GameState`](crate::session::GameState) as JSON, for JS agents
+    pub fn state_json(&self) -> String {
+        serde_json::to_string(&self.session.get_state()).unwrap_or_else(|_| "{}".to_string())
+    }
+
+    /// Render the current view as RGBA bytes at the given tile size,
+    /// sized [`render_width`](Self::render_width) ×
+    /// [`render_height`](Self::render_height)
+    pub fn render_rgba(&self, tile_size: u32) -> Vec<u8> {
+        let renderer = ImageRenderer::new(ImageRendererConfig {
+            tile_size: tile_size.max(1),
+            show_status_bars: true,
+            apply_lighting: true,
+        });
+        let rgb = renderer.render_bytes(&self.session.get_state());
+        let mut rgba = Vec::with_capacity(rgb.len() / 3 * 4);
+        for pixel in rgb.chunks_exact(3) {
+            rgba.extend_from_slice(pixel);
+            rgba.push(255);
+        }
+        rgba
+    }
+
+    /// Pixel width of [`render_rgba`](Self::render_rgba) output
+    pub fn render_width(&self, tile_size: u32) -> u32 {
+        (self.session.config.view_radius * 2 + 1) * tile_size.max(1)
+    }
+
+    /// Pixel height of [`render_rgba`](Self::render_rgba) output
+    /// (the view plus two status-bar rows)
+    pub fn render_height(&self, tile_size: u32) -> u32 {
+        (self.session.config.view_radius * 2 + 3) * tile_size.max(1)
+    }
+}
@@ -20,6 +20,11 @@ pub struct World {
     /// Position to object ID mapping for fast lookup
     pub object_positions: HashMap<Position, ObjectId>,
 
+    /// Per-tile mining progress (hits landed so far), only populated while a
+    /// tile is partially mined under multi-hit mining
+    #[serde(default)]
+    pub mining_progress: HashMap<Position, u8>,
+
     /// Current daylight level (0.0 = night, 1.0 = day)
     pub daylight: f32,
 
@@ -31,6 +36,69 @@ pub struct World {
 
     /// Player object ID (always exists after world gen)
     pub player_id: ObjectId,
+
+    /// Named terrain regions found during worldgen (lakes, forests,
+    /// caves, ...), so tasks and language observations can refer to
+    /// places by name
+    #[serde(default)]
+    pub regions: Vec<NamedRegion>,
+
+    /// Monotonic change counter bumped by every mutation path, used to
+    /// invalidate shared full-world snapshots. Not part of the saved
+    /// world; a loaded world simply starts a fresh count.
+    #[serde(skip)]
+    version: u64,
+}
+
+/// Category of a [`NamedRegion`]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum RegionKind {
+    Lake,
+    Forest,
+    Beach,
+    Cave,
+    LavaField,
+}
+
+impl RegionKind {
+    /// The prefix used for names of this kind (`lake_1`, `forest_2`, ...)
+    pub fn label(&self) -> &'static str {
+        match self {
+            RegionKind::Lake => "lake",
+            RegionKind::Forest => "forest",
+            RegionKind::Beach => "beach",
+            RegionKind::Cave => "cave",
+            RegionKind::LavaField => "lava_field",
+        }
+    }
+}
+
+/// A contiguous, semantically meaningful piece of terrain
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct NamedRegion {
+    /// Unique name like `lake_1` or `forest_2`, numbered in scan order
+    /// (deterministic for a given seed)
+    pub name: String,
+    pub kind: RegionKind,
+    /// Bounding box `(min_x, min_y, max_x, max_y)`, inclusive
+    pub bounds: (i32, i32, i32, i32),
+    /// Tiles actually in the region (regions are rarely full rectangles)
+    pub tiles: u32,
+}
+
+impl NamedRegion {
+    /// Whether the position falls inside the region's bounding box
+    pub fn contains(&self, pos: Position) -> bool {
+        let (min_x, min_y, max_x, max_y) = self.bounds;
+        pos.0 >= min_x && pos.0 <= max_x && pos.1 >= min_y && pos.1 <= max_y
+    }
+
+    /// Center of the bounding box — a reasonable navigation target
+    pub fn center(&self) -> Position {
+        let (min_x, min_y, max_x, max_y) = self.bounds;
+        ((min_x + max_x) / 2, (min_y + max_y) / 2)
+    }
 }
 
 impl World {
@@ -41,13 +109,31 @@ impl World {
             materials: vec![Material::Grass; (width * height) as usize],
             objects: BTreeMap::new(),
             object_positions: HashMap::new(),
+            mining_progress: HashMap::new(),
             daylight: 0.5,
             rng_seed: seed,
             next_object_id: 1,
             player_id: 0,
+            regions: Vec::new(),
+            version: 0,
         }
     }
 
+    /// Look up a named region (e.g. `lake_1`)
+    pub fn find_region(&self, name: &str) -> Option<&NamedRegion> {
+        self.regions.iter().find(|r| r.name == name)
+    }
+
+    /// The first region whose bounding box contains the position
+    pub fn region_at(&self, pos: Position) -> Option<&NamedRegion> {
+        self.regions.iter().find(|r| r.contains(pos))
+    }
+
+    /// The current value of the change counter
+    pub fn version(&self) -> u64 {
+        self.version
+    }
+
     /// Get the width of the world
     pub fn width(&self) -> u32 {
         self.area.0
@@ -81,6 +167,29 @@ impl World {
     pub fn set_material(&mut self, pos: Position, mat: Material) {
         if let Some(idx) = self.pos_to_index(pos) {
             self.materials[idx] = mat;
+            // Whatever was being chipped away at is gone now
+            self.mining_progress.remove(&pos);
+            self.version += 1;
+        }
+    }
+
+    /// Get accumulated mining progress on a tile (0 = untouched)
+    pub fn get_mining_progress(&self, pos: Position) -> u8 {
+        self.mining_progress.get(&pos).copied().unwrap_or(0)
+    }
+
+    /// Record one mining hit on a tile, returning the new total
+    pub fn add_mining_progress(&mut self, pos: Position) -> u8 {
+        let entry = self.mining_progress.entry(pos).or_insert(0);
+        *entry = entry.saturating_add(1);
+        self.version += 1;
+        *entry
+    }
+
+    /// Clear mining progress on a tile
+    pub fn clear_mining_progress(&mut self, pos: Position) {
+        if self.mining_progress.remove(&pos).is_some() {
+            self.version += 1;
         }
     }
 
@@ -94,6 +203,8 @@ impl World {
     /// Get mutable object at position
     pub fn get_object_at_mut(&mut self, pos: Position) -> Option<&mut GameObject> {
         if let Some(&id) = self.object_positions.get(&pos) {
+            // Conservative: assume the borrower mutates
+            self.version += 1;
             self.objects.get_mut(&id)
         } else {
             None
@@ -107,6 +218,8 @@ impl World {
 
     /// Get mutable object by ID
     pub fn get_object_mut(&mut self, id: ObjectId) -> Option<&mut GameObject> {
+        // Conservative: assume the borrower mutates
+        self.version += 1;
         self.objects.get_mut(&id)
     }
 
@@ -123,6 +236,7 @@ impl World {
         let pos = obj.position();
         self.object_positions.insert(pos, id);
         self.objects.insert(id, obj);
+        self.version += 1;
 
         id
     }
@@ -131,6 +245,7 @@ impl World {
     pub fn remove_object(&mut self, id: ObjectId) -> Option<GameObject> {
         if let Some(obj) = self.objects.remove(&id) {
             self.object_positions.remove(&obj.position());
+            self.version += 1;
             Some(obj)
         } else {
             None
@@ -144,6 +259,7 @@ impl World {
             self.object_positions.remove(&old_pos);
             obj.set_position(new_pos);
             self.object_positions.insert(new_pos, id);
+            self.version += 1;
             true
         } else {
             false
@@ -172,6 +288,9 @@ impl World {
                 obj,
                 GameObject::Player(_)
                     | GameObject::Cow(_)
+                    | GameObject::Sheep(_)
+                    | GameObject::Pig(_)
+                    | GameObject::EscortKnight(_)
                     | GameObject::Zombie(_)
                     | GameObject::Skeleton(_)
                     | GameObject::CraftaxMob(_)
@@ -194,6 +313,8 @@ impl World {
 
     /// Get mutable player object
     pub fn get_player_mut(&mut self) -> Option<&mut crate::entity::Player> {
+        // Conservative: assume the borrower mutates
+        self.version += 1;
         self.objects.get_mut(&self.player_id).and_then(|obj| {
             if let GameObject::Player(p) = obj {
                 Some(p)
@@ -256,6 +377,8 @@ impl World {
         let mut materials = vec![Material::Water; size * size];
         let mut in_bounds = vec![false; size * size];
         let mut objects = Vec::new();
+        let mut mining_progress = vec![0u8; size * size];
+        let mut any_progress = false;
 
         for dy in -r..=r {
             for dx in -r..=r {
@@ -267,6 +390,11 @@ impl World {
                 if let Some(mat) = self.get_material(world_pos) {
                     materials[view_idx] = mat;
                     in_bounds[view_idx] = true;
+                    let progress = self.get_mining_progress(world_pos);
+                    if progress > 0 {
+                        mining_progress[view_idx] = progress;
+                        any_progress = true;
+                    }
                 }
 
                 if let Some(obj) = self.get_object_at(world_pos) {
@@ -281,13 +409,187 @@ impl World {
             materials,
             in_bounds,
             objects,
+            mining_progress: if any_progress { mining_progress } else { Vec::new() },
         }
     }
 
     /// Update daylight based on step count
     pub fn update_daylight(&mut self, step: u64, day_cycle_period: u32) {
         let progress = (step as f32 / day_cycle_period as f32) % 1.0 + 0.3;
-        self.daylight = 1.0 - (std::f32::consts::PI * progress).cos().abs().powi(3);
+        let daylight = 1.0 - (std::f32::consts::PI * progress).cos().abs().powi(3);
+        if daylight != self.daylight {
+            self.daylight = daylight;
+            self.version += 1;
+        }
+    }
+
+    /// Stable 64-bit digest of terrain and objects (including the
+    /// player and its inventory), for reproducibility checks.
+    ///
+    /// Uses FNV-1a over canonical bytes like the other embedded hashes
+    /// (see `compat`), so equal worlds hash equal across platforms and
+    /// crate releases without serializing the full state.
+    pub fn state_hash(&self) -> u64 {
+        use std::hash::Hasher;
+        let mut hasher = crate::compat::fnv1a();
+        hasher.write_u32(self.area.0);
+        hasher.write_u32(self.area.1);
+        for &mat in &self.materials {
+            hasher.write_u8(mat as u8);
+        }
+        hasher.write_u32(self.daylight.to_bits());
+        // BTreeMap iteration is already ordered by object ID
+        for (&id, obj) in &self.objects {
+            hasher.write_u32(id);
+            let canonical = serde_json::to_string(obj).unwrap_or_default();
+            hasher.write(canonical.as_bytes());
+        }
+        hasher.write_u32(self.player_id);
+        hasher.finish()
+    }
+
+    /// Compute an aggregate census of the world.
+    ///
+    /// One full tile scan plus a few flood fills — cheap enough for
+    /// worldgen analysis, curriculum filters, and debug probes, but not
+    /// meant to run every step.
+    pub fn stats(&self) -> WorldStats {
+        let mut materials = HashMap::new();
+        for &mat in &self.materials {
+            *materials.entry(mat).or_insert(0) += 1;
+        }
+
+        let mut mobs = HashMap::new();
+        for obj in self.objects.values() {
+            if obj.is_player() {
+                continue;
+            }
+            *mobs.entry(obj.kind_name()).or_insert(0) += 1;
+        }
+
+        WorldStats {
+            materials,
+            mobs,
+            water_components: self.count_components(|mat| mat == Material::Water),
+            land_components: self.count_components(|mat| mat.is_walkable()),
+            reachable_resources: self.reachable_resources(),
+        }
+    }
+
+    /// Count 4-connected components of tiles matching `pred`
+    fn count_components(&self, pred: impl Fn(Material) -> bool) -> u32 {
+        let (width, height) = self.area;
+        let mut visited = vec![false; (width * height) as usize];
+        let mut components = 0;
+        for y in 0..height as i32 {
+            for x in 0..width as i32 {
+                let idx = (y as u32 * width + x as u32) as usize;
+                if visited[idx] || !self.get_material((x, y)).is_some_and(&pred) {
+                    continue;
+                }
+                components += 1;
+                let mut stack = vec![(x, y)];
+                visited[idx] = true;
+                while let Some((cx, cy)) = stack.pop() {
+                    for (dx, dy) in [(-1, 0), (1, 0), (0, -1), (0, 1)] {
+                        let (nx, ny) = (cx + dx, cy + dy);
+                        if !self.in_bounds((nx, ny)) {
+                            continue;
+                        }
+                        let nidx = (ny as u32 * width + nx as u32) as usize;
+                        if !visited[nidx] && self.get_material((nx, ny)).is_some_and(&pred) {
+                            visited[nidx] = true;
+                            stack.push((nx, ny));
+                        }
+                    }
+                }
+            }
+        }
+        components
+    }
+
+    /// Resource tiles bordering terrain the player can walk to (terrain
+    /// only; mobs move, so they are ignored as obstacles)
+    fn reachable_resources(&self) -> HashMap<Material, u32> {
+        const RESOURCES: [Material; 8] = [
+            Material::Tree,
+            Material::Stone,
+            Material::Coal,
+            Material::Iron,
+            Material::Diamond,
+            Material::Sapphire,
+            Material::Ruby,
+            Material::Water,
+        ];
+
+        let Some(player) = self.get_player() else {
+            return HashMap::new();
+        };
+        let (width, height) = self.area;
+        let mut visited = vec![false; (width * height) as usize];
+        let mut counted = vec![false; (width * height) as usize];
+        let mut reachable = HashMap::new();
+
+        let start = player.pos;
+        if !self.in_bounds(start) {
+            return HashMap::new();
+        }
+        let mut stack = vec![start];
+        visited[(start.1 as u32 * width + start.0 as u32) as usize] = true;
+        while let Some((cx, cy)) = stack.pop() {
+            for (dx, dy) in [(-1, 0), (1, 0), (0, -1), (0, 1)] {
+                let (nx, ny) = (cx + dx, cy + dy);
+                if !self.in_bounds((nx, ny)) {
+                    continue;
+                }
+                let nidx = (ny as u32 * width + nx as u32) as usize;
+                let Some(mat) = self.get_material((nx, ny)) else { continue };
+                if mat.is_walkable() {
+                    if !visited[nidx] {
+                        visited[nidx] = true;
+                        stack.push((nx, ny));
+                    }
+                } else if !counted[nidx] && RESOURCES.contains(&mat) {
+                    counted[nidx] = true;
+                    *reachable.entry(mat).or_insert(0) += 1;
+                }
+            }
+        }
+        reachable
+    }
+}
+
+/// Aggregate census of a world; see [`World::stats`]
+#[derive(Debug, Clone, Default)]
+pub struct WorldStats {
+    /// Tiles of each material on the map
+    pub materials: HashMap<Material, u32>,
+    /// Live mobs by type name (see [`GameObject::kind_name`]), player
+    /// excluded
+    pub mobs: HashMap<&'static str, u32>,
+    /// 4-connected components of water tiles
+    pub water_components: u32,
+    /// 4-connected components of walkable terrain
+    pub land_components: u32,
+    /// Resource tiles bordering terrain walkable from the player's
+    /// position; empty when the world has no player
+    pub reachable_resources: HashMap<Material, u32>,
+}
+
+impl WorldStats {
+    /// Tiles of one material (0 when absent)
+    pub fn material_count(&self, mat: Material) -> u32 {
+        self.materials.get(&mat).copied().unwrap_or(0)
+    }
+
+    /// Live mobs of one type (0 when absent)
+    pub fn mob_count(&self, kind: &str) -> u32 {
+        self.mobs.get(kind).copied().unwrap_or(0)
+    }
+
+    /// Reachable resource tiles of one material (0 when absent)
+    pub fn reachable_count(&self, mat: Material) -> u32 {
+        self.reachable_resources.get(&mat).copied().unwrap_or(0)
     }
 }
 
@@ -300,6 +602,10 @@ pub struct WorldView {
     #[serde(default)]
     pub in_bounds: Vec<bool>,
     pub objects: Vec<(i32, i32, GameObject)>,
+    /// Mining hits landed on each tile, parallel to `materials` (empty when
+    /// no tile in view is partially mined)
+    #[serde(default)]
+    pub mining_progress: Vec<u8>,
 }
 
 impl WorldView {
@@ -319,6 +625,16 @@ impl WorldView {
         }
     }
 
+    /// Get mining progress at view-local position (0 = untouched)
+    pub fn get_mining_progress(&self, x: i32, y: i32) -> u8 {
+        if x >= 0 && x < self.size() as i32 && y >= 0 && y < self.size() as i32 {
+            let idx = y as usize * self.size() + x as usize;
+            self.mining_progress.get(idx).copied().unwrap_or(0)
+        } else {
+            0
+        }
+    }
+
     pub fn is_in_bounds(&self, x: i32, y: i32) -> bool {
         let size = self.size() as i32;
         if x >= 0 && x < size && y >= 0 && y < size {
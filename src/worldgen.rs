@@ -7,9 +7,9 @@
 
 use crate::config::SessionConfig;
 use crate::craftax;
-use crate::entity::{Cow, GameObject, Player, Skeleton, Zombie};
+use crate::entity::{Cow, GameObject, Pig, Player, Sheep, Skeleton, Zombie};
 use crate::material::Material;
-use crate::world::World;
+use crate::world::{NamedRegion, RegionKind, World};
 use noise::{NoiseFn, OpenSimplex};
 use rand::prelude::*;
 use rand_chacha::ChaCha8Rng;
@@ -66,6 +66,9 @@ impl WorldGenerator {
         self.spawn_objects(&mut world, player_pos, &tunnels);
         craftax::worldgen::apply(&mut world, &mut self.rng, &self.config, player_pos, &tunnels);
 
+        // Label the finished terrain so places have names
+        world.regions = label_regions(&world);
+
         world
     }
 
@@ -235,6 +238,26 @@ impl WorldGenerator {
                     world.add_object(GameObject::Cow(cow));
                 }
 
+                // Sheep on grass if dist > 3 (~1% chance)
+                let sheep_threshold = scaled_threshold(0.01, self.config.wildlife.sheep_density);
+                if mat == Material::Grass
+                    && dist > 3.0
+                    && self.rng.gen::<f64>() > sheep_threshold
+                {
+                    let sheep = Sheep::with_health(pos, self.config.wildlife.sheep_health);
+                    world.add_object(GameObject::Sheep(sheep));
+                }
+
+                // Pig on grass or sand if dist > 3 (~0.8% chance)
+                let pig_threshold = scaled_threshold(0.008, self.config.wildlife.pig_density);
+                if (mat == Material::Grass || mat == Material::Sand)
+                    && dist > 3.0
+                    && self.rng.gen::<f64>() > pig_threshold
+                {
+                    let pig = Pig::with_health(pos, self.config.wildlife.pig_health);
+                    world.add_object(GameObject::Pig(pig));
+                }
+
                 // Zombie if dist > 10 and random > 0.993 (~0.7% chance)
                 let zombie_threshold = scaled_threshold(0.007, self.config.zombie_density);
                 if dist > 10.0 && self.rng.gen::<f64>() > zombie_threshold {
@@ -262,6 +285,196 @@ fn scaled_threshold(base_probability: f64, multiplier: f32) -> f64 {
     1.0 - probability
 }
 
+/// Caches pristine generated worlds so repeated resets with the same
+/// seed — the common RL setup — clone a finished map instead of
+/// re-running the noise and spawn passes. Entries are keyed by episode
+/// seed plus the resolved ruleset hash, so a curriculum changing the
+/// rules mid-session never serves a stale map.
+#[derive(Clone, Debug, Default)]
+pub struct WorldCache {
+    entries: std::collections::HashMap<(u64, u64), World>,
+    hits: u64,
+    misses: u64,
+}
+
+impl WorldCache {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The pristine world for `seed` under `config`'s rules, generated
+    /// on the first request and cloned thereafter
+    pub fn get_or_generate(&mut self, config: &SessionConfig, seed: u64) -> World {
+        let key = (seed, config.resolved().hash);
+        if let Some(world) = self.entries.get(&key) {
+            self.hits += 1;
+            return world.clone();
+        }
+        self.misses += 1;
+        let mut gen_config = config.clone();
+        gen_config.seed = Some(seed);
+        let world = WorldGenerator::new(gen_config).generate();
+        self.entries.insert(key, world.clone());
+        world
+    }
+
+    /// Cached worlds
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Requests served by cloning a cached world
+    pub fn hits(&self) -> u64 {
+        self.hits
+    }
+
+    /// Requests that ran full generation
+    pub fn misses(&self) -> u64 {
+        self.misses
+    }
+
+    /// Drop all cached worlds; the hit/miss tally survives
+    pub fn clear(&mut self) {
+        self.entries.clear();
+    }
+}
+
+/// A pristine generated world held behind an `Arc` for template resets:
+/// many sessions (or many episodes of one session) can share a single
+/// generation and clone only at reset time; see
+/// [`Session::reset_from_template`](crate::session::Session::reset_from_template).
+#[derive(Clone, Debug)]
+pub struct WorldTemplate {
+    seed: u64,
+    world: std::sync::Arc<World>,
+}
+
+impl WorldTemplate {
+    /// Generate the template world for `config`, resolving a random
+    /// seed the same way a session would
+    pub fn generate(config: &SessionConfig) -> Self {
+        let seed = config.seed.unwrap_or_else(|| rand::thread_rng().gen());
+        let mut gen_config = config.clone();
+        gen_config.seed = Some(seed);
+        Self::from_world(WorldGenerator::new(gen_config).generate())
+    }
+
+    /// Wrap an already-generated pristine world
+    pub fn from_world(world: World) -> Self {
+        Self {
+            seed: world.rng_seed,
+            world: std::sync::Arc::new(world),
+        }
+    }
+
+    /// The seed the template world was generated from
+    pub fn seed(&self) -> u64 {
+        self.seed
+    }
+
+    /// The shared pristine world
+    pub fn world(&self) -> &World {
+        &self.world
+    }
+}
+
+// Folds "pool" (as ASCII) into the base seed, like the substream salts
+// in `rng`, so pool seeds never collide with the session's own streams
+const POOL_SALT: u64 = 0x706f_6f6c;
+
+/// The episode seeds for a world pool of `size` worlds derived from one
+/// base seed. The first entry is the base seed itself, so a pooled
+/// session's first episode matches an unpooled one; the rest come from
+/// a dedicated ChaCha8 stream, deterministic per base seed.
+pub fn pool_seeds(base_seed: u64, size: u32) -> Vec<u64> {
+    let mut rng = ChaCha8Rng::seed_from_u64(base_seed ^ POOL_SALT);
+    let mut seeds = vec![base_seed];
+    while seeds.len() < size.max(1) as usize {
+        seeds.push(rng.gen());
+    }
+    seeds
+}
+
+/// Smaller connected patches than this are noise, not places
+const MIN_REGION_TILES: u32 = 5;
+
+/// Which region category a terrain material belongs to, if any
+fn region_kind(material: Material) -> Option<RegionKind> {
+    match material {
+        Material::Water => Some(RegionKind::Lake),
+        Material::Tree => Some(RegionKind::Forest),
+        Material::Sand => Some(RegionKind::Beach),
+        Material::Path => Some(RegionKind::Cave),
+        Material::Lava => Some(RegionKind::LavaField),
+        _ => None,
+    }
+}
+
+/// Find and name contiguous terrain regions (4-connected flood fill).
+/// Scan order makes numbering deterministic for a given seed.
+fn label_regions(world: &World) -> Vec<NamedRegion> {
+    let (width, height) = world.area;
+    let mut visited = vec![false; (width * height) as usize];
+    let mut regions = Vec::new();
+    let mut counts: std::collections::HashMap<RegionKind, u32> = std::collections::HashMap::new();
+
+    for start_y in 0..height as i32 {
+        for start_x in 0..width as i32 {
+            let start_idx = (start_y as u32 * width + start_x as u32) as usize;
+            if visited[start_idx] {
+                continue;
+            }
+            let Some(kind) = world.get_material((start_x, start_y)).and_then(region_kind) else {
+                continue;
+            };
+
+            // Flood fill this component
+            let mut queue = vec![(start_x, start_y)];
+            visited[start_idx] = true;
+            let (mut min_x, mut min_y, mut max_x, mut max_y) = (start_x, start_y, start_x, start_y);
+            let mut tiles = 0u32;
+            while let Some((x, y)) = queue.pop() {
+                tiles += 1;
+                min_x = min_x.min(x);
+                min_y = min_y.min(y);
+                max_x = max_x.max(x);
+                max_y = max_y.max(y);
+                for (dx, dy) in [(-1, 0), (1, 0), (0, -1), (0, 1)] {
+                    let next = (x + dx, y + dy);
+                    if !world.in_bounds(next) {
+                        continue;
+                    }
+                    let idx = (next.1 as u32 * width + next.0 as u32) as usize;
+                    if visited[idx] {
+                        continue;
+                    }
+                    if world.get_material(next).and_then(region_kind) == Some(kind) {
+                        visited[idx] = true;
+                        queue.push(next);
+                    }
+                }
+            }
+
+            if tiles < MIN_REGION_TILES {
+                continue;
+            }
+            let number = counts.entry(kind).or_insert(0);
+            *number += 1;
+            regions.push(NamedRegion {
+                name: format!("{}_{}", kind.label(), number),
+                kind,
+                bounds: (min_x, min_y, max_x, max_y),
+                tiles,
+            });
+        }
+    }
+    regions
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -285,6 +498,42 @@ mod tests {
         assert_eq!(player.pos, (32, 32));
     }
 
+    #[test]
+    fn test_regions_are_labeled_and_consistent() {
+        let config = SessionConfig {
+            world_size: (64, 64),
+            seed: Some(42),
+            ..Default::default()
+        };
+
+        let mut gen = WorldGenerator::new(config);
+        let world = gen.generate();
+
+        assert!(!world.regions.is_empty(), "a 64x64 world should have named places");
+
+        let mut seen = std::collections::HashSet::new();
+        for region in &world.regions {
+            // Names are unique and match their kind
+            assert!(seen.insert(region.name.clone()));
+            assert!(region.name.starts_with(region.kind.label()));
+            assert!(region.tiles >= MIN_REGION_TILES);
+
+            // Every region's bounding box holds at least one tile of
+            // the right material
+            let (min_x, min_y, max_x, max_y) = region.bounds;
+            let has_material = (min_y..=max_y).any(|y| {
+                (min_x..=max_x).any(|x| {
+                    world.get_material((x, y)).and_then(region_kind) == Some(region.kind)
+                })
+            });
+            assert!(has_material);
+
+            // Lookups agree
+            assert_eq!(world.find_region(&region.name), Some(region));
+            assert!(region.contains(region.center()));
+        }
+    }
+
     #[test]
     fn test_deterministic_generation() {
         let config = SessionConfig {
@@ -415,7 +664,7 @@ mod tests {
         for y in 0..64 {
             let mut row = String::new();
             for x in 0..64 {
-                let pos = (x as i32, y as i32);
+                let pos = (x, y);
 
                 // Check for player
                 if let Some(player) = world.get_player() {
@@ -457,6 +706,10 @@ mod tests {
                     Material::Path => '_',
                     Material::Table => '+',
                     Material::Furnace => 'F',
+                    Material::SpikeTrap => '^',
+                    Material::DoorClosed => 'D',
+                    Material::DoorOpen => '/',
+                    Material::Fence => '=',
                 };
                 row.push(ch);
             }
@@ -480,4 +733,90 @@ mod tests {
         }
         } // end for seed
     }
+
+    #[test]
+    fn test_world_cache_serves_identical_worlds() {
+        let config = SessionConfig {
+            world_size: (32, 32),
+            seed: Some(42),
+            ..Default::default()
+        };
+
+        let mut cache = WorldCache::new();
+        let first = cache.get_or_generate(&config, 42);
+        let second = cache.get_or_generate(&config, 42);
+        assert_eq!(first.materials, second.materials);
+        assert_eq!(cache.misses(), 1);
+        assert_eq!(cache.hits(), 1);
+        assert_eq!(cache.len(), 1);
+
+        // A different seed or ruleset is a fresh generation
+        cache.get_or_generate(&config, 43);
+        let other_rules = SessionConfig {
+            tree_density: config.tree_density * 2.0,
+            ..config
+        };
+        cache.get_or_generate(&other_rules, 42);
+        assert_eq!(cache.misses(), 3);
+        assert_eq!(cache.len(), 3);
+    }
+
+    #[test]
+    fn test_pool_seeds_are_deterministic() {
+        let seeds = pool_seeds(42, 4);
+        assert_eq!(seeds.len(), 4);
+        // The base seed leads, so a pooled first episode matches an
+        // unpooled one
+        assert_eq!(seeds[0], 42);
+        assert_eq!(seeds, pool_seeds(42, 4));
+        assert_ne!(seeds[1..], pool_seeds(43, 4)[1..]);
+    }
+
+    #[test]
+    fn test_world_stats_census() {
+        use crate::entity::{Cow, GameObject, Player};
+        use crate::material::Material;
+
+        let mut world = World::new(12, 12, 0); // all grass
+        let player_id = world.add_object(GameObject::Player(Player::new((2, 2))));
+        world.player_id = player_id;
+        world.add_object(GameObject::Cow(Cow::new((3, 3))));
+
+        // One lone water tile plus a two-tile lake, and one tree
+        world.set_material((0, 0), Material::Water);
+        world.set_material((5, 5), Material::Water);
+        world.set_material((5, 6), Material::Water);
+        world.set_material((8, 8), Material::Tree);
+
+        let stats = world.stats();
+        assert_eq!(stats.material_count(Material::Water), 3);
+        assert_eq!(stats.material_count(Material::Tree), 1);
+        assert_eq!(stats.material_count(Material::Grass), 12 * 12 - 4);
+        assert_eq!(stats.water_components, 2);
+        assert_eq!(stats.land_components, 1);
+        assert_eq!(stats.mob_count("cow"), 1);
+        assert_eq!(stats.mob_count("player"), 0, "player is not a mob");
+        // Everything borders walkable grass, so it is all reachable
+        assert_eq!(stats.reachable_count(Material::Water), 3);
+        assert_eq!(stats.reachable_count(Material::Tree), 1);
+    }
+
+    #[test]
+    fn test_generated_world_stats_are_consistent() {
+        let config = SessionConfig {
+            world_size: (64, 64),
+            seed: Some(42),
+            ..Default::default()
+        };
+        let session = crate::session::Session::new(config);
+        let stats = session.world.stats();
+
+        let total: u32 = stats.materials.values().sum();
+        assert_eq!(total, 64 * 64);
+        assert!(stats.land_components >= 1);
+        assert!(
+            stats.reachable_count(crate::material::Material::Tree)
+                <= stats.material_count(crate::material::Material::Tree)
+        );
+    }
 }
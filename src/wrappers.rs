@@ -0,0 +1,466 @@
+//! Composable observation wrappers over [`Env`]
+//!
+//! The standard DQN/PPO preprocessing stack — grayscale, resize, frame
+//! stacking — as wrappers that are themselves [`Env`]s, so they compose
+//! in any order:
+//!
+//! ```
+//! use crafter_core::env::{CrafterEnv, Env};
+//! use crafter_core::wrappers::{FrameStack, GrayScale, ResizeObs};
+//! use crafter_core::config::SessionConfig;
+//!
+//! let mut env = FrameStack::new(
+//!     ResizeObs::new(GrayScale::new(CrafterEnv::new(SessionConfig::default())), (5, 5)),
+//!     4,
+//! );
+//! let obs = env.reset();
+//! assert_eq!(obs.shape, (4, 5, 5));
+//! ```
+
+use crate::action::Action;
+use crate::env::{Env, Info, Observation};
+use serde::{Deserialize, Serialize};
+
+/// Stacks the last K observations along the channel axis, producing
+/// `[K * C, H, W]`. After a reset the first observation fills all K
+/// slots, the conventional fill that keeps the shape fixed.
+pub struct FrameStack<E> {
+    inner: E,
+    depth: usize,
+    frames: std::collections::VecDeque<Observation>,
+}
+
+impl<E: Env> FrameStack<E> {
+    pub fn new(inner: E, depth: usize) -> Self {
+        Self {
+            inner,
+            depth: depth.max(1),
+            frames: std::collections::VecDeque::new(),
+        }
+    }
+
+    pub fn inner(&self) -> &E {
+        &self.inner
+    }
+
+    fn push(&mut self, frame: Observation) -> Observation {
+        // A shape change means a reconfigured inner env: start over
+        if self.frames.front().is_some_and(|f| f.shape != frame.shape) {
+            self.frames.clear();
+        }
+        if self.frames.is_empty() {
+            for _ in 0..self.depth {
+                self.frames.push_back(frame.clone());
+            }
+        } else {
+            self.frames.push_back(frame);
+            while self.frames.len() > self.depth {
+                self.frames.pop_front();
+            }
+        }
+
+        let (c, h, w) = self.frames[0].shape;
+        let mut data = Vec::with_capacity(self.depth * c * h * w);
+        for frame in &self.frames {
+            data.extend_from_slice(&frame.data);
+        }
+        Observation {
+            data,
+            shape: (self.depth * c, h, w),
+        }
+    }
+}
+
+impl<E: Env> Env for FrameStack<E> {
+    fn reset(&mut self) -> Observation {
+        self.frames.clear();
+        let first = self.inner.reset();
+        self.push(first)
+    }
+
+    fn step(&mut self, action: Action) -> (Observation, f32, bool, bool, Info) {
+        let (obs, reward, terminated, truncated, info) = self.inner.step(action);
+        (self.push(obs), reward, terminated, truncated, info)
+    }
+}
+
+/// Collapses all channels into one by averaging, producing `[1, H, W]`.
+/// For tile-id observations this is lossy the way RGB grayscale is —
+/// use it where the preprocessing stack expects single-channel input.
+pub struct GrayScale<E> {
+    inner: E,
+}
+
+impl<E: Env> GrayScale<E> {
+    pub fn new(inner: E) -> Self {
+        Self { inner }
+    }
+
+    pub fn inner(&self) -> &E {
+        &self.inner
+    }
+
+    fn transform(obs: Observation) -> Observation {
+        let (c, h, w) = obs.shape;
+        let plane = h * w;
+        let mut data = vec![0u8; plane];
+        for (idx, out) in data.iter_mut().enumerate() {
+            let sum: u32 = (0..c).map(|ch| obs.data[ch * plane + idx] as u32).sum();
+            *out = (sum / c.max(1) as u32) as u8;
+        }
+        Observation {
+            data,
+            shape: (1, h, w),
+        }
+    }
+}
+
+impl<E: Env> Env for GrayScale<E> {
+    fn reset(&mut self) -> Observation {
+        Self::transform(self.inner.reset())
+    }
+
+    fn step(&mut self, action: Action) -> (Observation, f32, bool, bool, Info) {
+        let (obs, reward, terminated, truncated, info) = self.inner.step(action);
+        (Self::transform(obs), reward, terminated, truncated, info)
+    }
+}
+
+/// Resizes each channel plane to a fixed `(height, width)` with
+/// nearest-neighbor sampling. Tile ids are categorical, so nearest
+/// neighbor is the only interpolation that doesn't invent materials.
+pub struct ResizeObs<E> {
+    inner: E,
+    size: (usize, usize),
+}
+
+impl<E: Env> ResizeObs<E> {
+    pub fn new(inner: E, size: (usize, usize)) -> Self {
+        Self {
+            inner,
+            size: (size.0.max(1), size.1.max(1)),
+        }
+    }
+
+    pub fn inner(&self) -> &E {
+        &self.inner
+    }
+
+    fn transform(&self, obs: Observation) -> Observation {
+        let (c, h, w) = obs.shape;
+        let (out_h, out_w) = self.size;
+        let mut data = vec![0u8; c * out_h * out_w];
+        for ch in 0..c {
+            for y in 0..out_h {
+                let src_y = y * h / out_h;
+                for x in 0..out_w {
+                    let src_x = x * w / out_w;
+                    data[ch * out_h * out_w + y * out_w + x] =
+                        obs.data[ch * h * w + src_y * w + src_x];
+                }
+            }
+        }
+        Observation {
+            data,
+            shape: (c, out_h, out_w),
+        }
+    }
+}
+
+impl<E: Env> Env for ResizeObs<E> {
+    fn reset(&mut self) -> Observation {
+        let obs = self.inner.reset();
+        self.transform(obs)
+    }
+
+    fn step(&mut self, action: Action) -> (Observation, f32, bool, bool, Info) {
+        let (obs, reward, terminated, truncated, info) = self.inner.step(action);
+        (self.transform(obs), reward, terminated, truncated, info)
+    }
+}
+
+/// Clamps each step's reward into `[min, max]`, the standard DQN
+/// stabilization against reward-scale blowups.
+pub struct ClipReward<E> {
+    inner: E,
+    min: f32,
+    max: f32,
+}
+
+impl<E: Env> ClipReward<E> {
+    pub fn new(inner: E, min: f32, max: f32) -> Self {
+        Self {
+            inner,
+            min: min.min(max),
+            max: max.max(min),
+        }
+    }
+
+    pub fn inner(&self) -> &E {
+        &self.inner
+    }
+}
+
+impl<E: Env> Env for ClipReward<E> {
+    fn reset(&mut self) -> Observation {
+        self.inner.reset()
+    }
+
+    fn step(&mut self, action: Action) -> (Observation, f32, bool, bool, Info) {
+        let (obs, reward, terminated, truncated, info) = self.inner.step(action);
+        (obs, reward.clamp(self.min, self.max), terminated, truncated, info)
+    }
+}
+
+/// Rescales observation bytes to the full 0–255 range using a running
+/// per-channel maximum, so models see one consistent scale whether a
+/// channel holds tile ids (0–19) or mining progress. Observations stay
+/// `u8`; conversion to floats remains model business.
+pub struct NormalizeObservation<E> {
+    inner: E,
+    channel_max: Vec<u8>,
+}
+
+impl<E: Env> NormalizeObservation<E> {
+    pub fn new(inner: E) -> Self {
+        Self {
+            inner,
+            channel_max: Vec::new(),
+        }
+    }
+
+    pub fn inner(&self) -> &E {
+        &self.inner
+    }
+
+    fn transform(&mut self, mut obs: Observation) -> Observation {
+        let (c, h, w) = obs.shape;
+        let plane = h * w;
+        self.channel_max.resize(c, 1);
+        for ch in 0..c {
+            let values = &mut obs.data[ch * plane..(ch + 1) * plane];
+            let seen = values.iter().copied().max().unwrap_or(0);
+            self.channel_max[ch] = self.channel_max[ch].max(seen).max(1);
+            let max = self.channel_max[ch] as u16;
+            for v in values {
+                *v = (*v as u16 * 255 / max) as u8;
+            }
+        }
+        obs
+    }
+}
+
+impl<E: Env> Env for NormalizeObservation<E> {
+    fn reset(&mut self) -> Observation {
+        let obs = self.inner.reset();
+        self.transform(obs)
+    }
+
+    fn step(&mut self, action: Action) -> (Observation, f32, bool, bool, Info) {
+        let (obs, reward, terminated, truncated, info) = self.inner.step(action);
+        (self.transform(obs), reward, terminated, truncated, info)
+    }
+}
+
+/// Subtracts a constant penalty from every step's reward, pressuring
+/// policies toward shorter solutions without touching the session's
+/// own reward model.
+pub struct TimePenalty<E> {
+    inner: E,
+    penalty: f32,
+}
+
+impl<E: Env> TimePenalty<E> {
+    pub fn new(inner: E, penalty: f32) -> Self {
+        Self { inner, penalty }
+    }
+
+    pub fn inner(&self) -> &E {
+        &self.inner
+    }
+}
+
+impl<E: Env> Env for TimePenalty<E> {
+    fn reset(&mut self) -> Observation {
+        self.inner.reset()
+    }
+
+    fn step(&mut self, action: Action) -> (Observation, f32, bool, bool, Info) {
+        let (obs, reward, terminated, truncated, info) = self.inner.step(action);
+        (obs, reward - self.penalty, terminated, truncated, info)
+    }
+}
+
+/// With probability `prob`, replaces the agent's action with a uniform
+/// random one before stepping — the standard robustness perturbation.
+/// Draws come from a dedicated seeded RNG, so noisy runs reproduce
+/// exactly under the same seed.
+pub struct ActionNoise<E> {
+    inner: E,
+    prob: f32,
+    rng: crate::rng::SessionRng,
+}
+
+impl<E: Env> ActionNoise<E> {
+    pub fn new(inner: E, prob: f32, seed: u64) -> Self {
+        Self {
+            inner,
+            prob,
+            rng: crate::rng::SessionRng::from_seed_kind(crate::rng::RngKind::default(), seed),
+        }
+    }
+
+    pub fn inner(&self) -> &E {
+        &self.inner
+    }
+}
+
+impl<E: Env> Env for ActionNoise<E> {
+    fn reset(&mut self) -> Observation {
+        self.inner.reset()
+    }
+
+    fn step(&mut self, action: Action) -> (Observation, f32, bool, bool, Info) {
+        use rand::Rng;
+        let action = if self.prob > 0.0 && self.rng.gen::<f32>() < self.prob {
+            Action::ALL[self.rng.gen_range(0..Action::ALL.len())]
+        } else {
+            action
+        };
+        self.inner.step(action)
+    }
+}
+
+/// Declarative description of one wrapper, so a whole preprocessing
+/// stack can live in an experiment config and be rebuilt exactly
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case", tag = "wrapper")]
+pub enum WrapperConfig {
+    FrameStack { depth: usize },
+    GrayScale,
+    ResizeObs { height: usize, width: usize },
+    ClipReward { min: f32, max: f32 },
+    NormalizeObservation,
+    TimePenalty { penalty: f32 },
+    ActionNoise { prob: f32, seed: u64 },
+}
+
+/// Build a wrapper stack around `env` from its serialized description,
+/// innermost first: `[GrayScale, FrameStack]` produces
+/// `FrameStack<GrayScale<E>>`.
+pub fn wrap(env: impl Env + 'static, stack: &[WrapperConfig]) -> Box<dyn Env> {
+    let mut env: Box<dyn Env> = Box::new(env);
+    for config in stack {
+        env = match *config {
+            WrapperConfig::FrameStack { depth } => Box::new(FrameStack::new(env, depth)),
+            WrapperConfig::GrayScale => Box::new(GrayScale::new(env)),
+            WrapperConfig::ResizeObs { height, width } => {
+                Box::new(ResizeObs::new(env, (height, width)))
+            }
+            WrapperConfig::ClipReward { min, max } => Box::new(ClipReward::new(env, min, max)),
+            WrapperConfig::NormalizeObservation => Box::new(NormalizeObservation::new(env)),
+            WrapperConfig::TimePenalty { penalty } => Box::new(TimePenalty::new(env, penalty)),
+            WrapperConfig::ActionNoise { prob, seed } => {
+                Box::new(ActionNoise::new(env, prob, seed))
+            }
+        };
+    }
+    env
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::SessionConfig;
+    use crate::env::CrafterEnv;
+    use crate::obs::NUM_CHANNELS;
+
+    fn env() -> CrafterEnv {
+        CrafterEnv::new(SessionConfig {
+            world_size: (32, 32),
+            seed: Some(42),
+            view_radius: 3,
+            ..Default::default()
+        })
+    }
+
+    #[test]
+    fn test_wrappers_compose_into_preprocessing_stack() {
+        let mut env = FrameStack::new(ResizeObs::new(GrayScale::new(env()), (5, 5)), 4);
+
+        let obs = env.reset();
+        assert_eq!(obs.shape, (4, 5, 5));
+        assert_eq!(obs.data.len(), 4 * 5 * 5);
+        // First frame fills all stack slots
+        assert_eq!(obs.data[..25], obs.data[75..]);
+
+        let (obs, _, terminated, truncated, info) = env.step(Action::MoveRight);
+        assert_eq!(obs.shape, (4, 5, 5));
+        assert!(!terminated);
+        assert!(!truncated);
+        assert_eq!(info.step, 1);
+    }
+
+    #[test]
+    fn test_grayscale_and_resize_shapes() {
+        let mut gray = GrayScale::new(env());
+        let obs = gray.reset();
+        assert_eq!(obs.shape, (1, 7, 7));
+
+        let mut resized = ResizeObs::new(env(), (3, 9));
+        let obs = resized.reset();
+        assert_eq!(obs.shape, (NUM_CHANNELS, 3, 9));
+        assert_eq!(obs.data.len(), NUM_CHANNELS * 3 * 9);
+    }
+
+    #[test]
+    fn test_reward_and_noise_wrappers() {
+        // Time penalty pushes a zero-reward step negative, and the clip
+        // wrapper floors it back up
+        let mut clipped = ClipReward::new(TimePenalty::new(env(), 0.5), -0.1, 1.0);
+        clipped.reset();
+        let (_, reward, _, _, _) = clipped.step(Action::Noop);
+        assert!((reward - -0.1).abs() < 1e-6);
+
+        // Noise at probability 1 with the same seed substitutes the same
+        // action sequence, so two runs stay in lockstep
+        let mut a = ActionNoise::new(env(), 1.0, 7);
+        let mut b = ActionNoise::new(env(), 1.0, 7);
+        a.reset();
+        b.reset();
+        for _ in 0..8 {
+            let (obs_a, ..) = a.step(Action::Noop);
+            let (obs_b, ..) = b.step(Action::Noop);
+            assert_eq!(obs_a.data, obs_b.data);
+        }
+
+        // Normalization leaves the shape alone and tops out at 255
+        let mut norm = NormalizeObservation::new(env());
+        let obs = norm.reset();
+        assert_eq!(obs.shape, (NUM_CHANNELS, 7, 7));
+        assert_eq!(obs.data.iter().copied().max(), Some(255));
+    }
+
+    #[test]
+    fn test_wrapper_stack_builds_from_config() {
+        let json = r#"[
+            {"wrapper": "gray_scale"},
+            {"wrapper": "resize_obs", "height": 5, "width": 5},
+            {"wrapper": "frame_stack", "depth": 4},
+            {"wrapper": "clip_reward", "min": -1.0, "max": 1.0}
+        ]"#;
+        let stack: Vec<WrapperConfig> = serde_json::from_str(json).unwrap();
+        let mut env = wrap(env(), &stack);
+
+        let obs = env.reset();
+        assert_eq!(obs.shape, (4, 5, 5));
+        let (obs, reward, ..) = env.step(Action::MoveRight);
+        assert_eq!(obs.shape, (4, 5, 5));
+        assert!((-1.0..=1.0).contains(&reward));
+
+        // The stack round-trips through serialization unchanged
+        let back: Vec<WrapperConfig> =
+            serde_json::from_str(&serde_json::to_string(&stack).unwrap()).unwrap();
+        assert_eq!(back.len(), stack.len());
+    }
+}